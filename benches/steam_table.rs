@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use scientists_toolbox::thermo::steam::iapws97::{get_steam_table_entries, get_steam_table_entry};
+use scientists_toolbox::thermo::steam::*;
+use scientists_toolbox::thermo::PtvEntry;
+use scientists_toolbox::units::*;
+
+// Each temperature is repeated at every pressure, which the batch evaluates once
+fn get_pt_queries() -> Vec<SteamQuery> {
+    let mut queries = Vec::new();
+    for pressure in [0.01e6, 0.1e6, 1e6, 10e6, 25e6, 50e6] {
+        for temperature in (0..50).map(|x| 280.0 + 15.0 * x as f64) {
+            queries.push(SteamQuery::Pt(PtPoint {
+                pressure: Pressure::Pa(Pa::new(pressure)),
+                temperature: Temperature::K(K::new(temperature)),
+            }));
+        }
+    }
+    queries
+}
+
+// Backward queries are solved one at a time, so only the pressure-temperature grid shares work
+fn get_enthalpy_queries() -> Vec<SteamQuery> {
+    let mut queries = Vec::new();
+    for pressure in [0.1e6, 1e6, 10e6] {
+        for enthalpy in (0..20).map(|x| 200e3 + 150e3 * x as f64) {
+            queries.push(SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+                pressure: Pressure::Pa(Pa::new(pressure)),
+            });
+        }
+    }
+    queries
+}
+
+fn bench_queries(c: &mut Criterion, group_name: &str, queries: &[SteamQuery]) {
+    let mut group = c.benchmark_group(group_name);
+    group.bench_with_input(
+        BenchmarkId::new("scalar", queries.len()),
+        queries,
+        |b, x| {
+            b.iter(|| {
+                x.iter()
+                    .map(|query| get_steam_table_entry(black_box(*query)))
+                    .collect::<Vec<Result<PtvEntry, SteamQueryErr>>>()
+            })
+        },
+    );
+    group.bench_with_input(BenchmarkId::new("batch", queries.len()), queries, |b, x| {
+        b.iter(|| get_steam_table_entries(black_box(x)))
+    });
+    group.finish();
+}
+
+fn steam_table_benchmark(c: &mut Criterion) {
+    bench_queries(c, "pt_queries", &get_pt_queries());
+    bench_queries(c, "enthalpy_queries", &get_enthalpy_queries());
+}
+
+criterion_group!(benches, steam_table_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//! Prints the deviation of IAPWS-IF97 from IAPWS-95 over a temperature and pressure grid
+//!
+//! cargo run --example compare_steam_backends
+use scientists_toolbox::thermo::steam::comparison::{compare_backends, summarize_deviations};
+use scientists_toolbox::thermo::steam::iapws95::Iapws95;
+use scientists_toolbox::thermo::steam::iapws97::Iapws97;
+use scientists_toolbox::units::*;
+
+fn main() {
+    let temperatures = (0..17)
+        .map(|i| Temperature::K(K::new(275.0 + 50.0 * i as f64)))
+        .collect::<Vec<Temperature>>();
+    let pressures = [0.01e6, 0.1e6, 1e6, 10e6, 25e6, 50e6, 100e6]
+        .iter()
+        .map(|p| Pressure::Pa(Pa::new(*p)))
+        .collect::<Vec<Pressure>>();
+    let comparisons = compare_backends(&Iapws95, &Iapws97, &temperatures, &pressures);
+
+    println!(
+        "{:>10} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "T (K)", "p (MPa)", "dv/v (%)", "dh (kJ/kg)", "ds (J/kg/K)", "dcp/cp (%)", "dw/w (%)"
+    );
+    for (point, deviation) in comparisons.iter() {
+        let t = point.temperature.convert_to_si_unit().value;
+        let p = point.pressure.convert_to_si_unit().value / 1e6;
+        match deviation {
+            Ok(x) => println!(
+                "{:>10.2} {:>10.3} {:>12.5} {:>12.4} {:>12.4} {:>12.4} {:>12.4}",
+                t,
+                p,
+                x.specific_volume * 100.0,
+                x.enthalpy.convert_to_si_unit().value / 1e3,
+                x.entropy.convert_to_si_unit().value,
+                x.cp * 100.0,
+                x.speed_of_sound * 100.0,
+            ),
+            Err(err) => println!("{:>10.2} {:>10.3} {:?}", t, p, err),
+        }
+    }
+
+    let summary = summarize_deviations(&comparisons);
+    println!();
+    println!(
+        "compared {} points ({} failed)",
+        summary.points_compared, summary.points_failed
+    );
+    println!("max |dv/v|   = {:.5} %", summary.specific_volume * 100.0);
+    println!(
+        "max |dh|     = {:.4} kJ/kg",
+        summary.enthalpy.convert_to_si_unit().value / 1e3
+    );
+    println!(
+        "max |ds|     = {:.4} J/(kg K)",
+        summary.entropy.convert_to_si_unit().value
+    );
+    println!("max |dcp/cp| = {:.4} %", summary.cp * 100.0);
+    println!("max |dw/w|   = {:.4} %", summary.speed_of_sound * 100.0);
+}
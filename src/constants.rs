@@ -0,0 +1,2 @@
+// m/s²
+pub static STANDARD_GRAVITY: f64 = 9.80665;
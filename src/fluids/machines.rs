@@ -0,0 +1,392 @@
+use crate::constants::STANDARD_GRAVITY;
+use crate::thermo::ideal_gas::UNIVERSAL_GAS_CONSTANT;
+use crate::units::*;
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Pump {
+    pub flow_rate: VolumetricFlowRate,
+    pub head: Head,
+    pub density: Density,
+    // Between 0 and 1, hydraulic power over shaft power
+    pub efficiency: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct PumpPower {
+    // Power given to the liquid
+    pub hydraulic_power: Power,
+    // Power the driver delivers to the shaft
+    pub shaft_power: Power,
+    pub pressure_rise: Pressure,
+}
+
+/// Which efficiency of a compressor is known
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum CompressorEfficiency {
+    Isentropic(f64),
+    Polytropic(f64),
+}
+
+/// Ideal gas compression with a constant isentropic exponent and compressibility factor
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Compressor {
+    // Measured at the inlet conditions
+    pub flow_rate: VolumetricFlowRate,
+    pub inlet_pressure: Pressure,
+    pub inlet_temperature: Temperature,
+    pub discharge_pressure: Pressure,
+    // kg/mol
+    pub molar_mass: f64,
+    // cp / cv
+    pub isentropic_exponent: f64,
+    pub compressibility_factor: f64,
+    // Applied to each stage
+    pub efficiency: CompressorEfficiency,
+    // Stages share the pressure ratio equally with intercooling back to the inlet temperature
+    pub stages: u32,
+}
+
+/// Heads and power are totals over every stage
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct CompressorPower {
+    pub stage_pressure_ratio: f64,
+    // Leaving each stage
+    pub discharge_temperature: Temperature,
+    pub isentropic_head: Head,
+    pub polytropic_head: Head,
+    pub isentropic_efficiency: f64,
+    pub polytropic_efficiency: f64,
+    // n of P * v^n, the polytropic path through each stage
+    pub polytropic_exponent: f64,
+    pub mass_flow_rate: MassFlowRate,
+    // Power given to the gas
+    pub gas_power: Power,
+    // Heat removed by the intercoolers between stages
+    pub intercooler_duty: Power,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MachineErr {
+    EfficiencyLow,
+    EfficiencyHigh,
+    // The discharge pressure must be above the inlet pressure
+    PressureOutOfOrder,
+    // The isentropic exponent must be above 1
+    IsentropicExponentLow,
+    NoStages,
+}
+
+fn check_efficiency(efficiency: f64) -> Result<(), MachineErr> {
+    if efficiency.is_nan() || efficiency <= 0.0 {
+        Err(MachineErr::EfficiencyLow)
+    } else if efficiency > 1.0 {
+        Err(MachineErr::EfficiencyHigh)
+    } else {
+        Ok(())
+    }
+}
+
+pub fn get_pump_power(pump: &Pump) -> Result<PumpPower, MachineErr> {
+    check_efficiency(pump.efficiency)?;
+    let flow_rate = pump.flow_rate.convert_to_si_unit().value;
+    let head = pump.head.convert_to_si_unit().value;
+    let density = pump.density.convert_to_si_unit().value;
+    let pressure_rise = density * STANDARD_GRAVITY * head;
+    let hydraulic_power = pressure_rise * flow_rate;
+    Ok(PumpPower {
+        hydraulic_power: Power::W(W::new(hydraulic_power)),
+        shaft_power: Power::W(W::new(hydraulic_power / pump.efficiency)),
+        pressure_rise: Pressure::Pa(Pa::new(pressure_rise)),
+    })
+}
+
+/// Compressor heads follow from the inlet state and the pressure ratio of each stage,
+/// given one efficiency the other is found from the discharge temperature
+pub fn get_compressor_power(compressor: &Compressor) -> Result<CompressorPower, MachineErr> {
+    let efficiency = match compressor.efficiency {
+        CompressorEfficiency::Isentropic(x) | CompressorEfficiency::Polytropic(x) => x,
+    };
+    check_efficiency(efficiency)?;
+    if compressor.stages == 0 {
+        return Err(MachineErr::NoStages);
+    }
+    let k = compressor.isentropic_exponent;
+    if k.is_nan() || k <= 1.0 {
+        return Err(MachineErr::IsentropicExponentLow);
+    }
+    let inlet_pressure = compressor.inlet_pressure.convert_to_si_unit().value;
+    let discharge_pressure = compressor.discharge_pressure.convert_to_si_unit().value;
+    if inlet_pressure.is_nan()
+        || discharge_pressure.is_nan()
+        || discharge_pressure <= inlet_pressure
+    {
+        return Err(MachineErr::PressureOutOfOrder);
+    }
+
+    let stages = compressor.stages as f64;
+    let ratio = f64::powf(discharge_pressure / inlet_pressure, 1f64 / stages);
+    let inlet_temperature = compressor.inlet_temperature.convert_to_si_unit().value;
+    // Z * R * T at the inlet of each stage
+    let zrt = compressor.compressibility_factor * UNIVERSAL_GAS_CONSTANT / compressor.molar_mass
+        * inlet_temperature;
+    let isentropic_factor = (k - 1f64) / k;
+    let isentropic_head = zrt / isentropic_factor * (ratio.powf(isentropic_factor) - 1f64);
+
+    // (n - 1) / n of the polytropic path
+    let (polytropic_factor, work) = match compressor.efficiency {
+        CompressorEfficiency::Isentropic(x) => {
+            let work = isentropic_head / x;
+            let temperature_ratio = 1f64 + (ratio.powf(isentropic_factor) - 1f64) / x;
+            (temperature_ratio.ln() / ratio.ln(), work)
+        }
+        CompressorEfficiency::Polytropic(x) => {
+            let factor = isentropic_factor / x;
+            (
+                factor,
+                zrt / isentropic_factor * (ratio.powf(factor) - 1f64),
+            )
+        }
+    };
+    let polytropic_head = zrt / polytropic_factor * (ratio.powf(polytropic_factor) - 1f64);
+    let discharge_temperature = inlet_temperature * ratio.powf(polytropic_factor);
+
+    let inlet_density = inlet_pressure / zrt;
+    let mass_flow_rate = compressor.flow_rate.convert_to_si_unit().value * inlet_density;
+    // Intercoolers take out the gas's enthalpy rise, which is all of the work of each stage
+    let intercooler_duty = mass_flow_rate * work * (stages - 1f64);
+    let to_head = |x: f64| Head::MOfFluid(MOfFluid::new(stages * x / STANDARD_GRAVITY));
+    Ok(CompressorPower {
+        stage_pressure_ratio: ratio,
+        discharge_temperature: Temperature::K(K::new(discharge_temperature)),
+        isentropic_head: to_head(isentropic_head),
+        polytropic_head: to_head(polytropic_head),
+        isentropic_efficiency: isentropic_head / work,
+        polytropic_efficiency: polytropic_head / work,
+        polytropic_exponent: 1f64 / (1f64 - polytropic_factor),
+        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(mass_flow_rate)),
+        gas_power: Power::W(W::new(mass_flow_rate * work * stages)),
+        intercooler_duty: Power::W(W::new(intercooler_duty)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! pump_power_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (m³/s, m, kg/m³, efficiency, W hydraulic power)
+                let (flow_rate, head, density, efficiency, expected): (f64, f64, f64, f64, f64) = $value;
+                let result = get_pump_power(&Pump {
+                    flow_rate: VolumetricFlowRate::M3PerSec(M3PerSec::new(flow_rate)),
+                    head: Head::MOfFluid(MOfFluid::new(head)),
+                    density: Density::KgPerM3(KgPerM3::new(density)),
+                    efficiency,
+                })
+                .unwrap();
+                assert_approx_eq!(expected, result.hydraulic_power.convert_to_si_unit().value, 1e-6);
+                assert_approx_eq!(
+                    expected / efficiency,
+                    result.shaft_power.convert_to_si_unit().value,
+                    1e-6
+                );
+            }
+        )*
+        }
+    }
+
+    pump_power_tests! {
+        pump_power_0: (0.05, 30.0, 1000.0, 0.75, 14709.975),
+        pump_power_1: (0.01, 100.0, 850.0, 0.6, 8335.6525),
+        pump_power_2: (0.2, 5.0, 998.0, 1.0, 9787.0367),
+    }
+
+    macro_rules! compressor_power_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // Air from 100 kPa and 300 K to 800 kPa at 1 m³/s
+                let (
+                    efficiency,
+                    stages,
+                    expected_discharge_k,
+                    expected_isentropic_efficiency,
+                    expected_polytropic_efficiency,
+                ): (CompressorEfficiency, u32, f64, f64, f64) = $value;
+                let compressor = Compressor {
+                    flow_rate: VolumetricFlowRate::M3PerSec(M3PerSec::new(1f64)),
+                    inlet_pressure: Pressure::KPa(KPa::new(100.0)),
+                    inlet_temperature: Temperature::K(K::new(300.0)),
+                    discharge_pressure: Pressure::KPa(KPa::new(800.0)),
+                    molar_mass: 0.028965,
+                    isentropic_exponent: 1.4,
+                    compressibility_factor: 1f64,
+                    efficiency,
+                    stages,
+                };
+                let result = get_compressor_power(&compressor).unwrap();
+                let stages = stages as f64;
+                let ratio = 8f64.powf(1f64 / stages);
+                assert_approx_eq!(ratio, result.stage_pressure_ratio, 1e-9);
+                assert_approx_eq!(
+                    expected_discharge_k,
+                    result.discharge_temperature.convert_to_si_unit().value,
+                    0.01
+                );
+                assert_approx_eq!(
+                    expected_isentropic_efficiency,
+                    result.isentropic_efficiency,
+                    1e-3
+                );
+                assert_approx_eq!(
+                    expected_polytropic_efficiency,
+                    result.polytropic_efficiency,
+                    1e-3
+                );
+                // cp * (T2 - T1) per unit mass of each stage
+                let gas_constant = UNIVERSAL_GAS_CONSTANT / 0.028965;
+                let isentropic_work =
+                    gas_constant * 3.5 * 300f64 * (ratio.powf(0.4 / 1.4) - 1f64) * stages;
+                assert_approx_eq!(
+                    isentropic_work / STANDARD_GRAVITY,
+                    result.isentropic_head.convert_to_si_unit().value,
+                    1e-6
+                );
+                let mass_flow_rate = 100e3 / (gas_constant * 300f64);
+                let gas_power = result.gas_power.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    mass_flow_rate * isentropic_work / result.isentropic_efficiency,
+                    gas_power,
+                    1e-6
+                );
+                assert_approx_eq!(
+                    gas_power * (stages - 1f64) / stages,
+                    result.intercooler_duty.convert_to_si_unit().value,
+                    1e-6
+                );
+                // The same compressor described by its isentropic efficiency
+                let isentropic = get_compressor_power(&Compressor {
+                    efficiency: CompressorEfficiency::Isentropic(result.isentropic_efficiency),
+                    ..compressor
+                })
+                .unwrap();
+                assert_approx_eq!(
+                    result.polytropic_efficiency,
+                    isentropic.polytropic_efficiency,
+                    1e-9
+                );
+                assert_approx_eq!(gas_power, isentropic.gas_power.convert_to_si_unit().value, 1e-6);
+                // Intercooling saves work and lowers the discharge temperature
+                if stages > 1f64 {
+                    let single = get_compressor_power(&Compressor {
+                        stages: 1,
+                        ..compressor
+                    })
+                    .unwrap();
+                    assert!(gas_power < single.gas_power.convert_to_si_unit().value);
+                    assert!(
+                        result.discharge_temperature.convert_to_si_unit().value
+                            < single.discharge_temperature.convert_to_si_unit().value
+                    );
+                }
+            }
+        )*
+        }
+    }
+
+    compressor_power_tests! {
+        isentropic_compressor: (
+            CompressorEfficiency::Isentropic(1f64),
+            1,
+            300f64 * 8f64.powf(0.4 / 1.4),
+            1f64,
+            1f64
+        ),
+        compressor_with_isentropic_efficiency: (
+            CompressorEfficiency::Isentropic(0.8),
+            1,
+            604.29,
+            0.8,
+            0.848
+        ),
+        polytropic_compressor: (CompressorEfficiency::Polytropic(0.85), 1, 603.51, 0.802, 0.85),
+        intercooled_compressor: (CompressorEfficiency::Isentropic(0.8), 2, 429.71, 0.8, 0.827),
+        three_stage_polytropic_compressor: (
+            CompressorEfficiency::Polytropic(0.85),
+            3,
+            378.71,
+            0.835,
+            0.85
+        ),
+    }
+
+    #[test]
+    fn pump_efficiency_high() {
+        let pump = Pump {
+            flow_rate: VolumetricFlowRate::M3PerSec(M3PerSec::new(1f64)),
+            head: Head::MOfFluid(MOfFluid::new(1f64)),
+            density: Density::KgPerM3(KgPerM3::new(1000.0)),
+            efficiency: 1.2,
+        };
+        assert_eq!(Err(MachineErr::EfficiencyHigh), get_pump_power(&pump));
+    }
+
+    macro_rules! compressor_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (efficiency, stages, discharge_kpa, isentropic_exponent, expected) = $value;
+                let compressor = Compressor {
+                    flow_rate: VolumetricFlowRate::M3PerSec(M3PerSec::new(1f64)),
+                    inlet_pressure: Pressure::KPa(KPa::new(100.0)),
+                    inlet_temperature: Temperature::K(K::new(300.0)),
+                    discharge_pressure: Pressure::KPa(KPa::new(discharge_kpa)),
+                    molar_mass: 0.028965,
+                    isentropic_exponent,
+                    compressibility_factor: 1f64,
+                    efficiency,
+                    stages,
+                };
+                assert_eq!(Err(expected), get_compressor_power(&compressor));
+            }
+        )*
+        }
+    }
+
+    compressor_err_tests! {
+        compressor_efficiency_low: (
+            CompressorEfficiency::Polytropic(0f64),
+            1,
+            800.0,
+            1.4,
+            MachineErr::EfficiencyLow
+        ),
+        compressor_without_stages: (
+            CompressorEfficiency::Isentropic(0.8),
+            0,
+            800.0,
+            1.4,
+            MachineErr::NoStages
+        ),
+        discharge_below_inlet_pressure: (
+            CompressorEfficiency::Isentropic(0.8),
+            1,
+            50.0,
+            1.4,
+            MachineErr::PressureOutOfOrder
+        ),
+        isentropic_exponent_of_one: (
+            CompressorEfficiency::Isentropic(0.8),
+            1,
+            500.0,
+            1f64,
+            MachineErr::IsentropicExponentLow
+        ),
+    }
+}
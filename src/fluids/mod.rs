@@ -1,3 +1,4 @@
+pub mod machines;
 pub mod orifice_plate;
 use crate::units::*;
 
@@ -0,0 +1,520 @@
+use crate::constants::STANDARD_GRAVITY;
+use crate::units::*;
+
+/// Properties at the bulk or film temperature the correlation asks for
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct FluidProperties {
+    pub density: Density,
+    pub dynamic_viscosity: DynamicViscosity,
+    pub specific_heat: EnergyPerMassTemperature,
+    pub thermal_conductivity: ThermalConductivity,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum FluidHeating {
+    // The wall is hotter than the fluid
+    Heated,
+    // The wall is colder than the fluid
+    Cooled,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum NaturalConvectionGeometry {
+    // Length is the plate's height
+    VerticalPlate,
+    // Length is the cylinder's diameter
+    HorizontalCylinder,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Correlation {
+    // Fully developed turbulent pipe flow with a moderate wall to fluid temperature difference
+    DittusBoelter {
+        velocity: Velocity,
+        diameter: Length,
+        heating: FluidHeating,
+    },
+    // Fully developed turbulent pipe flow with a large wall to fluid temperature difference
+    SiederTate {
+        velocity: Velocity,
+        diameter: Length,
+        // Evaluated at the wall temperature
+        wall_viscosity: DynamicViscosity,
+    },
+    // Fully developed transitional and turbulent pipe flow in smooth pipes
+    Gnielinski {
+        velocity: Velocity,
+        diameter: Length,
+    },
+    // Average over a cylinder in crossflow, properties at the film temperature
+    ChurchillBernstein {
+        velocity: Velocity,
+        diameter: Length,
+    },
+    // Average natural convection, properties at the film temperature
+    ChurchillChu {
+        geometry: NaturalConvectionGeometry,
+        length: Length,
+        surface_temperature: Temperature,
+        fluid_temperature: Temperature,
+        expansion_coefficient: InverseTemperature,
+    },
+    // Average over a plate with a laminar boundary layer, properties at the film temperature
+    FlatPlateLaminar {
+        velocity: Velocity,
+        length: Length,
+    },
+    // Average over a plate whose boundary layer turns turbulent at Re = 5 × 10^5,
+    // properties at the film temperature
+    FlatPlateTurbulent {
+        velocity: Velocity,
+        length: Length,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Convection {
+    pub heat_transfer_coefficient: PowerPerAreaTemperature,
+    pub nusselt_number: f64,
+    pub prandtl_number: f64,
+    // Only for forced convection
+    pub reynolds_number: Option<f64>,
+    // Only for natural convection
+    pub rayleigh_number: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvectionErr {
+    // Density, viscosity, specific heat, conductivity and lengths must be above 0
+    PropertyNotPositive,
+    ReynoldsNumberLow,
+    ReynoldsNumberHigh,
+    PrandtlNumberLow,
+    PrandtlNumberHigh,
+    // Reynolds times Prandtl number
+    PecletNumberLow,
+    RayleighNumberLow,
+    RayleighNumberHigh,
+}
+
+fn check_range(
+    value: f64,
+    min: f64,
+    max: f64,
+    low: ConvectionErr,
+    high: ConvectionErr,
+) -> Result<(), ConvectionErr> {
+    if value.is_nan() || value < min {
+        Err(low)
+    } else if value > max {
+        Err(high)
+    } else {
+        Ok(())
+    }
+}
+
+fn check_reynolds_number(value: f64, min: f64, max: f64) -> Result<(), ConvectionErr> {
+    check_range(
+        value,
+        min,
+        max,
+        ConvectionErr::ReynoldsNumberLow,
+        ConvectionErr::ReynoldsNumberHigh,
+    )
+}
+
+fn check_prandtl_number(value: f64, min: f64, max: f64) -> Result<(), ConvectionErr> {
+    check_range(
+        value,
+        min,
+        max,
+        ConvectionErr::PrandtlNumberLow,
+        ConvectionErr::PrandtlNumberHigh,
+    )
+}
+
+fn check_positive(values: &[f64]) -> Result<(), ConvectionErr> {
+    if values.iter().all(|x| *x > 0f64) {
+        Ok(())
+    } else {
+        Err(ConvectionErr::PropertyNotPositive)
+    }
+}
+
+/// Average heat transfer coefficient from a Nusselt number correlation,
+/// errors when the flow is outside of the range the correlation was fit to
+pub fn get_convection(
+    fluid: &FluidProperties,
+    correlation: &Correlation,
+) -> Result<Convection, ConvectionErr> {
+    let density = fluid.density.convert_to_si_unit().value;
+    let viscosity = fluid.dynamic_viscosity.convert_to_si_unit().value;
+    let specific_heat = fluid.specific_heat.convert_to_si_unit().value;
+    let conductivity = fluid.thermal_conductivity.convert_to_si_unit().value;
+    check_positive(&[density, viscosity, specific_heat, conductivity])?;
+    let prandtl = viscosity * specific_heat / conductivity;
+    let get_reynolds = |velocity: Velocity, length: f64| {
+        density * velocity.convert_to_si_unit().value * length / viscosity
+    };
+
+    let (length, nusselt, reynolds_number, rayleigh_number) = match *correlation {
+        Correlation::DittusBoelter {
+            velocity,
+            diameter,
+            heating,
+        } => {
+            let diameter = diameter.convert_to_si_unit().value;
+            check_positive(&[diameter])?;
+            let reynolds = get_reynolds(velocity, diameter);
+            check_reynolds_number(reynolds, 1e4, f64::INFINITY)?;
+            check_prandtl_number(prandtl, 0.6, 160.0)?;
+            let n = match heating {
+                FluidHeating::Heated => 0.4,
+                FluidHeating::Cooled => 0.3,
+            };
+            let nusselt = 0.023 * reynolds.powf(0.8) * prandtl.powf(n);
+            (diameter, nusselt, Some(reynolds), None)
+        }
+        Correlation::SiederTate {
+            velocity,
+            diameter,
+            wall_viscosity,
+        } => {
+            let diameter = diameter.convert_to_si_unit().value;
+            let wall_viscosity = wall_viscosity.convert_to_si_unit().value;
+            check_positive(&[diameter, wall_viscosity])?;
+            let reynolds = get_reynolds(velocity, diameter);
+            check_reynolds_number(reynolds, 1e4, f64::INFINITY)?;
+            check_prandtl_number(prandtl, 0.7, 16700.0)?;
+            let nusselt = 0.027
+                * reynolds.powf(0.8)
+                * prandtl.powf(1f64 / 3f64)
+                * (viscosity / wall_viscosity).powf(0.14);
+            (diameter, nusselt, Some(reynolds), None)
+        }
+        Correlation::Gnielinski { velocity, diameter } => {
+            let diameter = diameter.convert_to_si_unit().value;
+            check_positive(&[diameter])?;
+            let reynolds = get_reynolds(velocity, diameter);
+            check_reynolds_number(reynolds, 3e3, 5e6)?;
+            check_prandtl_number(prandtl, 0.5, 2000.0)?;
+            // Petukhov's smooth pipe friction factor
+            let friction_factor = (0.790 * reynolds.ln() - 1.64).powi(-2);
+            let nusselt = friction_factor / 8f64 * (reynolds - 1000f64) * prandtl
+                / (1f64
+                    + 12.7
+                        * f64::sqrt(friction_factor / 8f64)
+                        * (prandtl.powf(2f64 / 3f64) - 1f64));
+            (diameter, nusselt, Some(reynolds), None)
+        }
+        Correlation::ChurchillBernstein { velocity, diameter } => {
+            let diameter = diameter.convert_to_si_unit().value;
+            check_positive(&[diameter])?;
+            let reynolds = get_reynolds(velocity, diameter);
+            if reynolds.is_nan() || reynolds * prandtl < 0.2 {
+                return Err(ConvectionErr::PecletNumberLow);
+            }
+            let nusselt = 0.3
+                + 0.62 * reynolds.sqrt() * prandtl.powf(1f64 / 3f64)
+                    / (1f64 + (0.4 / prandtl).powf(2f64 / 3f64)).powf(0.25)
+                    * (1f64 + (reynolds / 282000f64).powf(5f64 / 8f64)).powf(0.8);
+            (diameter, nusselt, Some(reynolds), None)
+        }
+        Correlation::ChurchillChu {
+            geometry,
+            length,
+            surface_temperature,
+            fluid_temperature,
+            expansion_coefficient,
+        } => {
+            let length = length.convert_to_si_unit().value;
+            check_positive(&[length])?;
+            let temperature_difference = (surface_temperature.convert_to_si_unit().value
+                - fluid_temperature.convert_to_si_unit().value)
+                .abs();
+            let kinematic_viscosity = viscosity / density;
+            let thermal_diffusivity = conductivity / (density * specific_heat);
+            let rayleigh = STANDARD_GRAVITY
+                * expansion_coefficient.convert_to_si_unit().value
+                * temperature_difference
+                * length.powi(3)
+                / (kinematic_viscosity * thermal_diffusivity);
+            let (rayleigh_min, rayleigh_max, constant, prandtl_constant) = match geometry {
+                NaturalConvectionGeometry::VerticalPlate => {
+                    (f64::MIN_POSITIVE, f64::INFINITY, 0.825, 0.492)
+                }
+                NaturalConvectionGeometry::HorizontalCylinder => (1e-5, 1e12, 0.60, 0.559),
+            };
+            check_range(
+                rayleigh,
+                rayleigh_min,
+                rayleigh_max,
+                ConvectionErr::RayleighNumberLow,
+                ConvectionErr::RayleighNumberHigh,
+            )?;
+            let nusselt = (constant
+                + 0.387 * rayleigh.powf(1f64 / 6f64)
+                    / (1f64 + (prandtl_constant / prandtl).powf(9f64 / 16f64)).powf(8f64 / 27f64))
+            .powi(2);
+            (length, nusselt, None, Some(rayleigh))
+        }
+        Correlation::FlatPlateLaminar { velocity, length } => {
+            let length = length.convert_to_si_unit().value;
+            check_positive(&[length])?;
+            let reynolds = get_reynolds(velocity, length);
+            check_reynolds_number(reynolds, f64::MIN_POSITIVE, 5e5)?;
+            check_prandtl_number(prandtl, 0.6, f64::INFINITY)?;
+            let nusselt = 0.664 * reynolds.sqrt() * prandtl.powf(1f64 / 3f64);
+            (length, nusselt, Some(reynolds), None)
+        }
+        Correlation::FlatPlateTurbulent { velocity, length } => {
+            let length = length.convert_to_si_unit().value;
+            check_positive(&[length])?;
+            let reynolds = get_reynolds(velocity, length);
+            check_reynolds_number(reynolds, 5e5, 1e8)?;
+            check_prandtl_number(prandtl, 0.6, 60.0)?;
+            let nusselt = (0.037 * reynolds.powf(0.8) - 871f64) * prandtl.powf(1f64 / 3f64);
+            (length, nusselt, Some(reynolds), None)
+        }
+    };
+    Ok(Convection {
+        heat_transfer_coefficient: PowerPerAreaTemperature::WPerM2K(WPerM2K::new(
+            nusselt * conductivity / length,
+        )),
+        nusselt_number: nusselt,
+        prandtl_number: prandtl,
+        reynolds_number,
+        rayleigh_number,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! get_convection_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // Fluid in kg/m³, Pa * s, J/(kg * K) and W/(m * K),
+                // then the expected Nusselt number with its tolerance
+                let (fluid, correlation, expected_result): (
+                    (f64, f64, f64, f64),
+                    Correlation,
+                    Result<(f64, f64), ConvectionErr>,
+                ) = $value;
+                let (density, viscosity, specific_heat, conductivity) = fluid;
+                let fluid = FluidProperties {
+                    density: Density::KgPerM3(KgPerM3::new(density)),
+                    dynamic_viscosity: DynamicViscosity::PaS(PaS::new(viscosity)),
+                    specific_heat: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(specific_heat)),
+                    thermal_conductivity: ThermalConductivity::WPerMK(WPerMK::new(conductivity)),
+                };
+                let actual_result = get_convection(&fluid, &correlation);
+                match (expected_result, actual_result) {
+                    (Ok((expected, tol)), Ok(actual)) => {
+                        assert_approx_eq!(expected, actual.nusselt_number, tol);
+                    },
+                    (Err(x), Err(y)) => assert_eq!(x, y),
+                    (x, y) => panic!("expected {:?} but got {:?}", x, y),
+                };
+            }
+        )*
+        }
+    }
+
+    // The liquid (1000.0, 1e-3, 5000.0, 1.0) has Pr = 5 and Re = 10^5 at 1 m/s in a 0.1 m pipe,
+    // the gas (1.0, 1e-5, 1750.0, 0.025) has Pr = 0.7 with ν = 10^-5 m²/s
+    get_convection_tests! {
+        dittus_boelter_heated: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::DittusBoelter {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+                heating: FluidHeating::Heated,
+            },
+            Ok((437.85, 0.01))
+        ),
+        dittus_boelter_cooled: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::DittusBoelter {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+                heating: FluidHeating::Cooled,
+            },
+            Ok((372.75, 0.01))
+        ),
+        dittus_boelter_laminar: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::DittusBoelter {
+                velocity: Velocity::MPerSec(MPerSec::new(0.05)),
+                diameter: Length::M(M::new(0.1)),
+                heating: FluidHeating::Heated,
+            },
+            Err(ConvectionErr::ReynoldsNumberLow)
+        ),
+        dittus_boelter_viscous_oil: (
+            (900.0, 0.1, 2000.0, 0.15),
+            Correlation::DittusBoelter {
+                velocity: Velocity::MPerSec(MPerSec::new(20.0)),
+                diameter: Length::M(M::new(0.1)),
+                heating: FluidHeating::Heated,
+            },
+            Err(ConvectionErr::PrandtlNumberHigh)
+        ),
+        sieder_tate: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::SiederTate {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+                wall_viscosity: DynamicViscosity::PaS(PaS::new(1e-3)),
+            },
+            Ok((461.69, 0.01))
+        ),
+        sieder_tate_liquid_metal: (
+            (10000.0, 1e-3, 150.0, 15.0),
+            Correlation::SiederTate {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+                wall_viscosity: DynamicViscosity::PaS(PaS::new(1e-3)),
+            },
+            Err(ConvectionErr::PrandtlNumberLow)
+        ),
+        gnielinski: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::Gnielinski {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+            },
+            Ok((515.7, 0.1))
+        ),
+        gnielinski_too_fast: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::Gnielinski {
+                velocity: Velocity::MPerSec(MPerSec::new(100.0)),
+                diameter: Length::M(M::new(0.1)),
+            },
+            Err(ConvectionErr::ReynoldsNumberHigh)
+        ),
+        // Example 7.4 of Incropera and DeWitt, Fundamentals of Heat and Mass Transfer
+        churchill_bernstein: (
+            (0.995, 20.92e-6 * 0.995, 1009.0, 0.030),
+            Correlation::ChurchillBernstein {
+                velocity: Velocity::MPerSec(MPerSec::new(10.0)),
+                diameter: Length::M(M::new(0.0127)),
+            },
+            Ok((40.6, 0.1))
+        ),
+        churchill_bernstein_creeping_flow: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::ChurchillBernstein {
+                velocity: Velocity::MPerSec(MPerSec::new(1e-4)),
+                diameter: Length::M(M::new(0.01)),
+            },
+            Err(ConvectionErr::PecletNumberLow)
+        ),
+        // Example 9.2 of Incropera and DeWitt, Fundamentals of Heat and Mass Transfer
+        churchill_chu_vertical_plate: (
+            (0.8711, 26.41e-6 * 0.8711, 0.0338 / (0.8711 * 38.3e-6), 0.0338),
+            Correlation::ChurchillChu {
+                geometry: NaturalConvectionGeometry::VerticalPlate,
+                length: Length::M(M::new(0.71)),
+                surface_temperature: Temperature::C(C::new(232.0)),
+                fluid_temperature: Temperature::C(C::new(23.0)),
+                expansion_coefficient: InverseTemperature::PerK(PerK::new(1f64 / 400f64)),
+            },
+            Ok((147.0, 1.0))
+        ),
+        churchill_chu_large_cylinder: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::ChurchillChu {
+                geometry: NaturalConvectionGeometry::HorizontalCylinder,
+                length: Length::M(M::new(10.0)),
+                surface_temperature: Temperature::C(C::new(200.0)),
+                fluid_temperature: Temperature::C(C::new(20.0)),
+                expansion_coefficient: InverseTemperature::PerK(PerK::new(1f64 / 400f64)),
+            },
+            Err(ConvectionErr::RayleighNumberHigh)
+        ),
+        churchill_chu_no_temperature_difference: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::ChurchillChu {
+                geometry: NaturalConvectionGeometry::VerticalPlate,
+                length: Length::M(M::new(1.0)),
+                surface_temperature: Temperature::C(C::new(20.0)),
+                fluid_temperature: Temperature::C(C::new(20.0)),
+                expansion_coefficient: InverseTemperature::PerK(PerK::new(1f64 / 300f64)),
+            },
+            Err(ConvectionErr::RayleighNumberLow)
+        ),
+        flat_plate_laminar: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::FlatPlateLaminar {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                length: Length::M(M::new(1.0)),
+            },
+            Ok((186.44, 0.01))
+        ),
+        flat_plate_laminar_tripped: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::FlatPlateLaminar {
+                velocity: Velocity::MPerSec(MPerSec::new(10.0)),
+                length: Length::M(M::new(1.0)),
+            },
+            Err(ConvectionErr::ReynoldsNumberHigh)
+        ),
+        flat_plate_turbulent: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::FlatPlateTurbulent {
+                velocity: Velocity::MPerSec(MPerSec::new(10.0)),
+                length: Length::M(M::new(1.0)),
+            },
+            Ok((1299.5, 0.1))
+        ),
+        flat_plate_turbulent_still_laminar: (
+            (1.0, 1e-5, 1750.0, 0.025),
+            Correlation::FlatPlateTurbulent {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                length: Length::M(M::new(1.0)),
+            },
+            Err(ConvectionErr::ReynoldsNumberLow)
+        ),
+        no_diameter: (
+            (1000.0, 1e-3, 5000.0, 1.0),
+            Correlation::Gnielinski {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.0)),
+            },
+            Err(ConvectionErr::PropertyNotPositive)
+        ),
+    }
+
+    #[test]
+    fn heat_transfer_coefficient() {
+        let result = get_convection(
+            &FluidProperties {
+                density: Density::KgPerM3(KgPerM3::new(1000.0)),
+                dynamic_viscosity: DynamicViscosity::PaS(PaS::new(1e-3)),
+                specific_heat: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(5000.0)),
+                thermal_conductivity: ThermalConductivity::WPerMK(WPerMK::new(1.0)),
+            },
+            &Correlation::DittusBoelter {
+                velocity: Velocity::MPerSec(MPerSec::new(1.0)),
+                diameter: Length::M(M::new(0.1)),
+                heating: FluidHeating::Heated,
+            },
+        )
+        .unwrap();
+        assert_approx_eq!(5f64, result.prandtl_number, 1e-12);
+        assert_eq!(Some(1e5), result.reynolds_number.map(|x| x.round()));
+        assert_eq!(None, result.rayleigh_number);
+        // h = Nu * k / D
+        assert_approx_eq!(
+            result.nusselt_number * 10f64,
+            result.heat_transfer_coefficient.convert_to_si_unit().value,
+            1e-9
+        );
+    }
+}
@@ -0,0 +1,667 @@
+use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::*;
+use crate::thermo::steam::iapws97::get_steam_table_entry;
+use crate::thermo::steam::*;
+use crate::thermo::PtvEntry;
+use crate::units::*;
+
+// Outlet temperatures must settle within this many kelvin
+static OUTLET_TOLERANCE: f64 = 1e-6;
+static MAX_ITERATIONS: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum FlowArrangement {
+    ParallelFlow,
+    CounterFlow,
+    // Single pass crossflow with both streams unmixed
+    CrossFlowUnmixed,
+    // Single pass crossflow with the larger capacity rate stream mixed
+    CrossFlowCmaxMixed,
+    // Single pass crossflow with the smaller capacity rate stream mixed
+    CrossFlowCminMixed,
+    // Any even number of tube passes in each shell pass
+    ShellAndTube { shell_passes: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum StreamFluid {
+    // Constant specific heat
+    SpecificHeat(EnergyPerMassTemperature),
+    // Liquid water or steam which must stay in one phase,
+    // the specific heat is averaged over the stream's temperature change with the steam table
+    Water { pressure: Pressure },
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Stream {
+    pub mass_flow_rate: MassFlowRate,
+    pub inlet_temperature: Temperature,
+    pub fluid: StreamFluid,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum ExchangerQuery {
+    // Given UA find the duty and outlet temperatures
+    Rating { conductance: PowerPerTemperature },
+    // Given the duty find the UA needed
+    Sizing { duty: Power },
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct HeatExchanger {
+    pub hot_stream: Stream,
+    pub cold_stream: Stream,
+    pub arrangement: FlowArrangement,
+    pub query: ExchangerQuery,
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct HeatExchangerResult {
+    pub duty: Power,
+    pub hot_outlet_temperature: Temperature,
+    pub cold_outlet_temperature: Temperature,
+    // UA
+    pub conductance: PowerPerTemperature,
+    pub hot_capacity_rate: PowerPerTemperature,
+    pub cold_capacity_rate: PowerPerTemperature,
+    // Cmin / Cmax
+    pub capacity_ratio: f64,
+    pub effectiveness: f64,
+    pub ntu: f64,
+    // K, for the same terminal temperatures in counterflow
+    pub log_mean_temperature_difference: f64,
+    // F in Q = U * A * F * LMTD
+    pub correction_factor: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExchangerErr {
+    // The hot stream must enter hotter than the cold stream
+    TemperatureOutOfOrder,
+    CapacityRateLow,
+    ConductanceLow,
+    DutyLow,
+    // The duty is more than the arrangement can transfer with any area
+    EffectivenessUnreachable,
+    NoShellPasses,
+    // A water stream boils or condenses
+    PhaseChange,
+    SteamQueryErr(SteamQueryErr),
+    FailedToConverge(RootFinderErr),
+}
+
+/// Fraction of the largest possible duty, Cmin * (Th,in - Tc,in), that is transferred
+pub fn get_effectiveness(
+    arrangement: FlowArrangement,
+    ntu: f64,
+    capacity_ratio: f64,
+) -> Result<f64, ExchangerErr> {
+    let cr = capacity_ratio;
+    let effectiveness = match arrangement {
+        // Cmax is infinite, as in a condenser or boiler, so every crossflow arrangement is the same
+        FlowArrangement::CrossFlowUnmixed
+        | FlowArrangement::CrossFlowCmaxMixed
+        | FlowArrangement::CrossFlowCminMixed
+            if cr.abs() < 1e-9 =>
+        {
+            1f64 - f64::exp(-ntu)
+        }
+        FlowArrangement::ParallelFlow => (1f64 - f64::exp(-ntu * (1f64 + cr))) / (1f64 + cr),
+        FlowArrangement::CounterFlow => get_counterflow_effectiveness(ntu, cr),
+        FlowArrangement::CrossFlowUnmixed => {
+            1f64 - f64::exp(ntu.powf(0.22) / cr * (f64::exp(-cr * ntu.powf(0.78)) - 1f64))
+        }
+        FlowArrangement::CrossFlowCmaxMixed => {
+            (1f64 - f64::exp(-cr * (1f64 - f64::exp(-ntu)))) / cr
+        }
+        FlowArrangement::CrossFlowCminMixed => 1f64 - f64::exp(-(1f64 - f64::exp(-cr * ntu)) / cr),
+        FlowArrangement::ShellAndTube { shell_passes } => {
+            if shell_passes == 0 {
+                return Err(ExchangerErr::NoShellPasses);
+            }
+            let n = shell_passes as f64;
+            let gamma = f64::sqrt(1f64 + cr * cr);
+            let decay = f64::exp(-ntu / n * gamma);
+            let shell = 2f64 / (1f64 + cr + gamma * (1f64 + decay) / (1f64 - decay));
+            get_shells_in_series_effectiveness(shell, cr, n)
+        }
+    };
+    Ok(effectiveness)
+}
+
+fn get_counterflow_effectiveness(ntu: f64, cr: f64) -> f64 {
+    if (1f64 - cr).abs() < 1e-9 {
+        ntu / (1f64 + ntu)
+    } else {
+        let decay = f64::exp(-ntu * (1f64 - cr));
+        (1f64 - decay) / (1f64 - cr * decay)
+    }
+}
+
+fn get_shells_in_series_effectiveness(shell: f64, cr: f64, n: f64) -> f64 {
+    if (1f64 - cr).abs() < 1e-9 {
+        n * shell / (1f64 + (n - 1f64) * shell)
+    } else {
+        let x = ((1f64 - shell * cr) / (1f64 - shell)).powf(n);
+        (x - 1f64) / (x - cr)
+    }
+}
+
+fn get_counterflow_ntu(effectiveness: f64, cr: f64) -> f64 {
+    if (1f64 - cr).abs() < 1e-9 {
+        effectiveness / (1f64 - effectiveness)
+    } else {
+        f64::ln((effectiveness - 1f64) / (effectiveness * cr - 1f64)) / (cr - 1f64)
+    }
+}
+
+/// Number of transfer units, UA / Cmin, needed to reach the effectiveness
+pub fn get_ntu(
+    arrangement: FlowArrangement,
+    effectiveness: f64,
+    capacity_ratio: f64,
+) -> Result<f64, ExchangerErr> {
+    if effectiveness.is_nan() || effectiveness <= 0f64 || effectiveness >= 1f64 {
+        return Err(ExchangerErr::EffectivenessUnreachable);
+    }
+    let cr = capacity_ratio;
+    let ntu = match arrangement {
+        FlowArrangement::CrossFlowUnmixed
+        | FlowArrangement::CrossFlowCmaxMixed
+        | FlowArrangement::CrossFlowCminMixed
+            if cr.abs() < 1e-9 =>
+        {
+            -f64::ln(1f64 - effectiveness)
+        }
+        FlowArrangement::ParallelFlow => -f64::ln(1f64 - effectiveness * (1f64 + cr)) / (1f64 + cr),
+        FlowArrangement::CounterFlow => get_counterflow_ntu(effectiveness, cr),
+        FlowArrangement::CrossFlowUnmixed => {
+            // No closed form so solve in log space to keep NTU positive
+            let guess = get_counterflow_ntu(effectiveness, cr).ln();
+            let f = |x: f64| get_effectiveness(arrangement, x.exp(), cr).unwrap() - effectiveness;
+            secant_method(f, guess, 1e-12)
+                .map_err(ExchangerErr::FailedToConverge)?
+                .exp()
+        }
+        FlowArrangement::CrossFlowCmaxMixed => {
+            -f64::ln(1f64 + f64::ln(1f64 - effectiveness * cr) / cr)
+        }
+        FlowArrangement::CrossFlowCminMixed => {
+            -f64::ln(1f64 + cr * f64::ln(1f64 - effectiveness)) / cr
+        }
+        FlowArrangement::ShellAndTube { shell_passes } => {
+            if shell_passes == 0 {
+                return Err(ExchangerErr::NoShellPasses);
+            }
+            let n = shell_passes as f64;
+            let shell = get_single_shell_effectiveness(effectiveness, cr, n);
+            let gamma = f64::sqrt(1f64 + cr * cr);
+            let e = (2f64 / shell - (1f64 + cr)) / gamma;
+            -n * f64::ln((e - 1f64) / (e + 1f64)) / gamma
+        }
+    };
+    if ntu.is_finite() && ntu > 0f64 {
+        Ok(ntu)
+    } else {
+        Err(ExchangerErr::EffectivenessUnreachable)
+    }
+}
+
+fn get_single_shell_effectiveness(effectiveness: f64, cr: f64, n: f64) -> f64 {
+    if (1f64 - cr).abs() < 1e-9 {
+        effectiveness / (n - effectiveness * (n - 1f64))
+    } else {
+        let x = ((effectiveness * cr - 1f64) / (effectiveness - 1f64)).powf(1f64 / n);
+        (x - 1f64) / (x - cr)
+    }
+}
+
+/// Log mean of the temperature differences at the two ends of an exchanger
+pub fn get_log_mean_temperature_difference(first_difference: f64, second_difference: f64) -> f64 {
+    if (first_difference - second_difference).abs() < 1e-9 * first_difference.abs() {
+        first_difference
+    } else {
+        (first_difference - second_difference) / f64::ln(first_difference / second_difference)
+    }
+}
+
+/// Bowman's LMTD correction factor for shell and tube exchangers with any even number of tube passes,
+/// p = (t_out - t_in) / (T_in - t_in) and r = (T_in - T_out) / (t_out - t_in)
+/// where t is the tube side and T is the shell side
+pub fn get_correction_factor(p: f64, r: f64, shell_passes: u32) -> Result<f64, ExchangerErr> {
+    if shell_passes == 0 {
+        return Err(ExchangerErr::NoShellPasses);
+    }
+    let n = shell_passes as f64;
+    // Every shell sees the same r with a smaller p
+    let p = if (1f64 - r).abs() < 1e-9 {
+        p / (n - p * (n - 1f64))
+    } else {
+        let x = ((1f64 - p * r) / (1f64 - p)).powf(1f64 / n);
+        (x - 1f64) / (x - r)
+    };
+    let root = f64::sqrt(r * r + 1f64);
+    let factor = if (1f64 - r).abs() < 1e-9 {
+        p * root / (1f64 - p) / f64::ln((2f64 - p * (2f64 - root)) / (2f64 - p * (2f64 + root)))
+    } else {
+        root / (r - 1f64) * f64::ln((1f64 - p) / (1f64 - p * r))
+            / f64::ln((2f64 - p * (r + 1f64 - root)) / (2f64 - p * (r + 1f64 + root)))
+    };
+    if factor.is_finite() && factor > 0f64 {
+        Ok(factor)
+    } else {
+        Err(ExchangerErr::EffectivenessUnreachable)
+    }
+}
+
+fn get_water_entry(pressure: Pressure, temperature: f64) -> Result<PtvEntry, ExchangerErr> {
+    get_steam_table_entry(SteamQuery::Pt(PtPoint {
+        pressure,
+        temperature: Temperature::K(K::new(temperature)),
+    }))
+    .map_err(ExchangerErr::SteamQueryErr)
+}
+
+fn get_capacity_rate(stream: &Stream, outlet_temperature: f64) -> Result<f64, ExchangerErr> {
+    let mass_flow_rate = stream.mass_flow_rate.convert_to_si_unit().value;
+    let specific_heat = match stream.fluid {
+        StreamFluid::SpecificHeat(cp) => cp.convert_to_si_unit().value,
+        StreamFluid::Water { pressure } => {
+            let inlet_temperature = stream.inlet_temperature.convert_to_si_unit().value;
+            let inlet = get_water_entry(pressure, inlet_temperature)?;
+            if (inlet_temperature - outlet_temperature).abs() < OUTLET_TOLERANCE {
+                inlet.cp.convert_to_si_unit().value
+            } else {
+                let outlet = get_water_entry(pressure, outlet_temperature)?;
+                if inlet.phase_region != outlet.phase_region {
+                    return Err(ExchangerErr::PhaseChange);
+                }
+                (inlet.enthalpy.convert_to_si_unit().value
+                    - outlet.enthalpy.convert_to_si_unit().value)
+                    / (inlet_temperature - outlet_temperature)
+            }
+        }
+    };
+    let capacity_rate = mass_flow_rate * specific_heat;
+    if capacity_rate.is_nan() || capacity_rate <= 0f64 {
+        Err(ExchangerErr::CapacityRateLow)
+    } else {
+        Ok(capacity_rate)
+    }
+}
+
+/// Rates or sizes a two stream exchanger with the ε-NTU method,
+/// water streams are iterated until their mean specific heats match the outlet temperatures
+pub fn get_heat_exchanger(exchanger: &HeatExchanger) -> Result<HeatExchangerResult, ExchangerErr> {
+    let hot_inlet = exchanger
+        .hot_stream
+        .inlet_temperature
+        .convert_to_si_unit()
+        .value;
+    let cold_inlet = exchanger
+        .cold_stream
+        .inlet_temperature
+        .convert_to_si_unit()
+        .value;
+    if hot_inlet.is_nan() || cold_inlet.is_nan() || hot_inlet <= cold_inlet {
+        return Err(ExchangerErr::TemperatureOutOfOrder);
+    }
+    let max_difference = hot_inlet - cold_inlet;
+
+    let mut hot_outlet = hot_inlet;
+    let mut cold_outlet = cold_inlet;
+    for _ in 0..MAX_ITERATIONS {
+        let hot_capacity_rate = get_capacity_rate(&exchanger.hot_stream, hot_outlet)?;
+        let cold_capacity_rate = get_capacity_rate(&exchanger.cold_stream, cold_outlet)?;
+        let min_capacity_rate = hot_capacity_rate.min(cold_capacity_rate);
+        let capacity_ratio = min_capacity_rate / hot_capacity_rate.max(cold_capacity_rate);
+        let max_duty = min_capacity_rate * max_difference;
+
+        let (duty, conductance, effectiveness, ntu) = match exchanger.query {
+            ExchangerQuery::Rating { conductance } => {
+                let conductance = conductance.convert_to_si_unit().value;
+                if conductance.is_nan() || conductance <= 0f64 {
+                    return Err(ExchangerErr::ConductanceLow);
+                }
+                let ntu = conductance / min_capacity_rate;
+                let effectiveness = get_effectiveness(exchanger.arrangement, ntu, capacity_ratio)?;
+                (effectiveness * max_duty, conductance, effectiveness, ntu)
+            }
+            ExchangerQuery::Sizing { duty } => {
+                let duty = duty.convert_to_si_unit().value;
+                if duty.is_nan() || duty <= 0f64 {
+                    return Err(ExchangerErr::DutyLow);
+                }
+                let effectiveness = duty / max_duty;
+                let ntu = get_ntu(exchanger.arrangement, effectiveness, capacity_ratio)?;
+                (duty, ntu * min_capacity_rate, effectiveness, ntu)
+            }
+        };
+
+        let next_hot_outlet = hot_inlet - duty / hot_capacity_rate;
+        let next_cold_outlet = cold_inlet + duty / cold_capacity_rate;
+        let settled = (next_hot_outlet - hot_outlet).abs() < OUTLET_TOLERANCE
+            && (next_cold_outlet - cold_outlet).abs() < OUTLET_TOLERANCE;
+        hot_outlet = next_hot_outlet;
+        cold_outlet = next_cold_outlet;
+        if settled {
+            let log_mean_temperature_difference = get_log_mean_temperature_difference(
+                hot_inlet - cold_outlet,
+                hot_outlet - cold_inlet,
+            );
+            let correction_factor = match exchanger.arrangement {
+                // Taking the cold stream as the tube side
+                FlowArrangement::ShellAndTube { shell_passes } => get_correction_factor(
+                    (cold_outlet - cold_inlet) / max_difference,
+                    (hot_inlet - hot_outlet) / (cold_outlet - cold_inlet),
+                    shell_passes,
+                )?,
+                _ => duty / (conductance * log_mean_temperature_difference),
+            };
+            let to_rate = |x| PowerPerTemperature::WPerK(WPerK::new(x));
+            return Ok(HeatExchangerResult {
+                duty: Power::W(W::new(duty)),
+                hot_outlet_temperature: Temperature::K(K::new(hot_outlet)),
+                cold_outlet_temperature: Temperature::K(K::new(cold_outlet)),
+                conductance: to_rate(conductance),
+                hot_capacity_rate: to_rate(hot_capacity_rate),
+                cold_capacity_rate: to_rate(cold_capacity_rate),
+                capacity_ratio,
+                effectiveness,
+                ntu,
+                log_mean_temperature_difference,
+                correction_factor,
+            });
+        }
+    }
+    Err(ExchangerErr::FailedToConverge(
+        RootFinderErr::MaxIterationsReached,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! effectiveness_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (arrangement, ntu, capacity_ratio, expected): (FlowArrangement, f64, f64, f64) = $value;
+                let effectiveness = get_effectiveness(arrangement, ntu, capacity_ratio).unwrap();
+                assert_approx_eq!(expected, effectiveness, 1e-5);
+                assert_approx_eq!(
+                    ntu,
+                    get_ntu(arrangement, effectiveness, capacity_ratio).unwrap(),
+                    1e-6
+                );
+            }
+        )*
+        }
+    }
+
+    // Relations of table 11.3 of Incropera and DeWitt, Fundamentals of Heat and Mass Transfer
+    effectiveness_tests! {
+        effectiveness_parallel_flow: (FlowArrangement::ParallelFlow, 2.0, 0.5, 0.633475),
+        effectiveness_counter_flow: (FlowArrangement::CounterFlow, 2.0, 0.5, 0.774600),
+        effectiveness_balanced_counter_flow: (FlowArrangement::CounterFlow, 2.0, 1.0, 0.666667),
+        effectiveness_cross_flow_unmixed: (FlowArrangement::CrossFlowUnmixed, 2.0, 0.5, 0.738763),
+        effectiveness_cross_flow_cmax_mixed: (FlowArrangement::CrossFlowCmaxMixed, 2.0, 0.5, 0.702013),
+        effectiveness_cross_flow_cmin_mixed: (FlowArrangement::CrossFlowCminMixed, 2.0, 0.5, 0.717546),
+        effectiveness_one_shell_pass: (FlowArrangement::ShellAndTube { shell_passes: 1 }, 2.0, 0.5, 0.693093),
+        // With Cr = 0 every arrangement reduces to 1 - exp(-NTU)
+        effectiveness_no_capacity_ratio_cross_flow_unmixed: (FlowArrangement::CrossFlowUnmixed, 2.0, 0.0, 0.864665),
+        effectiveness_no_capacity_ratio_cross_flow_cmax_mixed: (FlowArrangement::CrossFlowCmaxMixed, 2.0, 0.0, 0.864665),
+        effectiveness_no_capacity_ratio_cross_flow_cmin_mixed: (FlowArrangement::CrossFlowCminMixed, 2.0, 0.0, 0.864665),
+        effectiveness_no_capacity_ratio_counter_flow: (FlowArrangement::CounterFlow, 2.0, 0.0, 0.864665),
+    }
+
+    #[test]
+    fn shells_in_series() {
+        // Two shells are two single shells in counterflow with half of the NTU each
+        let single =
+            get_effectiveness(FlowArrangement::ShellAndTube { shell_passes: 1 }, 1.0, 0.5).unwrap();
+        let expected = get_shells_in_series_effectiveness(single, 0.5, 2f64);
+        let effectiveness =
+            get_effectiveness(FlowArrangement::ShellAndTube { shell_passes: 2 }, 2.0, 0.5).unwrap();
+        assert_approx_eq!(expected, effectiveness, 1e-9);
+        assert!(effectiveness > 0.693093);
+        assert!(effectiveness < 0.774600);
+    }
+
+    #[test]
+    fn correction_factor() {
+        // Read off of the one shell pass chart as about 0.8
+        assert_approx_eq!(0.802, get_correction_factor(0.5, 1.0, 1).unwrap(), 0.001);
+        // F is the ratio of counterflow NTU to the actual NTU at the same effectiveness
+        for shell_passes in [1, 2, 3] {
+            for (ntu, cr) in [(1.0, 0.5), (2.0, 0.8), (1.5, 1.0)] {
+                let arrangement = FlowArrangement::ShellAndTube { shell_passes };
+                let effectiveness = get_effectiveness(arrangement, ntu, cr).unwrap();
+                // Taking the tube side as the Cmin stream
+                let p = effectiveness;
+                let r = cr;
+                assert_approx_eq!(
+                    get_counterflow_ntu(effectiveness, cr) / ntu,
+                    get_correction_factor(p, r, shell_passes).unwrap(),
+                    1e-9
+                );
+            }
+        }
+    }
+
+    // Oil at 2 kg/s and 2100 J/(kg * K) from 150 °C cooled by water at 1.2 kg/s
+    // and 4180 J/(kg * K) from 20 °C, so NTU = 5000 / 4200 and Cr = 4200 / 5016
+    macro_rules! rating_and_sizing_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (arrangement, expected_effectiveness, expected_correction_factor): (
+                    FlowArrangement,
+                    f64,
+                    f64,
+                ) = $value;
+                let exchanger = HeatExchanger {
+                    hot_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(2.0)),
+                        inlet_temperature: Temperature::C(C::new(150.0)),
+                        fluid: StreamFluid::SpecificHeat(EnergyPerMassTemperature::JPerKgK(
+                            JPerKgK::new(2100.0),
+                        )),
+                    },
+                    cold_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(1.2)),
+                        inlet_temperature: Temperature::C(C::new(20.0)),
+                        fluid: StreamFluid::SpecificHeat(EnergyPerMassTemperature::JPerKgK(
+                            JPerKgK::new(4180.0),
+                        )),
+                    },
+                    arrangement,
+                    query: ExchangerQuery::Rating {
+                        conductance: PowerPerTemperature::WPerK(WPerK::new(5000.0)),
+                    },
+                };
+                let rating = get_heat_exchanger(&exchanger).unwrap();
+                assert_approx_eq!(expected_effectiveness, rating.effectiveness, 1e-5);
+                assert_approx_eq!(expected_correction_factor, rating.correction_factor, 1e-5);
+                let hot_outlet = rating.hot_outlet_temperature.convert_to_si_unit().value;
+                let cold_outlet = rating.cold_outlet_temperature.convert_to_si_unit().value;
+                let duty = rating.duty.convert_to_si_unit().value;
+                assert_approx_eq!(expected_effectiveness * 4200.0 * 130.0, duty, 1.0);
+                assert_approx_eq!(duty, 4200.0 * (423.15 - hot_outlet), 1e-6);
+                assert_approx_eq!(duty, 5016.0 * (cold_outlet - 293.15), 1e-6);
+                assert_approx_eq!(4200.0 / 5016.0, rating.capacity_ratio, 1e-9);
+                // Q = UA * F * LMTD with the shell and tube F from Bowman's relation
+                assert_approx_eq!(
+                    duty,
+                    5000.0 * rating.correction_factor * rating.log_mean_temperature_difference,
+                    1e-6
+                );
+
+                let sizing = get_heat_exchanger(&HeatExchanger {
+                    query: ExchangerQuery::Sizing { duty: rating.duty },
+                    ..exchanger
+                })
+                .unwrap();
+                assert_approx_eq!(5000.0, sizing.conductance.convert_to_si_unit().value, 1e-4);
+                assert_approx_eq!(rating.effectiveness, sizing.effectiveness, 1e-9);
+            }
+        )*
+        }
+    }
+
+    rating_and_sizing_tests! {
+        parallel_flow_rating_and_sizing: (FlowArrangement::ParallelFlow, 0.483192, 0.731077),
+        // Worked by hand
+        counter_flow_rating_and_sizing: (FlowArrangement::CounterFlow, 0.567768, 1f64),
+        cross_flow_unmixed_rating_and_sizing: (FlowArrangement::CrossFlowUnmixed, 0.534893, 0.885564),
+        cross_flow_cmax_mixed_rating_and_sizing: (FlowArrangement::CrossFlowCmaxMixed, 0.527418, 0.861441),
+        cross_flow_cmin_mixed_rating_and_sizing: (FlowArrangement::CrossFlowCminMixed, 0.529297, 0.867446),
+        one_shell_pass_rating_and_sizing: (FlowArrangement::ShellAndTube { shell_passes: 1 }, 0.520560, 0.839880),
+        two_shell_passes_rating_and_sizing: (FlowArrangement::ShellAndTube { shell_passes: 2 }, 0.554844, 0.953303),
+    }
+
+    macro_rules! water_stream_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (water_kpa, expected): (f64, Result<f64, ExchangerErr>) = $value;
+                // The counterflow oil cooler of rating_and_sizing_tests,
+                // with the water's properties from IAPWS-IF97
+                let result = get_heat_exchanger(&HeatExchanger {
+                    hot_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(2.0)),
+                        inlet_temperature: Temperature::C(C::new(150.0)),
+                        fluid: StreamFluid::SpecificHeat(EnergyPerMassTemperature::JPerKgK(
+                            JPerKgK::new(2100.0),
+                        )),
+                    },
+                    cold_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(1.2)),
+                        inlet_temperature: Temperature::C(C::new(20.0)),
+                        fluid: StreamFluid::Water {
+                            pressure: Pressure::KPa(KPa::new(water_kpa)),
+                        },
+                    },
+                    arrangement: FlowArrangement::CounterFlow,
+                    query: ExchangerQuery::Rating {
+                        conductance: PowerPerTemperature::WPerK(WPerK::new(5000.0)),
+                    },
+                });
+                match (expected, result) {
+                    (Ok(duty), Ok(water)) => {
+                        // Liquid water's cp is close to 4180 J/(kg * K) between 20 °C and 75 °C
+                        assert_approx_eq!(
+                            4180.0 * 1.2,
+                            water.cold_capacity_rate.convert_to_si_unit().value,
+                            10.0
+                        );
+                        assert_approx_eq!(duty, water.duty.convert_to_si_unit().value, 1000.0);
+                    }
+                    (Err(expected), result) => assert_eq!(Some(expected), result.err()),
+                    (Ok(_), Err(err)) => panic!("{:?}", err),
+                }
+            }
+        )*
+        }
+    }
+
+    water_stream_tests! {
+        // The duty with a constant 4180 J/(kg * K)
+        water_stream: (200.0, Ok(310001.0)),
+        // Heating the water past its boiling point
+        boiling_water_stream: (10.0, Err(ExchangerErr::PhaseChange)),
+    }
+
+    macro_rules! exchanger_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (arrangement, query, cold_inlet_c, hot_flow, expected) = $value;
+                let exchanger = HeatExchanger {
+                    hot_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(hot_flow)),
+                        inlet_temperature: Temperature::C(C::new(150.0)),
+                        fluid: StreamFluid::SpecificHeat(EnergyPerMassTemperature::JPerKgK(
+                            JPerKgK::new(2100.0),
+                        )),
+                    },
+                    cold_stream: Stream {
+                        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(1.2)),
+                        inlet_temperature: Temperature::C(C::new(cold_inlet_c)),
+                        fluid: StreamFluid::SpecificHeat(EnergyPerMassTemperature::JPerKgK(
+                            JPerKgK::new(4180.0),
+                        )),
+                    },
+                    arrangement,
+                    query,
+                };
+                assert_eq!(Err(expected), get_heat_exchanger(&exchanger));
+            }
+        )*
+        }
+    }
+
+    exchanger_err_tests! {
+        conductance_low: (
+            FlowArrangement::CounterFlow,
+            ExchangerQuery::Rating {
+                conductance: PowerPerTemperature::WPerK(WPerK::new(0.0)),
+            },
+            20.0,
+            2.0,
+            ExchangerErr::ConductanceLow
+        ),
+        // Parallel flow can not bring the outlets closer than their mixed temperature
+        effectiveness_unreachable: (
+            FlowArrangement::ParallelFlow,
+            ExchangerQuery::Sizing {
+                duty: Power::KW(KW::new(320.0)),
+            },
+            20.0,
+            2.0,
+            ExchangerErr::EffectivenessUnreachable
+        ),
+        duty_low: (
+            FlowArrangement::ParallelFlow,
+            ExchangerQuery::Sizing {
+                duty: Power::KW(KW::new(-1.0)),
+            },
+            20.0,
+            2.0,
+            ExchangerErr::DutyLow
+        ),
+        no_shell_passes: (
+            FlowArrangement::ShellAndTube { shell_passes: 0 },
+            ExchangerQuery::Sizing {
+                duty: Power::KW(KW::new(100.0)),
+            },
+            20.0,
+            2.0,
+            ExchangerErr::NoShellPasses
+        ),
+        cold_inlet_hotter_than_hot_inlet: (
+            FlowArrangement::CounterFlow,
+            ExchangerQuery::Sizing {
+                duty: Power::KW(KW::new(100.0)),
+            },
+            200.0,
+            2.0,
+            ExchangerErr::TemperatureOutOfOrder
+        ),
+        capacity_rate_low: (
+            FlowArrangement::CounterFlow,
+            ExchangerQuery::Sizing {
+                duty: Power::KW(KW::new(100.0)),
+            },
+            20.0,
+            0.0,
+            ExchangerErr::CapacityRateLow
+        ),
+    }
+}
@@ -0,0 +1,2 @@
+pub mod convection;
+pub mod exchangers;
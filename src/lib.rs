@@ -1,10 +1,12 @@
 use wasm_bindgen::prelude::*;
 
-mod fluids;
-mod numerical_methods;
-mod thermo;
+pub mod constants;
+pub mod fluids;
+pub mod heat_transfer;
+pub mod numerical_methods;
+pub mod thermo;
 mod ui;
-mod units;
+pub mod units;
 
 #[wasm_bindgen(start)]
 pub fn run_app() {
@@ -1,7 +1,10 @@
+pub mod multivariate;
+pub mod optimizers;
 pub mod root_finders;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RootFinderErr {
     ToleranceBelowZero,
     MaxIterationsReached,
+    SingularMatrix,
 }
@@ -0,0 +1,159 @@
+use super::*;
+
+/// Solves A * x = b by Gaussian elimination with partial pivoting
+pub fn solve_linear_system(matrix: &[Vec<f64>], rhs: &[f64]) -> Result<Vec<f64>, RootFinderErr> {
+    let n = rhs.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut b = rhs.to_vec();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|x, y| a[*x][col].abs().total_cmp(&a[*y][col].abs()))
+            .unwrap_or(col);
+        if a[pivot][col] == 0f64 || !a[pivot][col].is_finite() {
+            return Err(RootFinderErr::SingularMatrix);
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in (col + 1)..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, pivot_value) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot_value;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0f64; n];
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Ok(x)
+}
+
+fn get_norm(y: &[f64]) -> f64 {
+    y.iter().map(|x| x.abs()).fold(0f64, f64::max)
+}
+
+/// Newton-Raphson for a system f(x) = 0 using a forward difference Jacobian,
+/// steps are halved while the residual is not finite or grows
+pub fn newton_raphson_system(
+    f: impl Fn(&[f64]) -> Vec<f64>,
+    x0: &[f64],
+    tol: f64,
+) -> Result<Vec<f64>, RootFinderErr> {
+    let max_iter = 50;
+    if tol < 0.0 {
+        return Err(RootFinderErr::ToleranceBelowZero);
+    }
+
+    let mut x = x0.to_vec();
+    let mut y = f(&x);
+    for _ in 0..max_iter {
+        let norm = get_norm(&y);
+        if norm <= tol {
+            return Ok(x);
+        }
+        if !norm.is_finite() {
+            break;
+        }
+        let mut jacobian = vec![vec![0f64; x.len()]; y.len()];
+        for j in 0..x.len() {
+            let h = 1e-7 * f64::max(x[j].abs(), 1f64);
+            let mut x_step = x.clone();
+            x_step[j] += h;
+            let y_step = f(&x_step);
+            for i in 0..y.len() {
+                jacobian[i][j] = (y_step[i] - y[i]) / h;
+            }
+        }
+        let neg_y: Vec<f64> = y.iter().map(|v| -v).collect();
+        let dx = solve_linear_system(&jacobian, &neg_y)?;
+        let mut damping = 1f64;
+        let (mut x_new, mut y_new) = (x.clone(), y.clone());
+        for _ in 0..20 {
+            x_new = x.iter().zip(&dx).map(|(x, dx)| x + damping * dx).collect();
+            y_new = f(&x_new);
+            let new_norm = get_norm(&y_new);
+            if new_norm.is_finite() && new_norm < norm {
+                break;
+            }
+            damping /= 2f64;
+        }
+        x = x_new;
+        y = y_new;
+    }
+    Err(RootFinderErr::MaxIterationsReached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn linear_system() {
+        let matrix = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, -2.0, -3.0],
+            vec![-1.0, 1.0, 2.0],
+        ];
+        let x = solve_linear_system(&matrix, &[-8.0, 0.0, 3.0]).unwrap();
+        assert_approx_eq!(-4.0, x[0], 1e-12);
+        assert_approx_eq!(-5.0, x[1], 1e-12);
+        assert_approx_eq!(2.0, x[2], 1e-12);
+    }
+
+    #[test]
+    fn singular_linear_system() {
+        let matrix = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert_eq!(
+            Err(RootFinderErr::SingularMatrix),
+            solve_linear_system(&matrix, &[1.0, 2.0])
+        );
+    }
+
+    macro_rules! newton_raphson_system_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (f, x0, expected): (fn(&[f64]) -> Vec<f64>, Vec<f64>, Vec<f64>) = $value;
+                let x = newton_raphson_system(f, &x0, 1e-10).unwrap();
+                for (expected, actual) in expected.iter().zip(&x) {
+                    assert_approx_eq!(expected, actual, 1e-6);
+                }
+            }
+        )*
+        }
+    }
+
+    newton_raphson_system_tests! {
+        circle_and_line: (
+            |x| vec![x[0] * x[0] + x[1] * x[1] - 4.0, x[0] - x[1]],
+            vec![1.0, 2.0],
+            vec![f64::sqrt(2.0), f64::sqrt(2.0)],
+        ),
+        exponential_system: (
+            |x| vec![f64::exp(x[0]) - x[1], x[0] + x[1] - 1.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+        ),
+        rosenbrock_gradient: (
+            |x| vec![
+                -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+                200.0 * (x[1] - x[0] * x[0]),
+            ],
+            vec![0.8, 0.6],
+            vec![1.0, 1.0],
+        ),
+    }
+
+    #[test]
+    fn newton_raphson_system_negative_tolerance() {
+        assert_eq!(
+            Err(RootFinderErr::ToleranceBelowZero),
+            newton_raphson_system(|x| vec![x[0]], &[1.0], -1.0)
+        );
+    }
+}
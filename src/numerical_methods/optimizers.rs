@@ -0,0 +1,72 @@
+use super::*;
+
+/// Golden section search for the minimum of a unimodal function between lower and upper,
+/// stops once the bracket is narrower than tol
+pub fn golden_section_search(
+    f: impl Fn(f64) -> f64,
+    lower: f64,
+    upper: f64,
+    tol: f64,
+) -> Result<f64, RootFinderErr> {
+    let max_iter = 200;
+    if tol <= 0.0 {
+        return Err(RootFinderErr::ToleranceBelowZero);
+    }
+    let ratio = (f64::sqrt(5f64) - 1f64) / 2f64;
+    let (mut a, mut b) = (f64::min(lower, upper), f64::max(lower, upper));
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let mut fc = f(c);
+    let mut fd = f(d);
+    for _ in 0..max_iter {
+        if b - a < tol {
+            return Ok((a + b) / 2f64);
+        }
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - ratio * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + ratio * (b - a);
+            fd = f(d);
+        }
+    }
+    Err(RootFinderErr::MaxIterationsReached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! golden_section_search_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (f, expected) : (fn(f64) -> f64, f64) = $value;
+                let x = golden_section_search(f, -3.0, 5.0, 1e-8).unwrap();
+                assert_approx_eq!(expected, x, 1e-6);
+            }
+        )*
+        }
+    }
+
+    golden_section_search_tests! {
+        golden_section_search_01: (|x| x * x - 2.0 * x - 1.0, 1.0),
+        golden_section_search_02: (|x| x.exp() - 3.0 * x, f64::ln(3.0)),
+    }
+
+    #[test]
+    fn golden_section_search_should_rtn_err_if_negative_torr() {
+        assert_eq!(
+            RootFinderErr::ToleranceBelowZero,
+            golden_section_search(|x| x * x, -1.0, 1.0, -1e-6).unwrap_err()
+        );
+    }
+}
@@ -40,6 +40,31 @@ pub fn secant_method(f: impl Fn(f64) -> f64, x0: f64, tol: f64) -> Result<f64, R
     solver((x0, y0), (x1, y1), 0, f, max_iter, tol)
 }
 
+pub fn newton_method(
+    f: impl Fn(f64) -> f64,
+    df: impl Fn(f64) -> f64,
+    x0: f64,
+    tol: f64,
+) -> Result<f64, RootFinderErr> {
+    let max_iter = 50;
+    if tol < 0.0 {
+        return Err(RootFinderErr::ToleranceBelowZero);
+    }
+
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let y = f(x);
+        if relative_eq!(0.0, y, epsilon = tol) {
+            return Ok(x);
+        }
+        x -= y / df(x);
+        if !x.is_finite() {
+            break;
+        }
+    }
+    Err(RootFinderErr::MaxIterationsReached)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +88,40 @@ mod tests {
         secant_method_02: |x| x.exp() - x.sin(),
     }
 
+    macro_rules! newton_method_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (f, df) : (fn(f64) -> f64, fn(f64) -> f64) = $value;
+                let x = newton_method(f, df, 3.0, 1e-6).unwrap();
+                assert_approx_eq!(0.0, f(x));
+            }
+        )*
+        }
+    }
+
+    newton_method_tests! {
+        newton_method_01: (|x| x * x - 2.0 * x - 1.0, |x| 2.0 * x - 2.0),
+        newton_method_02: (|x| x.exp() - 10.0, |x| x.exp()),
+    }
+
+    #[test]
+    fn newton_should_rtn_err_if_negative_torr() {
+        assert_eq!(
+            RootFinderErr::ToleranceBelowZero,
+            newton_method(|x| x, |_| 1f64, 3.0, -1e-6).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn newton_should_rtn_err_if_cannot_converge() {
+        assert_eq!(
+            RootFinderErr::MaxIterationsReached,
+            newton_method(|x| x * x + 1.0, |x| 2.0 * x, 3.0, 1e-15).unwrap_err()
+        );
+    }
+
     #[test]
     fn secant_should_rtn_err_if_negative_torr() {
         assert_eq!(
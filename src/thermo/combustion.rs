@@ -0,0 +1,464 @@
+// Complete combustion of gaseous fuels in dry air, carbon burns to CO2, hydrogen to H2O
+// and nitrogen leaves as N2. Dissociation is left out so the flame temperature is an upper bound.
+use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::*;
+use crate::thermo::ideal_gas::*;
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::ice::get_sublimation_temperature;
+use crate::thermo::steam::*;
+use crate::units::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CombustionErr {
+    // The fuel does not need any oxygen to burn
+    NoCombustibles,
+    NegativeExcessAir,
+    // No amount of excess air gives the measured flue gas fraction
+    FlueGasFractionOutOfRange,
+    IdealGasErr(IdealGasErr),
+    SteamQueryErr(SteamQueryErr),
+    FailedToConverge(RootFinderErr),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Combustion {
+    // Mole fractions of the fuel, inerts like N2 and CO2 are allowed
+    pub fuel: Mixture,
+    // Air supplied beyond the stoichiometric amount, 0.2 is 20 % excess air
+    pub excess_air: f64,
+    pub fuel_temperature: Temperature,
+    pub air_temperature: Temperature,
+    pub pressure: Pressure,
+}
+
+/// Heating values are per unit mass of fuel at 25 °C
+#[derive(Debug, PartialEq, Clone)]
+pub struct CombustionResult {
+    // kg of air per kg of fuel
+    pub stoichiometric_air_fuel_ratio: f64,
+    pub air_fuel_ratio: f64,
+    // Wet basis
+    pub flue_gas: Mixture,
+    // Water removed, as measured by most flue gas analyzers
+    pub dry_flue_gas: Mixture,
+    // Moles of flue gas per mole of fuel
+    pub flue_gas_moles: f64,
+    // None when the flue gas holds no water
+    pub dew_point: Option<Temperature>,
+    pub higher_heating_value: EnergyPerMass,
+    pub lower_heating_value: EnergyPerMass,
+    pub adiabatic_flame_temperature: Temperature,
+}
+
+/// Moles of O2 needed to burn a mole of fuel completely
+pub fn get_stoichiometric_oxygen(fuel: &Mixture) -> f64 {
+    fuel.get_mole_fractions()
+        .iter()
+        .map(|(species, x)| {
+            x * (species.get_element_count(Element::C)
+                + species.get_element_count(Element::H) / 4f64
+                - species.get_element_count(Element::O) / 2f64)
+        })
+        .sum()
+}
+
+fn get_air_oxygen_fraction() -> f64 {
+    get_fraction(&Mixture::air(), Species::O2)
+}
+
+fn get_fraction(mixture: &Mixture, species: Species) -> f64 {
+    mixture
+        .get_mole_fractions()
+        .iter()
+        .filter(|(x, _)| *x == species)
+        .map(|(_, x)| x)
+        .sum()
+}
+
+/// Moles of air per mole of fuel at zero excess air
+fn get_stoichiometric_air(fuel: &Mixture) -> Result<f64, CombustionErr> {
+    let oxygen = get_stoichiometric_oxygen(fuel);
+    if oxygen <= 0f64 {
+        return Err(CombustionErr::NoCombustibles);
+    }
+    Ok(oxygen / get_air_oxygen_fraction())
+}
+
+// Moles of each product per mole of fuel
+fn get_product_moles(
+    fuel: &Mixture,
+    excess_air: f64,
+) -> Result<Vec<(Species, f64)>, CombustionErr> {
+    let air_moles = get_stoichiometric_air(fuel)? * (1f64 + excess_air);
+    let count = |element| -> f64 {
+        fuel.get_mole_fractions()
+            .iter()
+            .map(|(species, x)| x * species.get_element_count(element))
+            .sum()
+    };
+    let air = Mixture::air();
+    let from_air = |species| air_moles * get_fraction(&air, species);
+    Ok(vec![
+        (Species::CO2, count(Element::C) + from_air(Species::CO2)),
+        (Species::H2O, count(Element::H) / 2f64),
+        (
+            Species::N2,
+            count(Element::N) / 2f64 + from_air(Species::N2),
+        ),
+        (Species::O2, excess_air * get_stoichiometric_oxygen(fuel)),
+        (Species::Ar, count(Element::Ar) + from_air(Species::Ar)),
+        (Species::He, count(Element::He)),
+    ]
+    .into_iter()
+    .filter(|(_, moles)| *moles > 0f64)
+    .collect())
+}
+
+fn to_mixture(moles: &[(Species, f64)]) -> Result<Mixture, CombustionErr> {
+    let total: f64 = moles.iter().map(|(_, x)| x).sum();
+    Mixture::from_mole_fractions(
+        moles
+            .iter()
+            .map(|(species, x)| (*species, x / total))
+            .collect(),
+    )
+    .map_err(CombustionErr::IdealGasErr)
+}
+
+fn get_dry_moles(moles: &[(Species, f64)]) -> Vec<(Species, f64)> {
+    moles
+        .iter()
+        .filter(|(species, _)| *species != Species::H2O)
+        .cloned()
+        .collect()
+}
+
+/// Higher and lower heating values, the higher one condenses the water formed by the fuel
+pub fn get_heating_values(fuel: &Mixture) -> Result<(EnergyPerMass, EnergyPerMass), CombustionErr> {
+    let products = get_product_moles(fuel, 0f64)?;
+    let oxygen = get_stoichiometric_oxygen(fuel);
+    let temperature = REFERENCE_TEMPERATURE;
+    let enthalpy = |species: Species| {
+        species
+            .get_molar_enthalpy(temperature)
+            .map_err(CombustionErr::IdealGasErr)
+    };
+    // Inerts and the air's own N2, Ar and CO2 pass through unchanged
+    let reactants = fuel
+        .get_molar_enthalpy(temperature)
+        .map_err(CombustionErr::IdealGasErr)?
+        + oxygen * enthalpy(Species::O2)?;
+    let air_carried =
+        |species| get_stoichiometric_air(fuel).map(|x| x * get_fraction(&Mixture::air(), species));
+    let mut product_enthalpy = 0f64;
+    for (species, moles) in products.iter() {
+        let formed = match species {
+            Species::O2 => 0f64,
+            Species::N2 | Species::Ar | Species::CO2 => moles - air_carried(*species)?,
+            _ => *moles,
+        };
+        product_enthalpy += formed * enthalpy(*species)?;
+    }
+    let molar_mass = fuel.get_molar_mass();
+    let lower = (reactants - product_enthalpy) / molar_mass;
+
+    let latent_heat = |phase_region| {
+        Iapws97
+            .get_steam_table_entry(SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::K(K::new(temperature)),
+                phase_region,
+            }))
+            .map(|x| x.enthalpy.convert_to_si_unit().value)
+            .map_err(CombustionErr::SteamQueryErr)
+    };
+    let water_formed = products
+        .iter()
+        .filter(|(species, _)| *species == Species::H2O)
+        .map(|(_, x)| x)
+        .sum::<f64>()
+        - get_fraction(fuel, Species::H2O);
+    let higher = lower
+        + water_formed
+            * Species::H2O.get_molar_mass()
+            * (latent_heat(SteamNonCriticalPhaseRegion::Vapor)?
+                - latent_heat(SteamNonCriticalPhaseRegion::Liquid)?)
+            / molar_mass;
+    Ok((
+        EnergyPerMass::JPerKg(JPerKg::new(higher)),
+        EnergyPerMass::JPerKg(JPerKg::new(lower)),
+    ))
+}
+
+/// Excess air from the O2 mole fraction of the dry flue gas
+pub fn get_excess_air_from_dry_oxygen(
+    fuel: &Mixture,
+    oxygen_fraction: f64,
+) -> Result<f64, CombustionErr> {
+    let air = get_stoichiometric_air(fuel)?;
+    let dry_moles: f64 = get_dry_moles(&get_product_moles(fuel, 0f64)?)
+        .iter()
+        .map(|(_, x)| x)
+        .sum();
+    // Each unit of excess air adds its O2 and the whole air to the dry flue gas
+    let excess_air =
+        oxygen_fraction * dry_moles / (get_stoichiometric_oxygen(fuel) - oxygen_fraction * air);
+    if oxygen_fraction.is_nan() || excess_air.is_nan() || excess_air < 0f64 {
+        Err(CombustionErr::FlueGasFractionOutOfRange)
+    } else {
+        Ok(excess_air)
+    }
+}
+
+/// Excess air from the CO2 mole fraction of the dry flue gas
+pub fn get_excess_air_from_dry_carbon_dioxide(
+    fuel: &Mixture,
+    carbon_dioxide_fraction: f64,
+) -> Result<f64, CombustionErr> {
+    let air = get_stoichiometric_air(fuel)?;
+    let dry = get_dry_moles(&get_product_moles(fuel, 0f64)?);
+    let dry_moles: f64 = dry.iter().map(|(_, x)| x).sum();
+    let carbon_dioxide: f64 = dry
+        .iter()
+        .filter(|(species, _)| *species == Species::CO2)
+        .map(|(_, x)| x)
+        .sum();
+    let air_carbon_dioxide = get_fraction(&Mixture::air(), Species::CO2);
+    // Excess air dilutes the CO2 but also carries a little of its own
+    let excess_air = (carbon_dioxide - carbon_dioxide_fraction * dry_moles)
+        / (air * (carbon_dioxide_fraction - air_carbon_dioxide));
+    if carbon_dioxide_fraction.is_nan() || excess_air.is_nan() || excess_air < 0f64 {
+        Err(CombustionErr::FlueGasFractionOutOfRange)
+    } else {
+        Ok(excess_air)
+    }
+}
+
+/// Saturation temperature of the water's partial pressure in the flue gas,
+/// below the triple point pressure the water frosts out as ice instead
+fn get_dew_point(flue_gas: &Mixture, pressure: f64) -> Result<Option<Temperature>, CombustionErr> {
+    let water = get_fraction(flue_gas, Species::H2O);
+    if water <= 0f64 {
+        return Ok(None);
+    }
+    let vapor_pressure = Pressure::Pa(Pa::new(water * pressure));
+    match Iapws97.get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+        pressure: vapor_pressure,
+        phase_region: SteamNonCriticalPhaseRegion::Vapor,
+    })) {
+        Ok(x) => Ok(x.temperature),
+        Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow)) => {
+            get_sublimation_temperature(vapor_pressure)
+        }
+        Err(x) => Err(x),
+    }
+    .map(Some)
+    .map_err(CombustionErr::SteamQueryErr)
+}
+
+pub fn get_combustion(combustion: &Combustion) -> Result<CombustionResult, CombustionErr> {
+    if combustion.excess_air.is_nan() || combustion.excess_air < 0f64 {
+        return Err(CombustionErr::NegativeExcessAir);
+    }
+    let fuel = &combustion.fuel;
+    let stoichiometric_air = get_stoichiometric_air(fuel)?;
+    let air_moles = stoichiometric_air * (1f64 + combustion.excess_air);
+    let air = Mixture::air();
+    let product_moles = get_product_moles(fuel, combustion.excess_air)?;
+    let flue_gas_moles: f64 = product_moles.iter().map(|(_, x)| x).sum();
+    let flue_gas = to_mixture(&product_moles)?;
+    let dry_flue_gas = to_mixture(&get_dry_moles(&product_moles))?;
+    let pressure = combustion.pressure.convert_to_si_unit().value;
+    let (higher_heating_value, lower_heating_value) = get_heating_values(fuel)?;
+
+    // Reactant enthalpy per mole of fuel is carried by the products at the flame temperature
+    let reactant_enthalpy = fuel
+        .get_molar_enthalpy(combustion.fuel_temperature.convert_to_si_unit().value)
+        .and_then(|fuel_enthalpy| {
+            air.get_molar_enthalpy(combustion.air_temperature.convert_to_si_unit().value)
+                .map(|air_enthalpy| fuel_enthalpy + air_moles * air_enthalpy)
+        })
+        .map_err(CombustionErr::IdealGasErr)?;
+    let f = |temperature| match flue_gas.get_molar_enthalpy(temperature) {
+        Ok(enthalpy) => (flue_gas_moles * enthalpy - reactant_enthalpy) / flue_gas_moles,
+        Err(_) => f64::NAN,
+    };
+    let adiabatic_flame_temperature =
+        secant_method(f, 2000f64, 1e-6).map_err(CombustionErr::FailedToConverge)?;
+
+    Ok(CombustionResult {
+        stoichiometric_air_fuel_ratio: stoichiometric_air * air.get_molar_mass()
+            / fuel.get_molar_mass(),
+        air_fuel_ratio: air_moles * air.get_molar_mass() / fuel.get_molar_mass(),
+        dew_point: get_dew_point(&flue_gas, pressure)?,
+        flue_gas,
+        dry_flue_gas,
+        flue_gas_moles,
+        higher_heating_value,
+        lower_heating_value,
+        adiabatic_flame_temperature: Temperature::K(K::new(adiabatic_flame_temperature)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::ice::get_sublimation_pressure;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_standard_combustion(fuel: Species, excess_air: f64) -> CombustionResult {
+        get_combustion(&Combustion {
+            fuel: Mixture::pure(fuel),
+            excess_air,
+            fuel_temperature: Temperature::K(K::new(298.15)),
+            air_temperature: Temperature::K(K::new(298.15)),
+            pressure: Pressure::Pa(Pa::new(101325.0)),
+        })
+        .unwrap()
+    }
+
+    macro_rules! heating_value_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (fuel, MJ/kg HHV, MJ/kg LHV, kg/kg stoichiometric air fuel ratio)
+                let (fuel, higher, lower, air_fuel_ratio): (Species, f64, f64, f64) = $value;
+                let result = get_standard_combustion(fuel, 0f64);
+                let to_mj = |x: EnergyPerMass| x.convert_to_si_unit().value / 1e6;
+                assert_approx_eq!(higher, to_mj(result.higher_heating_value), 0.005 * higher);
+                assert_approx_eq!(lower, to_mj(result.lower_heating_value), 0.005 * lower);
+                assert_approx_eq!(air_fuel_ratio, result.stoichiometric_air_fuel_ratio, 0.02);
+            }
+        )*
+        }
+    }
+
+    // Heating values from Turns, An Introduction to Combustion, appendix B. Air fuel ratios
+    // use dry air with argon rather than the 3.76 N2 per O2 approximation.
+    heating_value_tests! {
+        methane_heating_values: (Species::CH4, 55.528, 50.016, 17.24),
+        ethane_heating_values: (Species::C2H6, 51.901, 47.489, 16.10),
+        propane_heating_values: (Species::C3H8, 50.368, 46.357, 15.68),
+        hydrogen_heating_values: (Species::H2, 141.8, 119.95, 34.30),
+        carbon_monoxide_heating_values: (Species::CO, 10.1, 10.1, 2.47),
+    }
+
+    #[test]
+    fn methane_flue_gas() {
+        let result = get_standard_combustion(Species::CH4, 0f64);
+        // CH4 + 2 O2 gives 1 CO2 and 2 H2O with 2 / 0.20946 moles of air
+        assert_approx_eq!(1f64 + 2f64 / 0.20946, result.flue_gas_moles, 1e-9);
+        assert_approx_eq!(
+            2f64 / result.flue_gas_moles,
+            get_fraction(&result.flue_gas, Species::H2O),
+            1e-9
+        );
+        assert_eq!(0f64, get_fraction(&result.flue_gas, Species::O2));
+        assert_eq!(0f64, get_fraction(&result.dry_flue_gas, Species::H2O));
+        let dew_point = result.dew_point.unwrap().convert_to_si_unit().value - 273.15;
+        assert_approx_eq!(59.0, dew_point, 0.5);
+    }
+
+    #[test]
+    fn frost_point_below_triple_point() {
+        // Syngas that is mostly CO burned with lots of excess air leaves little water
+        let result = get_combustion(&Combustion {
+            fuel: Mixture::from_mole_fractions(vec![(Species::CO, 0.95), (Species::H2, 0.05)])
+                .unwrap(),
+            excess_air: 3.0,
+            fuel_temperature: Temperature::K(K::new(298.15)),
+            air_temperature: Temperature::K(K::new(298.15)),
+            pressure: Pressure::Pa(Pa::new(101325.0)),
+        })
+        .unwrap();
+        let vapor_pressure = 101325.0 * get_fraction(&result.flue_gas, Species::H2O);
+        assert!(vapor_pressure < 611.657);
+        let dew_point = result.dew_point.unwrap();
+        assert!(dew_point.convert_to_si_unit().value < 273.16);
+        assert_approx_eq!(
+            vapor_pressure,
+            get_sublimation_pressure(dew_point)
+                .unwrap()
+                .convert_to_si_unit()
+                .value,
+            1e-3
+        );
+        // Pure CO leaves no water at all
+        assert_eq!(None, get_standard_combustion(Species::CO, 3.0).dew_point);
+    }
+
+    #[test]
+    fn adiabatic_flame_temperature() {
+        // Complete combustion without dissociation
+        let stoichiometric = get_standard_combustion(Species::CH4, 0f64);
+        let flame = |x: &CombustionResult| x.adiabatic_flame_temperature.convert_to_si_unit().value;
+        assert_approx_eq!(2328.0, flame(&stoichiometric), 15.0);
+        let lean = get_standard_combustion(Species::CH4, 0.5);
+        assert!(flame(&lean) < flame(&stoichiometric));
+        // Preheated air raises the flame temperature
+        let preheated = get_combustion(&Combustion {
+            fuel: Mixture::pure(Species::CH4),
+            excess_air: 0f64,
+            fuel_temperature: Temperature::K(K::new(298.15)),
+            air_temperature: Temperature::K(K::new(600.0)),
+            pressure: Pressure::Pa(Pa::new(101325.0)),
+        })
+        .unwrap();
+        assert!(flame(&preheated) > flame(&stoichiometric) + 150.0);
+    }
+
+    #[test]
+    fn excess_air_from_flue_gas() {
+        let fuel = Mixture::from_mole_fractions(vec![
+            (Species::CH4, 0.9),
+            (Species::C2H6, 0.05),
+            (Species::N2, 0.03),
+            (Species::CO2, 0.02),
+        ])
+        .unwrap();
+        let result = get_combustion(&Combustion {
+            fuel: fuel.clone(),
+            excess_air: 0.15,
+            fuel_temperature: Temperature::K(K::new(298.15)),
+            air_temperature: Temperature::K(K::new(298.15)),
+            pressure: Pressure::Pa(Pa::new(101325.0)),
+        })
+        .unwrap();
+        let oxygen = get_fraction(&result.dry_flue_gas, Species::O2);
+        let carbon_dioxide = get_fraction(&result.dry_flue_gas, Species::CO2);
+        assert_approx_eq!(
+            0.15,
+            get_excess_air_from_dry_oxygen(&fuel, oxygen).unwrap(),
+            1e-9
+        );
+        assert_approx_eq!(
+            0.15,
+            get_excess_air_from_dry_carbon_dioxide(&fuel, carbon_dioxide).unwrap(),
+            1e-9
+        );
+        // About 3 % O2 in the dry flue gas of natural gas is 15 % excess air
+        assert_approx_eq!(0.028, oxygen, 0.002);
+    }
+
+    #[test]
+    fn combustion_errors() {
+        assert_eq!(
+            Err(CombustionErr::NoCombustibles),
+            get_heating_values(&Mixture::pure(Species::N2))
+        );
+        assert_eq!(
+            Err(CombustionErr::FlueGasFractionOutOfRange),
+            get_excess_air_from_dry_oxygen(&Mixture::pure(Species::CH4), 0.25)
+        );
+        assert_eq!(
+            Err(CombustionErr::NegativeExcessAir),
+            get_combustion(&Combustion {
+                fuel: Mixture::pure(Species::CH4),
+                excess_air: -0.1,
+                fuel_temperature: Temperature::K(K::new(298.15)),
+                air_temperature: Temperature::K(K::new(298.15)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            })
+        );
+    }
+}
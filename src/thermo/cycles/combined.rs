@@ -0,0 +1,167 @@
+use super::gas::*;
+use super::rankine::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CombinedCycle {
+    // Topping cycle whose exhaust raises the steam
+    pub gas_cycle: BraytonCycle,
+    // Bottoming cycle heated only by the gas turbine exhaust
+    pub steam_cycle: RankineCycle,
+    // Exhaust gas leaving the heat recovery steam generator
+    pub stack_temperature: Temperature,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CombinedCycleErr {
+    // The stack must be colder than the gas turbine exhaust and hotter than the boiler feed water
+    StackTemperatureOutOfOrder,
+    // The steam can not leave the heat recovery steam generator hotter than the exhaust entering it
+    SteamHotterThanExhaust,
+    GasCycleErr(GasCycleErr),
+    RankineCycleErr(RankineCycleErr),
+}
+
+/// Work and heat are per unit mass of air
+#[derive(Debug, PartialEq, Clone)]
+pub struct CombinedCycleResult {
+    pub gas_cycle: BraytonCycleResult,
+    // Per unit mass of steam
+    pub steam_cycle: RankineCycleResult,
+    // Steam raised per unit mass of exhaust gas
+    pub steam_to_air_ratio: f64,
+    pub net_work: EnergyPerMass,
+    pub heat_input: EnergyPerMass,
+    pub heat_rejected: EnergyPerMass,
+    pub thermal_efficiency: f64,
+}
+
+fn get_temperature(entry: &PtvEntry) -> f64 {
+    entry.temperature.convert_to_si_unit().value
+}
+
+/// Brayton topping cycle with an IAPWS-IF97 Rankine bottoming cycle,
+/// all of the steam cycle's heat comes from cooling the gas turbine exhaust to the stack temperature
+pub fn get_combined_cycle(cycle: &CombinedCycle) -> Result<CombinedCycleResult, CombinedCycleErr> {
+    let gas_cycle = get_brayton_cycle(&cycle.gas_cycle).map_err(CombinedCycleErr::GasCycleErr)?;
+    let steam_cycle = get_rankine_cycle(&Iapws97, &cycle.steam_cycle)
+        .map_err(CombinedCycleErr::RankineCycleErr)?;
+
+    // The gas leaves the last state of the Brayton cycle to reject its heat
+    let exhaust = &gas_cycle.states[gas_cycle.states.len() - 1].entry;
+    let stack_temperature = cycle.stack_temperature.convert_to_si_unit().value;
+    let boiler_feed = steam_cycle
+        .states
+        .iter()
+        .zip(steam_cycle.states.iter().skip(1))
+        .find(|(_, next)| {
+            next.entry.pressure == cycle.steam_cycle.boiler_pressure
+                && next.process == ProcessKind::Isobaric
+        })
+        .map(|(feed, _)| get_temperature(&feed.entry))
+        .unwrap_or(f64::MIN);
+    if stack_temperature >= get_temperature(exhaust) || stack_temperature <= boiler_feed {
+        return Err(CombinedCycleErr::StackTemperatureOutOfOrder);
+    }
+    let hottest_steam = steam_cycle
+        .states
+        .iter()
+        .map(|x| get_temperature(&x.entry))
+        .fold(f64::MIN, f64::max);
+    if hottest_steam >= get_temperature(exhaust) {
+        return Err(CombinedCycleErr::SteamHotterThanExhaust);
+    }
+
+    let stack = cycle
+        .gas_cycle
+        .air_standard
+        .get_entry(
+            stack_temperature,
+            exhaust.pressure.convert_to_si_unit().value,
+        )
+        .map_err(CombinedCycleErr::GasCycleErr)?;
+    let recovered_heat =
+        exhaust.enthalpy.convert_to_si_unit().value - stack.enthalpy.convert_to_si_unit().value;
+    let value = |x: EnergyPerMass| x.convert_to_si_unit().value;
+    let steam_to_air_ratio = recovered_heat / value(steam_cycle.heat_input);
+    let net_work = value(gas_cycle.net_work) + steam_to_air_ratio * value(steam_cycle.net_work);
+    let heat_input = value(gas_cycle.heat_input);
+    let to_energy = |x| EnergyPerMass::JPerKg(JPerKg::new(x));
+    Ok(CombinedCycleResult {
+        steam_to_air_ratio,
+        net_work: to_energy(net_work),
+        heat_input: to_energy(heat_input),
+        heat_rejected: to_energy(heat_input - net_work),
+        thermal_efficiency: net_work / heat_input,
+        gas_cycle,
+        steam_cycle,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Gas side of example 9-6 of Cengel and Boles, Thermodynamics: An Engineering Approach,
+    // raising steam for an ideal Rankine cycle as in example 10-9
+    macro_rules! combined_cycle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (stack_k, steam_turbine_inlet_c, expected): (
+                    f64,
+                    f64,
+                    Result<(f64, f64), CombinedCycleErr>,
+                ) = $value;
+                let cycle = CombinedCycle {
+                    gas_cycle: BraytonCycle {
+                        air_standard: AirStandard::Variable,
+                        compressor_inlet_pressure: Pressure::KPa(KPa::new(100.0)),
+                        compressor_inlet_temperature: Temperature::K(K::new(300.0)),
+                        pressure_ratio: 8.0,
+                        turbine_inlet_temperature: Temperature::K(K::new(1300.0)),
+                        compressor_efficiency: 0.8,
+                        turbine_efficiency: 0.85,
+                        regenerator_effectiveness: None,
+                        compression_stages: 1,
+                        expansion_stages: 1,
+                    },
+                    steam_cycle: RankineCycle {
+                        boiler_pressure: Pressure::Pa(Pa::new(8e6)),
+                        turbine_inlet_temperature: Temperature::C(C::new(steam_turbine_inlet_c)),
+                        condenser_pressure: Pressure::KPa(KPa::new(5.0)),
+                        turbine_efficiency: 1f64,
+                        pump_efficiency: 1f64,
+                        reheat: None,
+                        feedwater_heater_pressures: vec![],
+                    },
+                    stack_temperature: Temperature::K(K::new(stack_k)),
+                };
+                match (expected, get_combined_cycle(&cycle)) {
+                    (Ok((steam_to_air_ratio, thermal_efficiency)), Ok(result)) => {
+                        assert_approx_eq!(steam_to_air_ratio, result.steam_to_air_ratio, 0.003);
+                        assert_approx_eq!(thermal_efficiency, result.thermal_efficiency, 0.005);
+                        assert!(result.thermal_efficiency > result.gas_cycle.thermal_efficiency);
+                        assert!(result.thermal_efficiency > result.steam_cycle.thermal_efficiency);
+                    }
+                    (Err(expected), actual) => assert_eq!(Some(expected), actual.err()),
+                    (Ok(_), Err(err)) => panic!("{:?}", err),
+                }
+            }
+        )*
+        }
+    }
+
+    combined_cycle_tests! {
+        // Worked by hand from the air and steam tables
+        combined_cycle: (450.0, 400.0, Ok((0.1452, 0.485))),
+        stack_hotter_than_exhaust: (
+            900.0,
+            400.0,
+            Err(CombinedCycleErr::StackTemperatureOutOfOrder)
+        ),
+        steam_hotter_than_exhaust: (450.0, 700.0, Err(CombinedCycleErr::SteamHotterThanExhaust)),
+    }
+}
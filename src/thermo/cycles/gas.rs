@@ -0,0 +1,913 @@
+use super::*;
+use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::*;
+use crate::thermo::ideal_gas::*;
+
+// J/(kg * K), air at room temperature
+static COLD_AIR_CP: f64 = 1005.0;
+static COLD_AIR_GAS_CONSTANT: f64 = 287.0;
+
+/// How the air's specific heats are modelled
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AirStandard {
+    // Constant specific heats of air at room temperature
+    Cold,
+    // Specific heats change with temperature following the NASA polynomials of dry air
+    Variable,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GasCycleErr {
+    InvalidEfficiency,
+    InvalidEffectiveness,
+    // Compression, pressure and cutoff ratios must be above 1
+    InvalidRatio,
+    // Brayton cycles need at least one compressor and one turbine stage
+    NoStages,
+    // The turbine inlet must be hotter than the compressor outlet
+    TemperatureOutOfOrder,
+    // The turbine exhaust is colder than the compressor outlet so a regenerator can not work
+    RegeneratorTemperatureCross,
+    HeatInputMustBePositive,
+    IdealGasErr(IdealGasErr),
+    FailedToConverge(RootFinderErr),
+}
+
+impl AirStandard {
+    /// J/(kg * K)
+    pub fn get_gas_constant(&self) -> f64 {
+        match self {
+            AirStandard::Cold => COLD_AIR_GAS_CONSTANT,
+            AirStandard::Variable => Mixture::air().get_gas_constant(),
+        }
+    }
+
+    /// Properties of air, temperature in K and pressure in Pa
+    pub fn get_entry(&self, temperature: f64, pressure: f64) -> Result<PtvEntry, GasCycleErr> {
+        let point = PtPoint {
+            temperature: Temperature::K(K::new(temperature)),
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        };
+        match self {
+            AirStandard::Cold => get_cold_air_entry(point),
+            AirStandard::Variable => {
+                get_ideal_gas_entry(&Mixture::air(), point).map_err(GasCycleErr::IdealGasErr)
+            }
+        }
+    }
+
+    // Temperature where the residual of the entry is zero starting from a guess,
+    // the pressure may follow the temperature
+    fn find_entry(
+        &self,
+        guess: f64,
+        pressure: impl Fn(f64) -> f64,
+        residual: impl Fn(&PtvEntry) -> f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        let f = |temperature: f64| match self.get_entry(temperature, pressure(temperature)) {
+            Ok(entry) => residual(&entry),
+            Err(_) => f64::NAN,
+        };
+        let temperature = secant_method(f, guess, 1e-9).map_err(GasCycleErr::FailedToConverge)?;
+        self.get_entry(temperature, pressure(temperature))
+    }
+
+    fn get_entry_from_enthalpy(
+        &self,
+        enthalpy: f64,
+        pressure: f64,
+        guess: f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        self.find_entry(
+            guess,
+            |_| pressure,
+            |x| (x.enthalpy.convert_to_si_unit().value - enthalpy) / COLD_AIR_CP,
+        )
+    }
+
+    // Isentropic compression or expansion in a steady flow device
+    fn get_isentropic_entry(
+        &self,
+        inlet: &PtvEntry,
+        pressure: f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        let entropy = inlet.entropy.convert_to_si_unit().value;
+        let ratio = pressure / inlet.pressure.convert_to_si_unit().value;
+        self.find_entry(
+            inlet.temperature.convert_to_si_unit().value * ratio.powf(0.4 / 1.4),
+            |_| pressure,
+            |x| x.entropy.convert_to_si_unit().value - entropy,
+        )
+    }
+
+    // Isentropic compression or expansion of a closed system to a specific volume
+    fn get_isentropic_entry_from_volume(
+        &self,
+        inlet: &PtvEntry,
+        specific_volume: f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        let entropy = inlet.entropy.convert_to_si_unit().value;
+        let ratio = inlet.specific_volume.convert_to_si_unit().value / specific_volume;
+        let gas_constant = self.get_gas_constant();
+        self.find_entry(
+            inlet.temperature.convert_to_si_unit().value * ratio.powf(0.4),
+            |x| gas_constant * x / specific_volume,
+            |x| x.entropy.convert_to_si_unit().value - entropy,
+        )
+    }
+
+    fn get_entry_from_internal_energy(
+        &self,
+        internal_energy: f64,
+        specific_volume: f64,
+        guess: f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        let gas_constant = self.get_gas_constant();
+        self.find_entry(
+            guess,
+            |x| gas_constant * x / specific_volume,
+            |x| (x.internal_energy.convert_to_si_unit().value - internal_energy) / COLD_AIR_CP,
+        )
+    }
+
+    // The actual enthalpy change is the isentropic change scaled by the efficiency,
+    // divided for a compressor and multiplied for a turbine
+    fn change_pressure(
+        &self,
+        inlet: &PtvEntry,
+        pressure: f64,
+        efficiency: f64,
+    ) -> Result<PtvEntry, GasCycleErr> {
+        let isentropic = self.get_isentropic_entry(inlet, pressure)?;
+        let inlet_enthalpy = inlet.enthalpy.convert_to_si_unit().value;
+        let isentropic_change = isentropic.enthalpy.convert_to_si_unit().value - inlet_enthalpy;
+        let change = if isentropic_change > 0f64 {
+            isentropic_change / efficiency
+        } else {
+            isentropic_change * efficiency
+        };
+        self.get_entry_from_enthalpy(
+            inlet_enthalpy + change,
+            pressure,
+            isentropic.temperature.convert_to_si_unit().value,
+        )
+    }
+}
+
+// Enthalpy and internal energy are zero at 0 K, entropy is zero at 298.15 K and 1 bar
+fn get_cold_air_entry(point: PtPoint) -> Result<PtvEntry, GasCycleErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    if temperature.is_nan() || temperature <= 0f64 {
+        return Err(GasCycleErr::IdealGasErr(IdealGasErr::TemperatureLow));
+    }
+    if pressure.is_nan() || pressure <= 0f64 {
+        return Err(GasCycleErr::IdealGasErr(
+            IdealGasErr::PressureMustBePositive,
+        ));
+    }
+    let cp = COLD_AIR_CP;
+    let gas_constant = COLD_AIR_GAS_CONSTANT;
+    let cv = cp - gas_constant;
+    let enthalpy = cp * temperature;
+    let internal_energy = cv * temperature;
+    let entropy = cp * f64::ln(temperature / REFERENCE_TEMPERATURE)
+        - gas_constant * f64::ln(pressure / STANDARD_PRESSURE);
+    let specific_volume = gas_constant * temperature / pressure;
+    let isentropic_exponent = cp / cv;
+
+    Ok(PtvEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region: PhaseRegion::Gas,
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(f64::sqrt(
+            isentropic_exponent * gas_constant * temperature,
+        ))),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy - temperature * entropy)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(
+            internal_energy - temperature * entropy,
+        )),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(1f64 / temperature)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(1f64 / pressure)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(0f64)),
+        isentropic_exponent,
+    })
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BraytonCycle {
+    pub air_standard: AirStandard,
+    pub compressor_inlet_pressure: Pressure,
+    pub compressor_inlet_temperature: Temperature,
+    // Overall compressor outlet over inlet pressure
+    pub pressure_ratio: f64,
+    pub turbine_inlet_temperature: Temperature,
+    // Isentropic efficiency between 0 and 1, applied to each compressor stage
+    pub compressor_efficiency: f64,
+    // Isentropic efficiency between 0 and 1, applied to each turbine stage
+    pub turbine_efficiency: f64,
+    // Share of the largest possible heat recovered from the exhaust, None without a regenerator
+    pub regenerator_effectiveness: Option<f64>,
+    // Stages share the pressure ratio equally, with intercooling back to the compressor inlet temperature
+    pub compression_stages: u32,
+    // Stages share the pressure ratio equally, with reheat back to the turbine inlet temperature
+    pub expansion_stages: u32,
+}
+
+/// Work and heat are per unit mass of air
+#[derive(Debug, PartialEq, Clone)]
+pub struct BraytonCycleResult {
+    pub states: Vec<CycleState>,
+    pub compressor_work: EnergyPerMass,
+    pub turbine_work: EnergyPerMass,
+    pub net_work: EnergyPerMass,
+    pub heat_input: EnergyPerMass,
+    pub heat_rejected: EnergyPerMass,
+    pub thermal_efficiency: f64,
+    // Compressor work over turbine work
+    pub back_work_ratio: f64,
+}
+
+fn is_efficiency(x: f64) -> bool {
+    x > 0f64 && x <= 1f64
+}
+
+fn get_cycle_state(entry: PtvEntry, process: ProcessKind) -> CycleState {
+    CycleState {
+        label: String::new(),
+        entry,
+        mass_fraction: 1f64,
+        process,
+    }
+}
+
+fn label_states(states: &mut [CycleState]) {
+    for (index, state) in states.iter_mut().enumerate() {
+        state.label = (index + 1).to_string();
+    }
+}
+
+fn check_brayton_cycle(cycle: &BraytonCycle) -> Result<(), GasCycleErr> {
+    if !is_efficiency(cycle.compressor_efficiency) || !is_efficiency(cycle.turbine_efficiency) {
+        return Err(GasCycleErr::InvalidEfficiency);
+    }
+    if !cycle
+        .regenerator_effectiveness
+        .map(is_efficiency)
+        .unwrap_or(true)
+    {
+        return Err(GasCycleErr::InvalidEffectiveness);
+    }
+    if cycle.pressure_ratio.is_nan() || cycle.pressure_ratio <= 1f64 {
+        return Err(GasCycleErr::InvalidRatio);
+    }
+    if cycle.compression_stages == 0 || cycle.expansion_stages == 0 {
+        return Err(GasCycleErr::NoStages);
+    }
+    Ok(())
+}
+
+/// Brayton cycle with optional intercooling, reheat and regeneration.
+/// States are listed in flow order starting from the compressor inlet.
+pub fn get_brayton_cycle(cycle: &BraytonCycle) -> Result<BraytonCycleResult, GasCycleErr> {
+    check_brayton_cycle(cycle)?;
+    let air = cycle.air_standard;
+    let low_pressure = cycle.compressor_inlet_pressure.convert_to_si_unit().value;
+    let high_pressure = low_pressure * cycle.pressure_ratio;
+    let inlet_temperature = cycle
+        .compressor_inlet_temperature
+        .convert_to_si_unit()
+        .value;
+    let turbine_inlet_temperature = cycle.turbine_inlet_temperature.convert_to_si_unit().value;
+
+    let mut states = vec![get_cycle_state(
+        air.get_entry(inlet_temperature, low_pressure)?,
+        ProcessKind::Isobaric,
+    )];
+    let stage_ratio = cycle
+        .pressure_ratio
+        .powf(1f64 / cycle.compression_stages as f64);
+    let mut pressure = low_pressure;
+    for stage in 0..cycle.compression_stages {
+        if stage > 0 {
+            states.push(get_cycle_state(
+                air.get_entry(inlet_temperature, pressure)?,
+                ProcessKind::Isobaric,
+            ));
+        }
+        pressure *= stage_ratio;
+        let inlet = &states[states.len() - 1].entry;
+        let outlet = air.change_pressure(inlet, pressure, cycle.compressor_efficiency)?;
+        states.push(get_cycle_state(outlet, ProcessKind::Adiabatic));
+    }
+    let compressor_outlet = states[states.len() - 1].entry.clone();
+    if compressor_outlet.temperature.convert_to_si_unit().value >= turbine_inlet_temperature {
+        return Err(GasCycleErr::TemperatureOutOfOrder);
+    }
+
+    let mut expansion = vec![get_cycle_state(
+        air.get_entry(turbine_inlet_temperature, high_pressure)?,
+        ProcessKind::Isobaric,
+    )];
+    let stage_ratio = cycle
+        .pressure_ratio
+        .powf(1f64 / cycle.expansion_stages as f64);
+    let mut pressure = high_pressure;
+    for stage in 0..cycle.expansion_stages {
+        if stage > 0 {
+            expansion.push(get_cycle_state(
+                air.get_entry(turbine_inlet_temperature, pressure)?,
+                ProcessKind::Isobaric,
+            ));
+        }
+        pressure /= stage_ratio;
+        let inlet = &expansion[expansion.len() - 1].entry;
+        let outlet = air.change_pressure(inlet, pressure, cycle.turbine_efficiency)?;
+        expansion.push(get_cycle_state(outlet, ProcessKind::Adiabatic));
+    }
+    let turbine_exhaust = expansion[expansion.len() - 1].entry.clone();
+
+    match cycle.regenerator_effectiveness {
+        Some(effectiveness) => {
+            let cold_inlet = compressor_outlet.enthalpy.convert_to_si_unit().value;
+            let hot_inlet = turbine_exhaust.enthalpy.convert_to_si_unit().value;
+            if hot_inlet <= cold_inlet {
+                return Err(GasCycleErr::RegeneratorTemperatureCross);
+            }
+            let heat = effectiveness * (hot_inlet - cold_inlet);
+            states.push(get_cycle_state(
+                air.get_entry_from_enthalpy(
+                    cold_inlet + heat,
+                    high_pressure,
+                    turbine_exhaust.temperature.convert_to_si_unit().value,
+                )?,
+                ProcessKind::Regeneration,
+            ));
+            states.extend(expansion);
+            states.push(get_cycle_state(
+                air.get_entry_from_enthalpy(
+                    hot_inlet - heat,
+                    low_pressure,
+                    compressor_outlet.temperature.convert_to_si_unit().value,
+                )?,
+                ProcessKind::Regeneration,
+            ));
+        }
+        None => states.extend(expansion),
+    }
+    label_states(&mut states);
+
+    let balance = get_energy_balance(&states);
+    let net_work = balance.work_out - balance.work_in;
+    let to_energy = |x| EnergyPerMass::JPerKg(JPerKg::new(x));
+    Ok(BraytonCycleResult {
+        compressor_work: to_energy(balance.work_in),
+        turbine_work: to_energy(balance.work_out),
+        net_work: to_energy(net_work),
+        heat_input: to_energy(balance.heat_in),
+        heat_rejected: to_energy(balance.heat_out),
+        thermal_efficiency: net_work / balance.heat_in,
+        back_work_ratio: balance.work_in / balance.work_out,
+        states,
+    })
+}
+
+/// How heat is added to the air in a piston engine
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PistonCycleKind {
+    // Spark ignition, heat added at constant volume
+    Otto {
+        heat_input: EnergyPerMass,
+    },
+    // Compression ignition, heat added at constant pressure while the volume grows by the cutoff ratio
+    Diesel {
+        cutoff_ratio: f64,
+    },
+    // Heat added at constant volume until the pressure grows by the pressure ratio
+    // then at constant pressure while the volume grows by the cutoff ratio
+    Dual {
+        pressure_ratio: f64,
+        cutoff_ratio: f64,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PistonCycle {
+    pub air_standard: AirStandard,
+    pub kind: PistonCycleKind,
+    // State at the start of compression
+    pub initial_pressure: Pressure,
+    pub initial_temperature: Temperature,
+    // Largest over smallest cylinder volume
+    pub compression_ratio: f64,
+}
+
+/// Work and heat are per unit mass of air in the cylinder
+#[derive(Debug, PartialEq, Clone)]
+pub struct PistonCycleResult {
+    pub states: Vec<CycleState>,
+    pub net_work: EnergyPerMass,
+    pub heat_input: EnergyPerMass,
+    pub heat_rejected: EnergyPerMass,
+    pub thermal_efficiency: f64,
+    // Constant pressure that would give the net work over the piston's stroke
+    pub mean_effective_pressure: Pressure,
+}
+
+fn check_piston_cycle(cycle: &PistonCycle) -> Result<(), GasCycleErr> {
+    let is_ratio = |x: f64| x >= 1f64;
+    let ratios_ok = cycle.compression_ratio > 1f64
+        && match cycle.kind {
+            PistonCycleKind::Otto { .. } => true,
+            PistonCycleKind::Diesel { cutoff_ratio } => cutoff_ratio > 1f64,
+            PistonCycleKind::Dual {
+                pressure_ratio,
+                cutoff_ratio,
+            } => {
+                is_ratio(pressure_ratio)
+                    && is_ratio(cutoff_ratio)
+                    && pressure_ratio * cutoff_ratio > 1f64
+            }
+        };
+    if !ratios_ok {
+        return Err(GasCycleErr::InvalidRatio);
+    }
+    match cycle.kind {
+        PistonCycleKind::Otto { heat_input } if heat_input.convert_to_si_unit().value <= 0f64 => {
+            Err(GasCycleErr::HeatInputMustBePositive)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Otto, Diesel or Dual cycle of a closed system with isentropic compression and expansion.
+/// States are listed from the start of compression.
+pub fn get_piston_cycle(cycle: &PistonCycle) -> Result<PistonCycleResult, GasCycleErr> {
+    check_piston_cycle(cycle)?;
+    let air = cycle.air_standard;
+    let initial = air.get_entry(
+        cycle.initial_temperature.convert_to_si_unit().value,
+        cycle.initial_pressure.convert_to_si_unit().value,
+    )?;
+    let largest_volume = initial.specific_volume.convert_to_si_unit().value;
+    let smallest_volume = largest_volume / cycle.compression_ratio;
+    let compressed = air.get_isentropic_entry_from_volume(&initial, smallest_volume)?;
+    let temperature = compressed.temperature.convert_to_si_unit().value;
+    let pressure = compressed.pressure.convert_to_si_unit().value;
+
+    let mut states = vec![
+        get_cycle_state(initial, ProcessKind::Isochoric),
+        get_cycle_state(compressed.clone(), ProcessKind::Adiabatic),
+    ];
+    match cycle.kind {
+        PistonCycleKind::Otto { heat_input } => {
+            let heat = heat_input.convert_to_si_unit().value;
+            let internal_energy = compressed.internal_energy.convert_to_si_unit().value + heat;
+            let guess = temperature + heat / (COLD_AIR_CP - COLD_AIR_GAS_CONSTANT);
+            states.push(get_cycle_state(
+                air.get_entry_from_internal_energy(internal_energy, smallest_volume, guess)?,
+                ProcessKind::Isochoric,
+            ));
+        }
+        PistonCycleKind::Diesel { cutoff_ratio } => {
+            // At constant pressure the temperature grows with the volume
+            states.push(get_cycle_state(
+                air.get_entry(temperature * cutoff_ratio, pressure)?,
+                ProcessKind::Isobaric,
+            ));
+        }
+        PistonCycleKind::Dual {
+            pressure_ratio,
+            cutoff_ratio,
+        } => {
+            states.push(get_cycle_state(
+                air.get_entry(temperature * pressure_ratio, pressure * pressure_ratio)?,
+                ProcessKind::Isochoric,
+            ));
+            states.push(get_cycle_state(
+                air.get_entry(
+                    temperature * pressure_ratio * cutoff_ratio,
+                    pressure * pressure_ratio,
+                )?,
+                ProcessKind::Isobaric,
+            ));
+        }
+    }
+    let expanded =
+        air.get_isentropic_entry_from_volume(&states[states.len() - 1].entry, largest_volume)?;
+    states.push(get_cycle_state(expanded, ProcessKind::Adiabatic));
+    label_states(&mut states);
+
+    // Boundary work makes the adiabatic enthalpy changes meaningless, so only the heat is used
+    let balance = get_energy_balance(&states);
+    let net_work = balance.heat_in - balance.heat_out;
+    let to_energy = |x| EnergyPerMass::JPerKg(JPerKg::new(x));
+    Ok(PistonCycleResult {
+        net_work: to_energy(net_work),
+        heat_input: to_energy(balance.heat_in),
+        heat_rejected: to_energy(balance.heat_out),
+        thermal_efficiency: net_work / balance.heat_in,
+        mean_effective_pressure: Pressure::Pa(Pa::new(
+            net_work / (largest_volume - smallest_volume),
+        )),
+        states,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_value(value: EnergyPerMass) -> f64 {
+        value.convert_to_si_unit().value
+    }
+
+    fn get_temperature(state: &CycleState) -> f64 {
+        state.entry.temperature.convert_to_si_unit().value
+    }
+
+    fn get_cold_air_k() -> f64 {
+        COLD_AIR_CP / (COLD_AIR_CP - COLD_AIR_GAS_CONSTANT)
+    }
+
+    // Examples 9-2, 9-3, 9-5 to 9-8 of Cengel and Boles, Thermodynamics: An Engineering Approach
+
+    macro_rules! brayton_cycle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    air_standard,
+                    (compressor_efficiency, turbine_efficiency),
+                    regenerator_effectiveness,
+                    (compression_stages, expansion_stages),
+                    expected_states,
+                    expected_temperatures,
+                    expected_back_work_ratio,
+                    expected_thermal_efficiency,
+                    tolerance,
+                ): (_, _, _, _, usize, &[(usize, f64, f64)], f64, f64, f64) = $value;
+                let cycle = BraytonCycle {
+                    air_standard,
+                    compressor_inlet_pressure: Pressure::KPa(KPa::new(100.0)),
+                    compressor_inlet_temperature: Temperature::K(K::new(300.0)),
+                    pressure_ratio: 8.0,
+                    turbine_inlet_temperature: Temperature::K(K::new(1300.0)),
+                    compressor_efficiency,
+                    turbine_efficiency,
+                    regenerator_effectiveness,
+                    compression_stages,
+                    expansion_stages,
+                };
+                let result = get_brayton_cycle(&cycle).unwrap();
+                assert_eq!(expected_states, result.states.len());
+                for (index, temperature, temperature_tolerance) in expected_temperatures {
+                    assert_approx_eq!(
+                        *temperature,
+                        get_temperature(&result.states[*index]),
+                        *temperature_tolerance
+                    );
+                }
+                assert_approx_eq!(expected_back_work_ratio, result.back_work_ratio, tolerance);
+                assert_approx_eq!(
+                    expected_thermal_efficiency,
+                    result.thermal_efficiency,
+                    tolerance
+                );
+                assert_approx_eq!(
+                    get_value(result.heat_input) - get_value(result.heat_rejected),
+                    get_value(result.net_work),
+                    1e-6
+                );
+                // The regenerator follows the last compressor and only cuts the heat input
+                if regenerator_effectiveness.is_some() {
+                    let regenerator = &result.states[2 * compression_stages as usize];
+                    assert_eq!(ProcessKind::Regeneration, regenerator.process);
+                    let unregenerated = get_brayton_cycle(&BraytonCycle {
+                        regenerator_effectiveness: None,
+                        ..cycle
+                    })
+                    .unwrap();
+                    assert_eq!(unregenerated.net_work, result.net_work);
+                }
+            }
+        )*
+        }
+    }
+
+    brayton_cycle_tests! {
+        cold_air_brayton_cycle: (
+            AirStandard::Cold,
+            (1f64, 1f64),
+            None,
+            (1, 1),
+            4,
+            &[],
+            0.418,
+            1f64 - 8f64.powf(-0.4 / 1.4),
+            1e-3
+        ),
+        ideal_brayton_cycle: (
+            AirStandard::Variable,
+            (1f64, 1f64),
+            None,
+            (1, 1),
+            4,
+            &[(1, 540.0, 3.0), (3, 770.0, 4.0)],
+            0.403,
+            0.426,
+            0.005
+        ),
+        actual_brayton_cycle: (
+            AirStandard::Variable,
+            (0.8, 0.85),
+            None,
+            (1, 1),
+            4,
+            &[(3, 853.0, 4.0)],
+            0.592,
+            0.266,
+            0.005
+        ),
+        regenerated_brayton_cycle: (
+            AirStandard::Variable,
+            (0.8, 0.85),
+            Some(0.8),
+            (1, 1),
+            6,
+            &[(4, 853.0, 4.0)],
+            0.592,
+            0.369,
+            0.005
+        ),
+        // Inlet, compressor, intercooler, compressor, combustor, turbine, reheat, turbine
+        intercooled_and_reheated_brayton_cycle: (
+            AirStandard::Variable,
+            (1f64, 1f64),
+            None,
+            (2, 2),
+            8,
+            &[(2, 300.0, 1e-6), (6, 1300.0, 1e-6)],
+            0.304,
+            0.357,
+            0.005
+        ),
+        regenerated_intercooled_and_reheated_brayton_cycle: (
+            AirStandard::Variable,
+            (1f64, 1f64),
+            Some(1f64),
+            (2, 2),
+            10,
+            &[],
+            0.304,
+            0.693,
+            0.005
+        ),
+    }
+
+    macro_rules! piston_cycle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    air_standard,
+                    kind,
+                    compression_ratio,
+                    expected_states,
+                    expected_thermal_efficiency,
+                    tolerance,
+                ) = $value;
+                let result = get_piston_cycle(&PistonCycle {
+                    air_standard,
+                    kind,
+                    initial_pressure: Pressure::KPa(KPa::new(100.0)),
+                    initial_temperature: Temperature::K(K::new(290.0)),
+                    compression_ratio,
+                })
+                .unwrap();
+                assert_eq!(expected_states, result.states.len());
+                assert_approx_eq!(
+                    expected_thermal_efficiency,
+                    result.thermal_efficiency,
+                    tolerance
+                );
+                assert_approx_eq!(
+                    get_value(result.heat_input) - get_value(result.heat_rejected),
+                    get_value(result.net_work),
+                    1e-6
+                );
+            }
+        )*
+        }
+    }
+
+    piston_cycle_tests! {
+        otto_cycle: (
+            AirStandard::Variable,
+            PistonCycleKind::Otto {
+                heat_input: EnergyPerMass::JPerKg(JPerKg::new(800e3)),
+            },
+            8.0,
+            4,
+            0.523,
+            0.005
+        ),
+        cold_air_otto_cycle: (
+            AirStandard::Cold,
+            PistonCycleKind::Otto {
+                heat_input: EnergyPerMass::JPerKg(JPerKg::new(800e3)),
+            },
+            8.0,
+            4,
+            1f64 - 8f64.powf(-0.4),
+            1e-3
+        ),
+        diesel_cycle: (
+            AirStandard::Cold,
+            PistonCycleKind::Diesel { cutoff_ratio: 2.0 },
+            18.0,
+            4,
+            0.632,
+            0.002
+        ),
+        dual_cycle: (
+            AirStandard::Cold,
+            PistonCycleKind::Dual {
+                pressure_ratio: 1.5,
+                cutoff_ratio: 1.6,
+            },
+            14.0,
+            5,
+            1f64 - 14f64.powf(1f64 - get_cold_air_k())
+                * (1.5 * 1.6f64.powf(get_cold_air_k()) - 1f64)
+                / (1.5 - 1f64 + get_cold_air_k() * 1.5 * (1.6 - 1f64)),
+            1e-6
+        ),
+        // The Dual cycle falls back to the Diesel cycle without constant volume heating
+        dual_cycle_without_constant_volume_heating: (
+            AirStandard::Cold,
+            PistonCycleKind::Dual {
+                pressure_ratio: 1f64,
+                cutoff_ratio: 1.6,
+            },
+            14.0,
+            5,
+            1f64 - 14f64.powf(1f64 - get_cold_air_k()) * (1.6f64.powf(get_cold_air_k()) - 1f64)
+                / (get_cold_air_k() * (1.6 - 1f64)),
+            1e-6
+        ),
+        cold_air_diesel_cycle: (
+            AirStandard::Cold,
+            PistonCycleKind::Diesel { cutoff_ratio: 1.6 },
+            14.0,
+            4,
+            1f64 - 14f64.powf(1f64 - get_cold_air_k()) * (1.6f64.powf(get_cold_air_k()) - 1f64)
+                / (get_cold_air_k() * (1.6 - 1f64)),
+            1e-6
+        ),
+    }
+
+    #[test]
+    fn otto_cycle_states() {
+        let result = get_piston_cycle(&PistonCycle {
+            air_standard: AirStandard::Variable,
+            kind: PistonCycleKind::Otto {
+                heat_input: EnergyPerMass::JPerKg(JPerKg::new(800e3)),
+            },
+            initial_pressure: Pressure::KPa(KPa::new(100.0)),
+            initial_temperature: Temperature::K(K::new(290.0)),
+            compression_ratio: 8.0,
+        })
+        .unwrap();
+        assert_approx_eq!(1575.1, get_temperature(&result.states[2]), 8.0);
+        assert_approx_eq!(
+            4.345e6,
+            result.states[2].entry.pressure.convert_to_si_unit().value,
+            0.03e6
+        );
+        assert_approx_eq!(418.17e3, get_value(result.net_work), 3e3);
+        assert_approx_eq!(
+            574e3,
+            result.mean_effective_pressure.convert_to_si_unit().value,
+            5e3
+        );
+    }
+
+    #[test]
+    fn diesel_cycle_against_otto_cycle() {
+        let diesel = get_piston_cycle(&PistonCycle {
+            air_standard: AirStandard::Cold,
+            kind: PistonCycleKind::Diesel { cutoff_ratio: 2.0 },
+            initial_pressure: Pressure::KPa(KPa::new(100.0)),
+            initial_temperature: Temperature::K(K::new(290.0)),
+            compression_ratio: 18.0,
+        })
+        .unwrap();
+        let temperatures: Vec<f64> = diesel.states.iter().map(get_temperature).collect();
+        assert_approx_eq!(temperatures[1] * 2f64, temperatures[2], 1e-6);
+        // A Diesel cycle is less efficient than an Otto cycle of the same compression ratio
+        let otto = get_piston_cycle(&PistonCycle {
+            air_standard: AirStandard::Cold,
+            kind: PistonCycleKind::Otto {
+                heat_input: diesel.heat_input,
+            },
+            initial_pressure: Pressure::KPa(KPa::new(100.0)),
+            initial_temperature: Temperature::K(K::new(290.0)),
+            compression_ratio: 18.0,
+        })
+        .unwrap();
+        assert!(otto.thermal_efficiency > diesel.thermal_efficiency);
+    }
+
+    macro_rules! brayton_cycle_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    turbine_inlet_k,
+                    pressure_ratio,
+                    regenerator_effectiveness,
+                    compression_stages,
+                    expected,
+                ) = $value;
+                let cycle = BraytonCycle {
+                    air_standard: AirStandard::Cold,
+                    compressor_inlet_pressure: Pressure::KPa(KPa::new(100.0)),
+                    compressor_inlet_temperature: Temperature::K(K::new(300.0)),
+                    pressure_ratio,
+                    turbine_inlet_temperature: Temperature::K(K::new(turbine_inlet_k)),
+                    compressor_efficiency: 1f64,
+                    turbine_efficiency: 1f64,
+                    regenerator_effectiveness,
+                    compression_stages,
+                    expansion_stages: 1,
+                };
+                assert_eq!(Err(expected), get_brayton_cycle(&cycle));
+            }
+        )*
+        }
+    }
+
+    brayton_cycle_err_tests! {
+        turbine_inlet_colder_than_compressor_outlet: (
+            500.0,
+            8.0,
+            None,
+            1,
+            GasCycleErr::TemperatureOutOfOrder
+        ),
+        regenerator_temperature_cross: (
+            1300.0,
+            30.0,
+            Some(0.8),
+            1,
+            GasCycleErr::RegeneratorTemperatureCross
+        ),
+        brayton_cycle_without_stages: (1300.0, 8.0, None, 0, GasCycleErr::NoStages),
+    }
+
+    macro_rules! piston_cycle_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (kind, compression_ratio, expected) = $value;
+                let cycle = PistonCycle {
+                    air_standard: AirStandard::Cold,
+                    kind,
+                    initial_pressure: Pressure::KPa(KPa::new(100.0)),
+                    initial_temperature: Temperature::K(K::new(290.0)),
+                    compression_ratio,
+                };
+                assert_eq!(Err(expected), get_piston_cycle(&cycle));
+            }
+        )*
+        }
+    }
+
+    piston_cycle_err_tests! {
+        diesel_cutoff_ratio_of_one: (
+            PistonCycleKind::Diesel { cutoff_ratio: 1f64 },
+            18.0,
+            GasCycleErr::InvalidRatio
+        ),
+        otto_without_heat_input: (
+            PistonCycleKind::Otto {
+                heat_input: EnergyPerMass::JPerKg(JPerKg::new(0f64)),
+            },
+            8.0,
+            GasCycleErr::HeatInputMustBePositive
+        ),
+    }
+}
@@ -0,0 +1,193 @@
+use crate::thermo::diagrams::*;
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::property_diagrams::*;
+use crate::thermo::steam::tables::*;
+use crate::thermo::steam::*;
+use crate::thermo::*;
+
+pub mod combined;
+pub mod gas;
+pub mod rankine;
+pub mod refrigeration;
+
+/// How the fluid gets from the previous state of a cycle to a state
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ProcessKind {
+    // Heat added or rejected at constant pressure
+    Isobaric,
+    // Work done on or by the fluid in a turbine, pump or compressor
+    Adiabatic,
+    // Streams mixed at constant pressure inside the cycle, like an open feedwater heater
+    Mixing,
+    // Adiabatic expansion through a valve without work, so the enthalpy is unchanged
+    Throttling,
+    // Heat added or rejected at constant volume in a closed system, like an engine cylinder
+    Isochoric,
+    // Heat passed from one part of the cycle to another at constant pressure, like a regenerator
+    Regeneration,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CycleState {
+    pub label: String,
+    pub entry: PtvEntry,
+    // Mass leaving the state per unit mass through the boiler
+    pub mass_fraction: f64,
+    // Process ending at this state, the first state is reached from the last one
+    pub process: ProcessKind,
+}
+
+// Work and heat per unit mass through the boiler, all positive
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct CycleEnergyBalance {
+    work_out: f64,
+    work_in: f64,
+    heat_in: f64,
+    heat_out: f64,
+}
+
+fn get_enthalpy(state: &CycleState) -> f64 {
+    state.entry.enthalpy.convert_to_si_unit().value
+}
+
+fn get_internal_energy(state: &CycleState) -> f64 {
+    state.entry.internal_energy.convert_to_si_unit().value
+}
+
+fn get_energy_balance(states: &[CycleState]) -> CycleEnergyBalance {
+    let mut balance = CycleEnergyBalance {
+        work_out: 0f64,
+        work_in: 0f64,
+        heat_in: 0f64,
+        heat_out: 0f64,
+    };
+    for (index, state) in states.iter().enumerate() {
+        let previous = &states[(index + states.len() - 1) % states.len()];
+        let change = previous.mass_fraction
+            * match state.process {
+                ProcessKind::Isochoric => {
+                    get_internal_energy(state) - get_internal_energy(previous)
+                }
+                _ => get_enthalpy(state) - get_enthalpy(previous),
+            };
+        match state.process {
+            ProcessKind::Adiabatic if change < 0f64 => balance.work_out -= change,
+            ProcessKind::Adiabatic => balance.work_in += change,
+            ProcessKind::Isobaric | ProcessKind::Isochoric if change > 0f64 => {
+                balance.heat_in += change
+            }
+            ProcessKind::Isobaric | ProcessKind::Isochoric => balance.heat_out -= change,
+            ProcessKind::Mixing | ProcessKind::Throttling | ProcessKind::Regeneration => (),
+        }
+    }
+    balance
+}
+
+// Heat exchange follows the isobar, so the line bends through the saturation dome
+fn get_isobar_points(
+    backend: &(impl SteamProperties + Sync),
+    kind: PropertyDiagramKind,
+    previous: &PtvEntry,
+    state: &PtvEntry,
+) -> Vec<(f64, f64)> {
+    let start = previous.enthalpy.convert_to_si_unit().value;
+    let end = state.enthalpy.convert_to_si_unit().value;
+    let queries = (1..20)
+        .map(|x| SteamQuery::EnthalpyP {
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(start + (end - start) * x as f64 / 20f64)),
+            pressure: state.pressure,
+        })
+        .collect::<Vec<SteamQuery>>();
+    std::iter::once(get_diagram_coordinates(kind, previous))
+        .chain(
+            get_steam_table_entries(backend, &queries)
+                .iter()
+                .filter_map(|x| x.as_ref().ok())
+                .map(|x| get_diagram_coordinates(kind, x)),
+        )
+        .chain(std::iter::once(get_diagram_coordinates(kind, state)))
+        .collect()
+}
+
+/// The states of a steam cycle in flow order drawn over an IAPWS-IF97 property diagram
+pub fn get_steam_cycle_diagram(kind: PropertyDiagramKind, states: &[CycleState]) -> Diagram {
+    add_cycle_to_diagram(get_property_diagram(kind, None), &Iapws97, kind, states)
+}
+
+/// Draws the states of a cycle in flow order and the processes between them over a diagram
+pub fn add_cycle_to_diagram(
+    mut diagram: Diagram,
+    backend: &(impl SteamProperties + Sync),
+    kind: PropertyDiagramKind,
+    states: &[CycleState],
+) -> Diagram {
+    let mut points = Vec::new();
+    for (index, state) in states.iter().enumerate() {
+        let previous = &states[(index + states.len() - 1) % states.len()];
+        match state.process {
+            ProcessKind::Adiabatic | ProcessKind::Throttling | ProcessKind::Isochoric => points
+                .extend([
+                    get_diagram_coordinates(kind, &previous.entry),
+                    get_diagram_coordinates(kind, &state.entry),
+                ]),
+            ProcessKind::Isobaric | ProcessKind::Mixing | ProcessKind::Regeneration => points
+                .extend(get_isobar_points(
+                    backend,
+                    kind,
+                    &previous.entry,
+                    &state.entry,
+                )),
+        }
+    }
+    diagram.lines.push(DiagramLine {
+        label: "Cycle".to_owned(),
+        style: LineStyle::Process,
+        points,
+    });
+    diagram.points = states
+        .iter()
+        .map(|x| {
+            let (x_value, y_value) = get_diagram_coordinates(kind, &x.entry);
+            DiagramPoint {
+                label: x.label.clone(),
+                x: x_value,
+                y: y_value,
+            }
+        })
+        .collect();
+    diagram
+}
+
+/// One row per state with the mass fraction flowing through it
+pub fn get_cycle_state_table(
+    states: &[CycleState],
+    units: &SteamTableUnits,
+) -> Result<SteamTable, SteamTableErr> {
+    let mut rows = Vec::with_capacity(states.len());
+    for state in states.iter() {
+        let entry = &state.entry;
+        rows.push(vec![
+            state.label.clone(),
+            format_unit(entry.pressure, &units.pressure)?,
+            format_unit(entry.temperature, &units.temperature)?,
+            entry.phase_region.to_string(),
+            format_unit(entry.specific_volume, &units.specific_volume)?,
+            format_unit(entry.enthalpy, &units.energy_per_mass)?,
+            format_unit(entry.entropy, &units.entropy)?,
+            format_value(state.mass_fraction),
+        ]);
+    }
+    Ok(SteamTable {
+        headers: vec![
+            "State".to_owned(),
+            get_header("P", &units.pressure),
+            get_header("T", &units.temperature),
+            "Phase".to_owned(),
+            get_header("v", &units.specific_volume),
+            get_header("h", &units.energy_per_mass),
+            get_header("s", &units.entropy),
+            "Mass Fraction".to_owned(),
+        ],
+        rows,
+    })
+}
@@ -0,0 +1,697 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Reheat {
+    pub pressure: Pressure,
+    pub temperature: Temperature,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RankineCycle {
+    pub boiler_pressure: Pressure,
+    pub turbine_inlet_temperature: Temperature,
+    pub condenser_pressure: Pressure,
+    // Isentropic efficiency between 0 and 1, applied to each turbine section
+    pub turbine_efficiency: f64,
+    // Isentropic efficiency between 0 and 1, applied to every pump
+    pub pump_efficiency: f64,
+    pub reheat: Option<Reheat>,
+    // Pressures of open feedwater heaters fed by steam bled from the turbine
+    pub feedwater_heater_pressures: Vec<Pressure>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RankineCycleErr {
+    InvalidEfficiency,
+    // Reheat and feedwater heater pressures must lie between the condenser and boiler pressures
+    PressureOutOfOrder,
+    SteamQueryErr(SteamQueryErr),
+}
+
+/// Work and heat are per unit mass through the boiler
+#[derive(Debug, PartialEq, Clone)]
+pub struct RankineCycleResult {
+    pub states: Vec<CycleState>,
+    pub turbine_work: EnergyPerMass,
+    pub pump_work: EnergyPerMass,
+    pub net_work: EnergyPerMass,
+    pub heat_input: EnergyPerMass,
+    pub heat_rejected: EnergyPerMass,
+    pub thermal_efficiency: f64,
+    // Pump work over turbine work
+    pub back_work_ratio: f64,
+    // Steam needed for each unit of net work
+    pub steam_rate: MassPerEnergy,
+}
+
+fn get_entry(
+    backend: &impl SteamProperties,
+    query: SteamQuery,
+) -> Result<PtvEntry, RankineCycleErr> {
+    backend
+        .get_steam_table_entry(query)
+        .map_err(RankineCycleErr::SteamQueryErr)
+}
+
+fn get_saturated_liquid(
+    backend: &impl SteamProperties,
+    pressure: Pressure,
+) -> Result<PtvEntry, RankineCycleErr> {
+    get_entry(
+        backend,
+        SteamQuery::Sat(SatQuery::SatPQuery {
+            pressure,
+            phase_region: SteamNonCriticalPhaseRegion::Liquid,
+        }),
+    )
+}
+
+// The actual enthalpy change is the isentropic change scaled by the efficiency,
+// divided for a pump and multiplied for a turbine
+fn change_pressure(
+    backend: &impl SteamProperties,
+    inlet: &PtvEntry,
+    pressure: Pressure,
+    efficiency: f64,
+) -> Result<PtvEntry, RankineCycleErr> {
+    let isentropic = get_entry(
+        backend,
+        SteamQuery::EntropyP {
+            entropy: inlet.entropy,
+            pressure,
+        },
+    )?;
+    let inlet_enthalpy = inlet.enthalpy.convert_to_si_unit().value;
+    let isentropic_change = isentropic.enthalpy.convert_to_si_unit().value - inlet_enthalpy;
+    let change = if isentropic_change > 0f64 {
+        isentropic_change / efficiency
+    } else {
+        isentropic_change * efficiency
+    };
+    get_entry(
+        backend,
+        SteamQuery::EnthalpyP {
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(inlet_enthalpy + change)),
+            pressure,
+        },
+    )
+}
+
+fn check_cycle(cycle: &RankineCycle) -> Result<(), RankineCycleErr> {
+    let is_efficiency = |x: f64| x > 0f64 && x <= 1f64;
+    if !is_efficiency(cycle.turbine_efficiency) || !is_efficiency(cycle.pump_efficiency) {
+        return Err(RankineCycleErr::InvalidEfficiency);
+    }
+    let boiler_pressure = cycle.boiler_pressure.convert_to_si_unit().value;
+    let condenser_pressure = cycle.condenser_pressure.convert_to_si_unit().value;
+    let is_between = |x: Pressure| {
+        let pressure = x.convert_to_si_unit().value;
+        pressure > condenser_pressure && pressure < boiler_pressure
+    };
+    let reheat_ok = cycle.reheat.map(|x| is_between(x.pressure)).unwrap_or(true);
+    let heaters_ok = cycle
+        .feedwater_heater_pressures
+        .iter()
+        .all(|x| is_between(*x));
+    if condenser_pressure >= boiler_pressure || !reheat_ok || !heaters_ok {
+        Err(RankineCycleErr::PressureOutOfOrder)
+    } else {
+        Ok(())
+    }
+}
+
+fn get_cycle_state(entry: PtvEntry, mass_fraction: f64, process: ProcessKind) -> CycleState {
+    CycleState {
+        label: String::new(),
+        entry,
+        mass_fraction,
+        process,
+    }
+}
+
+/// Rankine cycle with optional reheat and any number of open feedwater heaters.
+/// States are listed in flow order starting from the condenser outlet.
+pub fn get_rankine_cycle(
+    backend: &impl SteamProperties,
+    cycle: &RankineCycle,
+) -> Result<RankineCycleResult, RankineCycleErr> {
+    check_cycle(cycle)?;
+    // Compared in Pa so the same pressure in two units is one heater
+    let mut heater_pressures = cycle
+        .feedwater_heater_pressures
+        .iter()
+        .map(|x| x.convert_to_si_unit().value)
+        .collect::<Vec<f64>>();
+    heater_pressures.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    heater_pressures.dedup_by(|a, b| (*a - *b).abs() <= 1e-9 * b.abs());
+    let heater_pressures = heater_pressures
+        .into_iter()
+        .map(|x| Pressure::Pa(Pa::new(x)))
+        .collect::<Vec<Pressure>>();
+
+    let turbine_inlet = get_entry(
+        backend,
+        SteamQuery::Pt(PtPoint {
+            pressure: cycle.boiler_pressure,
+            temperature: cycle.turbine_inlet_temperature,
+        }),
+    )?;
+    let reheat_pressure = cycle.reheat.map(|x| x.pressure.convert_to_si_unit().value);
+    let reheat_outlet = match cycle.reheat {
+        Some(reheat) => Some(get_entry(
+            backend,
+            SteamQuery::Pt(PtPoint {
+                pressure: reheat.pressure,
+                temperature: reheat.temperature,
+            }),
+        )?),
+        None => None,
+    };
+    // Steam is bled from the section of the turbine that spans the heater pressure
+    let expand_from = |pressure: Pressure| {
+        let is_high_pressure = reheat_pressure
+            .map(|x| pressure.convert_to_si_unit().value >= x)
+            .unwrap_or(true);
+        let inlet = match (&reheat_outlet, is_high_pressure) {
+            (Some(outlet), false) => outlet,
+            _ => &turbine_inlet,
+        };
+        change_pressure(backend, inlet, pressure, cycle.turbine_efficiency)
+    };
+    let bleeds = heater_pressures
+        .iter()
+        .map(|x| expand_from(*x))
+        .collect::<Result<Vec<PtvEntry>, RankineCycleErr>>()?;
+    let turbine_exhaust = expand_from(cycle.condenser_pressure)?;
+    let high_pressure_exhaust = match cycle.reheat {
+        Some(reheat) => Some(expand_from(reheat.pressure)?),
+        None => None,
+    };
+
+    // Heater outlets and the pump feeding each of them, from the highest pressure down
+    let condenser_outlet = get_saturated_liquid(backend, cycle.condenser_pressure)?;
+    let mut heater_outlets = Vec::with_capacity(heater_pressures.len());
+    for pressure in heater_pressures.iter() {
+        heater_outlets.push(get_saturated_liquid(backend, *pressure)?);
+    }
+    let mut heater_feeds = Vec::with_capacity(heater_pressures.len());
+    for (index, pressure) in heater_pressures.iter().enumerate() {
+        let source = heater_outlets.get(index + 1).unwrap_or(&condenser_outlet);
+        heater_feeds.push(change_pressure(
+            backend,
+            source,
+            *pressure,
+            cycle.pump_efficiency,
+        )?);
+    }
+    let boiler_feed = change_pressure(
+        backend,
+        heater_outlets.first().unwrap_or(&condenser_outlet),
+        cycle.boiler_pressure,
+        cycle.pump_efficiency,
+    )?;
+
+    // Each open heater heats its feed to saturated liquid with just enough bled steam
+    let mut heater_flows = Vec::with_capacity(heater_pressures.len());
+    let mut flow = 1f64;
+    for ((outlet, feed), bleed) in heater_outlets
+        .iter()
+        .zip(heater_feeds.iter())
+        .zip(bleeds.iter())
+    {
+        let h_outlet = outlet.enthalpy.convert_to_si_unit().value;
+        let h_feed = feed.enthalpy.convert_to_si_unit().value;
+        let h_bleed = bleed.enthalpy.convert_to_si_unit().value;
+        let bled = flow * (h_outlet - h_feed) / (h_bleed - h_feed);
+        heater_flows.push((flow, bled));
+        flow -= bled;
+    }
+
+    let mut states = vec![get_cycle_state(
+        condenser_outlet,
+        flow,
+        ProcessKind::Isobaric,
+    )];
+    for index in (0..heater_pressures.len()).rev() {
+        let (heater_flow, bled) = heater_flows[index];
+        states.push(get_cycle_state(
+            heater_feeds[index].clone(),
+            heater_flow - bled,
+            ProcessKind::Adiabatic,
+        ));
+        states.push(get_cycle_state(
+            heater_outlets[index].clone(),
+            heater_flow,
+            ProcessKind::Mixing,
+        ));
+    }
+    states.push(get_cycle_state(boiler_feed, 1f64, ProcessKind::Adiabatic));
+    states.push(get_cycle_state(turbine_inlet, 1f64, ProcessKind::Isobaric));
+    let mut turbine_flow = 1f64;
+    let mut bleed_iter = heater_flows.iter().zip(bleeds).peekable();
+    if let (Some(pressure), Some(exhaust), Some(outlet)) =
+        (reheat_pressure, high_pressure_exhaust, reheat_outlet)
+    {
+        while let Some(((_, bled), bleed)) =
+            bleed_iter.next_if(|(_, x)| x.pressure.convert_to_si_unit().value >= pressure)
+        {
+            turbine_flow -= bled;
+            states.push(get_cycle_state(bleed, turbine_flow, ProcessKind::Adiabatic));
+        }
+        // A heater at the reheat pressure is fed from the high pressure exhaust
+        if states
+            .last()
+            .map(|x| x.entry.pressure.convert_to_si_unit().value)
+            != Some(pressure)
+        {
+            states.push(get_cycle_state(
+                exhaust,
+                turbine_flow,
+                ProcessKind::Adiabatic,
+            ));
+        }
+        states.push(get_cycle_state(outlet, turbine_flow, ProcessKind::Isobaric));
+    }
+    for ((_, bled), bleed) in bleed_iter {
+        turbine_flow -= bled;
+        states.push(get_cycle_state(bleed, turbine_flow, ProcessKind::Adiabatic));
+    }
+    states.push(get_cycle_state(
+        turbine_exhaust,
+        turbine_flow,
+        ProcessKind::Adiabatic,
+    ));
+    for (index, state) in states.iter_mut().enumerate() {
+        state.label = (index + 1).to_string();
+    }
+
+    let balance = get_energy_balance(&states);
+    let net_work = balance.work_out - balance.work_in;
+    let to_energy = |x| EnergyPerMass::JPerKg(JPerKg::new(x));
+    Ok(RankineCycleResult {
+        turbine_work: to_energy(balance.work_out),
+        pump_work: to_energy(balance.work_in),
+        net_work: to_energy(net_work),
+        heat_input: to_energy(balance.heat_in),
+        heat_rejected: to_energy(balance.heat_out),
+        thermal_efficiency: net_work / balance.heat_in,
+        back_work_ratio: balance.work_in / balance.work_out,
+        steam_rate: MassPerEnergy::KgPerJ(KgPerJ::new(1f64 / net_work)),
+        states,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws97::Iapws97;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! rankine_cycle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    cycle,
+                    expected_state_count,
+                    expected_efficiency,
+                    expected_heat_input,
+                    expected_pump_and_turbine_work,
+                    expected_bleed_fraction,
+                ): (
+                    RankineCycle,
+                    usize,
+                    Option<f64>,
+                    Option<f64>,
+                    Option<(f64, f64)>,
+                    Option<f64>,
+                ) = $value;
+                let result = get_rankine_cycle(&Iapws97, &cycle).unwrap();
+                let labels = result
+                    .states
+                    .iter()
+                    .map(|x| x.label.clone())
+                    .collect::<Vec<String>>();
+                let expected_labels = (1..=expected_state_count)
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>();
+                assert_eq!(expected_labels, labels);
+                assert!(result
+                    .states
+                    .iter()
+                    .all(|x| x.mass_fraction > 0f64 && x.mass_fraction <= 1f64));
+                if let Some(efficiency) = expected_efficiency {
+                    assert_approx_eq!(efficiency, result.thermal_efficiency, 0.002);
+                }
+                let heat_input = result.heat_input.convert_to_si_unit().value;
+                let heat_rejected = result.heat_rejected.convert_to_si_unit().value;
+                let net_work = result.net_work.convert_to_si_unit().value;
+                if let Some(heat) = expected_heat_input {
+                    assert_approx_eq!(heat, heat_input, 3e3);
+                }
+                if let Some((pump_work, turbine_work)) = expected_pump_and_turbine_work {
+                    assert_approx_eq!(
+                        pump_work,
+                        result.pump_work.convert_to_si_unit().value,
+                        0.05e3
+                    );
+                    assert_approx_eq!(
+                        turbine_work,
+                        result.turbine_work.convert_to_si_unit().value,
+                        2e3
+                    );
+                }
+                if let Some(bleed_fraction) = expected_bleed_fraction {
+                    assert_approx_eq!(bleed_fraction, 1f64 - result.states[0].mass_fraction, 1e-3);
+                }
+                assert_approx_eq!(heat_input - heat_rejected, net_work, 1e-6);
+                assert_approx_eq!(net_work / heat_input, result.thermal_efficiency, 1e-12);
+                assert_approx_eq!(
+                    3.6e6 / net_work,
+                    result
+                        .steam_rate
+                        .try_convert("kg/(kW · h)".to_owned())
+                        .unwrap()
+                        .get_value(),
+                    1e-9
+                );
+            }
+        )*
+        }
+    }
+
+    // Examples 10-1 to 10-5 of Cengel and Boles, Thermodynamics: An Engineering Approach,
+    // rows of (cycle, state count, efficiency, heat input, pump and turbine work,
+    // fraction bled to the heater) with work and heat in J/kg
+    rankine_cycle_tests! {
+        simple_ideal_cycle: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: None,
+                feedwater_heater_pressures: vec![],
+            },
+            4,
+            Some(0.26),
+            Some(2728.6e3),
+            Some((3.03e3, 713.1e3)),
+            None,
+        ),
+        ideal_reheat_cycle: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(15e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(600.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(10.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: Some(Reheat {
+                    pressure: Pressure::Pa(Pa::new(4e6)),
+                    temperature: Temperature::C(C::new(600.0)),
+                }),
+                feedwater_heater_pressures: vec![],
+            },
+            6,
+            Some(0.450),
+            None,
+            None,
+            None,
+        ),
+        ideal_regenerative_cycle: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(15e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(600.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(10.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: None,
+                feedwater_heater_pressures: vec![Pressure::Pa(Pa::new(1.2e6))],
+            },
+            7,
+            Some(0.463),
+            Some(2769.3e3),
+            None,
+            Some(0.2270),
+        ),
+        // The same heater given twice in different units is only counted once
+        repeated_feedwater_heater: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(15e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(600.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(10.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: None,
+                feedwater_heater_pressures: vec![
+                    Pressure::Pa(Pa::new(1.2e6)),
+                    Pressure::KPa(KPa::new(1200.0)),
+                ],
+            },
+            7,
+            Some(0.463),
+            Some(2769.3e3),
+            None,
+            Some(0.2270),
+        ),
+        // Condenser, two pumps and heaters, feed pump, boiler, high pressure exhaust,
+        // reheat, low pressure bleed and exhaust
+        reheat_with_heater_at_reheat_pressure: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(15e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(600.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(10.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: Some(Reheat {
+                    pressure: Pressure::Pa(Pa::new(4e6)),
+                    temperature: Temperature::C(C::new(600.0)),
+                }),
+                feedwater_heater_pressures: vec![
+                    Pressure::Pa(Pa::new(0.5e6)),
+                    Pressure::Pa(Pa::new(4e6)),
+                ],
+            },
+            11,
+            None,
+            None,
+            None,
+            None,
+        ),
+    }
+
+    macro_rules! component_efficiency_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (turbine_efficiency, pump_efficiency): (f64, f64) = $value;
+                // Example 10-1 of Cengel and Boles
+                let ideal_cycle = RankineCycle {
+                    boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                    turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                    condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                    turbine_efficiency: 1f64,
+                    pump_efficiency: 1f64,
+                    reheat: None,
+                    feedwater_heater_pressures: vec![],
+                };
+                let ideal = get_rankine_cycle(&Iapws97, &ideal_cycle).unwrap();
+                let actual = get_rankine_cycle(
+                    &Iapws97,
+                    &RankineCycle {
+                        turbine_efficiency,
+                        pump_efficiency,
+                        ..ideal_cycle
+                    },
+                )
+                .unwrap();
+                assert_approx_eq!(
+                    ideal.turbine_work.convert_to_si_unit().value * turbine_efficiency,
+                    actual.turbine_work.convert_to_si_unit().value,
+                    1f64
+                );
+                assert_approx_eq!(
+                    ideal.pump_work.convert_to_si_unit().value / pump_efficiency,
+                    actual.pump_work.convert_to_si_unit().value,
+                    1f64
+                );
+                assert!(actual.back_work_ratio > ideal.back_work_ratio);
+                assert!(actual.thermal_efficiency < ideal.thermal_efficiency);
+            }
+        )*
+        }
+    }
+
+    component_efficiency_tests! {
+        actual_cycle: (0.87, 0.85),
+        actual_turbine_only: (0.87, 1f64),
+        actual_pump_only: (1f64, 0.85),
+    }
+
+    macro_rules! feedwater_heater_gain_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (reheat, feedwater_heater_pressures): (Option<Reheat>, Vec<Pressure>) = $value;
+                let cycle = RankineCycle {
+                    boiler_pressure: Pressure::Pa(Pa::new(15e6)),
+                    turbine_inlet_temperature: Temperature::C(C::new(600.0)),
+                    condenser_pressure: Pressure::KPa(KPa::new(10.0)),
+                    turbine_efficiency: 1f64,
+                    pump_efficiency: 1f64,
+                    reheat,
+                    feedwater_heater_pressures,
+                };
+                let with_heaters = get_rankine_cycle(&Iapws97, &cycle).unwrap();
+                let without_heaters = get_rankine_cycle(
+                    &Iapws97,
+                    &RankineCycle {
+                        feedwater_heater_pressures: vec![],
+                        ..cycle
+                    },
+                )
+                .unwrap();
+                assert!(with_heaters.thermal_efficiency > without_heaters.thermal_efficiency);
+                assert!(
+                    with_heaters.heat_input.convert_to_si_unit().value
+                        < without_heaters.heat_input.convert_to_si_unit().value
+                );
+            }
+        )*
+        }
+    }
+
+    feedwater_heater_gain_tests! {
+        regenerative_heater_gain: (None, vec![Pressure::Pa(Pa::new(1.2e6))]),
+        reheat_regenerative_heater_gain: (
+            Some(Reheat {
+                pressure: Pressure::Pa(Pa::new(4e6)),
+                temperature: Temperature::C(C::new(600.0)),
+            }),
+            vec![Pressure::Pa(Pa::new(0.5e6)), Pressure::Pa(Pa::new(4e6))],
+        ),
+    }
+
+    macro_rules! rankine_cycle_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (cycle, expected): (RankineCycle, RankineCycleErr) = $value;
+                assert_eq!(Err(expected), get_rankine_cycle(&Iapws97, &cycle));
+            }
+        )*
+        }
+    }
+
+    rankine_cycle_err_tests! {
+        zero_turbine_efficiency: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                turbine_efficiency: 0f64,
+                pump_efficiency: 1f64,
+                reheat: None,
+                feedwater_heater_pressures: vec![],
+            },
+            RankineCycleErr::InvalidEfficiency,
+        ),
+        pump_efficiency_above_one: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1.1,
+                reheat: None,
+                feedwater_heater_pressures: vec![],
+            },
+            RankineCycleErr::InvalidEfficiency,
+        ),
+        heater_above_boiler_pressure: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: None,
+                feedwater_heater_pressures: vec![Pressure::Pa(Pa::new(5e6))],
+            },
+            RankineCycleErr::PressureOutOfOrder,
+        ),
+        reheat_below_condenser_pressure: (
+            RankineCycle {
+                boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                turbine_efficiency: 1f64,
+                pump_efficiency: 1f64,
+                reheat: Some(Reheat {
+                    pressure: Pressure::KPa(KPa::new(50.0)),
+                    temperature: Temperature::C(C::new(350.0)),
+                }),
+                feedwater_heater_pressures: vec![],
+            },
+            RankineCycleErr::PressureOutOfOrder,
+        ),
+    }
+
+    macro_rules! cycle_diagram_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (kind, is_boiling_point): (PropertyDiagramKind, fn(&(f64, f64)) -> bool) =
+                    $value;
+                // Example 10-1 of Cengel and Boles
+                let result = get_rankine_cycle(
+                    &Iapws97,
+                    &RankineCycle {
+                        boiler_pressure: Pressure::Pa(Pa::new(3e6)),
+                        turbine_inlet_temperature: Temperature::C(C::new(350.0)),
+                        condenser_pressure: Pressure::KPa(KPa::new(75.0)),
+                        turbine_efficiency: 1f64,
+                        pump_efficiency: 1f64,
+                        reheat: None,
+                        feedwater_heater_pressures: vec![],
+                    },
+                )
+                .unwrap();
+                let diagram = get_steam_cycle_diagram(kind, &result.states);
+                let cycle_line = diagram.lines.last().unwrap();
+                assert_eq!(LineStyle::Process, cycle_line.style);
+                assert_eq!(4, diagram.points.len());
+                assert!(cycle_line.points.iter().any(is_boiling_point));
+                let table =
+                    get_cycle_state_table(&result.states, &SteamTableUnits::default()).unwrap();
+                assert_eq!(4, table.rows.len());
+                assert_eq!("State", table.headers[0]);
+                assert_eq!(vec!["1", "75000.0"], table.rows[0][..2].to_vec());
+            }
+        )*
+        }
+    }
+
+    // The boiler isobar is drawn through the dome at 3 MPa and 233.85 °C, between
+    // the saturated liquid and vapor at 1008.3 and 2803.2 kJ/kg, 0.001217 and 0.06667 m³/kg
+    cycle_diagram_tests! {
+        temperature_entropy_cycle_diagram: (
+            PropertyDiagramKind::TemperatureEntropy,
+            |x| f64::abs(x.1 - 233.85) < 0.05 && x.0 > 3.0 && x.0 < 6.0,
+        ),
+        pressure_enthalpy_cycle_diagram: (
+            PropertyDiagramKind::PressureEnthalpy,
+            |x| f64::abs(x.1 - 3.0) < 1e-9 && x.0 > 1010.0 && x.0 < 2800.0,
+        ),
+        pressure_volume_cycle_diagram: (
+            PropertyDiagramKind::PressureVolume,
+            |x| f64::abs(x.1 - 3.0) < 1e-9 && x.0 > 0.0013 && x.0 < 0.066,
+        ),
+    }
+}
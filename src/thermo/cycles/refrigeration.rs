@@ -0,0 +1,498 @@
+use super::*;
+use crate::thermo::refrigerants::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct VaporCompressionCycle {
+    pub refrigerant: Refrigerant,
+    // Saturation temperature in the evaporator
+    pub evaporator_temperature: Temperature,
+    // Saturation temperature in the condenser
+    pub condenser_temperature: Temperature,
+    // K above the evaporator temperature at the compressor inlet
+    pub superheat: f64,
+    // K below the condenser temperature at the expansion valve inlet
+    pub subcooling: f64,
+    // Isentropic efficiency between 0 and 1
+    pub compressor_efficiency: f64,
+    // Heat removed in the evaporator
+    pub capacity: Power,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum VaporCompressionCycleErr {
+    InvalidEfficiency,
+    // Superheat and subcooling can not be negative
+    NegativeTemperatureDifference,
+    // The condenser must be hotter than the evaporator
+    TemperatureOutOfOrder,
+    CapacityMustBePositive,
+    SteamQueryErr(SteamQueryErr),
+}
+
+/// Energies are per unit mass of refrigerant, rates follow from the capacity
+#[derive(Debug, PartialEq, Clone)]
+pub struct VaporCompressionCycleResult {
+    pub states: Vec<CycleState>,
+    pub evaporator_pressure: Pressure,
+    pub condenser_pressure: Pressure,
+    pub refrigerating_effect: EnergyPerMass,
+    pub compressor_work: EnergyPerMass,
+    pub heat_rejected: EnergyPerMass,
+    // Refrigerating effect over compressor work
+    pub refrigeration_cop: f64,
+    // Heat rejected over compressor work
+    pub heat_pump_cop: f64,
+    pub mass_flow_rate: MassFlowRate,
+    pub compressor_power: Power,
+    pub heat_rejection_rate: Power,
+}
+
+fn get_entry(
+    refrigerant: Refrigerant,
+    query: SteamQuery,
+) -> Result<PtvEntry, VaporCompressionCycleErr> {
+    refrigerant
+        .get_steam_table_entry(query)
+        .map_err(VaporCompressionCycleErr::SteamQueryErr)
+}
+
+// Saturated at zero difference, otherwise a single phase state at the offset temperature
+fn get_offset_entry(
+    refrigerant: Refrigerant,
+    saturation_temperature: f64,
+    pressure: Pressure,
+    difference: f64,
+    phase_region: SteamNonCriticalPhaseRegion,
+) -> Result<PtvEntry, VaporCompressionCycleErr> {
+    let query = if difference == 0f64 {
+        SteamQuery::Sat(SatQuery::SatPQuery {
+            pressure,
+            phase_region,
+        })
+    } else {
+        SteamQuery::Pt(PtPoint {
+            pressure,
+            temperature: Temperature::K(K::new(saturation_temperature + difference)),
+        })
+    };
+    get_entry(refrigerant, query)
+}
+
+fn check_cycle(cycle: &VaporCompressionCycle) -> Result<(), VaporCompressionCycleErr> {
+    if cycle.compressor_efficiency <= 0f64 || cycle.compressor_efficiency > 1f64 {
+        Err(VaporCompressionCycleErr::InvalidEfficiency)
+    } else if cycle.superheat.is_nan()
+        || cycle.subcooling.is_nan()
+        || cycle.superheat < 0f64
+        || cycle.subcooling < 0f64
+    {
+        Err(VaporCompressionCycleErr::NegativeTemperatureDifference)
+    } else if cycle.condenser_temperature.convert_to_si_unit().value
+        <= cycle.evaporator_temperature.convert_to_si_unit().value
+    {
+        Err(VaporCompressionCycleErr::TemperatureOutOfOrder)
+    } else if cycle.capacity.convert_to_si_unit().value <= 0f64 {
+        Err(VaporCompressionCycleErr::CapacityMustBePositive)
+    } else {
+        Ok(())
+    }
+}
+
+/// Single stage vapor compression cycle, states are listed in flow order starting from the
+/// compressor inlet: compressor outlet, condenser outlet and expansion valve outlet
+pub fn get_vapor_compression_cycle(
+    cycle: &VaporCompressionCycle,
+) -> Result<VaporCompressionCycleResult, VaporCompressionCycleErr> {
+    check_cycle(cycle)?;
+    let refrigerant = cycle.refrigerant;
+    let saturated_vapor = |temperature| {
+        get_entry(
+            refrigerant,
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature,
+                phase_region: SteamNonCriticalPhaseRegion::Vapor,
+            }),
+        )
+    };
+    let evaporator_pressure = saturated_vapor(cycle.evaporator_temperature)?.pressure;
+    let condenser_pressure = saturated_vapor(cycle.condenser_temperature)?.pressure;
+
+    let compressor_inlet = get_offset_entry(
+        refrigerant,
+        cycle.evaporator_temperature.convert_to_si_unit().value,
+        evaporator_pressure,
+        cycle.superheat,
+        SteamNonCriticalPhaseRegion::Vapor,
+    )?;
+    let isentropic_outlet = get_entry(
+        refrigerant,
+        SteamQuery::EntropyP {
+            entropy: compressor_inlet.entropy,
+            pressure: condenser_pressure,
+        },
+    )?;
+    let inlet_enthalpy = compressor_inlet.enthalpy.convert_to_si_unit().value;
+    let compressor_outlet = get_entry(
+        refrigerant,
+        SteamQuery::EnthalpyP {
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(
+                inlet_enthalpy
+                    + (isentropic_outlet.enthalpy.convert_to_si_unit().value - inlet_enthalpy)
+                        / cycle.compressor_efficiency,
+            )),
+            pressure: condenser_pressure,
+        },
+    )?;
+    let condenser_outlet = get_offset_entry(
+        refrigerant,
+        cycle.condenser_temperature.convert_to_si_unit().value,
+        condenser_pressure,
+        -cycle.subcooling,
+        SteamNonCriticalPhaseRegion::Liquid,
+    )?;
+    let valve_outlet = get_entry(
+        refrigerant,
+        SteamQuery::EnthalpyP {
+            enthalpy: condenser_outlet.enthalpy,
+            pressure: evaporator_pressure,
+        },
+    )?;
+
+    let states = [
+        (compressor_inlet, ProcessKind::Isobaric),
+        (compressor_outlet, ProcessKind::Adiabatic),
+        (condenser_outlet, ProcessKind::Isobaric),
+        (valve_outlet, ProcessKind::Throttling),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, (entry, process))| CycleState {
+        label: (index + 1).to_string(),
+        entry,
+        mass_fraction: 1f64,
+        process,
+    })
+    .collect::<Vec<CycleState>>();
+
+    let balance = get_energy_balance(&states);
+    let mass_flow_rate = cycle.capacity.convert_to_si_unit().value / balance.heat_in;
+    let to_energy = |x| EnergyPerMass::JPerKg(JPerKg::new(x));
+    let to_power = |x| Power::W(W::new(x * mass_flow_rate));
+    Ok(VaporCompressionCycleResult {
+        states,
+        evaporator_pressure,
+        condenser_pressure,
+        refrigerating_effect: to_energy(balance.heat_in),
+        compressor_work: to_energy(balance.work_in),
+        heat_rejected: to_energy(balance.heat_out),
+        refrigeration_cop: balance.heat_in / balance.work_in,
+        heat_pump_cop: balance.heat_out / balance.work_in,
+        mass_flow_rate: MassFlowRate::KgPerSec(KgPerSec::new(mass_flow_rate)),
+        compressor_power: to_power(balance.work_in),
+        heat_rejection_rate: to_power(balance.heat_out),
+    })
+}
+
+/// The states of a refrigeration cycle drawn over the refrigerant's pressure enthalpy diagram
+pub fn get_refrigeration_cycle_diagram(refrigerant: Refrigerant, states: &[CycleState]) -> Diagram {
+    let kind = PropertyDiagramKind::PressureEnthalpy;
+    add_cycle_to_diagram(
+        get_refrigerant_diagram(refrigerant, kind),
+        &refrigerant,
+        kind,
+        states,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! vapor_compression_cycle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    cycle,
+                    (expected_evaporator_pressure, expected_condenser_pressure),
+                    (expected_refrigerating_effect, refrigerating_effect_eps),
+                    (expected_cop, cop_eps),
+                ): (VaporCompressionCycle, (f64, f64), (f64, f64), (f64, f64)) = $value;
+                let result = get_vapor_compression_cycle(&cycle).unwrap();
+                assert_eq!(4, result.states.len());
+                let evaporator_pressure = result.evaporator_pressure.convert_to_si_unit().value;
+                let condenser_pressure = result.condenser_pressure.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    expected_evaporator_pressure,
+                    evaporator_pressure,
+                    0.03 * expected_evaporator_pressure
+                );
+                assert_approx_eq!(
+                    expected_condenser_pressure,
+                    condenser_pressure,
+                    0.03 * expected_condenser_pressure
+                );
+                let refrigerating_effect = result.refrigerating_effect.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    expected_refrigerating_effect,
+                    refrigerating_effect,
+                    refrigerating_effect_eps
+                );
+                assert_approx_eq!(expected_cop, result.refrigeration_cop, cop_eps);
+                assert_approx_eq!(result.refrigeration_cop + 1f64, result.heat_pump_cop, 1e-9);
+                let capacity = cycle.capacity.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    capacity / refrigerating_effect,
+                    result.mass_flow_rate.convert_to_si_unit().value,
+                    1e-9
+                );
+                assert_approx_eq!(
+                    result.compressor_power.convert_to_si_unit().value + capacity,
+                    result.heat_rejection_rate.convert_to_si_unit().value,
+                    1e-6
+                );
+                // The valve leaves a liquid vapor mixture at the evaporator pressure
+                assert!(matches!(
+                    result.states[3].entry.phase_region,
+                    PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(_))
+                ));
+                assert_approx_eq!(
+                    evaporator_pressure,
+                    result.states[3].entry.pressure.convert_to_si_unit().value,
+                    1e-6
+                );
+            }
+        )*
+        }
+    }
+
+    // Rows of (cycle, evaporator and condenser pressure in Pa within 3 %,
+    // refrigerating effect in J/kg, refrigeration COP)
+    vapor_compression_cycle_tests! {
+        // Example 11-1 of Cengel and Boles, R-134a between 0.14 and 0.8 MPa
+        // with a mass flow of 0.05 kg/s
+        ideal_cycle: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(-18.77)),
+                condenser_temperature: Temperature::C(C::new(31.31)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            (0.14e6, 0.8e6),
+            (143.7e3, 5e3),
+            (3.97, 0.15),
+        ),
+        // Ammonia's large latent heat gives a refrigerating effect near 1100 kJ/kg,
+        // saturated at 290.9 kPa and 1350.4 kPa
+        ammonia_cycle: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::Ammonia,
+                evaporator_temperature: Temperature::C(C::new(-10.0)),
+                condenser_temperature: Temperature::C(C::new(35.0)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            (290.9e3, 1350.4e3),
+            (1100e3, 50e3),
+            (4.8, 0.3),
+        ),
+    }
+
+    macro_rules! component_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (compressor_efficiency, superheat, subcooling): (f64, f64, f64) = $value;
+                let saturated_cycle = VaporCompressionCycle {
+                    refrigerant: Refrigerant::R134a,
+                    evaporator_temperature: Temperature::C(C::new(-10.0)),
+                    condenser_temperature: Temperature::C(C::new(40.0)),
+                    superheat: 0f64,
+                    subcooling: 0f64,
+                    compressor_efficiency: 1f64,
+                    capacity: Power::KW(KW::new(7.18)),
+                };
+                let isentropic_cycle = VaporCompressionCycle {
+                    superheat,
+                    subcooling,
+                    ..saturated_cycle
+                };
+                let saturated = get_vapor_compression_cycle(&saturated_cycle).unwrap();
+                let isentropic = get_vapor_compression_cycle(&isentropic_cycle).unwrap();
+                let actual = get_vapor_compression_cycle(&VaporCompressionCycle {
+                    compressor_efficiency,
+                    ..isentropic_cycle
+                })
+                .unwrap();
+                assert_approx_eq!(
+                    isentropic.compressor_work.convert_to_si_unit().value / compressor_efficiency,
+                    actual.compressor_work.convert_to_si_unit().value,
+                    1e-3
+                );
+                let refrigerating_effect = actual.refrigerating_effect.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    isentropic.refrigerating_effect.convert_to_si_unit().value,
+                    refrigerating_effect,
+                    1e-6
+                );
+                // Superheat in the evaporator and subcooling both add to the effect
+                let saturated_effect = saturated.refrigerating_effect.convert_to_si_unit().value;
+                if superheat + subcooling > 0f64 {
+                    assert!(refrigerating_effect > saturated_effect);
+                } else {
+                    assert_approx_eq!(saturated_effect, refrigerating_effect, 1e-6);
+                }
+                assert_approx_eq!(
+                    -10.0 + superheat,
+                    actual.states[0].entry.temperature.convert_to_si_unit().value - 273.15,
+                    1e-6
+                );
+                assert_approx_eq!(
+                    40.0 - subcooling,
+                    actual.states[2].entry.temperature.convert_to_si_unit().value - 273.15,
+                    1e-6
+                );
+            }
+        )*
+        }
+    }
+
+    // R-134a between -10 and 40 °C, rows of
+    // (compressor efficiency, superheat in K, subcooling in K)
+    component_tests! {
+        compressor_efficiency_only: (0.8, 0f64, 0f64),
+        compressor_efficiency_and_subcooling: (0.8, 5.0, 5.0),
+        subcooling_only: (1f64, 0f64, 5.0),
+        superheat_only: (1f64, 5.0, 0f64),
+    }
+
+    macro_rules! vapor_compression_cycle_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (cycle, expected): (VaporCompressionCycle, VaporCompressionCycleErr) = $value;
+                assert_eq!(Err(expected), get_vapor_compression_cycle(&cycle));
+            }
+        )*
+        }
+    }
+
+    vapor_compression_cycle_err_tests! {
+        compressor_efficiency_above_one: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(-10.0)),
+                condenser_temperature: Temperature::C(C::new(40.0)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1.2,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            VaporCompressionCycleErr::InvalidEfficiency,
+        ),
+        negative_superheat: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(-10.0)),
+                condenser_temperature: Temperature::C(C::new(40.0)),
+                superheat: -1.0,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            VaporCompressionCycleErr::NegativeTemperatureDifference,
+        ),
+        condenser_colder_than_evaporator: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(40.0)),
+                condenser_temperature: Temperature::C(C::new(-10.0)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            VaporCompressionCycleErr::TemperatureOutOfOrder,
+        ),
+        zero_capacity: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(-10.0)),
+                condenser_temperature: Temperature::C(C::new(40.0)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(0f64)),
+            },
+            VaporCompressionCycleErr::CapacityMustBePositive,
+        ),
+        // R-134a's critical temperature is 101.06 °C
+        condenser_above_critical_temperature: (
+            VaporCompressionCycle {
+                refrigerant: Refrigerant::R134a,
+                evaporator_temperature: Temperature::C(C::new(-10.0)),
+                condenser_temperature: Temperature::C(C::new(110.0)),
+                superheat: 0f64,
+                subcooling: 0f64,
+                compressor_efficiency: 1f64,
+                capacity: Power::KW(KW::new(7.18)),
+            },
+            VaporCompressionCycleErr::SteamQueryErr(SteamQueryErr::OutOfRange(
+                OutOfRange::AboveCriticalTemperature,
+            )),
+        ),
+    }
+
+    macro_rules! cycle_diagram_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let refrigerant: Refrigerant = $value;
+                let result = get_vapor_compression_cycle(&VaporCompressionCycle {
+                    refrigerant,
+                    evaporator_temperature: Temperature::C(C::new(-10.0)),
+                    condenser_temperature: Temperature::C(C::new(40.0)),
+                    superheat: 0f64,
+                    subcooling: 0f64,
+                    compressor_efficiency: 1f64,
+                    capacity: Power::KW(KW::new(7.18)),
+                })
+                .unwrap();
+                let diagram = get_refrigeration_cycle_diagram(refrigerant, &result.states);
+                assert_eq!(AxisScale::Log, diagram.y_axis.scale);
+                assert_eq!(LineStyle::Saturation, diagram.lines[0].style);
+                let cycle_line = diagram.lines.last().unwrap();
+                assert_eq!(LineStyle::Process, cycle_line.style);
+                assert_eq!(4, diagram.points.len());
+                // Evaporation and condensation are flat on a pressure enthalpy diagram
+                for pressure in [result.evaporator_pressure, result.condenser_pressure] {
+                    let pressure = pressure.convert_to_si_unit().value / 1e6;
+                    assert!(
+                        cycle_line
+                            .points
+                            .iter()
+                            .filter(|x| f64::abs(x.1 - pressure) < 1e-9)
+                            .count()
+                            > 10
+                    );
+                }
+            }
+        )*
+        }
+    }
+
+    cycle_diagram_tests! {
+        r134a_cycle_diagram: Refrigerant::R134a,
+        ammonia_cycle_diagram: Refrigerant::Ammonia,
+    }
+}
@@ -0,0 +1,429 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AxisScale {
+    Linear,
+    // Base 10, values that are not positive are left out of the plot
+    Log,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Axis {
+    pub label: String,
+    pub scale: AxisScale,
+}
+
+/// Decides how a line is drawn, every style has its own color in the rendered diagram
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LineStyle {
+    Saturation,
+    Isobar,
+    Isotherm,
+    Isenthalp,
+    Quality,
+    RelativeHumidity,
+    WetBulb,
+    // A process or cycle drawn over the property lines
+    Process,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiagramLine {
+    pub label: String,
+    pub style: LineStyle,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// A state point marked with a dot and a label
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiagramPoint {
+    pub label: String,
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagram {
+    pub title: String,
+    pub x_axis: Axis,
+    pub y_axis: Axis,
+    pub lines: Vec<DiagramLine>,
+    pub points: Vec<DiagramPoint>,
+}
+
+const MARGIN_LEFT: f64 = 70f64;
+const MARGIN_RIGHT: f64 = 20f64;
+const MARGIN_TOP: f64 = 40f64;
+const MARGIN_BOTTOM: f64 = 50f64;
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn to_scale(value: f64, scale: AxisScale) -> Option<f64> {
+    match scale {
+        AxisScale::Linear if value.is_finite() => Some(value),
+        AxisScale::Log if value.is_finite() && value > 0f64 => Some(f64::log10(value)),
+        _ => None,
+    }
+}
+
+fn from_scale(value: f64, scale: AxisScale) -> f64 {
+    match scale {
+        AxisScale::Linear => value,
+        AxisScale::Log => f64::powf(10f64, value),
+    }
+}
+
+// Range of the scaled values, padded when every value is the same
+fn get_bounds(values: impl Iterator<Item = f64>, scale: AxisScale) -> (f64, f64) {
+    let (min, max) = values
+        .filter_map(|x| to_scale(x, scale))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (f64::min(min, x), f64::max(max, x))
+        });
+    if min > max {
+        (0f64, 1f64)
+    } else if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    }
+}
+
+// Step of 1, 2 or 5 times a power of ten giving roughly the requested number of ticks
+fn get_nice_step(range: f64, ticks: f64) -> f64 {
+    let rough = range / ticks;
+    let magnitude = f64::powf(10f64, f64::floor(f64::log10(rough)));
+    let normalized = rough / magnitude;
+    let nice = if normalized < 1.5 {
+        1f64
+    } else if normalized < 3.5 {
+        2f64
+    } else if normalized < 7.5 {
+        5f64
+    } else {
+        10f64
+    };
+    nice * magnitude
+}
+
+/// Tick values of an axis in unscaled units, decades for a log axis
+pub fn get_axis_ticks(min: f64, max: f64, scale: AxisScale) -> Vec<f64> {
+    match scale {
+        AxisScale::Log if f64::floor(max) - f64::ceil(min) >= 1f64 => (f64::ceil(min) as i32
+            ..=f64::floor(max) as i32)
+            .map(|x| f64::powi(10f64, x))
+            .collect(),
+        _ => {
+            let step = get_nice_step(max - min, 5f64);
+            let first = f64::ceil(min / step) as i64;
+            let last = f64::floor(max / step) as i64;
+            (first..=last)
+                .map(|x| from_scale(x as f64 * step, scale))
+                .collect()
+        }
+    }
+}
+
+fn format_tick(value: f64) -> String {
+    let magnitude = f64::abs(value);
+    if value == 0f64 {
+        "0".to_owned()
+    } else if !(1e-3..1e6).contains(&magnitude) {
+        format!("{:e}", value)
+    } else {
+        let decimals = i32::max(0, 3 - f64::floor(f64::log10(magnitude)) as i32) as usize;
+        let text = format!("{:.*}", decimals, value);
+        if text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.').to_owned()
+        } else {
+            text
+        }
+    }
+}
+
+fn get_line_attributes(style: LineStyle) -> &'static str {
+    match style {
+        LineStyle::Saturation => r##"stroke="#000000" stroke-width="2""##,
+        LineStyle::Isobar => r##"stroke="#1f77b4" stroke-width="1""##,
+        LineStyle::Isotherm => r##"stroke="#d62728" stroke-width="1""##,
+        LineStyle::Isenthalp => r##"stroke="#9467bd" stroke-width="1""##,
+        LineStyle::Quality => r##"stroke="#7f7f7f" stroke-width="1" stroke-dasharray="4 3""##,
+        LineStyle::RelativeHumidity => {
+            r##"stroke="#17becf" stroke-width="1" stroke-dasharray="4 3""##
+        }
+        LineStyle::WetBulb => r##"stroke="#8c564b" stroke-width="1""##,
+        LineStyle::Process => r##"stroke="#2ca02c" stroke-width="2.5""##,
+    }
+}
+
+struct PlotArea {
+    x_scale: AxisScale,
+    y_scale: AxisScale,
+    x_bounds: (f64, f64),
+    y_bounds: (f64, f64),
+    width: f64,
+    height: f64,
+}
+
+impl PlotArea {
+    fn to_pixels(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let x = to_scale(x, self.x_scale)?;
+        let y = to_scale(y, self.y_scale)?;
+        let (x_min, x_max) = self.x_bounds;
+        let (y_min, y_max) = self.y_bounds;
+        Some((
+            MARGIN_LEFT + (x - x_min) / (x_max - x_min) * self.width,
+            MARGIN_TOP + (y_max - y) / (y_max - y_min) * self.height,
+        ))
+    }
+}
+
+impl Diagram {
+    /// Standalone SVG document, the plot bounds are fitted to the lines and points
+    pub fn to_svg(&self, width: u32, height: u32) -> String {
+        let x_values = self
+            .lines
+            .iter()
+            .flat_map(|x| x.points.iter().map(|y| y.0))
+            .chain(self.points.iter().map(|x| x.x));
+        let y_values = self
+            .lines
+            .iter()
+            .flat_map(|x| x.points.iter().map(|y| y.1))
+            .chain(self.points.iter().map(|x| x.y));
+        let area = PlotArea {
+            x_scale: self.x_axis.scale,
+            y_scale: self.y_axis.scale,
+            x_bounds: get_bounds(x_values, self.x_axis.scale),
+            y_bounds: get_bounds(y_values, self.y_axis.scale),
+            width: f64::max(width as f64 - MARGIN_LEFT - MARGIN_RIGHT, 1f64),
+            height: f64::max(height as f64 - MARGIN_TOP - MARGIN_BOTTOM, 1f64),
+        };
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" "#,
+                r#"viewBox="0 0 {0} {1}" font-family="sans-serif" font-size="12">"#,
+                "\n",
+                r##"<rect width="{0}" height="{1}" fill="#ffffff"/>"##,
+                "\n",
+                r#"<clipPath id="plot-area"><rect x="{2}" y="{3}" width="{4}" height="{5}"/></clipPath>"#,
+                "\n",
+                r#"<text x="{6}" y="24" text-anchor="middle" font-size="16">{7}</text>"#,
+                "\n"
+            ),
+            width,
+            height,
+            MARGIN_LEFT,
+            MARGIN_TOP,
+            area.width,
+            area.height,
+            MARGIN_LEFT + area.width / 2f64,
+            escape_xml(&self.title),
+        );
+        svg.push_str(&self.get_axes_svg(&area));
+        svg.push_str("<g clip-path=\"url(#plot-area)\" fill=\"none\">\n");
+        for line in self.lines.iter() {
+            svg.push_str(&get_line_svg(line, &area));
+        }
+        svg.push_str("</g>\n");
+        for point in self.points.iter() {
+            if let Some((x, y)) = area.to_pixels(point.x, point.y) {
+                svg.push_str(&format!(
+                    concat!(
+                        r##"<circle cx="{0:.2}" cy="{1:.2}" r="5" fill="#ff7f0e" stroke="#000000">"##,
+                        "<title>{2}</title></circle>\n",
+                        r#"<text x="{3:.2}" y="{4:.2}">{2}</text>"#,
+                        "\n"
+                    ),
+                    x,
+                    y,
+                    escape_xml(&point.label),
+                    x + 8f64,
+                    y - 8f64,
+                ));
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn get_axes_svg(&self, area: &PlotArea) -> String {
+        let left = MARGIN_LEFT;
+        let right = MARGIN_LEFT + area.width;
+        let top = MARGIN_TOP;
+        let bottom = MARGIN_TOP + area.height;
+        let mut svg = format!(
+            r##"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="none" stroke="#000000"/>"##,
+            left, top, area.width, area.height
+        );
+        svg.push('\n');
+        let (x_min, x_max) = area.x_bounds;
+        for tick in get_axis_ticks(x_min, x_max, area.x_scale) {
+            if let Some((x, _)) = area.to_pixels(tick, from_scale(area.y_bounds.0, area.y_scale)) {
+                svg.push_str(&format!(
+                    concat!(
+                        r##"<line x1="{0:.2}" y1="{1:.2}" x2="{0:.2}" y2="{2:.2}" stroke="#dddddd"/>"##,
+                        r#"<text x="{0:.2}" y="{3:.2}" text-anchor="middle">{4}</text>"#,
+                        "\n"
+                    ),
+                    x,
+                    top,
+                    bottom,
+                    bottom + 16f64,
+                    format_tick(tick),
+                ));
+            }
+        }
+        let (y_min, y_max) = area.y_bounds;
+        for tick in get_axis_ticks(y_min, y_max, area.y_scale) {
+            if let Some((_, y)) = area.to_pixels(from_scale(area.x_bounds.0, area.x_scale), tick) {
+                svg.push_str(&format!(
+                    concat!(
+                        r##"<line x1="{0:.2}" y1="{1:.2}" x2="{2:.2}" y2="{1:.2}" stroke="#dddddd"/>"##,
+                        r#"<text x="{3:.2}" y="{4:.2}" text-anchor="end">{5}</text>"#,
+                        "\n"
+                    ),
+                    left,
+                    y,
+                    right,
+                    left - 6f64,
+                    y + 4f64,
+                    format_tick(tick),
+                ));
+            }
+        }
+        svg.push_str(&format!(
+            concat!(
+                r#"<text x="{0:.2}" y="{1:.2}" text-anchor="middle">{2}</text>"#,
+                "\n",
+                r#"<text x="16" y="{3:.2}" text-anchor="middle" transform="rotate(-90 16 {3:.2})">{4}</text>"#,
+                "\n"
+            ),
+            left + area.width / 2f64,
+            bottom + 38f64,
+            escape_xml(&self.x_axis.label),
+            top + area.height / 2f64,
+            escape_xml(&self.y_axis.label),
+        ));
+        svg
+    }
+}
+
+// Points that cannot be shown on the axis scale split the line into separate segments
+fn get_line_svg(line: &DiagramLine, area: &PlotArea) -> String {
+    let mut path = String::new();
+    let mut pen_down = false;
+    let mut last_pixel = None;
+    for (x, y) in line.points.iter() {
+        match area.to_pixels(*x, *y) {
+            Some((px, py)) => {
+                path.push_str(&format!(
+                    "{}{:.2} {:.2} ",
+                    if pen_down { "L" } else { "M" },
+                    px,
+                    py
+                ));
+                pen_down = true;
+                last_pixel = Some((px, py));
+            }
+            None => pen_down = false,
+        }
+    }
+    match last_pixel {
+        None => String::new(),
+        Some((x, y)) => format!(
+            concat!(
+                r#"<path d="{0}" {1}><title>{2}</title></path>"#,
+                "\n",
+                r##"<text x="{3:.2}" y="{4:.2}" fill="#555555" stroke="none" font-size="10">{2}</text>"##,
+                "\n"
+            ),
+            path.trim_end(),
+            get_line_attributes(line.style),
+            escape_xml(&line.label),
+            x + 3f64,
+            y - 3f64,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_diagram(scale: AxisScale) -> Diagram {
+        Diagram {
+            title: "P & v".to_owned(),
+            x_axis: Axis {
+                label: "v".to_owned(),
+                scale,
+            },
+            y_axis: Axis {
+                label: "P".to_owned(),
+                scale: AxisScale::Linear,
+            },
+            lines: vec![DiagramLine {
+                label: "line".to_owned(),
+                style: LineStyle::Isotherm,
+                points: vec![(-1.0, 0.0), (1.0, 0.0), (100.0, 10.0)],
+            }],
+            points: vec![DiagramPoint {
+                label: "State".to_owned(),
+                x: 10.0,
+                y: 5.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn linear_ticks() {
+        assert_eq!(
+            vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0],
+            get_axis_ticks(0.0, 10.0, AxisScale::Linear)
+        );
+        let ticks = get_axis_ticks(0.3, 1.2, AxisScale::Linear);
+        assert_eq!(4, ticks.len());
+        assert_approx_eq!(0.4, ticks[0], 1e-12);
+        assert_approx_eq!(1.0, ticks[3], 1e-12);
+    }
+
+    #[test]
+    fn log_ticks() {
+        assert_eq!(
+            vec![0.1, 1.0, 10.0],
+            get_axis_ticks(-1.5, 1.2, AxisScale::Log)
+        );
+    }
+
+    #[test]
+    fn tick_labels() {
+        assert_eq!("0", format_tick(0.0));
+        assert_eq!("2.5", format_tick(2.5));
+        assert_eq!("1000", format_tick(1000.0));
+        assert_eq!("0.001", format_tick(0.001));
+        assert_eq!("1e-5", format_tick(1e-5));
+    }
+
+    #[test]
+    fn svg_document() {
+        let svg = get_diagram(AxisScale::Linear).to_svg(400, 300);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains("P &amp; v"));
+        assert!(svg.contains("<title>line</title>"));
+        assert!(svg.contains("<title>State</title>"));
+        // x from -1 to 100 over 310 px, y from 0 to 10 over 210 px
+        assert!(svg.contains(r#"cx="103.76" cy="145.00""#));
+    }
+
+    #[test]
+    fn log_axis_skips_negative_points() {
+        let svg = get_diagram(AxisScale::Log).to_svg(400, 300);
+        // Only the positive points remain, so the line starts at x = 1
+        assert!(svg.contains(r#"d="M70.00 250.00 L380.00 40.00""#));
+    }
+}
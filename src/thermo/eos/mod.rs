@@ -0,0 +1,732 @@
+// Peng and Robinson (1976), Soave (1972), both written in the generic cubic form
+// P = R * T / (v - b) - a / ((v + δ1 * b) * (v + δ2 * b))
+// Mixtures use the van der Waals one fluid mixing rules without binary interaction parameters
+use std::f64::consts::PI;
+use std::fmt::Display;
+
+use crate::thermo::ideal_gas::*;
+use crate::thermo::steam::PtPoint;
+use crate::thermo::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CubicEos {
+    PengRobinson,
+    SoaveRedlichKwong,
+}
+
+impl CubicEos {
+    // (δ1, δ2)
+    fn get_deltas(&self) -> (f64, f64) {
+        match self {
+            CubicEos::PengRobinson => (1f64 + f64::sqrt(2f64), 1f64 - f64::sqrt(2f64)),
+            CubicEos::SoaveRedlichKwong => (1f64, 0f64),
+        }
+    }
+
+    fn get_omega_a(&self) -> f64 {
+        match self {
+            CubicEos::PengRobinson => 0.45723553,
+            CubicEos::SoaveRedlichKwong => 0.42748023,
+        }
+    }
+
+    fn get_omega_b(&self) -> f64 {
+        match self {
+            CubicEos::PengRobinson => 0.07779607,
+            CubicEos::SoaveRedlichKwong => 0.08664035,
+        }
+    }
+
+    /// Critical compressibility factor predicted by the equation
+    fn get_critical_compressibility(&self) -> f64 {
+        match self {
+            CubicEos::PengRobinson => 0.30740131,
+            CubicEos::SoaveRedlichKwong => 1f64 / 3f64,
+        }
+    }
+
+    /// Slope of the alpha function from the acentric factor
+    fn get_kappa(&self, acentric_factor: f64) -> f64 {
+        let w = acentric_factor;
+        match self {
+            CubicEos::PengRobinson => 0.37464 + 1.54226 * w - 0.26992 * w * w,
+            CubicEos::SoaveRedlichKwong => 0.480 + 1.574 * w - 0.176 * w * w,
+        }
+    }
+}
+
+impl TryFrom<String> for CubicEos {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Peng-Robinson" => Ok(CubicEos::PengRobinson),
+            "Soave-Redlich-Kwong" => Ok(CubicEos::SoaveRedlichKwong),
+            _ => Err(format!("Unknown Equation of State \"{}\"", value)),
+        }
+    }
+}
+
+impl From<CubicEos> for String {
+    fn from(val: CubicEos) -> Self {
+        val.to_string()
+    }
+}
+
+impl Display for CubicEos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CubicEos::PengRobinson => "Peng-Robinson",
+                CubicEos::SoaveRedlichKwong => "Soave-Redlich-Kwong",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CriticalConstants {
+    // K
+    pub temperature: f64,
+    // Pa
+    pub pressure: f64,
+    pub acentric_factor: f64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EosErr {
+    // Radicals and atoms have no critical point
+    MissingCriticalConstants(Species),
+    PressureMustBePositive,
+    TemperatureMustBePositive,
+    AboveCriticalTemperature,
+    FailedToConverge,
+    IdealGasErr(IdealGasErr),
+}
+
+/// Poling, Prausnitz and O'Connell (2001) appendix A
+pub fn get_critical_constants(species: Species) -> Result<CriticalConstants, EosErr> {
+    let (temperature, pressure, acentric_factor) = match species {
+        Species::N2 => (126.19, 3.3958e6, 0.0372),
+        Species::O2 => (154.58, 5.043e6, 0.0222),
+        Species::Ar => (150.69, 4.863e6, -0.0022),
+        Species::He => (5.1953, 0.22746e6, -0.382),
+        Species::CO2 => (304.13, 7.3773e6, 0.2239),
+        Species::H2O => (647.096, 22.064e6, 0.3443),
+        Species::CO => (132.86, 3.494e6, 0.0497),
+        Species::H2 => (33.145, 1.2964e6, -0.219),
+        Species::CH4 => (190.56, 4.5992e6, 0.0114),
+        Species::C2H6 => (305.32, 4.8722e6, 0.0995),
+        Species::C3H8 => (369.89, 4.2512e6, 0.1521),
+        x => return Err(EosErr::MissingCriticalConstants(x)),
+    };
+    Ok(CriticalConstants {
+        temperature,
+        pressure,
+        acentric_factor,
+    })
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CubicRoot {
+    // Lowest residual Gibbs free energy
+    Stable,
+    // Smallest compressibility factor
+    Liquid,
+    // Largest compressibility factor
+    Vapor,
+}
+
+/// Ideal gas part of the properties at the temperature and pressure of interest
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct IdealGasState {
+    // J/(mol * K)
+    pub cp: f64,
+    // J/mol
+    pub enthalpy: f64,
+    // J/(mol * K)
+    pub entropy: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DepartureFunctions {
+    // J/mol, real minus ideal gas at the same temperature and pressure
+    pub enthalpy: f64,
+    // J/(mol * K)
+    pub entropy: f64,
+}
+
+/// Mixture parameters at one temperature, a is in Pa * m6 / mol2 and b in m3 / mol
+struct CubicParameters {
+    deltas: (f64, f64),
+    mole_fractions: Vec<f64>,
+    sqrt_a_i: Vec<f64>,
+    b_i: Vec<f64>,
+    a: f64,
+    // da/dT and d2a/dT2
+    da: f64,
+    d2a: f64,
+    b: f64,
+    // Kay's rule
+    pseudo_critical_temperature: f64,
+    pseudo_critical_pressure: f64,
+}
+
+impl CubicParameters {
+    fn new(
+        eos: CubicEos,
+        components: &[(CriticalConstants, f64)],
+        temperature: f64,
+    ) -> Result<CubicParameters, EosErr> {
+        if temperature.is_nan() || temperature <= 0f64 {
+            return Err(EosErr::TemperatureMustBePositive);
+        }
+        let r = UNIVERSAL_GAS_CONSTANT;
+        let mut mole_fractions = vec![];
+        let mut sqrt_a_i = vec![];
+        let mut b_i = vec![];
+        // sqrt(a) = sum(x * sqrt(a_i)) and its temperature derivatives
+        let (mut q, mut dq, mut d2q) = (0f64, 0f64, 0f64);
+        let (mut pseudo_critical_temperature, mut pseudo_critical_pressure) = (0f64, 0f64);
+        for (critical, x) in components {
+            let tc = critical.temperature;
+            let pc = critical.pressure;
+            let kappa = eos.get_kappa(critical.acentric_factor);
+            let sqrt_ac = f64::sqrt(eos.get_omega_a() * r * r * tc * tc / pc);
+            let f = 1f64 + kappa * (1f64 - f64::sqrt(temperature / tc));
+            let df = -kappa / (2f64 * f64::sqrt(temperature * tc));
+            let d2f = kappa / (4f64 * temperature * f64::sqrt(temperature * tc));
+            q += x * sqrt_ac * f;
+            dq += x * sqrt_ac * df;
+            d2q += x * sqrt_ac * d2f;
+            pseudo_critical_temperature += x * tc;
+            pseudo_critical_pressure += x * pc;
+            mole_fractions.push(*x);
+            sqrt_a_i.push(sqrt_ac * f);
+            b_i.push(eos.get_omega_b() * r * tc / pc);
+        }
+        let b = mole_fractions.iter().zip(&b_i).map(|(x, b)| x * b).sum();
+        Ok(CubicParameters {
+            deltas: eos.get_deltas(),
+            mole_fractions,
+            sqrt_a_i,
+            b_i,
+            a: q * q,
+            da: 2f64 * q * dq,
+            d2a: 2f64 * (dq * dq + q * d2q),
+            b,
+            pseudo_critical_temperature,
+            pseudo_critical_pressure,
+        })
+    }
+
+    // (A, B)
+    fn get_dimensionless(&self, temperature: f64, pressure: f64) -> (f64, f64) {
+        let rt = UNIVERSAL_GAS_CONSTANT * temperature;
+        (self.a * pressure / (rt * rt), self.b * pressure / rt)
+    }
+
+    /// ln((Z + δ1 * B) / (Z + δ2 * B)) / (δ1 - δ2)
+    fn get_log_term(&self, z: f64, b: f64) -> f64 {
+        let (d1, d2) = self.deltas;
+        f64::ln((z + d1 * b) / (z + d2 * b)) / (d1 - d2)
+    }
+
+    fn get_roots(&self, temperature: f64, pressure: f64) -> Vec<f64> {
+        let (a, b) = self.get_dimensionless(temperature, pressure);
+        let (d1, d2) = self.deltas;
+        let u = d1 + d2;
+        let w = d1 * d2;
+        solve_cubic(
+            -(1f64 + b - u * b),
+            a + w * b * b - u * b - u * b * b,
+            -(a * b + w * b * b + w * b * b * b),
+        )
+        .into_iter()
+        .filter(|z| *z > b)
+        .collect()
+    }
+
+    fn get_ln_fugacity_coefficients(&self, temperature: f64, pressure: f64, z: f64) -> Vec<f64> {
+        let (a, b) = self.get_dimensionless(temperature, pressure);
+        let q = f64::sqrt(self.a);
+        let log_term = self.get_log_term(z, b);
+        self.sqrt_a_i
+            .iter()
+            .zip(&self.b_i)
+            .map(|(sqrt_a_i, b_i)| {
+                b_i / self.b * (z - 1f64)
+                    - f64::ln(z - b)
+                    - a / b * (2f64 * sqrt_a_i / q - b_i / self.b) * log_term
+            })
+            .collect()
+    }
+
+    /// Dimensionless residual Gibbs free energy, used to pick the stable root
+    fn get_residual_gibbs(&self, temperature: f64, pressure: f64, z: f64) -> f64 {
+        self.mole_fractions
+            .iter()
+            .zip(self.get_ln_fugacity_coefficients(temperature, pressure, z))
+            .map(|(x, ln_phi)| x * ln_phi)
+            .sum()
+    }
+
+    fn get_departure_functions(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        z: f64,
+    ) -> DepartureFunctions {
+        let r = UNIVERSAL_GAS_CONSTANT;
+        let (_, b) = self.get_dimensionless(temperature, pressure);
+        let log_term = self.get_log_term(z, b);
+        DepartureFunctions {
+            enthalpy: r * temperature * (z - 1f64)
+                + (temperature * self.da - self.a) / self.b * log_term,
+            entropy: r * f64::ln(z - b) + self.da / self.b * log_term,
+        }
+    }
+
+    fn get_root(&self, temperature: f64, pressure: f64, root: CubicRoot) -> Result<f64, EosErr> {
+        let roots = self.get_roots(temperature, pressure);
+        match root {
+            CubicRoot::Stable => roots
+                .into_iter()
+                .map(|z| (z, self.get_residual_gibbs(temperature, pressure, z)))
+                .min_by(|(_, x), (_, y)| x.total_cmp(y))
+                .map(|(z, _)| z),
+            CubicRoot::Liquid => roots.first().copied(),
+            CubicRoot::Vapor => roots.last().copied(),
+        }
+        .ok_or(EosErr::FailedToConverge)
+    }
+}
+
+/// Real roots of z^3 + c2 * z^2 + c1 * z + c0 in ascending order
+fn solve_cubic(c2: f64, c1: f64, c0: f64) -> Vec<f64> {
+    let shift = c2 / 3f64;
+    let p = c1 - c2 * c2 / 3f64;
+    let q = 2f64 * c2 * c2 * c2 / 27f64 - c2 * c1 / 3f64 + c0;
+    let discriminant = q * q / 4f64 + p * p * p / 27f64;
+    if discriminant > 0f64 || p == 0f64 {
+        let sqrt_discriminant = f64::sqrt(f64::max(discriminant, 0f64));
+        let root =
+            f64::cbrt(-q / 2f64 + sqrt_discriminant) + f64::cbrt(-q / 2f64 - sqrt_discriminant);
+        vec![root - shift]
+    } else {
+        let m = 2f64 * f64::sqrt(-p / 3f64);
+        let theta = f64::acos(f64::clamp(3f64 * q / (p * m), -1f64, 1f64)) / 3f64;
+        let mut roots: Vec<f64> = (0..3)
+            .map(|k| m * f64::cos(theta - 2f64 * PI * k as f64 / 3f64) - shift)
+            .collect();
+        roots.sort_by(|x, y| x.total_cmp(y));
+        roots.dedup_by(|x, y| f64::abs(*x - *y) < 1e-12);
+        roots
+    }
+}
+
+fn get_components(mixture: &Mixture) -> Result<Vec<(CriticalConstants, f64)>, EosErr> {
+    mixture
+        .get_mole_fractions()
+        .iter()
+        .map(|(species, x)| get_critical_constants(*species).map(|critical| (critical, *x)))
+        .collect()
+}
+
+fn get_si_point(point: PtPoint) -> Result<(f64, f64), EosErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    if pressure.is_nan() || pressure <= 0f64 {
+        return Err(EosErr::PressureMustBePositive);
+    }
+    Ok((temperature, pressure))
+}
+
+/// Physical compressibility factor roots in ascending order
+pub fn get_compressibility_roots(
+    eos: CubicEos,
+    mixture: &Mixture,
+    point: PtPoint,
+) -> Result<Vec<f64>, EosErr> {
+    let (temperature, pressure) = get_si_point(point)?;
+    let parameters = CubicParameters::new(eos, &get_components(mixture)?, temperature)?;
+    Ok(parameters.get_roots(temperature, pressure))
+}
+
+/// Fugacity coefficients of each species in the order of the mixture's mole fractions
+pub fn get_fugacity_coefficients(
+    eos: CubicEos,
+    mixture: &Mixture,
+    point: PtPoint,
+    compressibility: f64,
+) -> Result<Vec<f64>, EosErr> {
+    let (temperature, pressure) = get_si_point(point)?;
+    let parameters = CubicParameters::new(eos, &get_components(mixture)?, temperature)?;
+    Ok(parameters
+        .get_ln_fugacity_coefficients(temperature, pressure, compressibility)
+        .into_iter()
+        .map(f64::exp)
+        .collect())
+}
+
+pub fn get_departure_functions(
+    eos: CubicEos,
+    mixture: &Mixture,
+    point: PtPoint,
+    compressibility: f64,
+) -> Result<DepartureFunctions, EosErr> {
+    let (temperature, pressure) = get_si_point(point)?;
+    let parameters = CubicParameters::new(eos, &get_components(mixture)?, temperature)?;
+    Ok(parameters.get_departure_functions(temperature, pressure, compressibility))
+}
+
+/// Pressure where the liquid and vapor fugacities of a pure species are equal
+pub fn get_saturation_pressure(
+    eos: CubicEos,
+    species: Species,
+    temperature: Temperature,
+) -> Result<Pressure, EosErr> {
+    get_critical_constants(species)
+        .and_then(|critical| get_saturation_pressure_from_constants(eos, critical, temperature))
+}
+
+/// Saturation pressure of a pure fluid that is only known by its critical constants
+pub fn get_saturation_pressure_from_constants(
+    eos: CubicEos,
+    critical: CriticalConstants,
+    temperature: Temperature,
+) -> Result<Pressure, EosErr> {
+    let temperature = temperature.convert_to_si_unit().value;
+    if temperature >= critical.temperature {
+        return Err(EosErr::AboveCriticalTemperature);
+    }
+    let parameters = CubicParameters::new(eos, &[(critical, 1f64)], temperature)?;
+    let critical_volume_ratio = eos.get_critical_compressibility() / eos.get_omega_b();
+    // Wilson's correlation as the first guess
+    let mut pressure = critical.pressure
+        * f64::exp(
+            5.373 * (1f64 + critical.acentric_factor) * (1f64 - critical.temperature / temperature),
+        );
+    for _ in 0..500 {
+        let roots = parameters.get_roots(temperature, pressure);
+        let (liquid, vapor) = match (roots.first(), roots.last()) {
+            (Some(liquid), Some(vapor)) if roots.len() > 1 => (*liquid, *vapor),
+            (Some(z), _) => {
+                let reduced_volume =
+                    z * UNIVERSAL_GAS_CONSTANT * temperature / pressure / parameters.b;
+                // Only a liquid root means the pressure is above the vapor spinodal
+                pressure *= if reduced_volume < critical_volume_ratio {
+                    0.9
+                } else {
+                    1.1
+                };
+                continue;
+            }
+            _ => return Err(EosErr::FailedToConverge),
+        };
+        let ln_ratio = parameters.get_ln_fugacity_coefficients(temperature, pressure, liquid)[0]
+            - parameters.get_ln_fugacity_coefficients(temperature, pressure, vapor)[0];
+        pressure *= f64::exp(ln_ratio);
+        if f64::abs(ln_ratio) < 1e-10 {
+            return Ok(Pressure::Pa(Pa::new(pressure)));
+        }
+    }
+    Err(EosErr::FailedToConverge)
+}
+
+/// Properties per unit mass using the most stable root,
+/// the ideal gas part comes from the NASA polynomials so the enthalpy includes the heats of formation
+pub fn get_eos_entry(eos: CubicEos, mixture: &Mixture, point: PtPoint) -> Result<PtvEntry, EosErr> {
+    let (temperature, pressure) = get_si_point(point)?;
+    let ideal_gas = IdealGasState {
+        cp: mixture
+            .get_molar_cp(temperature)
+            .map_err(EosErr::IdealGasErr)?,
+        enthalpy: mixture
+            .get_molar_enthalpy(temperature)
+            .map_err(EosErr::IdealGasErr)?,
+        entropy: mixture
+            .get_molar_entropy(temperature, pressure)
+            .map_err(EosErr::IdealGasErr)?,
+    };
+    get_cubic_entry(
+        eos,
+        &get_components(mixture)?,
+        mixture.get_molar_mass(),
+        ideal_gas,
+        point,
+        CubicRoot::Stable,
+    )
+}
+
+/// Properties per unit mass of components given by their critical constants and mole fractions,
+/// molar mass is in kg/mol and the ideal gas state sets the reference state
+pub fn get_cubic_entry(
+    eos: CubicEos,
+    components: &[(CriticalConstants, f64)],
+    molar_mass: f64,
+    ideal_gas: IdealGasState,
+    point: PtPoint,
+    root: CubicRoot,
+) -> Result<PtvEntry, EosErr> {
+    let (temperature, pressure) = get_si_point(point)?;
+    let parameters = CubicParameters::new(eos, components, temperature)?;
+    let z = parameters.get_root(temperature, pressure, root)?;
+    let r = UNIVERSAL_GAS_CONSTANT;
+    let (_, b_dimensionless) = parameters.get_dimensionless(temperature, pressure);
+    let (d1, d2) = parameters.deltas;
+    let a = parameters.a;
+    let b = parameters.b;
+
+    // Molar quantities
+    let v = z * r * temperature / pressure;
+    let attraction = (v + d1 * b) * (v + d2 * b);
+    let dp_dt = r / (v - b) - parameters.da / attraction;
+    let dp_dv = -r * temperature / f64::powi(v - b, 2)
+        + a * (2f64 * v + (d1 + d2) * b) / f64::powi(attraction, 2);
+
+    let departure = parameters.get_departure_functions(temperature, pressure, z);
+    let cv = ideal_gas.cp - r
+        + temperature * parameters.d2a / b * parameters.get_log_term(z, b_dimensionless);
+    let cp = cv - temperature * dp_dt * dp_dt / dp_dv;
+    let enthalpy = ideal_gas.enthalpy + departure.enthalpy;
+    let entropy = ideal_gas.entropy + departure.entropy;
+    let internal_energy = enthalpy - pressure * v;
+    let isobaric_expansion = -dp_dt / (v * dp_dv);
+    let isothermal_compressibility = -1f64 / (v * dp_dv);
+    let joule_thomson = v * (temperature * isobaric_expansion - 1f64) / cp;
+    let isentropic_exponent = -v / pressure * cp / cv * dp_dv;
+    let speed_of_sound = f64::sqrt(-v * v / molar_mass * cp / cv * dp_dv);
+
+    let reduced_volume = v / b;
+    let phase_region = if temperature >= parameters.pseudo_critical_temperature {
+        if pressure >= parameters.pseudo_critical_pressure {
+            PhaseRegion::SupercriticalFluid
+        } else {
+            PhaseRegion::Gas
+        }
+    } else if reduced_volume < eos.get_critical_compressibility() / eos.get_omega_b() {
+        PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid)
+    } else {
+        PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor)
+    };
+
+    let specific_volume = v / molar_mass;
+    Ok(PtvEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region,
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy / molar_mass)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy / molar_mass)),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy / molar_mass)),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv / molar_mass)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp / molar_mass)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(
+            (enthalpy - temperature * entropy) / molar_mass,
+        )),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(
+            (internal_energy - temperature * entropy) / molar_mass,
+        )),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(isobaric_expansion)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(isothermal_compressibility)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(joule_thomson)),
+        isentropic_exponent,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn point(temperature: f64, pressure: f64) -> PtPoint {
+        PtPoint {
+            temperature: Temperature::K(K::new(temperature)),
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        }
+    }
+
+    fn si(entry: &PtvEntry) -> (f64, f64, f64, f64, f64) {
+        (
+            entry.enthalpy.convert_to_si_unit().value,
+            entry.entropy.convert_to_si_unit().value,
+            entry.cp.convert_to_si_unit().value,
+            entry.specific_volume.convert_to_si_unit().value,
+            entry.gibbs_free_energy.convert_to_si_unit().value,
+        )
+    }
+
+    macro_rules! acentric_factor_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // The alpha functions are fit so P_sat(0.7 * Tc) = Pc * 10^(-1 - ω)
+                let (eos, species, tol) = $value;
+                let critical = get_critical_constants(species).unwrap();
+                let temperature = Temperature::K(K::new(0.7 * critical.temperature));
+                let actual = get_saturation_pressure(eos, species, temperature)
+                    .unwrap()
+                    .convert_to_si_unit()
+                    .value;
+                let expected =
+                    critical.pressure * f64::powf(10f64, -1f64 - critical.acentric_factor);
+                assert_approx_eq!(1f64, actual / expected, tol);
+            }
+        )*
+        }
+    }
+
+    acentric_factor_tests! {
+        pr_methane_acentric_factor: (CubicEos::PengRobinson, Species::CH4, 0.03),
+        pr_propane_acentric_factor: (CubicEos::PengRobinson, Species::C3H8, 0.03),
+        pr_carbon_dioxide_acentric_factor: (CubicEos::PengRobinson, Species::CO2, 0.03),
+        srk_methane_acentric_factor: (CubicEos::SoaveRedlichKwong, Species::CH4, 0.03),
+        srk_propane_acentric_factor: (CubicEos::SoaveRedlichKwong, Species::C3H8, 0.03),
+        srk_carbon_dioxide_acentric_factor: (CubicEos::SoaveRedlichKwong, Species::CO2, 0.03),
+    }
+
+    #[test]
+    fn propane_saturation_pressure() {
+        // NIST webbook, 300 K
+        let actual = get_saturation_pressure(
+            CubicEos::PengRobinson,
+            Species::C3H8,
+            Temperature::K(K::new(300.0)),
+        )
+        .unwrap()
+        .convert_to_si_unit()
+        .value;
+        assert_approx_eq!(0.9978e6, actual, 0.02e6);
+    }
+
+    #[test]
+    fn critical_point() {
+        for eos in [CubicEos::PengRobinson, CubicEos::SoaveRedlichKwong] {
+            let critical = get_critical_constants(Species::CO2).unwrap();
+            let roots = get_compressibility_roots(
+                eos,
+                &Mixture::pure(Species::CO2),
+                point(critical.temperature, critical.pressure),
+            )
+            .unwrap();
+            assert_eq!(1, roots.len());
+            // The triple root is sensitive to rounding in the constants
+            assert_approx_eq!(eos.get_critical_compressibility(), roots[0], 5e-3);
+        }
+    }
+
+    #[test]
+    fn low_pressure_is_ideal_gas() {
+        let air = Mixture::air();
+        let low_pressure = point(300.0, 10.0);
+        let ideal = get_ideal_gas_entry(&air, low_pressure).unwrap();
+        let real = get_eos_entry(CubicEos::PengRobinson, &air, low_pressure).unwrap();
+        let (h_ideal, s_ideal, cp_ideal, v_ideal, _) = si(&ideal);
+        let (h_real, s_real, cp_real, v_real, _) = si(&real);
+        assert_approx_eq!(h_ideal, h_real, 0.1);
+        assert_approx_eq!(s_ideal, s_real, 1e-4);
+        assert_approx_eq!(cp_ideal, cp_real, 1e-3);
+        assert_approx_eq!(1f64, v_real / v_ideal, 1e-6);
+        assert_eq!(PhaseRegion::Gas, real.phase_region);
+    }
+
+    #[test]
+    fn properties_are_consistent() {
+        let eos = CubicEos::PengRobinson;
+        let mixture =
+            Mixture::from_mole_fractions(vec![(Species::CH4, 0.8), (Species::C2H6, 0.2)]).unwrap();
+        for (t, p) in [(250.0, 2e6), (350.0, 10e6), (210.0, 6e6)] {
+            let dt = 1e-3;
+            let dp = 1.0;
+            let (h, s, cp, v, g) = si(&get_eos_entry(eos, &mixture, point(t, p)).unwrap());
+            let (h_up, s_up, _, _, _) =
+                si(&get_eos_entry(eos, &mixture, point(t + dt, p)).unwrap());
+            let (h_down, s_down, _, _, _) =
+                si(&get_eos_entry(eos, &mixture, point(t - dt, p)).unwrap());
+            let (_, _, _, _, g_up) = si(&get_eos_entry(eos, &mixture, point(t, p + dp)).unwrap());
+            let (_, _, _, _, g_down) = si(&get_eos_entry(eos, &mixture, point(t, p - dp)).unwrap());
+            assert_approx_eq!(cp, (h_up - h_down) / (2f64 * dt), cp * 1e-5);
+            assert_approx_eq!(cp / t, (s_up - s_down) / (2f64 * dt), cp / t * 1e-5);
+            assert_approx_eq!(v, (g_up - g_down) / (2f64 * dp), v * 1e-5);
+            assert_approx_eq!(g, h - t * s, 1e-6);
+        }
+    }
+
+    #[test]
+    fn fugacity_matches_residual_gibbs() {
+        // For a pure species ln(φ) = (h_dep - T * s_dep) / (R * T)
+        for eos in [CubicEos::PengRobinson, CubicEos::SoaveRedlichKwong] {
+            let pure = Mixture::pure(Species::CO2);
+            let state = point(280.0, 3e6);
+            for z in get_compressibility_roots(eos, &pure, state).unwrap() {
+                let phi = get_fugacity_coefficients(eos, &pure, state, z).unwrap()[0];
+                let departure = get_departure_functions(eos, &pure, state, z).unwrap();
+                let expected = (departure.enthalpy - 280.0 * departure.entropy)
+                    / (UNIVERSAL_GAS_CONSTANT * 280.0);
+                assert_approx_eq!(expected, f64::ln(phi), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn phase_regions() {
+        let eos = CubicEos::PengRobinson;
+        let propane = Mixture::pure(Species::C3H8);
+        let liquid = get_eos_entry(eos, &propane, point(300.0, 2e6)).unwrap();
+        let vapor = get_eos_entry(eos, &propane, point(300.0, 0.5e6)).unwrap();
+        let supercritical = get_eos_entry(eos, &propane, point(400.0, 5e6)).unwrap();
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+            liquid.phase_region
+        );
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            vapor.phase_region
+        );
+        assert_eq!(PhaseRegion::SupercriticalFluid, supercritical.phase_region);
+        // NIST webbook gives 491 kg/m3, Peng-Robinson is known to under predict liquid densities
+        assert_approx_eq!(491.0, liquid.density.convert_to_si_unit().value, 50.0);
+    }
+
+    #[test]
+    fn methane_compressibility() {
+        // Lee-Kesler generalized chart at Tr = 1.31 and Pr = 1.09
+        let roots = get_compressibility_roots(
+            CubicEos::PengRobinson,
+            &Mixture::pure(Species::CH4),
+            point(250.0, 5e6),
+        )
+        .unwrap();
+        assert_approx_eq!(0.818, roots[0], 0.01);
+    }
+
+    #[test]
+    fn eos_errors() {
+        assert_eq!(
+            Err(EosErr::MissingCriticalConstants(Species::OH)),
+            get_eos_entry(
+                CubicEos::PengRobinson,
+                &Mixture::pure(Species::OH),
+                point(300.0, 1e5)
+            )
+        );
+        assert_eq!(
+            Err(EosErr::AboveCriticalTemperature),
+            get_saturation_pressure(
+                CubicEos::PengRobinson,
+                Species::CH4,
+                Temperature::K(K::new(200.0))
+            )
+        );
+        assert_eq!(
+            Err(EosErr::PressureMustBePositive),
+            get_eos_entry(CubicEos::PengRobinson, &Mixture::air(), point(300.0, 0.0))
+        );
+    }
+}
@@ -0,0 +1,512 @@
+// Gordon and McBride (1994) NASA RP-1311, chemical equilibrium by minimizing the Gibbs energy
+// with Lagrange multipliers for each element (the element potentials), ideal gases only
+use crate::numerical_methods::multivariate::solve_linear_system;
+use crate::numerical_methods::*;
+use crate::thermo::ideal_gas::*;
+use crate::thermo::steam::PtPoint;
+use crate::thermo::PtvEntry;
+use crate::units::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EquilibriumErr {
+    // None of the product species holds an element of the reactants
+    MissingElement(Element),
+    IdealGasErr(IdealGasErr),
+    FailedToConverge(RootFinderErr),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EquilibriumCondition {
+    Tp(PtPoint),
+    // The enthalpy includes the heats of formation, as given by get_ideal_gas_entry
+    Hp {
+        enthalpy: EnergyPerMass,
+        pressure: Pressure,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Equilibrium {
+    pub reactants: Mixture,
+    // Species which may form, get_product_species gives every species that can
+    pub products: Vec<Species>,
+    pub condition: EquilibriumCondition,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EquilibriumResult {
+    pub mixture: Mixture,
+    // Moles of products per mole of reactants
+    pub moles: f64,
+    // Frozen properties of the equilibrium mixture
+    pub entry: PtvEntry,
+}
+
+/// Every species made only of the elements in the reactants
+pub fn get_product_species(reactants: &Mixture) -> Vec<Species> {
+    let elements = get_elements(reactants);
+    Species::all()
+        .into_iter()
+        .filter(|species| {
+            Element::all()
+                .into_iter()
+                .all(|x| species.get_element_count(x) == 0f64 || elements.contains(&x))
+        })
+        .collect()
+}
+
+fn get_elements(reactants: &Mixture) -> Vec<Element> {
+    Element::all()
+        .into_iter()
+        .filter(|x| get_element_moles(reactants, *x) > 0f64)
+        .collect()
+}
+
+fn get_element_moles(reactants: &Mixture, element: Element) -> f64 {
+    reactants
+        .get_mole_fractions()
+        .iter()
+        .map(|(species, x)| x * species.get_element_count(element))
+        .sum()
+}
+
+// Dimensionless properties of each product species at a temperature
+struct SpeciesState {
+    // H / RT
+    enthalpy: Vec<f64>,
+    // G / RT at the standard state pressure
+    gibbs_free_energy: Vec<f64>,
+    // cp / R
+    cp: Vec<f64>,
+}
+
+fn get_species_state(products: &[Species], temperature: f64) -> Result<SpeciesState, IdealGasErr> {
+    let rt = UNIVERSAL_GAS_CONSTANT * temperature;
+    let mut state = SpeciesState {
+        enthalpy: vec![],
+        gibbs_free_energy: vec![],
+        cp: vec![],
+    };
+    for species in products.iter() {
+        state
+            .enthalpy
+            .push(species.get_molar_enthalpy(temperature)? / rt);
+        state
+            .gibbs_free_energy
+            .push(species.get_molar_standard_gibbs_free_energy(temperature)? / rt);
+        state
+            .cp
+            .push(species.get_molar_cp(temperature)? / UNIVERSAL_GAS_CONSTANT);
+    }
+    Ok(state)
+}
+
+/// Equilibrium composition per mole of reactants,
+/// the Newton corrections and step control follow CEA
+pub fn get_equilibrium(equilibrium: &Equilibrium) -> Result<EquilibriumResult, EquilibriumErr> {
+    let max_iter = 200;
+    let reactants = &equilibrium.reactants;
+    let elements = get_elements(reactants);
+    let products: Vec<Species> = equilibrium
+        .products
+        .iter()
+        .filter(|species| {
+            Element::all()
+                .into_iter()
+                .all(|x| species.get_element_count(x) == 0f64 || elements.contains(&x))
+        })
+        .cloned()
+        .collect();
+    if let Some(element) = elements
+        .iter()
+        .find(|x| products.iter().all(|y| y.get_element_count(**x) == 0f64))
+    {
+        return Err(EquilibriumErr::MissingElement(*element));
+    }
+
+    let (mut temperature, pressure, enthalpy_opt) = match equilibrium.condition {
+        EquilibriumCondition::Tp(point) => (
+            point.temperature.convert_to_si_unit().value,
+            point.pressure.convert_to_si_unit().value,
+            None,
+        ),
+        EquilibriumCondition::Hp { enthalpy, pressure } => (
+            2500f64,
+            pressure.convert_to_si_unit().value,
+            // J per mole of reactants
+            Some(enthalpy.convert_to_si_unit().value * reactants.get_molar_mass()),
+        ),
+    };
+    if pressure.is_nan() || pressure <= 0f64 {
+        return Err(EquilibriumErr::IdealGasErr(
+            IdealGasErr::PressureMustBePositive,
+        ));
+    }
+    let ln_pressure = f64::ln(pressure / STANDARD_PRESSURE);
+    let (min_temperature, max_temperature) =
+        products
+            .iter()
+            .fold((f64::MIN, f64::MAX), |(min, max), species| {
+                let polynomial = species.get_data().polynomial;
+                (
+                    f64::max(min, polynomial.min_temperature),
+                    f64::min(max, polynomial.max_temperature),
+                )
+            });
+
+    // a[i][j] atoms of element i in species j
+    let a: Vec<Vec<f64>> = elements
+        .iter()
+        .map(|x| products.iter().map(|y| y.get_element_count(*x)).collect())
+        .collect();
+    let b0: Vec<f64> = elements
+        .iter()
+        .map(|x| get_element_moles(reactants, *x))
+        .collect();
+    let (l, ns) = (elements.len(), products.len());
+    let size = l + 1 + usize::from(enthalpy_opt.is_some());
+
+    let mut ln_total = 0f64;
+    let mut ln_moles = vec![-f64::ln(ns as f64); ns];
+    let mut converged = false;
+    for _ in 0..max_iter {
+        let state =
+            get_species_state(&products, temperature).map_err(EquilibriumErr::IdealGasErr)?;
+        let moles: Vec<f64> = ln_moles.iter().map(|x| x.exp()).collect();
+        let total = ln_total.exp();
+        let sum_moles: f64 = moles.iter().sum();
+        // mu / RT
+        let mu: Vec<f64> = (0..ns)
+            .map(|j| state.gibbs_free_energy[j] + ln_moles[j] - ln_total + ln_pressure)
+            .collect();
+
+        let mut matrix = vec![vec![0f64; size]; size];
+        let mut rhs = vec![0f64; size];
+        for k in 0..l {
+            for i in 0..l {
+                matrix[k][i] = (0..ns).map(|j| a[k][j] * a[i][j] * moles[j]).sum();
+            }
+            matrix[k][l] = (0..ns).map(|j| a[k][j] * moles[j]).sum();
+            rhs[k] =
+                b0[k] - matrix[k][l] + (0..ns).map(|j| a[k][j] * moles[j] * mu[j]).sum::<f64>();
+        }
+        matrix[l] = matrix.iter().take(l).map(|row| row[l]).collect();
+        matrix[l].resize(size, 0f64);
+        matrix[l][l] = sum_moles - total;
+        rhs[l] = total - sum_moles + (0..ns).map(|j| moles[j] * mu[j]).sum::<f64>();
+        if let Some(enthalpy) = enthalpy_opt {
+            let e = l + 1;
+            for k in 0..l {
+                matrix[k][e] = (0..ns)
+                    .map(|j| a[k][j] * moles[j] * state.enthalpy[j])
+                    .sum();
+                matrix[e][k] = matrix[k][e];
+            }
+            let moles_enthalpy: f64 = (0..ns).map(|j| moles[j] * state.enthalpy[j]).sum();
+            matrix[l][e] = moles_enthalpy;
+            matrix[e][l] = moles_enthalpy;
+            matrix[e][e] = (0..ns)
+                .map(|j| moles[j] * (state.cp[j] + state.enthalpy[j].powi(2)))
+                .sum();
+            rhs[e] = enthalpy / (UNIVERSAL_GAS_CONSTANT * temperature) - moles_enthalpy
+                + (0..ns)
+                    .map(|j| moles[j] * state.enthalpy[j] * mu[j])
+                    .sum::<f64>();
+        }
+
+        let solution =
+            solve_linear_system(&matrix, &rhs).map_err(EquilibriumErr::FailedToConverge)?;
+        let delta_ln_total = solution[l];
+        let delta_ln_temperature = if enthalpy_opt.is_some() {
+            solution[l + 1]
+        } else {
+            0f64
+        };
+        let delta_ln_moles: Vec<f64> = (0..ns)
+            .map(|j| {
+                -mu[j]
+                    + (0..l).map(|i| a[i][j] * solution[i]).sum::<f64>()
+                    + delta_ln_total
+                    + state.enthalpy[j] * delta_ln_temperature
+            })
+            .collect();
+
+        converged = (0..ns).all(|j| moles[j] * delta_ln_moles[j].abs() / sum_moles <= 0.5e-5)
+            && total * delta_ln_total.abs() / sum_moles <= 0.5e-5
+            && delta_ln_temperature.abs() <= 1e-4;
+        if converged {
+            break;
+        }
+
+        // Limit the step of the major species and keep trace species from jumping above 1e-4
+        let ln_trace = -f64::ln(1e8);
+        let major_step = (0..ns)
+            .filter(|j| ln_moles[*j] - ln_total > ln_trace && delta_ln_moles[*j] > 0f64)
+            .map(|j| delta_ln_moles[j])
+            .fold(
+                5f64 * f64::max(delta_ln_temperature.abs(), delta_ln_total.abs()),
+                f64::max,
+            );
+        let trace_damping = (0..ns)
+            .filter(|j| ln_moles[*j] - ln_total <= ln_trace && delta_ln_moles[*j] >= 0f64)
+            .map(|j| {
+                ((-(ln_moles[j] - ln_total) - f64::ln(1e4)) / (delta_ln_moles[j] - delta_ln_total))
+                    .abs()
+            })
+            .fold(f64::MAX, f64::min);
+        let damping = f64::min(1f64, f64::min(2f64 / major_step, trace_damping));
+
+        for j in 0..ns {
+            ln_moles[j] += damping * delta_ln_moles[j];
+        }
+        ln_total += damping * delta_ln_total;
+        temperature = (temperature * f64::exp(damping * delta_ln_temperature))
+            .clamp(min_temperature, max_temperature);
+    }
+    if !converged {
+        return Err(EquilibriumErr::FailedToConverge(
+            RootFinderErr::MaxIterationsReached,
+        ));
+    }
+
+    let moles: Vec<f64> = ln_moles.iter().map(|x| x.exp()).collect();
+    let sum_moles: f64 = moles.iter().sum();
+    let mixture = Mixture::from_mole_fractions(
+        products
+            .iter()
+            .zip(moles.iter())
+            .map(|(species, x)| (*species, x / sum_moles))
+            .collect(),
+    )
+    .map_err(EquilibriumErr::IdealGasErr)?;
+    let entry = get_ideal_gas_entry(
+        &mixture,
+        PtPoint {
+            temperature: Temperature::K(K::new(temperature)),
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        },
+    )
+    .map_err(EquilibriumErr::IdealGasErr)?;
+    Ok(EquilibriumResult {
+        mixture,
+        moles: sum_moles,
+        entry,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_fraction(mixture: &Mixture, species: Species) -> f64 {
+        mixture
+            .get_mole_fractions()
+            .iter()
+            .filter(|(x, _)| *x == species)
+            .map(|(_, x)| *x)
+            .sum()
+    }
+
+    fn get_tp_equilibrium(
+        reactants: Vec<(Species, f64)>,
+        products: Option<Vec<Species>>,
+        temperature: f64,
+        pressure: f64,
+    ) -> EquilibriumResult {
+        let reactants = Mixture::from_mole_fractions(reactants).unwrap();
+        get_equilibrium(&Equilibrium {
+            products: products.unwrap_or_else(|| get_product_species(&reactants)),
+            reactants,
+            condition: EquilibriumCondition::Tp(PtPoint {
+                temperature: Temperature::K(K::new(temperature)),
+                pressure: Pressure::Pa(Pa::new(pressure)),
+            }),
+        })
+        .unwrap()
+    }
+
+    fn get_stoichiometric_methane_air() -> Mixture {
+        // CH4 + 2 (O2 + 3.76 N2)
+        let total = 1f64 + 2f64 * 4.76;
+        Mixture::from_mole_fractions(vec![
+            (Species::CH4, 1f64 / total),
+            (Species::O2, 2f64 / total),
+            (Species::N2, 7.52 / total),
+        ])
+        .unwrap()
+    }
+
+    macro_rules! carbon_dioxide_dissociation_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (K, Pa)
+                let (temperature, pressure): (f64, f64) = $value;
+                let result = get_tp_equilibrium(
+                    vec![(Species::CO2, 1f64)],
+                    Some(vec![Species::CO2, Species::CO, Species::O2]),
+                    temperature,
+                    pressure,
+                );
+                // CO2 <=> CO + 1/2 O2
+                let delta_g = Species::CO.get_molar_standard_gibbs_free_energy(temperature).unwrap()
+                    + 0.5 * Species::O2.get_molar_standard_gibbs_free_energy(temperature).unwrap()
+                    - Species::CO2.get_molar_standard_gibbs_free_energy(temperature).unwrap();
+                let kp = f64::exp(-delta_g / (UNIVERSAL_GAS_CONSTANT * temperature));
+                let x = |species| get_fraction(&result.mixture, species);
+                assert_approx_eq!(
+                    kp,
+                    x(Species::CO) * x(Species::O2).sqrt() / x(Species::CO2)
+                        * f64::sqrt(pressure / STANDARD_PRESSURE),
+                    1e-4 * kp
+                );
+                // Oxygen comes only from the CO
+                assert_approx_eq!(0.5 * x(Species::CO), x(Species::O2), 1e-5);
+            }
+        )*
+        }
+    }
+
+    carbon_dioxide_dissociation_tests! {
+        carbon_dioxide_dissociation_0: (2000.0, 101325.0),
+        carbon_dioxide_dissociation_1: (2500.0, 101325.0),
+        carbon_dioxide_dissociation_2: (3000.0, 101325.0),
+        carbon_dioxide_dissociation_3: (3000.0, 1e6),
+    }
+
+    #[test]
+    fn carbon_dioxide_dissociation_at_2500_k() {
+        // Kp from the JANAF tables' Gibbs energies of formation at 2500 K
+        let result = get_tp_equilibrium(
+            vec![(Species::CO2, 1f64)],
+            Some(vec![Species::CO2, Species::CO, Species::O2]),
+            2500.0,
+            1e5,
+        );
+        assert_approx_eq!(0.1209, get_fraction(&result.mixture, Species::CO), 0.0015);
+        assert_approx_eq!(0.8186, get_fraction(&result.mixture, Species::CO2), 0.0015);
+    }
+
+    #[test]
+    fn complete_combustion_at_low_temperature() {
+        let result = get_tp_equilibrium(
+            vec![(Species::CH4, 1f64 / 3f64), (Species::O2, 2f64 / 3f64)],
+            None,
+            800.0,
+            101325.0,
+        );
+        assert_approx_eq!(
+            1f64 / 3f64,
+            get_fraction(&result.mixture, Species::CO2),
+            1e-5
+        );
+        assert_approx_eq!(
+            2f64 / 3f64,
+            get_fraction(&result.mixture, Species::H2O),
+            1e-5
+        );
+        assert_approx_eq!(1f64, result.moles, 1e-5);
+    }
+
+    #[test]
+    fn methane_partial_oxidation() {
+        // CH4 + 1/2 O2 -> CO + 2 H2 goes almost to completion at gasifier temperatures
+        let result = get_tp_equilibrium(
+            vec![(Species::CH4, 2f64 / 3f64), (Species::O2, 1f64 / 3f64)],
+            None,
+            1200.0,
+            101325.0,
+        );
+        assert_approx_eq!(
+            1f64 / 3f64,
+            get_fraction(&result.mixture, Species::CO),
+            0.02
+        );
+        assert_approx_eq!(
+            2f64 / 3f64,
+            get_fraction(&result.mixture, Species::H2),
+            0.02
+        );
+        assert!(get_fraction(&result.mixture, Species::CH4) < 0.01);
+    }
+
+    #[test]
+    fn stoichiometric_methane_air_flame() {
+        // NASA CEA, hp problem at 1 atm with reactants at 298.15 K
+        let reactants = get_stoichiometric_methane_air();
+        let reactant_entry = get_ideal_gas_entry(
+            &reactants,
+            PtPoint {
+                temperature: Temperature::K(K::new(298.15)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            },
+        )
+        .unwrap();
+        let result = get_equilibrium(&Equilibrium {
+            products: get_product_species(&reactants),
+            reactants: reactants.clone(),
+            condition: EquilibriumCondition::Hp {
+                enthalpy: reactant_entry.enthalpy,
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            },
+        })
+        .unwrap();
+        let x = |species| get_fraction(&result.mixture, species);
+        assert_approx_eq!(
+            2226.0,
+            result.entry.temperature.convert_to_si_unit().value,
+            10.0
+        );
+        assert_approx_eq!(0.0853, x(Species::CO2), 0.002);
+        assert_approx_eq!(0.1834, x(Species::H2O), 0.002);
+        assert_approx_eq!(0.0087, x(Species::CO), 0.001);
+        assert_approx_eq!(0.0045, x(Species::O2), 0.001);
+        assert_approx_eq!(0.0029, x(Species::OH), 0.0005);
+        assert_approx_eq!(0.0020, x(Species::NO), 0.0005);
+        // The energy balance closes
+        assert_approx_eq!(
+            reactant_entry.enthalpy.convert_to_si_unit().value,
+            result.entry.enthalpy.convert_to_si_unit().value,
+            1.0
+        );
+        // Elements are conserved
+        for element in [Element::C, Element::H, Element::O, Element::N] {
+            assert_approx_eq!(
+                get_element_moles(&reactants, element),
+                result.moles * get_element_moles(&result.mixture, element),
+                1e-5
+            );
+        }
+    }
+
+    #[test]
+    fn equilibrium_errors() {
+        let reactants = Mixture::pure(Species::CO2);
+        let query = |products, pressure| {
+            get_equilibrium(&Equilibrium {
+                reactants: reactants.clone(),
+                products,
+                condition: EquilibriumCondition::Tp(PtPoint {
+                    temperature: Temperature::K(K::new(2000.0)),
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                }),
+            })
+        };
+        assert_eq!(
+            Err(EquilibriumErr::MissingElement(Element::C)),
+            query(vec![Species::O2], 101325.0)
+        );
+        assert_eq!(
+            Err(EquilibriumErr::IdealGasErr(
+                IdealGasErr::PressureMustBePositive
+            )),
+            query(vec![Species::CO2], -1.0)
+        );
+        assert_eq!(
+            vec![Species::O2, Species::CO2, Species::CO, Species::O],
+            get_product_species(&reactants)
+        );
+    }
+}
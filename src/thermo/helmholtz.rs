@@ -0,0 +1,518 @@
+// Multiparameter equations of state explicit in the reduced Helmholtz free energy,
+// phi(delta, tau) = phi_o + phi_r with delta = rho / rho_r and tau = T_r / T
+use crate::numerical_methods::RootFinderErr;
+use crate::thermo::steam::*;
+use crate::thermo::*;
+
+/// Planck-Einstein term of the ideal part, n * ln(1 - exp(-gamma * tau))
+#[derive(Debug)]
+pub struct IdealTerm {
+    pub n: f64,
+    pub gamma: f64,
+}
+
+/// Power term of the ideal part, n * tau^t
+#[derive(Debug)]
+pub struct IdealPowerTerm {
+    pub n: f64,
+    pub t: f64,
+}
+
+/// n * delta^d * tau^t * exp(-delta^c), polynomial when c is zero
+#[derive(Debug)]
+pub struct ResidualTerm {
+    pub c: f64,
+    pub d: f64,
+    pub t: f64,
+    pub n: f64,
+}
+
+#[derive(Debug)]
+pub struct GaussianTerm {
+    pub d: f64,
+    pub t: f64,
+    pub n: f64,
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub epsilon: f64,
+}
+
+#[derive(Debug)]
+pub struct NonAnalyticTerm {
+    pub a: f64,
+    pub b: f64,
+    pub big_b: f64,
+    pub n: f64,
+    pub big_c: f64,
+    pub big_d: f64,
+    pub big_a: f64,
+    pub beta: f64,
+}
+
+#[derive(Debug)]
+pub struct HelmholtzEos {
+    // J/(kg * K)
+    pub specific_gas_constant: f64,
+    // K
+    pub reducing_temperature: f64,
+    // kg/m3
+    pub reducing_density: f64,
+    // phi_o = ln(delta) + n1 + n2 * tau + n3 * ln(tau) + the power and Planck-Einstein terms
+    pub ideal_n1: f64,
+    pub ideal_n2: f64,
+    pub ideal_n3: f64,
+    pub ideal_power: &'static [IdealPowerTerm],
+    pub ideal: &'static [IdealTerm],
+    pub residual: &'static [ResidualTerm],
+    pub gaussian: &'static [GaussianTerm],
+    pub non_analytic: &'static [NonAnalyticTerm],
+}
+
+#[derive(Debug)]
+pub struct HelmholtzPoint {
+    pub delta: f64,
+    pub tau: f64,
+    pub phi_o: f64,
+    pub phi_o_tau: f64,
+    pub phi_o_tau_tau: f64,
+    pub phi_r: f64,
+    pub phi_r_delta: f64,
+    pub phi_r_delta_delta: f64,
+    pub phi_r_tau: f64,
+    pub phi_r_tau_tau: f64,
+    pub phi_r_delta_tau: f64,
+}
+
+pub struct SaturationState {
+    // Pa
+    pub pressure: f64,
+    // kg/m3
+    pub liquid_density: f64,
+    // kg/m3
+    pub vapor_density: f64,
+}
+
+// Densities scanned for the limits of mechanical stability,
+// as multiples of the reducing density
+const SPINODAL_SCAN_MAX_DENSITY: f64 = 3.6;
+const SPINODAL_SCAN_STEPS: usize = 400;
+const MIN_DENSITY: f64 = 1e-12;
+
+impl HelmholtzEos {
+    pub fn get_helmholtz_point(&self, delta: f64, tau: f64) -> HelmholtzPoint {
+        let mut phi_o =
+            f64::ln(delta) + self.ideal_n1 + self.ideal_n2 * tau + self.ideal_n3 * f64::ln(tau);
+        let mut phi_o_tau = self.ideal_n2 + self.ideal_n3 / tau;
+        let mut phi_o_tau_tau = -self.ideal_n3 / f64::powi(tau, 2);
+        for term in self.ideal_power.iter() {
+            let base = term.n * f64::powf(tau, term.t);
+            phi_o += base;
+            phi_o_tau += base * term.t / tau;
+            phi_o_tau_tau += base * term.t * (term.t - 1f64) / f64::powi(tau, 2);
+        }
+        for term in self.ideal.iter() {
+            let e = f64::exp(-term.gamma * tau);
+            phi_o += term.n * f64::ln(1f64 - e);
+            phi_o_tau += term.n * term.gamma * (1f64 / (1f64 - e) - 1f64);
+            phi_o_tau_tau -= term.n * f64::powi(term.gamma, 2) * e / f64::powi(1f64 - e, 2);
+        }
+
+        let mut phi_r = 0f64;
+        let mut phi_r_delta = 0f64;
+        let mut phi_r_delta_delta = 0f64;
+        let mut phi_r_tau = 0f64;
+        let mut phi_r_tau_tau = 0f64;
+        let mut phi_r_delta_tau = 0f64;
+
+        for term in self.residual.iter() {
+            let (c, d, t, n) = (term.c, term.d, term.t, term.n);
+            // delta^c drops out of the polynomial terms
+            let (e, delta_c) = if c == 0f64 {
+                (1f64, 0f64)
+            } else {
+                let delta_c = f64::powf(delta, c);
+                (f64::exp(-delta_c), c * delta_c)
+            };
+            let base = n * e * f64::powf(delta, d) * f64::powf(tau, t);
+            phi_r += base;
+            phi_r_delta += base * (d - delta_c) / delta;
+            phi_r_delta_delta +=
+                base * ((d - delta_c) * (d - 1f64 - delta_c) - c * delta_c) / f64::powi(delta, 2);
+            phi_r_tau += base * t / tau;
+            phi_r_tau_tau += base * t * (t - 1f64) / f64::powi(tau, 2);
+            phi_r_delta_tau += base * t * (d - delta_c) / (delta * tau);
+        }
+
+        for term in self.gaussian.iter() {
+            let (d, t, n) = (term.d, term.t, term.n);
+            let delta_diff = delta - term.epsilon;
+            let tau_diff = tau - term.gamma;
+            let base = n
+                * f64::powf(delta, d)
+                * f64::powf(tau, t)
+                * f64::exp(
+                    -term.alpha * f64::powi(delta_diff, 2) - term.beta * f64::powi(tau_diff, 2),
+                );
+            let d_delta = d / delta - 2f64 * term.alpha * delta_diff;
+            let d_tau = t / tau - 2f64 * term.beta * tau_diff;
+            phi_r += base;
+            phi_r_delta += base * d_delta;
+            phi_r_delta_delta +=
+                base * (f64::powi(d_delta, 2) - d / f64::powi(delta, 2) - 2f64 * term.alpha);
+            phi_r_tau += base * d_tau;
+            phi_r_tau_tau +=
+                base * (f64::powi(d_tau, 2) - t / f64::powi(tau, 2) - 2f64 * term.beta);
+            phi_r_delta_tau += base * d_delta * d_tau;
+        }
+
+        // The non-analytic terms are singular at exactly the critical density
+        let delta_shifted = if delta == 1f64 { 1f64 + 1e-12 } else { delta };
+        for term in self.non_analytic.iter() {
+            let delta_diff = delta_shifted - 1f64;
+            let delta_diff_2 = f64::powi(delta_diff, 2);
+            let tau_diff = tau - 1f64;
+            let inv_2beta = 1f64 / (2f64 * term.beta);
+            let theta = (1f64 - tau) + term.big_a * f64::powf(delta_diff_2, inv_2beta);
+            let big_delta = f64::powi(theta, 2) + term.big_b * f64::powf(delta_diff_2, term.a);
+            let psi = f64::exp(-term.big_c * delta_diff_2 - term.big_d * f64::powi(tau_diff, 2));
+
+            let big_delta_d = delta_diff
+                * (term.big_a
+                    * theta
+                    * (2f64 / term.beta)
+                    * f64::powf(delta_diff_2, inv_2beta - 1f64)
+                    + 2f64 * term.big_b * term.a * f64::powf(delta_diff_2, term.a - 1f64));
+            let big_delta_dd = big_delta_d / delta_diff
+                + delta_diff_2
+                    * (4f64
+                        * term.big_b
+                        * term.a
+                        * (term.a - 1f64)
+                        * f64::powf(delta_diff_2, term.a - 2f64)
+                        + 2f64
+                            * f64::powi(term.big_a / term.beta, 2)
+                            * f64::powi(f64::powf(delta_diff_2, inv_2beta - 1f64), 2)
+                        + term.big_a
+                            * theta
+                            * (4f64 / term.beta)
+                            * (inv_2beta - 1f64)
+                            * f64::powf(delta_diff_2, inv_2beta - 2f64));
+
+            let b = term.b;
+            let db = f64::powf(big_delta, b);
+            let db_d = b * f64::powf(big_delta, b - 1f64) * big_delta_d;
+            let db_dd = b
+                * (f64::powf(big_delta, b - 1f64) * big_delta_dd
+                    + (b - 1f64) * f64::powf(big_delta, b - 2f64) * f64::powi(big_delta_d, 2));
+            let db_t = -2f64 * theta * b * f64::powf(big_delta, b - 1f64);
+            let db_tt = 2f64 * b * f64::powf(big_delta, b - 1f64)
+                + 4f64 * f64::powi(theta, 2) * b * (b - 1f64) * f64::powf(big_delta, b - 2f64);
+            let db_dt = -term.big_a
+                * b
+                * (2f64 / term.beta)
+                * f64::powf(big_delta, b - 1f64)
+                * delta_diff
+                * f64::powf(delta_diff_2, inv_2beta - 1f64)
+                - 2f64 * theta * b * (b - 1f64) * f64::powf(big_delta, b - 2f64) * big_delta_d;
+
+            let psi_d = -2f64 * term.big_c * delta_diff * psi;
+            let psi_dd = (2f64 * term.big_c * delta_diff_2 - 1f64) * 2f64 * term.big_c * psi;
+            let psi_t = -2f64 * term.big_d * tau_diff * psi;
+            let psi_tt =
+                (2f64 * term.big_d * f64::powi(tau_diff, 2) - 1f64) * 2f64 * term.big_d * psi;
+            let psi_dt = 4f64 * term.big_c * term.big_d * delta_diff * tau_diff * psi;
+
+            let n = term.n;
+            phi_r += n * db * delta * psi;
+            phi_r_delta += n * (db * (psi + delta * psi_d) + db_d * delta * psi);
+            phi_r_delta_delta += n
+                * (db * (2f64 * psi_d + delta * psi_dd)
+                    + 2f64 * db_d * (psi + delta * psi_d)
+                    + db_dd * delta * psi);
+            phi_r_tau += n * delta * (db_t * psi + db * psi_t);
+            phi_r_tau_tau += n * delta * (db_tt * psi + 2f64 * db_t * psi_t + db * psi_tt);
+            phi_r_delta_tau += n
+                * (db * (psi_t + delta * psi_dt)
+                    + delta * db_d * psi_t
+                    + db_t * (psi + delta * psi_d)
+                    + db_dt * delta * psi);
+        }
+
+        HelmholtzPoint {
+            delta,
+            tau,
+            phi_o,
+            phi_o_tau,
+            phi_o_tau_tau,
+            phi_r,
+            phi_r_delta,
+            phi_r_delta_delta,
+            phi_r_tau,
+            phi_r_tau_tau,
+            phi_r_delta_tau,
+        }
+    }
+
+    pub fn get_point(&self, temperature: f64, density: f64) -> HelmholtzPoint {
+        self.get_helmholtz_point(
+            density / self.reducing_density,
+            self.reducing_temperature / temperature,
+        )
+    }
+
+    pub fn get_pressure(&self, point: &HelmholtzPoint, temperature: f64, density: f64) -> f64 {
+        density
+            * self.specific_gas_constant
+            * temperature
+            * (1f64 + point.delta * point.phi_r_delta)
+    }
+
+    /// (dp/drho) at constant temperature
+    pub fn get_pressure_density_derivative(&self, point: &HelmholtzPoint, temperature: f64) -> f64 {
+        self.specific_gas_constant
+            * temperature
+            * (1f64
+                + 2f64 * point.delta * point.phi_r_delta
+                + f64::powi(point.delta, 2) * point.phi_r_delta_delta)
+    }
+
+    pub fn get_gibbs_free_energy(&self, point: &HelmholtzPoint, temperature: f64) -> f64 {
+        self.specific_gas_constant
+            * temperature
+            * (1f64 + point.phi_o + point.phi_r + point.delta * point.phi_r_delta)
+    }
+
+    pub fn get_entropy(&self, point: &HelmholtzPoint) -> f64 {
+        self.specific_gas_constant
+            * (point.tau * (point.phi_o_tau + point.phi_r_tau) - point.phi_o - point.phi_r)
+    }
+
+    pub fn create_entry(
+        &self,
+        point: &HelmholtzPoint,
+        temperature: f64,
+        density: f64,
+        phase_region: PhaseRegion,
+    ) -> PtvEntry {
+        let r = self.specific_gas_constant;
+        let delta = point.delta;
+        let tau = point.tau;
+        let phi = point.phi_o + point.phi_r;
+        let phi_tau = point.phi_o_tau + point.phi_r_tau;
+        let phi_tau_tau = point.phi_o_tau_tau + point.phi_r_tau_tau;
+        let pressure = self.get_pressure(point, temperature, density);
+        let specific_volume = 1f64 / density;
+
+        let internal_energy = r * temperature * tau * phi_tau;
+        let enthalpy = r * temperature * (1f64 + tau * phi_tau + delta * point.phi_r_delta);
+        let entropy = r * (tau * phi_tau - phi);
+        let cv = -r * f64::powi(tau, 2) * phi_tau_tau;
+        // rho * R * (dp/dT) at constant density
+        let dp_dt_term = 1f64 + delta * point.phi_r_delta - delta * tau * point.phi_r_delta_tau;
+        let dp_drho_term =
+            1f64 + 2f64 * delta * point.phi_r_delta + f64::powi(delta, 2) * point.phi_r_delta_delta;
+        let cp = cv + r * f64::powi(dp_dt_term, 2) / dp_drho_term;
+        let speed_of_sound = f64::sqrt(
+            r * temperature
+                * (dp_drho_term - f64::powi(dp_dt_term, 2) / (f64::powi(tau, 2) * phi_tau_tau)),
+        );
+        let gibbs_free_energy = self.get_gibbs_free_energy(point, temperature);
+        let helmholtz_free_energy = r * temperature * phi;
+        let isothermal_compressibility = 1f64 / (density * r * temperature * dp_drho_term);
+        let isobaric_expansion = isothermal_compressibility * density * r * dp_dt_term;
+        let joule_thomson = specific_volume * (temperature * isobaric_expansion - 1f64) / cp;
+        let isentropic_exponent = f64::powi(speed_of_sound, 2) / (pressure * specific_volume);
+        PtvEntry {
+            temperature: Temperature::K(K::new(temperature)),
+            pressure: Pressure::Pa(Pa::new(pressure)),
+            phase_region,
+            internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+            entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+            cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv)),
+            cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
+            speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
+            specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+            density: Density::KgPerM3(KgPerM3::new(density)),
+            gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(gibbs_free_energy)),
+            helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+            isobaric_expansion: InverseTemperature::PerK(PerK::new(isobaric_expansion)),
+            isothermal_compressibility: InversePressure::PerPa(PerPa::new(
+                isothermal_compressibility,
+            )),
+            joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(joule_thomson)),
+            isentropic_exponent,
+        }
+    }
+
+    fn get_pressure_at(&self, temperature: f64, density: f64) -> f64 {
+        self.get_pressure(&self.get_point(temperature, density), temperature, density)
+    }
+
+    fn is_stable_at(&self, temperature: f64, density: f64) -> bool {
+        self.get_pressure_density_derivative(&self.get_point(temperature, density), temperature)
+            > 0f64
+    }
+
+    /// Vapor and liquid densities where (dp/drho) at constant temperature falls to zero,
+    /// none above the critical temperature where the isotherm has no loop
+    pub fn get_spinodal_densities(&self, temperature: f64) -> Option<(f64, f64)> {
+        let max_density = SPINODAL_SCAN_MAX_DENSITY * self.reducing_density;
+        let densities = (0..=SPINODAL_SCAN_STEPS)
+            .map(|i| {
+                self.reducing_density * 1e-6 + max_density * i as f64 / SPINODAL_SCAN_STEPS as f64
+            })
+            .collect::<Vec<f64>>();
+        let stable = densities
+            .iter()
+            .map(|x| self.is_stable_at(temperature, *x))
+            .collect::<Vec<bool>>();
+        let vapor = (0..SPINODAL_SCAN_STEPS).find(|i| stable[*i] && !stable[i + 1])?;
+        let liquid = (1..=SPINODAL_SCAN_STEPS)
+            .rev()
+            .find(|i| stable[*i] && !stable[i - 1])?;
+        // Bisects between a stable and an unstable density
+        let bisect = |mut stable_density: f64, mut unstable_density: f64| {
+            for _ in 0..60 {
+                let mid = 0.5 * (stable_density + unstable_density);
+                if self.is_stable_at(temperature, mid) {
+                    stable_density = mid;
+                } else {
+                    unstable_density = mid;
+                }
+            }
+            0.5 * (stable_density + unstable_density)
+        };
+        Some((
+            bisect(densities[vapor], densities[vapor + 1]),
+            bisect(densities[liquid], densities[liquid - 1]),
+        ))
+    }
+
+    /// Density at a pressure between two densities which bracket it,
+    /// Newton's method falling back to bisection whenever a step leaves the bracket
+    pub fn get_density_between(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        min_density: f64,
+        max_density: f64,
+    ) -> Option<f64> {
+        let (mut lo, mut hi) = (min_density, max_density);
+        if self.get_pressure_at(temperature, lo) > pressure
+            || self.get_pressure_at(temperature, hi) < pressure
+        {
+            return None;
+        }
+        let mut density = 0.5 * (lo + hi);
+        for _ in 0..100 {
+            let point = self.get_point(temperature, density);
+            let residual = self.get_pressure(&point, temperature, density) - pressure;
+            if residual > 0f64 {
+                hi = density;
+            } else {
+                lo = density;
+            }
+            let slope = self.get_pressure_density_derivative(&point, temperature);
+            let next = density - residual / slope;
+            let next = if slope > 0f64 && lo < next && next < hi {
+                next
+            } else {
+                0.5 * (lo + hi)
+            };
+            if f64::abs(next - density) < 1e-13 * density {
+                return Some(next);
+            }
+            density = next;
+        }
+        Some(density)
+    }
+
+    /// Highest density needed to reach a pressure, beyond the spinodal scan for cold liquids
+    fn get_max_density(&self, temperature: f64, pressure: f64) -> f64 {
+        let mut max_density = SPINODAL_SCAN_MAX_DENSITY * self.reducing_density;
+        for _ in 0..20 {
+            if self.get_pressure_at(temperature, max_density) >= pressure {
+                break;
+            }
+            max_density *= 1.1;
+        }
+        max_density
+    }
+
+    /// Density on the liquid branch of the isotherm,
+    /// the only branch above the critical temperature
+    pub fn get_liquid_density(&self, temperature: f64, pressure: f64) -> Option<f64> {
+        let min_density = self
+            .get_spinodal_densities(temperature)
+            .map(|(_, liquid)| liquid)
+            .unwrap_or(MIN_DENSITY);
+        let max_density = self.get_max_density(temperature, pressure);
+        self.get_density_between(temperature, pressure, min_density, max_density)
+    }
+
+    /// Density on the vapor branch of the isotherm,
+    /// the only branch above the critical temperature
+    pub fn get_vapor_density(&self, temperature: f64, pressure: f64) -> Option<f64> {
+        match self.get_spinodal_densities(temperature) {
+            Some((vapor, _)) => self.get_density_between(temperature, pressure, MIN_DENSITY, vapor),
+            None => self.get_liquid_density(temperature, pressure),
+        }
+    }
+
+    /// Phase equilibrium (equal pressure and Gibbs free energy in both phases)
+    /// bracketed by the pressures at the spinodals so it needs no starting densities
+    pub fn get_saturation_state(&self, temperature: f64) -> Result<SaturationState, SteamQueryErr> {
+        let above_critical = SteamQueryErr::OutOfRange(OutOfRange::AboveCriticalTemperature);
+        if temperature >= self.reducing_temperature {
+            return Err(above_critical);
+        }
+        let (vapor_spinodal, liquid_spinodal) = self
+            .get_spinodal_densities(temperature)
+            .ok_or(above_critical)?;
+        let max_density = SPINODAL_SCAN_MAX_DENSITY * self.reducing_density;
+        let mut hi = self.get_pressure_at(temperature, vapor_spinodal);
+        let mut lo = f64::max(self.get_pressure_at(temperature, liquid_spinodal), 0f64);
+        let mut pressure = 0.5 * (lo + hi);
+        for _ in 0..200 {
+            let densities = (
+                self.get_density_between(temperature, pressure, liquid_spinodal, max_density),
+                self.get_density_between(temperature, pressure, MIN_DENSITY, vapor_spinodal),
+            );
+            let (liquid_density, vapor_density) = match densities {
+                (Some(liquid), Some(vapor)) => (liquid, vapor),
+                _ => break,
+            };
+            let gibbs_diff = self
+                .get_gibbs_free_energy(&self.get_point(temperature, liquid_density), temperature)
+                - self.get_gibbs_free_energy(
+                    &self.get_point(temperature, vapor_density),
+                    temperature,
+                );
+            if gibbs_diff > 0f64 {
+                lo = pressure;
+            } else {
+                hi = pressure;
+            }
+            // dg = dp / rho at constant temperature
+            let next = pressure - gibbs_diff / (1f64 / liquid_density - 1f64 / vapor_density);
+            let next = if lo < next && next < hi {
+                next
+            } else {
+                0.5 * (lo + hi)
+            };
+            if f64::abs(next - pressure) < 1e-12 * pressure {
+                return Ok(SaturationState {
+                    pressure,
+                    liquid_density,
+                    vapor_density,
+                });
+            }
+            pressure = next;
+        }
+        Err(SteamQueryErr::FailedToConverge(
+            RootFinderErr::MaxIterationsReached,
+        ))
+    }
+}
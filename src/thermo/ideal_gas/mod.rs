@@ -0,0 +1,615 @@
+// McBride, Gordon and Reno (1993) NASA TM-4513, coefficients for calculating thermodynamic properties of individual species
+use std::fmt::Display;
+
+use crate::thermo::steam::PtPoint;
+use crate::thermo::*;
+
+mod species_constants;
+
+use species_constants::*;
+
+// J/(mol * K)
+pub static UNIVERSAL_GAS_CONSTANT: f64 = 8.314462618;
+// Pa, standard state pressure of the polynomials
+pub static STANDARD_PRESSURE: f64 = 1e5;
+// K, the polynomials' enthalpy includes the heat of formation at this temperature
+pub static REFERENCE_TEMPERATURE: f64 = 298.15;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Element {
+    C,
+    H,
+    O,
+    N,
+    Ar,
+    He,
+}
+
+impl Element {
+    pub fn all() -> Vec<Element> {
+        vec![
+            Element::C,
+            Element::H,
+            Element::O,
+            Element::N,
+            Element::Ar,
+            Element::He,
+        ]
+    }
+
+    /// Atomic mass in kg/mol
+    pub fn get_atomic_mass(&self) -> f64 {
+        match self {
+            Element::C => 12.0107e-3,
+            Element::H => 1.00794e-3,
+            Element::O => 15.9994e-3,
+            Element::N => 14.0067e-3,
+            Element::Ar => 39.948e-3,
+            Element::He => 4.002602e-3,
+        }
+    }
+}
+
+/// Dimensionless NASA 7-coefficient fit with one set of coefficients
+/// below the mid temperature and another above it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NasaPolynomial {
+    // K
+    pub min_temperature: f64,
+    pub mid_temperature: f64,
+    pub max_temperature: f64,
+    pub low: [f64; 7],
+    pub high: [f64; 7],
+}
+
+impl NasaPolynomial {
+    fn get_coefficients(&self, temperature: f64) -> Result<&[f64; 7], IdealGasErr> {
+        if temperature < self.min_temperature {
+            Err(IdealGasErr::TemperatureLow)
+        } else if temperature > self.max_temperature {
+            Err(IdealGasErr::TemperatureHigh)
+        } else if temperature < self.mid_temperature {
+            Ok(&self.low)
+        } else {
+            Ok(&self.high)
+        }
+    }
+
+    /// cp / R
+    pub fn get_cp(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        let a = self.get_coefficients(temperature)?;
+        let t = temperature;
+        Ok(a[0] + t * (a[1] + t * (a[2] + t * (a[3] + t * a[4]))))
+    }
+
+    /// h / (R * T)
+    pub fn get_enthalpy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        let a = self.get_coefficients(temperature)?;
+        let t = temperature;
+        Ok(a[0]
+            + t * (a[1] / 2f64 + t * (a[2] / 3f64 + t * (a[3] / 4f64 + t * a[4] / 5f64)))
+            + a[5] / t)
+    }
+
+    /// s° / R at the standard state pressure
+    pub fn get_entropy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        let a = self.get_coefficients(temperature)?;
+        let t = temperature;
+        Ok(a[0] * f64::ln(t)
+            + t * (a[1] + t * (a[2] / 2f64 + t * (a[3] / 3f64 + t * a[4] / 4f64)))
+            + a[6])
+    }
+
+    /// g° / (R * T) at the standard state pressure
+    pub fn get_gibbs_free_energy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        Ok(self.get_enthalpy(temperature)? - self.get_entropy(temperature)?)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SpeciesData {
+    pub name: &'static str,
+    pub formula: &'static str,
+    // Number of atoms of each element in one molecule
+    pub elements: &'static [(Element, f64)],
+    pub polynomial: NasaPolynomial,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Species {
+    N2,
+    O2,
+    Ar,
+    He,
+    CO2,
+    H2O,
+    CO,
+    H2,
+    CH4,
+    C2H6,
+    C3H8,
+    OH,
+    H,
+    O,
+    NO,
+    N,
+}
+
+impl Species {
+    pub fn all() -> Vec<Species> {
+        vec![
+            Species::N2,
+            Species::O2,
+            Species::Ar,
+            Species::He,
+            Species::CO2,
+            Species::H2O,
+            Species::CO,
+            Species::H2,
+            Species::CH4,
+            Species::C2H6,
+            Species::C3H8,
+            Species::OH,
+            Species::H,
+            Species::O,
+            Species::NO,
+            Species::N,
+        ]
+    }
+
+    pub fn get_data(&self) -> &'static SpeciesData {
+        match self {
+            Species::N2 => &NITROGEN,
+            Species::O2 => &OXYGEN,
+            Species::Ar => &ARGON,
+            Species::He => &HELIUM,
+            Species::CO2 => &CARBON_DIOXIDE,
+            Species::H2O => &WATER,
+            Species::CO => &CARBON_MONOXIDE,
+            Species::H2 => &HYDROGEN,
+            Species::CH4 => &METHANE,
+            Species::C2H6 => &ETHANE,
+            Species::C3H8 => &PROPANE,
+            Species::OH => &HYDROXYL,
+            Species::H => &ATOMIC_HYDROGEN,
+            Species::O => &ATOMIC_OXYGEN,
+            Species::NO => &NITRIC_OXIDE,
+            Species::N => &ATOMIC_NITROGEN,
+        }
+    }
+
+    /// kg/mol
+    pub fn get_molar_mass(&self) -> f64 {
+        self.get_data()
+            .elements
+            .iter()
+            .map(|(element, count)| element.get_atomic_mass() * count)
+            .sum()
+    }
+
+    /// Number of atoms of the element in one molecule
+    pub fn get_element_count(&self, element: Element) -> f64 {
+        self.get_data()
+            .elements
+            .iter()
+            .filter(|(x, _)| *x == element)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// J/(mol * K), temperature in K
+    pub fn get_molar_cp(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        Ok(UNIVERSAL_GAS_CONSTANT * self.get_data().polynomial.get_cp(temperature)?)
+    }
+
+    /// J/mol including the heat of formation, temperature in K
+    pub fn get_molar_enthalpy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        Ok(UNIVERSAL_GAS_CONSTANT
+            * temperature
+            * self.get_data().polynomial.get_enthalpy(temperature)?)
+    }
+
+    /// J/(mol * K) at the standard state pressure, temperature in K
+    pub fn get_molar_standard_entropy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        Ok(UNIVERSAL_GAS_CONSTANT * self.get_data().polynomial.get_entropy(temperature)?)
+    }
+
+    /// J/mol at the standard state pressure, temperature in K
+    pub fn get_molar_standard_gibbs_free_energy(
+        &self,
+        temperature: f64,
+    ) -> Result<f64, IdealGasErr> {
+        Ok(UNIVERSAL_GAS_CONSTANT
+            * temperature
+            * self
+                .get_data()
+                .polynomial
+                .get_gibbs_free_energy(temperature)?)
+    }
+}
+
+impl TryFrom<String> for Species {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Species::all()
+            .into_iter()
+            .find(|x| x.get_data().formula == value)
+            .ok_or(format!("Unknown Species \"{}\"", value))
+    }
+}
+
+impl From<Species> for String {
+    fn from(val: Species) -> Self {
+        val.get_data().formula.to_owned()
+    }
+}
+
+impl Display for Species {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_data().formula)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum IdealGasErr {
+    // Outside of the polynomial's temperature range
+    TemperatureLow,
+    TemperatureHigh,
+    PressureMustBePositive,
+    NoSpecies,
+    FractionsMustBePositive,
+    FractionsDoNotAddUpToOne,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Mixture {
+    // Mole fractions with each species listed once
+    components: Vec<(Species, f64)>,
+}
+
+impl Mixture {
+    pub fn pure(species: Species) -> Mixture {
+        Mixture {
+            components: vec![(species, 1f64)],
+        }
+    }
+
+    /// Dry air by mole fraction
+    pub fn air() -> Mixture {
+        Mixture {
+            components: vec![
+                (Species::N2, 0.78084),
+                (Species::O2, 0.20946),
+                (Species::Ar, 0.00934),
+                (Species::CO2, 0.00036),
+            ],
+        }
+    }
+
+    pub fn from_mole_fractions(fractions: Vec<(Species, f64)>) -> Result<Mixture, IdealGasErr> {
+        if fractions.is_empty() {
+            return Err(IdealGasErr::NoSpecies);
+        }
+        if fractions.iter().any(|(_, x)| x.is_nan() || *x < 0f64) {
+            return Err(IdealGasErr::FractionsMustBePositive);
+        }
+        let total: f64 = fractions.iter().map(|(_, x)| x).sum();
+        if f64::abs(total - 1f64) > 1e-6 {
+            return Err(IdealGasErr::FractionsDoNotAddUpToOne);
+        }
+        let mut components: Vec<(Species, f64)> = vec![];
+        for (species, fraction) in fractions {
+            match components.iter_mut().find(|(x, _)| *x == species) {
+                Some((_, x)) => *x += fraction / total,
+                None => components.push((species, fraction / total)),
+            }
+        }
+        Ok(Mixture { components })
+    }
+
+    pub fn from_mass_fractions(fractions: Vec<(Species, f64)>) -> Result<Mixture, IdealGasErr> {
+        if fractions.iter().any(|(_, x)| x.is_nan() || *x < 0f64) {
+            return Err(IdealGasErr::FractionsMustBePositive);
+        }
+        let total: f64 = fractions.iter().map(|(_, x)| x).sum();
+        if !fractions.is_empty() && f64::abs(total - 1f64) > 1e-6 {
+            return Err(IdealGasErr::FractionsDoNotAddUpToOne);
+        }
+        let moles_per_mass: f64 = fractions
+            .iter()
+            .map(|(species, x)| x / species.get_molar_mass())
+            .sum();
+        Mixture::from_mole_fractions(
+            fractions
+                .into_iter()
+                .map(|(species, x)| (species, x / species.get_molar_mass() / moles_per_mass))
+                .collect(),
+        )
+    }
+
+    pub fn get_mole_fractions(&self) -> &[(Species, f64)] {
+        &self.components
+    }
+
+    pub fn get_mass_fractions(&self) -> Vec<(Species, f64)> {
+        let molar_mass = self.get_molar_mass();
+        self.components
+            .iter()
+            .map(|(species, x)| (*species, x * species.get_molar_mass() / molar_mass))
+            .collect()
+    }
+
+    /// kg/mol
+    pub fn get_molar_mass(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|(species, x)| x * species.get_molar_mass())
+            .sum()
+    }
+
+    /// J/(kg * K)
+    pub fn get_gas_constant(&self) -> f64 {
+        UNIVERSAL_GAS_CONSTANT / self.get_molar_mass()
+    }
+
+    fn sum_species(
+        &self,
+        property: impl Fn(&Species) -> Result<f64, IdealGasErr>,
+    ) -> Result<f64, IdealGasErr> {
+        self.components
+            .iter()
+            .map(|(species, x)| Ok(x * property(species)?))
+            .sum()
+    }
+
+    /// J/(mol * K), temperature in K
+    pub fn get_molar_cp(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        self.sum_species(|x| x.get_molar_cp(temperature))
+    }
+
+    /// J/mol including the heats of formation, temperature in K
+    pub fn get_molar_enthalpy(&self, temperature: f64) -> Result<f64, IdealGasErr> {
+        self.sum_species(|x| x.get_molar_enthalpy(temperature))
+    }
+
+    /// J/(mol * K) including the entropy of mixing, temperature in K and pressure in Pa
+    pub fn get_molar_entropy(&self, temperature: f64, pressure: f64) -> Result<f64, IdealGasErr> {
+        if pressure.is_nan() || pressure <= 0f64 {
+            return Err(IdealGasErr::PressureMustBePositive);
+        }
+        let standard_entropy = self.sum_species(|x| x.get_molar_standard_entropy(temperature))?;
+        let mixing_entropy: f64 = self
+            .components
+            .iter()
+            .filter(|(_, x)| *x > 0f64)
+            .map(|(_, x)| -x * f64::ln(*x))
+            .sum();
+        Ok(standard_entropy
+            + UNIVERSAL_GAS_CONSTANT * (mixing_entropy - f64::ln(pressure / STANDARD_PRESSURE)))
+    }
+
+    /// J/mol, temperature in K and pressure in Pa
+    pub fn get_molar_gibbs_free_energy(
+        &self,
+        temperature: f64,
+        pressure: f64,
+    ) -> Result<f64, IdealGasErr> {
+        Ok(self.get_molar_enthalpy(temperature)?
+            - temperature * self.get_molar_entropy(temperature, pressure)?)
+    }
+}
+
+/// Properties of an ideal gas mixture per unit mass,
+/// the enthalpy includes the heats of formation so reacting mixtures share one reference
+pub fn get_ideal_gas_entry(mixture: &Mixture, point: PtPoint) -> Result<PtvEntry, IdealGasErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    let molar_mass = mixture.get_molar_mass();
+    let gas_constant = mixture.get_gas_constant();
+
+    let cp = mixture.get_molar_cp(temperature)? / molar_mass;
+    let cv = cp - gas_constant;
+    let enthalpy = mixture.get_molar_enthalpy(temperature)? / molar_mass;
+    let entropy = mixture.get_molar_entropy(temperature, pressure)? / molar_mass;
+    let internal_energy = enthalpy - gas_constant * temperature;
+    let specific_volume = gas_constant * temperature / pressure;
+    let gibbs_free_energy = enthalpy - temperature * entropy;
+    let helmholtz_free_energy = internal_energy - temperature * entropy;
+    let isentropic_exponent = cp / cv;
+    let speed_of_sound = f64::sqrt(isentropic_exponent * gas_constant * temperature);
+
+    Ok(PtvEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region: PhaseRegion::Gas,
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(gibbs_free_energy)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(1f64 / temperature)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(1f64 / pressure)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(0f64)),
+        isentropic_exponent,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! standard_state_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // JANAF and ATcT values at 298.15 K, kJ/mol and J/(mol * K)
+                let (species, heat_of_formation, cp, entropy) = $value;
+                let t = REFERENCE_TEMPERATURE;
+                assert_approx_eq!(
+                    heat_of_formation,
+                    species.get_molar_enthalpy(t).unwrap() / 1e3,
+                    0.1
+                );
+                assert_approx_eq!(cp, species.get_molar_cp(t).unwrap(), 0.1);
+                assert_approx_eq!(entropy, species.get_molar_standard_entropy(t).unwrap(), 0.1);
+            }
+        )*
+        }
+    }
+
+    standard_state_tests! {
+        nitrogen_standard_state: (Species::N2, 0.0, 29.12, 191.61),
+        oxygen_standard_state: (Species::O2, 0.0, 29.38, 205.15),
+        argon_standard_state: (Species::Ar, 0.0, 20.79, 154.8),
+        helium_standard_state: (Species::He, 0.0, 20.79, 126.15),
+        carbon_dioxide_standard_state: (Species::CO2, -393.52, 37.12, 213.79),
+        water_standard_state: (Species::H2O, -241.83, 33.59, 188.84),
+        carbon_monoxide_standard_state: (Species::CO, -110.53, 29.14, 197.66),
+        hydrogen_standard_state: (Species::H2, 0.0, 28.84, 130.68),
+        methane_standard_state: (Species::CH4, -74.6, 35.70, 186.37),
+        ethane_standard_state: (Species::C2H6, -83.85, 52.49, 229.2),
+        // GRI-Mech keeps the older heat of formation for OH
+        hydroxyl_standard_state: (Species::OH, 39.35, 29.89, 183.7),
+        atomic_hydrogen_standard_state: (Species::H, 218.0, 20.79, 114.72),
+        atomic_oxygen_standard_state: (Species::O, 249.18, 21.91, 161.06),
+        nitric_oxide_standard_state: (Species::NO, 91.27, 29.86, 210.76),
+        atomic_nitrogen_standard_state: (Species::N, 472.68, 20.79, 153.3),
+    }
+
+    #[test]
+    fn propane_standard_state() {
+        // Fit starts at 300 K
+        let t = 300.0;
+        assert_approx_eq!(
+            -103.8,
+            Species::C3H8.get_molar_enthalpy(t).unwrap() / 1e3,
+            0.3
+        );
+        assert_approx_eq!(73.9, Species::C3H8.get_molar_cp(t).unwrap(), 0.5);
+        assert_approx_eq!(
+            270.3,
+            Species::C3H8.get_molar_standard_entropy(t).unwrap(),
+            0.5
+        );
+    }
+
+    #[test]
+    fn polynomials_are_continuous_at_mid_temperature() {
+        for species in Species::all() {
+            let polynomial = species.get_data().polynomial;
+            let t = polynomial.mid_temperature;
+            let below = NasaPolynomial {
+                mid_temperature: polynomial.max_temperature,
+                ..polynomial
+            };
+            let above = NasaPolynomial {
+                mid_temperature: polynomial.min_temperature,
+                ..polynomial
+            };
+            assert_approx_eq!(below.get_cp(t).unwrap(), above.get_cp(t).unwrap(), 1e-2);
+            assert_approx_eq!(
+                below.get_enthalpy(t).unwrap(),
+                above.get_enthalpy(t).unwrap(),
+                1e-2
+            );
+            assert_approx_eq!(
+                below.get_entropy(t).unwrap(),
+                above.get_entropy(t).unwrap(),
+                1e-2
+            );
+        }
+    }
+
+    #[test]
+    fn species_parse_round_trip() {
+        for species in Species::all() {
+            assert_eq!(Ok(species), Species::try_from(String::from(species)));
+        }
+    }
+
+    #[test]
+    fn air_properties() {
+        let point = PtPoint {
+            temperature: Temperature::K(K::new(300.0)),
+            pressure: Pressure::Pa(Pa::new(101325.0)),
+        };
+        let air = Mixture::air();
+        let entry = get_ideal_gas_entry(&air, point).unwrap();
+        assert_approx_eq!(28.96e-3, air.get_molar_mass(), 1e-5);
+        assert_approx_eq!(1005.0, entry.cp.convert_to_si_unit().value, 3.0);
+        assert_approx_eq!(1.400, entry.isentropic_exponent, 1e-3);
+        assert_approx_eq!(1.177, entry.density.convert_to_si_unit().value, 1e-3);
+        assert_approx_eq!(347.2, entry.speed_of_sound.convert_to_si_unit().value, 0.5);
+        assert_eq!(PhaseRegion::Gas, entry.phase_region);
+    }
+
+    #[test]
+    fn air_enthalpy_change() {
+        // Cengel table A-17 gives h(1000 K) - h(300 K) = 1046.04 - 300.19 kJ/kg
+        let air = Mixture::air();
+        let delta = (air.get_molar_enthalpy(1000.0).unwrap()
+            - air.get_molar_enthalpy(300.0).unwrap())
+            / air.get_molar_mass();
+        assert_approx_eq!(745.85e3, delta, 2e3);
+    }
+
+    #[test]
+    fn mass_fraction_round_trip() {
+        let mixture = Mixture::from_mass_fractions(vec![
+            (Species::CH4, 0.5),
+            (Species::CO2, 0.25),
+            (Species::H2O, 0.25),
+        ])
+        .unwrap();
+        let expected = [0.5, 0.25, 0.25];
+        for ((_, actual), expected) in mixture.get_mass_fractions().iter().zip(expected) {
+            assert_approx_eq!(expected, *actual, 1e-12);
+        }
+        let total: f64 = mixture.get_mole_fractions().iter().map(|(_, x)| x).sum();
+        assert_approx_eq!(1.0, total, 1e-12);
+    }
+
+    #[test]
+    fn entropy_of_mixing() {
+        let mixture =
+            Mixture::from_mole_fractions(vec![(Species::N2, 0.5), (Species::O2, 0.5)]).unwrap();
+        let t = 400.0;
+        let p = STANDARD_PRESSURE;
+        let expected = 0.5 * Species::N2.get_molar_standard_entropy(t).unwrap()
+            + 0.5 * Species::O2.get_molar_standard_entropy(t).unwrap()
+            + UNIVERSAL_GAS_CONSTANT * f64::ln(2.0);
+        assert_approx_eq!(expected, mixture.get_molar_entropy(t, p).unwrap(), 1e-9);
+    }
+
+    #[test]
+    fn mixture_errors() {
+        assert_eq!(
+            Err(IdealGasErr::NoSpecies),
+            Mixture::from_mole_fractions(vec![])
+        );
+        assert_eq!(
+            Err(IdealGasErr::FractionsDoNotAddUpToOne),
+            Mixture::from_mole_fractions(vec![(Species::N2, 0.5), (Species::O2, 0.4)])
+        );
+        assert_eq!(
+            Err(IdealGasErr::FractionsMustBePositive),
+            Mixture::from_mass_fractions(vec![(Species::N2, 1.5), (Species::O2, -0.5)])
+        );
+        assert_eq!(
+            Err(IdealGasErr::TemperatureHigh),
+            Species::H2O.get_molar_cp(4000.0)
+        );
+        assert_eq!(
+            Err(IdealGasErr::TemperatureLow),
+            Mixture::air().get_molar_cp(100.0)
+        );
+    }
+}
@@ -0,0 +1,432 @@
+// NASA 7-coefficient polynomials from the GRI-Mech 3.0 thermodynamic database
+// http://combustion.berkeley.edu/gri-mech/version30/files30/thermo30.dat
+// Coefficients are listed as [a1, a2, a3, a4, a5, a6, a7]
+use super::*;
+
+pub static NITROGEN: SpeciesData = SpeciesData {
+    name: "Nitrogen",
+    formula: "N2",
+    elements: &[(Element::N, 2f64)],
+    polynomial: NasaPolynomial {
+        // The published fit starts at 300 K and is extrapolated down to 200 K
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 5000.0,
+        low: [
+            3.298677,
+            1.4082404e-3,
+            -3.963222e-6,
+            5.641515e-9,
+            -2.444854e-12,
+            -1020.8999,
+            3.950372,
+        ],
+        high: [
+            2.92664,
+            1.4879768e-3,
+            -5.68476e-7,
+            1.0097038e-10,
+            -6.753351e-15,
+            -922.7977,
+            5.980528,
+        ],
+    },
+};
+
+pub static OXYGEN: SpeciesData = SpeciesData {
+    name: "Oxygen",
+    formula: "O2",
+    elements: &[(Element::O, 2f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            3.78245636,
+            -2.99673416e-3,
+            9.84730201e-6,
+            -9.68129509e-9,
+            3.24372837e-12,
+            -1.06394356e3,
+            3.65767573,
+        ],
+        high: [
+            3.28253784,
+            1.48308754e-3,
+            -7.57966669e-7,
+            2.09470555e-10,
+            -2.16717794e-14,
+            -1.08845772e3,
+            5.45323129,
+        ],
+    },
+};
+
+pub static ARGON: SpeciesData = SpeciesData {
+    name: "Argon",
+    formula: "Ar",
+    elements: &[(Element::Ar, 1f64)],
+    polynomial: NasaPolynomial {
+        // The published fit starts at 300 K and is extrapolated down to 200 K
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 5000.0,
+        low: [2.5, 0.0, 0.0, 0.0, 0.0, -745.375, 4.366],
+        high: [2.5, 0.0, 0.0, 0.0, 0.0, -745.375, 4.366],
+    },
+};
+
+// Not in GRI-Mech, Burcat's third millennium database
+pub static HELIUM: SpeciesData = SpeciesData {
+    name: "Helium",
+    formula: "He",
+    elements: &[(Element::He, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 6000.0,
+        low: [2.5, 0.0, 0.0, 0.0, 0.0, -745.375, 0.928723974],
+        high: [2.5, 0.0, 0.0, 0.0, 0.0, -745.375, 0.928723974],
+    },
+};
+
+pub static CARBON_DIOXIDE: SpeciesData = SpeciesData {
+    name: "Carbon Dioxide",
+    formula: "CO2",
+    elements: &[(Element::C, 1f64), (Element::O, 2f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            2.35677352,
+            8.98459677e-3,
+            -7.12356269e-6,
+            2.45919022e-9,
+            -1.43699548e-13,
+            -4.83719697e4,
+            9.90105222,
+        ],
+        high: [
+            3.85746029,
+            4.41437026e-3,
+            -2.21481404e-6,
+            5.23490188e-10,
+            -4.72084164e-14,
+            -4.8759166e4,
+            2.27163806,
+        ],
+    },
+};
+
+pub static WATER: SpeciesData = SpeciesData {
+    name: "Water",
+    formula: "H2O",
+    elements: &[(Element::H, 2f64), (Element::O, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            4.19864056,
+            -2.0364341e-3,
+            6.52040211e-6,
+            -5.48797062e-9,
+            1.77197817e-12,
+            -3.02937267e4,
+            -8.49032208e-1,
+        ],
+        high: [
+            3.03399249,
+            2.17691804e-3,
+            -1.64072518e-7,
+            -9.7041987e-11,
+            1.68200992e-14,
+            -3.00042971e4,
+            4.9667701,
+        ],
+    },
+};
+
+pub static CARBON_MONOXIDE: SpeciesData = SpeciesData {
+    name: "Carbon Monoxide",
+    formula: "CO",
+    elements: &[(Element::C, 1f64), (Element::O, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            3.57953347,
+            -6.1035368e-4,
+            1.01681433e-6,
+            9.07005884e-10,
+            -9.04424499e-13,
+            -1.4344086e4,
+            3.50840928,
+        ],
+        high: [
+            2.71518561,
+            2.06252743e-3,
+            -9.98825771e-7,
+            2.30053008e-10,
+            -2.03647716e-14,
+            -1.41518724e4,
+            7.81868772,
+        ],
+    },
+};
+
+pub static HYDROGEN: SpeciesData = SpeciesData {
+    name: "Hydrogen",
+    formula: "H2",
+    elements: &[(Element::H, 2f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            2.34433112,
+            7.98052075e-3,
+            -1.9478151e-5,
+            2.01572094e-8,
+            -7.37611761e-12,
+            -917.935173,
+            6.83010238e-1,
+        ],
+        high: [
+            3.3372792,
+            -4.94024731e-5,
+            4.99456778e-7,
+            -1.79566394e-10,
+            2.00255376e-14,
+            -950.158922,
+            -3.20502331,
+        ],
+    },
+};
+
+pub static METHANE: SpeciesData = SpeciesData {
+    name: "Methane",
+    formula: "CH4",
+    elements: &[(Element::C, 1f64), (Element::H, 4f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            5.14987613,
+            -1.36709788e-2,
+            4.91800599e-5,
+            -4.84743026e-8,
+            1.66693956e-11,
+            -1.02466476e4,
+            -4.64130376,
+        ],
+        high: [
+            7.4851495e-2,
+            1.33909467e-2,
+            -5.73285809e-6,
+            1.22292535e-9,
+            -1.0181523e-13,
+            -9.46834459e3,
+            1.8437318e1,
+        ],
+    },
+};
+
+pub static ETHANE: SpeciesData = SpeciesData {
+    name: "Ethane",
+    formula: "C2H6",
+    elements: &[(Element::C, 2f64), (Element::H, 6f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            4.29142492,
+            -5.5015427e-3,
+            5.99438288e-5,
+            -7.08466285e-8,
+            2.68685771e-11,
+            -1.15222055e4,
+            2.66682316,
+        ],
+        high: [
+            1.0718815,
+            2.16852677e-2,
+            -1.00256067e-5,
+            2.21412001e-9,
+            -1.9000289e-13,
+            -1.14263932e4,
+            1.51156107e1,
+        ],
+    },
+};
+
+pub static PROPANE: SpeciesData = SpeciesData {
+    name: "Propane",
+    formula: "C3H8",
+    elements: &[(Element::C, 3f64), (Element::H, 8f64)],
+    polynomial: NasaPolynomial {
+        // The published fit starts at 300 K and is extrapolated down to 200 K
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 5000.0,
+        low: [
+            0.93355381,
+            2.6424579e-2,
+            6.1059727e-6,
+            -2.1977499e-8,
+            9.5149253e-12,
+            -1.395852e4,
+            1.9201691e1,
+        ],
+        high: [
+            7.5341368,
+            1.8872239e-2,
+            -6.2718491e-6,
+            9.1475649e-10,
+            -4.7838069e-14,
+            -1.6467516e4,
+            -1.7892349e1,
+        ],
+    },
+};
+
+pub static HYDROXYL: SpeciesData = SpeciesData {
+    name: "Hydroxyl",
+    formula: "OH",
+    elements: &[(Element::O, 1f64), (Element::H, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            3.99201543,
+            -2.40131752e-3,
+            4.61793841e-6,
+            -3.88113333e-9,
+            1.3641147e-12,
+            3.61508056e3,
+            -1.03925458e-1,
+        ],
+        high: [
+            3.09288767,
+            5.48429716e-4,
+            1.26505228e-7,
+            -8.79461556e-11,
+            1.17412376e-14,
+            3.858657e3,
+            4.4766961,
+        ],
+    },
+};
+
+pub static ATOMIC_HYDROGEN: SpeciesData = SpeciesData {
+    name: "Atomic Hydrogen",
+    formula: "H",
+    elements: &[(Element::H, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            2.5,
+            7.05332819e-13,
+            -1.99591964e-15,
+            2.30081632e-18,
+            -9.27732332e-22,
+            2.54736599e4,
+            -4.46682853e-1,
+        ],
+        high: [
+            2.50000001,
+            -2.30842973e-11,
+            1.61561948e-14,
+            -4.73515235e-18,
+            4.98197357e-22,
+            2.54736599e4,
+            -4.46682914e-1,
+        ],
+    },
+};
+
+pub static ATOMIC_OXYGEN: SpeciesData = SpeciesData {
+    name: "Atomic Oxygen",
+    formula: "O",
+    elements: &[(Element::O, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 3500.0,
+        low: [
+            3.1682671,
+            -3.27931884e-3,
+            6.64306396e-6,
+            -6.12806624e-9,
+            2.11265971e-12,
+            2.91222592e4,
+            2.05193346,
+        ],
+        high: [
+            2.56942078,
+            -8.59741137e-5,
+            4.19484589e-8,
+            -1.00177799e-11,
+            1.22833691e-15,
+            2.92175791e4,
+            4.78433864,
+        ],
+    },
+};
+
+pub static NITRIC_OXIDE: SpeciesData = SpeciesData {
+    name: "Nitric Oxide",
+    formula: "NO",
+    elements: &[(Element::N, 1f64), (Element::O, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 6000.0,
+        low: [
+            4.2184763,
+            -4.638976e-3,
+            1.1041022e-5,
+            -9.3361354e-9,
+            2.803577e-12,
+            9.844623e3,
+            2.2808464,
+        ],
+        high: [
+            3.2606056,
+            1.1911043e-3,
+            -4.2917048e-7,
+            6.9457669e-11,
+            -4.0336099e-15,
+            9.9209746e3,
+            6.3693027,
+        ],
+    },
+};
+
+pub static ATOMIC_NITROGEN: SpeciesData = SpeciesData {
+    name: "Atomic Nitrogen",
+    formula: "N",
+    elements: &[(Element::N, 1f64)],
+    polynomial: NasaPolynomial {
+        min_temperature: 200.0,
+        mid_temperature: 1000.0,
+        max_temperature: 6000.0,
+        low: [2.5, 0.0, 0.0, 0.0, 0.0, 5.6104637e4, 4.1939087],
+        high: [
+            2.4159429,
+            1.7489065e-4,
+            -1.1902369e-7,
+            3.0226245e-11,
+            -2.0360982e-15,
+            5.6133773e4,
+            4.6496096,
+        ],
+    },
+};
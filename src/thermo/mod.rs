@@ -2,7 +2,19 @@ use std::fmt;
 
 use crate::units::*;
 
+pub mod combustion;
+pub mod cycles;
+pub mod diagrams;
+pub mod eos;
+pub mod equilibrium;
+pub mod helmholtz;
+pub mod ideal_gas;
+pub mod partial_derivatives;
+pub mod psychrometrics;
+mod refrigerant_constants;
+pub mod refrigerants;
 pub mod steam;
+pub mod vle;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PhaseRegion {
@@ -75,6 +87,8 @@ pub enum CompositePhaseRegion {
 pub enum CompositePhaseRegionErr {
     FractionsDoNotAddUpToOne,
     FractionsMustBePositive,
+    // Each phase must list the same components
+    CompositionLengthMismatch,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -109,6 +123,9 @@ impl SolidLiquid {
 pub struct LiquidVapor {
     liquid_frac: f64,
     vapor_frac: f64,
+    // Mole fractions of each component in each phase, empty for pure substances
+    liquid_composition: Vec<f64>,
+    vapor_composition: Vec<f64>,
 }
 
 impl LiquidVapor {
@@ -117,12 +134,40 @@ impl LiquidVapor {
             Ok(LiquidVapor {
                 liquid_frac,
                 vapor_frac,
+                liquid_composition: vec![],
+                vapor_composition: vec![],
             })
         } else {
             Err(CompositePhaseRegionErr::FractionsDoNotAddUpToOne)
         }
     }
 
+    /// Mixture split into a liquid and a vapor phase,
+    /// the compositions list the same components in the same order
+    pub fn with_compositions(
+        liquid_frac: f64,
+        vapor_frac: f64,
+        liquid_composition: Vec<f64>,
+        vapor_composition: Vec<f64>,
+    ) -> Result<LiquidVapor, CompositePhaseRegionErr> {
+        if liquid_composition.len() != vapor_composition.len() {
+            return Err(CompositePhaseRegionErr::CompositionLengthMismatch);
+        }
+        for composition in [&liquid_composition, &vapor_composition] {
+            if composition.iter().any(|x| x.is_nan() || *x < 0f64) {
+                return Err(CompositePhaseRegionErr::FractionsMustBePositive);
+            }
+            if f64::abs(composition.iter().sum::<f64>() - 1f64) > 1e-9 {
+                return Err(CompositePhaseRegionErr::FractionsDoNotAddUpToOne);
+            }
+        }
+        Ok(LiquidVapor {
+            liquid_composition,
+            vapor_composition,
+            ..LiquidVapor::new(liquid_frac, vapor_frac)?
+        })
+    }
+
     pub fn get_liquid_frac(&self) -> f64 {
         self.liquid_frac
     }
@@ -130,6 +175,14 @@ impl LiquidVapor {
     pub fn get_vapor_frac(&self) -> f64 {
         self.vapor_frac
     }
+
+    pub fn get_liquid_composition(&self) -> &[f64] {
+        &self.liquid_composition
+    }
+
+    pub fn get_vapor_composition(&self) -> &[f64] {
+        &self.vapor_composition
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -173,7 +226,7 @@ impl SolidLiquidVapor {
         solid_frac: f64,
         liquid_frac: f64,
         vapor_frac: f64,
-    ) -> Result<SolidLiquidVapor, String> {
+    ) -> Result<SolidLiquidVapor, CompositePhaseRegionErr> {
         if solid_frac + liquid_frac + vapor_frac == 1.0 {
             Ok(SolidLiquidVapor {
                 solid_frac,
@@ -181,7 +234,7 @@ impl SolidLiquidVapor {
                 vapor_frac,
             })
         } else {
-            Err(String::from("Fractions must add up to 1"))
+            Err(CompositePhaseRegionErr::FractionsDoNotAddUpToOne)
         }
     }
 
@@ -221,4 +274,22 @@ pub struct PtvEntry {
     pub speed_of_sound: Velocity,
     // in m3 / kg
     pub specific_volume: SpecificVolume,
+    // kg/m3
+    pub density: Density,
+    // J/kg
+    pub gibbs_free_energy: EnergyPerMass,
+    // J/kg
+    pub helmholtz_free_energy: EnergyPerMass,
+    /// Isobaric cubic expansion coefficient
+    /// (1/v) * (dv/dT) at constant pressure (1/K)
+    pub isobaric_expansion: InverseTemperature,
+    /// Isothermal compressibility
+    /// -(1/v) * (dv/dp) at constant temperature (1/Pa)
+    pub isothermal_compressibility: InversePressure,
+    /// Joule-Thomson coefficient
+    /// (dT/dp) at constant enthalpy (K/Pa)
+    pub joule_thomson: TemperaturePerPressure,
+    /// Isentropic exponent
+    /// -(v/p) * (dp/dv) at constant entropy
+    pub isentropic_exponent: f64,
 }
@@ -0,0 +1,130 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ThermoProperty {
+    Pressure,
+    Temperature,
+    SpecificVolume,
+    Density,
+    InternalEnergy,
+    Enthalpy,
+    Entropy,
+    GibbsFreeEnergy,
+    HelmholtzFreeEnergy,
+}
+
+// Partial derivatives of a property with respect to temperature and pressure
+// (dx/dT at constant p, dx/dp at constant T) in SI units
+fn get_tp_derivatives(entry: &PtvEntry, property: ThermoProperty) -> (f64, f64) {
+    let t = entry.temperature.convert_to_si_unit().value;
+    let p = entry.pressure.convert_to_si_unit().value;
+    let v = entry.specific_volume.convert_to_si_unit().value;
+    let s = entry.entropy.convert_to_si_unit().value;
+    let cp = entry.cp.convert_to_si_unit().value;
+    let alpha = entry.isobaric_expansion.convert_to_si_unit().value;
+    let kappa = entry.isothermal_compressibility.convert_to_si_unit().value;
+    match property {
+        ThermoProperty::Pressure => (0f64, 1f64),
+        ThermoProperty::Temperature => (1f64, 0f64),
+        ThermoProperty::SpecificVolume => (v * alpha, -v * kappa),
+        ThermoProperty::Density => (-alpha / v, kappa / v),
+        ThermoProperty::InternalEnergy => (cp - p * v * alpha, -t * v * alpha + p * v * kappa),
+        ThermoProperty::Enthalpy => (cp, v - t * v * alpha),
+        ThermoProperty::Entropy => (cp / t, -v * alpha),
+        ThermoProperty::GibbsFreeEnergy => (-s, v),
+        ThermoProperty::HelmholtzFreeEnergy => (-s - p * v * alpha, p * v * kappa),
+    }
+}
+
+// Bridgman's (dx)_z, written as the Jacobian d(x, z) / d(T, p)
+fn get_bridgman_term(entry: &PtvEntry, x: ThermoProperty, z: ThermoProperty) -> f64 {
+    let (x_t, x_p) = get_tp_derivatives(entry, x);
+    let (z_t, z_p) = get_tp_derivatives(entry, z);
+    x_t * z_p - x_p * z_t
+}
+
+/// (dx/dy) at constant z in SI units evaluated at the given state
+///
+/// Any of the listed properties can be used for x, y and z, which covers every
+/// entry of Bridgman's table. Returns NaN when y and z are the same property.
+pub fn get_partial_derivative(
+    entry: &PtvEntry,
+    x: ThermoProperty,
+    y: ThermoProperty,
+    z: ThermoProperty,
+) -> f64 {
+    get_bridgman_term(entry, x, z) / get_bridgman_term(entry, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws97::get_steam_table_entry;
+    use crate::thermo::steam::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_entry() -> PtvEntry {
+        get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            temperature: Temperature::K(K::new(823.15)),
+            pressure: Pressure::Pa(Pa::new(14e6)),
+        }))
+        .unwrap()
+    }
+
+    macro_rules! partial_derivative_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let entry = get_entry();
+                let ((x, y, z), expected, tol): ((ThermoProperty, ThermoProperty, ThermoProperty), fn(&PtvEntry) -> f64, f64) = $value;
+                assert_approx_eq!(expected(&entry), get_partial_derivative(&entry, x, y, z), tol);
+            }
+        )*
+        }
+    }
+
+    partial_derivative_tests! {
+        partial_derivative_01: (
+            (ThermoProperty::Enthalpy, ThermoProperty::Temperature, ThermoProperty::Pressure),
+            |e| e.cp.convert_to_si_unit().value,
+            1e-6
+        ),
+        partial_derivative_02: (
+            (ThermoProperty::InternalEnergy, ThermoProperty::Temperature, ThermoProperty::SpecificVolume),
+            |e| e.cv.convert_to_si_unit().value,
+            1e-6
+        ),
+        partial_derivative_03: (
+            (ThermoProperty::Temperature, ThermoProperty::Pressure, ThermoProperty::Enthalpy),
+            |e| e.joule_thomson.convert_to_si_unit().value,
+            1e-15
+        ),
+        partial_derivative_04: (
+            (ThermoProperty::Pressure, ThermoProperty::Density, ThermoProperty::Entropy),
+            |e| f64::powi(e.speed_of_sound.convert_to_si_unit().value, 2),
+            1e-3
+        ),
+        partial_derivative_05: (
+            (ThermoProperty::Enthalpy, ThermoProperty::Entropy, ThermoProperty::Pressure),
+            |e| e.temperature.convert_to_si_unit().value,
+            1e-9
+        ),
+        partial_derivative_06: (
+            (ThermoProperty::GibbsFreeEnergy, ThermoProperty::Pressure, ThermoProperty::Temperature),
+            |e| e.specific_volume.convert_to_si_unit().value,
+            1e-12
+        ),
+        partial_derivative_07: (
+            (ThermoProperty::HelmholtzFreeEnergy, ThermoProperty::Temperature, ThermoProperty::SpecificVolume),
+            |e| -e.entropy.convert_to_si_unit().value,
+            1e-6
+        ),
+        partial_derivative_08: (
+            (ThermoProperty::Pressure, ThermoProperty::Temperature, ThermoProperty::SpecificVolume),
+            |e| e.isobaric_expansion.convert_to_si_unit().value
+                / e.isothermal_compressibility.convert_to_si_unit().value,
+            1e-6
+        ),
+    }
+}
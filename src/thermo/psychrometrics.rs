@@ -0,0 +1,585 @@
+// ASHRAE Handbook Fundamentals (2017) chapter 1, moist air treated as an ideal gas mixture
+use crate::numerical_methods::root_finders::secant_method;
+use crate::thermo::diagrams::*;
+use crate::thermo::steam::iapws97::{get_saturation_pressure, get_saturation_temperature};
+use crate::thermo::steam::ice::{get_sublimation_pressure, get_sublimation_temperature};
+use crate::thermo::steam::*;
+use crate::units::*;
+
+// Molar mass of water over the molar mass of dry air
+static MOLAR_MASS_RATIO: f64 = 0.621945;
+// J/(kg * K)
+static DRY_AIR_GAS_CONSTANT: f64 = 287.042;
+// J/(kg * K), specific heats at constant pressure near room temperature
+static DRY_AIR_CP: f64 = 1006.0;
+static WATER_VAPOR_CP: f64 = 1860.0;
+static LIQUID_WATER_CP: f64 = 4186.0;
+static ICE_CP: f64 = 2100.0;
+// J/kg, enthalpy of water vapor and of sublimation at 0 °C
+static HEAT_OF_VAPORIZATION: f64 = 2501e3;
+static HEAT_OF_SUBLIMATION: f64 = 2830e3;
+static ZERO_CELSIUS: f64 = 273.15;
+// Saturated water vapor in air has a slightly higher partial pressure than pure saturated vapor,
+// ASHRAE tabulates between 1.004 and 1.005 near atmospheric pressure
+static ENHANCEMENT_FACTOR: f64 = 1.004;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PsychrometricQuery {
+    RelativeHumidity {
+        dry_bulb: Temperature,
+        pressure: Pressure,
+        // Between 0 and 1
+        relative_humidity: f64,
+    },
+    HumidityRatio {
+        dry_bulb: Temperature,
+        pressure: Pressure,
+        // kg of water vapor per kg of dry air
+        humidity_ratio: f64,
+    },
+    WetBulb {
+        dry_bulb: Temperature,
+        pressure: Pressure,
+        wet_bulb: Temperature,
+    },
+    DewPoint {
+        dry_bulb: Temperature,
+        pressure: Pressure,
+        dew_point: Temperature,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MoistAirEntry {
+    pub dry_bulb: Temperature,
+    pub pressure: Pressure,
+    // kg of water vapor per kg of dry air
+    pub humidity_ratio: f64,
+    pub relative_humidity: f64,
+    // Humidity ratio over the saturated humidity ratio at the dry bulb temperature
+    pub degree_of_saturation: f64,
+    pub dew_point: Temperature,
+    // Thermodynamic wet bulb temperature
+    pub wet_bulb: Temperature,
+    pub vapor_pressure: Pressure,
+    // Pure water vapor over liquid water above the triple point and over ice below it
+    pub saturation_pressure: Pressure,
+    // Per kg of dry air with dry air and liquid water at 0 °C as the reference
+    pub enthalpy: EnergyPerMass,
+    // Per kg of dry air
+    pub specific_volume: SpecificVolume,
+    // Mass of moist air per volume
+    pub density: Density,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PsychrometricErr {
+    // Must be above 0 and at most 1
+    RelativeHumidityOutOfRange,
+    // Must be above 0 and at most the saturated humidity ratio
+    HumidityRatioOutOfRange,
+    // Wet bulb and dew point temperatures cannot be above the dry bulb temperature
+    TemperatureAboveDryBulb,
+    // The saturation pressure at the dry bulb temperature must be below the total pressure
+    PressureBelowSaturation,
+    SteamQueryErr(SteamQueryErr),
+}
+
+/// Partial pressure of saturated water vapor in air over liquid water or ice
+fn get_saturation_vapor_pressure(temperature: f64) -> Result<f64, PsychrometricErr> {
+    let temperature = Temperature::K(K::new(temperature));
+    match get_saturation_pressure(temperature) {
+        Err(OutOfRange::TemperatureLow) => get_sublimation_pressure(temperature),
+        result => result,
+    }
+    .map(|x| ENHANCEMENT_FACTOR * x.convert_to_si_unit().value)
+    .map_err(|x| PsychrometricErr::SteamQueryErr(SteamQueryErr::OutOfRange(x)))
+}
+
+fn get_dew_point(vapor_pressure: f64) -> Result<f64, PsychrometricErr> {
+    let pressure = Pressure::Pa(Pa::new(vapor_pressure / ENHANCEMENT_FACTOR));
+    match get_saturation_temperature(pressure) {
+        Ok(x) => Ok(x),
+        Err(OutOfRange::PressureLow) => get_sublimation_temperature(pressure),
+        Err(x) => Err(SteamQueryErr::OutOfRange(x)),
+    }
+    .map(|x| x.convert_to_si_unit().value)
+    .map_err(PsychrometricErr::SteamQueryErr)
+}
+
+fn get_humidity_ratio(vapor_pressure: f64, pressure: f64) -> f64 {
+    MOLAR_MASS_RATIO * vapor_pressure / (pressure - vapor_pressure)
+}
+
+fn get_vapor_pressure(humidity_ratio: f64, pressure: f64) -> f64 {
+    pressure * humidity_ratio / (MOLAR_MASS_RATIO + humidity_ratio)
+}
+
+fn get_saturated_humidity_ratio(temperature: f64, pressure: f64) -> Result<f64, PsychrometricErr> {
+    let saturation_pressure = get_saturation_vapor_pressure(temperature)?;
+    if saturation_pressure >= pressure {
+        return Err(PsychrometricErr::PressureBelowSaturation);
+    }
+    Ok(get_humidity_ratio(saturation_pressure, pressure))
+}
+
+/// Humidity ratio from the adiabatic saturation energy balance, temperatures in K
+fn get_wet_bulb_humidity_ratio(
+    dry_bulb: f64,
+    wet_bulb: f64,
+    pressure: f64,
+) -> Result<f64, PsychrometricErr> {
+    let saturated = get_saturated_humidity_ratio(wet_bulb, pressure)?;
+    let t = dry_bulb - ZERO_CELSIUS;
+    let t_wet = wet_bulb - ZERO_CELSIUS;
+    // Water sprayed at the wet bulb freezes below 0 °C
+    let (latent_heat, water_cp) = if t_wet >= 0f64 {
+        (HEAT_OF_VAPORIZATION, LIQUID_WATER_CP)
+    } else {
+        (HEAT_OF_SUBLIMATION, ICE_CP)
+    };
+    Ok(
+        ((latent_heat - (water_cp - WATER_VAPOR_CP) * t_wet) * saturated
+            - DRY_AIR_CP * (t - t_wet))
+            / (latent_heat + WATER_VAPOR_CP * t - water_cp * t_wet),
+    )
+}
+
+fn get_wet_bulb(
+    dry_bulb: f64,
+    dew_point: f64,
+    humidity_ratio: f64,
+    pressure: f64,
+) -> Result<f64, PsychrometricErr> {
+    // Normalized so the tolerance does not depend on how humid the air is
+    let f = |x: f64| {
+        get_wet_bulb_humidity_ratio(dry_bulb, x, pressure)
+            .map(|y| (y - humidity_ratio) / (humidity_ratio + 1e-3))
+            .unwrap_or(f64::NAN)
+    };
+    secant_method(f, (dry_bulb + dew_point) / 2f64, 1e-10)
+        .map_err(|x| PsychrometricErr::SteamQueryErr(SteamQueryErr::FailedToConverge(x)))
+}
+
+fn get_humidity_ratio_from_query(query: &PsychrometricQuery) -> Result<f64, PsychrometricErr> {
+    match *query {
+        PsychrometricQuery::RelativeHumidity {
+            dry_bulb,
+            pressure,
+            relative_humidity,
+        } => {
+            if relative_humidity <= 0f64 || relative_humidity > 1f64 {
+                return Err(PsychrometricErr::RelativeHumidityOutOfRange);
+            }
+            let saturation_pressure =
+                get_saturation_vapor_pressure(dry_bulb.convert_to_si_unit().value)?;
+            Ok(get_humidity_ratio(
+                relative_humidity * saturation_pressure,
+                pressure.convert_to_si_unit().value,
+            ))
+        }
+        PsychrometricQuery::HumidityRatio { humidity_ratio, .. } => Ok(humidity_ratio),
+        PsychrometricQuery::WetBulb {
+            dry_bulb,
+            pressure,
+            wet_bulb,
+        } => {
+            let dry_bulb = dry_bulb.convert_to_si_unit().value;
+            let wet_bulb = wet_bulb.convert_to_si_unit().value;
+            if wet_bulb > dry_bulb {
+                return Err(PsychrometricErr::TemperatureAboveDryBulb);
+            }
+            get_wet_bulb_humidity_ratio(dry_bulb, wet_bulb, pressure.convert_to_si_unit().value)
+        }
+        PsychrometricQuery::DewPoint {
+            dry_bulb,
+            pressure,
+            dew_point,
+        } => {
+            let dew_point = dew_point.convert_to_si_unit().value;
+            if dew_point > dry_bulb.convert_to_si_unit().value {
+                return Err(PsychrometricErr::TemperatureAboveDryBulb);
+            }
+            Ok(get_humidity_ratio(
+                get_saturation_vapor_pressure(dew_point)?,
+                pressure.convert_to_si_unit().value,
+            ))
+        }
+    }
+}
+
+pub fn get_moist_air_entry(query: PsychrometricQuery) -> Result<MoistAirEntry, PsychrometricErr> {
+    let (dry_bulb, pressure) = match query {
+        PsychrometricQuery::RelativeHumidity {
+            dry_bulb, pressure, ..
+        }
+        | PsychrometricQuery::HumidityRatio {
+            dry_bulb, pressure, ..
+        }
+        | PsychrometricQuery::WetBulb {
+            dry_bulb, pressure, ..
+        }
+        | PsychrometricQuery::DewPoint {
+            dry_bulb, pressure, ..
+        } => (
+            dry_bulb.convert_to_si_unit().value,
+            pressure.convert_to_si_unit().value,
+        ),
+    };
+    let saturated_humidity_ratio = get_saturated_humidity_ratio(dry_bulb, pressure)?;
+    let humidity_ratio = get_humidity_ratio_from_query(&query)?;
+    // Allow for round off when the air is saturated
+    if humidity_ratio <= 0f64 || humidity_ratio > saturated_humidity_ratio * (1f64 + 1e-7) {
+        return Err(PsychrometricErr::HumidityRatioOutOfRange);
+    }
+    let humidity_ratio = f64::min(humidity_ratio, saturated_humidity_ratio);
+    let saturation_pressure = get_saturation_vapor_pressure(dry_bulb)?;
+    let vapor_pressure = get_vapor_pressure(humidity_ratio, pressure);
+    let dew_point = f64::min(get_dew_point(vapor_pressure)?, dry_bulb);
+    let wet_bulb = match query {
+        PsychrometricQuery::WetBulb { wet_bulb, .. } => wet_bulb.convert_to_si_unit().value,
+        _ if humidity_ratio == saturated_humidity_ratio => dry_bulb,
+        _ => get_wet_bulb(dry_bulb, dew_point, humidity_ratio, pressure)?,
+    };
+    let t = dry_bulb - ZERO_CELSIUS;
+    let specific_volume =
+        DRY_AIR_GAS_CONSTANT * dry_bulb * (1f64 + humidity_ratio / MOLAR_MASS_RATIO) / pressure;
+    Ok(MoistAirEntry {
+        dry_bulb: Temperature::K(K::new(dry_bulb)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        humidity_ratio,
+        relative_humidity: vapor_pressure / saturation_pressure,
+        degree_of_saturation: humidity_ratio / saturated_humidity_ratio,
+        dew_point: Temperature::K(K::new(dew_point)),
+        wet_bulb: Temperature::K(K::new(wet_bulb)),
+        vapor_pressure: Pressure::Pa(Pa::new(vapor_pressure)),
+        saturation_pressure: Pressure::Pa(Pa::new(saturation_pressure / ENHANCEMENT_FACTOR)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(
+            DRY_AIR_CP * t + humidity_ratio * (HEAT_OF_VAPORIZATION + WATER_VAPOR_CP * t),
+        )),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new((1f64 + humidity_ratio) / specific_volume)),
+    })
+}
+
+// Chart bounds in °C and g/kg
+static CHART_MIN_TEMPERATURE: f64 = 0.0;
+static CHART_MAX_TEMPERATURE: f64 = 50.0;
+static CHART_MAX_HUMIDITY_RATIO: f64 = 30.0;
+
+/// Drops the part of the line above the top of the chart
+fn clip_line(points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+    let mut clipped: Vec<(f64, f64)> = vec![];
+    for (x, y) in points {
+        if y <= CHART_MAX_HUMIDITY_RATIO {
+            clipped.push((x, y));
+            continue;
+        }
+        if let Some(&(x0, y0)) = clipped.last() {
+            let frac = (CHART_MAX_HUMIDITY_RATIO - y0) / (y - y0);
+            clipped.push((x0 + frac * (x - x0), CHART_MAX_HUMIDITY_RATIO));
+        }
+        break;
+    }
+    clipped
+}
+
+fn get_chart_temperatures(step: f64) -> Vec<f64> {
+    let count = ((CHART_MAX_TEMPERATURE - CHART_MIN_TEMPERATURE) / step).round() as usize;
+    (0..=count)
+        .map(|i| CHART_MIN_TEMPERATURE + step * i as f64)
+        .collect()
+}
+
+/// Humidity ratio in g/kg along a line of constant relative humidity
+fn get_relative_humidity_line(
+    relative_humidity: f64,
+    pressure: f64,
+) -> Result<Vec<(f64, f64)>, PsychrometricErr> {
+    get_chart_temperatures(1.0)
+        .into_iter()
+        .map(|t| {
+            let vapor_pressure =
+                relative_humidity * get_saturation_vapor_pressure(t + ZERO_CELSIUS)?;
+            Ok((t, 1e3 * get_humidity_ratio(vapor_pressure, pressure)))
+        })
+        .collect::<Result<Vec<(f64, f64)>, PsychrometricErr>>()
+        .map(clip_line)
+}
+
+/// Dry bulb against humidity ratio with saturation, relative humidity, enthalpy and wet bulb lines
+pub fn get_psychrometric_chart(
+    pressure: Pressure,
+    state: Option<&MoistAirEntry>,
+) -> Result<Diagram, PsychrometricErr> {
+    let p = pressure.convert_to_si_unit().value;
+    let mut lines = vec![DiagramLine {
+        label: "Saturation".to_owned(),
+        style: LineStyle::Saturation,
+        points: get_relative_humidity_line(1.0, p)?,
+    }];
+    for relative_humidity in (1..10).map(|x| x as f64 / 10f64) {
+        lines.push(DiagramLine {
+            label: format!("φ = {:.0} %", relative_humidity * 100f64),
+            style: LineStyle::RelativeHumidity,
+            points: get_relative_humidity_line(relative_humidity, p)?,
+        });
+    }
+    let temperatures = get_chart_temperatures(0.5);
+    let saturated = temperatures
+        .iter()
+        .map(|t| get_saturated_humidity_ratio(t + ZERO_CELSIUS, p).map(|x| 1e3 * x))
+        .collect::<Result<Vec<f64>, PsychrometricErr>>()?;
+    // Enthalpy in kJ/kg of dry air
+    for enthalpy in (1..=12).map(|x| 10f64 * x as f64) {
+        let points = temperatures
+            .iter()
+            .zip(saturated.iter())
+            .map(|(t, w_sat)| {
+                let w = 1e3 * (enthalpy * 1e3 - DRY_AIR_CP * t)
+                    / (HEAT_OF_VAPORIZATION + WATER_VAPOR_CP * t);
+                (*t, w, *w_sat)
+            })
+            .filter(|(_, w, w_sat)| *w >= 0f64 && w <= w_sat)
+            .map(|(t, w, _)| (t, w))
+            .collect::<Vec<(f64, f64)>>();
+        lines.push(DiagramLine {
+            label: format!("h = {:.0} kJ/kg", enthalpy),
+            style: LineStyle::Isenthalp,
+            points: clip_line(points),
+        });
+    }
+    for wet_bulb in (0..=6).map(|x| 5f64 * x as f64) {
+        let points = temperatures
+            .iter()
+            .filter(|t| **t >= wet_bulb)
+            .map(|t| {
+                get_wet_bulb_humidity_ratio(t + ZERO_CELSIUS, wet_bulb + ZERO_CELSIUS, p)
+                    .map(|w| (*t, 1e3 * w))
+            })
+            .collect::<Result<Vec<(f64, f64)>, PsychrometricErr>>()?
+            .into_iter()
+            .take_while(|(_, w)| *w >= 0f64)
+            .collect::<Vec<(f64, f64)>>();
+        lines.push(DiagramLine {
+            label: format!("Wet Bulb = {:.0} °C", wet_bulb),
+            style: LineStyle::WetBulb,
+            points: clip_line(points),
+        });
+    }
+    Ok(Diagram {
+        title: format!("Psychrometric Chart at {:.3} kPa", p / 1e3),
+        x_axis: Axis {
+            label: "Dry Bulb Temperature (°C)".to_owned(),
+            scale: AxisScale::Linear,
+        },
+        y_axis: Axis {
+            label: "Humidity Ratio (g/kg dry air)".to_owned(),
+            scale: AxisScale::Linear,
+        },
+        lines,
+        points: state
+            .map(|x| DiagramPoint {
+                label: "State".to_owned(),
+                x: x.dry_bulb.convert_to_si_unit().value - ZERO_CELSIUS,
+                y: 1e3 * x.humidity_ratio,
+            })
+            .into_iter()
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_standard_pressure() -> Pressure {
+        Pressure::Pa(Pa::new(101325.0))
+    }
+
+    #[test]
+    fn ashrae_wet_bulb_example() {
+        // ASHRAE Fundamentals 2017 chapter 1 example 2
+        let entry = get_moist_air_entry(PsychrometricQuery::WetBulb {
+            dry_bulb: Temperature::C(C::new(40.0)),
+            pressure: get_standard_pressure(),
+            wet_bulb: Temperature::C(C::new(20.0)),
+        })
+        .unwrap();
+        assert_approx_eq!(0.0065, entry.humidity_ratio, 1e-4);
+        assert_approx_eq!(0.14, entry.relative_humidity, 5e-3);
+        assert_approx_eq!(56.7e3, entry.enthalpy.convert_to_si_unit().value, 300.0);
+        assert_approx_eq!(
+            0.896,
+            entry.specific_volume.convert_to_si_unit().value,
+            2e-3
+        );
+        // pw / f = 1.04 kPa
+        assert_approx_eq!(
+            7.5 + 273.15,
+            entry.dew_point.convert_to_si_unit().value,
+            0.2
+        );
+    }
+
+    macro_rules! round_trip_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (dry_bulb_c, relative_humidity) = $value;
+                let dry_bulb = Temperature::C(C::new(dry_bulb_c));
+                let pressure = get_standard_pressure();
+                let expected = get_moist_air_entry(PsychrometricQuery::RelativeHumidity {
+                    dry_bulb,
+                    pressure,
+                    relative_humidity,
+                })
+                .unwrap();
+                assert_approx_eq!(relative_humidity, expected.relative_humidity, 1e-9);
+                let queries = [
+                    PsychrometricQuery::HumidityRatio {
+                        dry_bulb,
+                        pressure,
+                        humidity_ratio: expected.humidity_ratio,
+                    },
+                    PsychrometricQuery::WetBulb {
+                        dry_bulb,
+                        pressure,
+                        wet_bulb: expected.wet_bulb,
+                    },
+                    PsychrometricQuery::DewPoint {
+                        dry_bulb,
+                        pressure,
+                        dew_point: expected.dew_point,
+                    },
+                ];
+                for query in queries {
+                    let actual = get_moist_air_entry(query).unwrap();
+                    assert_approx_eq!(expected.humidity_ratio, actual.humidity_ratio, 1e-8);
+                    assert_approx_eq!(
+                        expected.wet_bulb.convert_to_si_unit().value,
+                        actual.wet_bulb.convert_to_si_unit().value,
+                        1e-4
+                    );
+                    assert_approx_eq!(
+                        expected.dew_point.convert_to_si_unit().value,
+                        actual.dew_point.convert_to_si_unit().value,
+                        1e-4
+                    );
+                }
+            }
+        )*
+        }
+    }
+
+    round_trip_tests! {
+        round_trip_room_air: (25.0, 0.5),
+        round_trip_dry_hot_air: (45.0, 0.1),
+        round_trip_freezing_air: (-10.0, 0.5),
+        round_trip_saturated_air: (20.0, 1.0),
+    }
+
+    #[test]
+    fn saturated_air_temperatures_match() {
+        let entry = get_moist_air_entry(PsychrometricQuery::RelativeHumidity {
+            dry_bulb: Temperature::C(C::new(20.0)),
+            pressure: get_standard_pressure(),
+            relative_humidity: 1.0,
+        })
+        .unwrap();
+        assert_approx_eq!(293.15, entry.wet_bulb.convert_to_si_unit().value, 1e-6);
+        assert_approx_eq!(293.15, entry.dew_point.convert_to_si_unit().value, 1e-4);
+        // ASHRAE table 2, 0.014758 kg/kg at 20 °C
+        assert_approx_eq!(0.014758, entry.humidity_ratio, 2e-5);
+    }
+
+    #[test]
+    fn below_freezing_uses_ice() {
+        let entry = get_moist_air_entry(PsychrometricQuery::RelativeHumidity {
+            dry_bulb: Temperature::C(C::new(-10.0)),
+            pressure: get_standard_pressure(),
+            relative_humidity: 1.0,
+        })
+        .unwrap();
+        // ASHRAE table 3, 0.25987 kPa over ice at -10 °C
+        assert_approx_eq!(
+            259.87,
+            entry.saturation_pressure.convert_to_si_unit().value,
+            0.1
+        );
+    }
+
+    macro_rules! psychrometric_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (query, expected) = $value;
+                assert_eq!(Err(expected), get_moist_air_entry(query));
+            }
+        )*
+        }
+    }
+
+    psychrometric_err_tests! {
+        relative_humidity_above_one: (
+            PsychrometricQuery::RelativeHumidity {
+                dry_bulb: Temperature::C(C::new(20.0)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+                relative_humidity: 1.2,
+            },
+            PsychrometricErr::RelativeHumidityOutOfRange
+        ),
+        supersaturated_humidity_ratio: (
+            PsychrometricQuery::HumidityRatio {
+                dry_bulb: Temperature::C(C::new(20.0)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+                humidity_ratio: 0.02,
+            },
+            PsychrometricErr::HumidityRatioOutOfRange
+        ),
+        wet_bulb_above_dry_bulb: (
+            PsychrometricQuery::WetBulb {
+                dry_bulb: Temperature::C(C::new(20.0)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+                wet_bulb: Temperature::C(C::new(25.0)),
+            },
+            PsychrometricErr::TemperatureAboveDryBulb
+        ),
+        boiling_air: (
+            PsychrometricQuery::RelativeHumidity {
+                dry_bulb: Temperature::C(C::new(120.0)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+                relative_humidity: 0.5,
+            },
+            PsychrometricErr::PressureBelowSaturation
+        ),
+    }
+
+    #[test]
+    fn psychrometric_chart_lines() {
+        let entry = get_moist_air_entry(PsychrometricQuery::RelativeHumidity {
+            dry_bulb: Temperature::C(C::new(25.0)),
+            pressure: get_standard_pressure(),
+            relative_humidity: 0.5,
+        })
+        .unwrap();
+        let chart = get_psychrometric_chart(get_standard_pressure(), Some(&entry)).unwrap();
+        let saturation = &chart.lines[0];
+        assert_eq!(LineStyle::Saturation, saturation.style);
+        // Saturation reaches the top of the chart just above 32 °C
+        let (t, w) = *saturation.points.last().unwrap();
+        assert_approx_eq!(CHART_MAX_HUMIDITY_RATIO, w, 1e-9);
+        assert!(t > 31.0 && t < 33.0);
+        assert!(chart
+            .lines
+            .iter()
+            .flat_map(|x| x.points.iter())
+            .all(|(_, w)| *w >= 0.0 && *w <= CHART_MAX_HUMIDITY_RATIO + 1e-9));
+        assert_eq!(1, chart.points.len());
+        assert_approx_eq!(25.0, chart.points[0].x, 1e-9);
+        assert!(chart.to_svg(800, 600).contains("Wet Bulb = 20 °C"));
+    }
+}
@@ -0,0 +1,808 @@
+// Reference multiparameter equations of state of the refrigerants,
+// explicit in the reduced Helmholtz free energy
+use crate::thermo::helmholtz::*;
+
+// R-134a, Tillner-Roth and Baehr (1994)
+static R134A_IDEAL_POWER: &[IdealPowerTerm] = &[
+    IdealPowerTerm {
+        n: -9.723916,
+        t: -0.5,
+    },
+    IdealPowerTerm {
+        n: -3.92717,
+        t: -0.75,
+    },
+];
+
+static R134A_RESIDUAL: &[ResidualTerm] = &[
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: -0.5,
+        n: 0.05586817,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.0,
+        n: 0.498223,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 3.0,
+        t: 0.0,
+        n: 0.02458698,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 6.0,
+        t: 0.0,
+        n: 0.0008570145,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 6.0,
+        t: 1.5,
+        n: 0.0004788584,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 1.5,
+        n: -1.800808,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 2.0,
+        n: 0.2671641,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 2.0,
+        n: -0.04781652,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 1.0,
+        n: 0.01423987,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 3.0,
+        n: 0.3324062,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 5.0,
+        n: -0.007485907,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 1.0,
+        n: 0.0001017263,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 5.0,
+        n: -0.5184567,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 5.0,
+        n: -0.08692288,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 6.0,
+        n: 0.2057144,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 10.0,
+        n: -0.005000457,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 10.0,
+        n: 0.0004603262,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 1.0,
+        t: 10.0,
+        n: -0.003497836,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 5.0,
+        t: 18.0,
+        n: 0.006995038,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 3.0,
+        t: 22.0,
+        n: -0.01452184,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 10.0,
+        t: 50.0,
+        n: -0.0001285458,
+    },
+];
+
+pub static R134A: HelmholtzEos = HelmholtzEos {
+    specific_gas_constant: 8.314471 / 102.032e-3,
+    reducing_temperature: 374.18,
+    reducing_density: 508.0,
+    ideal_n1: -1.019535,
+    ideal_n2: 9.047135,
+    ideal_n3: -1.629789,
+    ideal_power: R134A_IDEAL_POWER,
+    ideal: &[],
+    residual: R134A_RESIDUAL,
+    gaussian: &[],
+    non_analytic: &[],
+};
+
+// R-410A as a pseudo-pure fluid, Lemmon (2003)
+static R410A_IDEAL_POWER: &[IdealPowerTerm] = &[IdealPowerTerm {
+    n: -46.87575,
+    t: -0.1,
+}];
+
+static R410A_IDEAL: &[IdealTerm] = &[
+    IdealTerm {
+        n: 2.0623,
+        gamma: 2.02326,
+    },
+    IdealTerm {
+        n: 5.9751,
+        gamma: 5.00154,
+    },
+    IdealTerm {
+        n: 1.5612,
+        gamma: 11.2484,
+    },
+];
+
+static R410A_RESIDUAL: &[ResidualTerm] = &[
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.44,
+        n: 0.987252,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 1.2,
+        n: -1.03017,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 2.97,
+        n: 1.17666,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 2.95,
+        n: -0.138991,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 5.0,
+        t: 0.2,
+        n: 0.00302373,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 1.93,
+        n: -2.53639,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 1.78,
+        n: -1.9668,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 3.0,
+        t: 3.0,
+        n: -0.83048,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 0.2,
+        n: 0.172477,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 0.74,
+        n: -0.261116,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 3.0,
+        n: -0.0745473,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 2.1,
+        n: 0.679757,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 4.3,
+        n: -0.652431,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 0.25,
+        n: 0.0553849,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 7.0,
+        n: -0.071097,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 4.7,
+        n: -0.000875332,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 2.0,
+        t: 13.0,
+        n: 0.020076,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 2.0,
+        t: 16.0,
+        n: -0.0139761,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 4.0,
+        t: 25.0,
+        n: -0.018511,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 5.0,
+        t: 17.0,
+        n: 0.0171939,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 6.0,
+        t: 7.4,
+        n: -0.00482049,
+    },
+];
+
+pub static R410A: HelmholtzEos = HelmholtzEos {
+    specific_gas_constant: 8.314472 / 72.5854e-3,
+    reducing_temperature: 344.494,
+    // 6.324 mol/dm3
+    reducing_density: 6.324 * 72.5854,
+    ideal_n1: 36.8871,
+    ideal_n2: 7.15807,
+    ideal_n3: -1.0,
+    ideal_power: R410A_IDEAL_POWER,
+    ideal: R410A_IDEAL,
+    residual: R410A_RESIDUAL,
+    gaussian: &[],
+    non_analytic: &[],
+};
+
+// Ammonia, Tillner-Roth, Harms-Watzenberg and Baehr (1993)
+static AMMONIA_IDEAL_POWER: &[IdealPowerTerm] = &[
+    IdealPowerTerm {
+        n: 11.47434,
+        t: 1.0 / 3.0,
+    },
+    IdealPowerTerm {
+        n: -1.296211,
+        t: -1.5,
+    },
+    IdealPowerTerm {
+        n: 0.5706757,
+        t: -1.75,
+    },
+];
+
+static AMMONIA_RESIDUAL: &[ResidualTerm] = &[
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: -0.5,
+        n: 0.04554431,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.5,
+        n: 0.7238548,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 4.0,
+        t: 1.0,
+        n: 0.0122947,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 1.5,
+        n: -1.858814,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 15.0,
+        t: 3.0,
+        n: 2.141882e-11,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 3.0,
+        t: 0.0,
+        n: -0.0143002,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 3.0,
+        t: 3.0,
+        n: 0.3441324,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 4.0,
+        n: -0.2873571,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 8.0,
+        t: 4.0,
+        n: 2.352589e-05,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 5.0,
+        n: -0.03497111,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 3.0,
+        n: 0.02397852,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 8.0,
+        t: 5.0,
+        n: 0.001831117,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 6.0,
+        n: -0.04085375,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 8.0,
+        n: 0.2379275,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 3.0,
+        t: 8.0,
+        n: -0.03548972,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 10.0,
+        n: -0.1823729,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 10.0,
+        n: 0.02281556,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 3.0,
+        t: 5.0,
+        n: -0.006663444,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 1.0,
+        t: 7.5,
+        n: -0.008847486,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 2.0,
+        t: 15.0,
+        n: 0.002272635,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 4.0,
+        t: 30.0,
+        n: -0.0005588655,
+    },
+];
+
+pub static AMMONIA: HelmholtzEos = HelmholtzEos {
+    specific_gas_constant: 8.314471 / 17.03026e-3,
+    reducing_temperature: 405.40,
+    reducing_density: 225.0,
+    ideal_n1: -15.81502,
+    ideal_n2: 4.255726,
+    ideal_n3: -1.0,
+    ideal_power: AMMONIA_IDEAL_POWER,
+    ideal: &[],
+    residual: AMMONIA_RESIDUAL,
+    gaussian: &[],
+    non_analytic: &[],
+};
+
+// Carbon dioxide, Span and Wagner (1996)
+static CARBON_DIOXIDE_IDEAL: &[IdealTerm] = &[
+    IdealTerm {
+        n: 1.99427042,
+        gamma: 3.15163,
+    },
+    IdealTerm {
+        n: 0.62105248,
+        gamma: 6.1119,
+    },
+    IdealTerm {
+        n: 0.41195293,
+        gamma: 6.77708,
+    },
+    IdealTerm {
+        n: 1.04028922,
+        gamma: 11.32384,
+    },
+    IdealTerm {
+        n: 0.08327678,
+        gamma: 27.08792,
+    },
+];
+
+static CARBON_DIOXIDE_RESIDUAL: &[ResidualTerm] = &[
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.0,
+        n: 0.38856823203161,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.75,
+        n: 2.938547594274,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 1.0,
+        n: -5.5867188534934,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 2.0,
+        n: -0.76753199592477,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 0.75,
+        n: 0.31729005580416,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 2.0,
+        n: 0.54803315897767,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 3.0,
+        t: 0.75,
+        n: 0.12279411220335,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 1.5,
+        n: 2.165896154322,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 1.5,
+        n: 1.5841735109724,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 4.0,
+        t: 2.5,
+        n: -0.23132705405503,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 0.0,
+        n: 0.058116916431436,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 1.5,
+        n: -0.55369137205382,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 2.0,
+        n: 0.48946615909422,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 6.0,
+        t: 0.0,
+        n: -0.024275739843501,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 6.0,
+        t: 1.0,
+        n: 0.062494790501678,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 6.0,
+        t: 2.0,
+        n: -0.12175860225246,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 3.0,
+        n: -0.37055685270086,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 6.0,
+        n: -0.016775879700426,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 3.0,
+        n: -0.11960736637987,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 6.0,
+        n: -0.045619362508778,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 8.0,
+        n: 0.035612789270346,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 7.0,
+        t: 6.0,
+        n: -0.0074427727132052,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 8.0,
+        t: 0.0,
+        n: -0.0017395704902432,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 2.0,
+        t: 7.0,
+        n: -0.021810121289527,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 3.0,
+        t: 12.0,
+        n: 0.024332166559236,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 3.0,
+        t: 16.0,
+        n: -0.037440133423463,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 5.0,
+        t: 22.0,
+        n: 0.14338715756878,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 5.0,
+        t: 24.0,
+        n: -0.13491969083286,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 6.0,
+        t: 16.0,
+        n: -0.02315122505348,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 7.0,
+        t: 24.0,
+        n: 0.012363125492901,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 8.0,
+        t: 8.0,
+        n: 0.002105832197294,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 10.0,
+        t: 2.0,
+        n: -0.00033958519026368,
+    },
+    ResidualTerm {
+        c: 5.0,
+        d: 4.0,
+        t: 28.0,
+        n: 0.0055993651771592,
+    },
+    ResidualTerm {
+        c: 6.0,
+        d: 8.0,
+        t: 14.0,
+        n: -0.00030335118055646,
+    },
+];
+
+static CARBON_DIOXIDE_GAUSSIAN: &[GaussianTerm] = &[
+    GaussianTerm {
+        d: 2.0,
+        t: 1.0,
+        n: -213.6548868832,
+        alpha: 25.0,
+        beta: 325.0,
+        gamma: 1.16,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 2.0,
+        t: 0.0,
+        n: 26641.569149272,
+        alpha: 25.0,
+        beta: 300.0,
+        gamma: 1.19,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 2.0,
+        t: 1.0,
+        n: -24027.212204557,
+        alpha: 25.0,
+        beta: 300.0,
+        gamma: 1.19,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 3.0,
+        t: 3.0,
+        n: -283.41603423999,
+        alpha: 15.0,
+        beta: 275.0,
+        gamma: 1.25,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 3.0,
+        t: 3.0,
+        n: 212.47284400179,
+        alpha: 20.0,
+        beta: 275.0,
+        gamma: 1.22,
+        epsilon: 1.0,
+    },
+];
+
+static CARBON_DIOXIDE_NON_ANALYTIC: &[NonAnalyticTerm] = &[
+    NonAnalyticTerm {
+        a: 3.5,
+        b: 0.875,
+        big_b: 0.3,
+        n: -0.66642276540751,
+        big_c: 10.0,
+        big_d: 275.0,
+        big_a: 0.7,
+        beta: 0.3,
+    },
+    NonAnalyticTerm {
+        a: 3.5,
+        b: 0.925,
+        big_b: 0.3,
+        n: 0.72608632349897,
+        big_c: 10.0,
+        big_d: 275.0,
+        big_a: 0.7,
+        beta: 0.3,
+    },
+    NonAnalyticTerm {
+        a: 3.0,
+        b: 0.875,
+        big_b: 1.0,
+        n: 0.055068668612842,
+        big_c: 12.5,
+        big_d: 275.0,
+        big_a: 0.7,
+        beta: 0.3,
+    },
+];
+
+pub static CARBON_DIOXIDE: HelmholtzEos = HelmholtzEos {
+    specific_gas_constant: 8.31451 / 44.0098e-3,
+    reducing_temperature: 304.1282,
+    reducing_density: 467.6,
+    ideal_n1: 8.37304456,
+    ideal_n2: -3.70454304,
+    ideal_n3: 2.5,
+    ideal_power: &[],
+    ideal: CARBON_DIOXIDE_IDEAL,
+    residual: CARBON_DIOXIDE_RESIDUAL,
+    gaussian: CARBON_DIOXIDE_GAUSSIAN,
+    non_analytic: CARBON_DIOXIDE_NON_ANALYTIC,
+};
@@ -0,0 +1,877 @@
+// Refrigerant properties from the reference equations of state
+// explicit in the Helmholtz free energy.
+// R-410A is treated as a pseudo-pure fluid since its temperature glide is about 0.1 K.
+// R-32 still uses the Peng-Robinson equation with a Peneloux volume translation
+// fitted to the saturated liquid density at 0 °C and a fitted ideal gas heat capacity.
+// Enthalpy and entropy use the IIR reference state,
+// 200 kJ/kg and 1 kJ/(kg * K) for saturated liquid at 0 °C
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::*;
+use crate::thermo::diagrams::*;
+use crate::thermo::eos::*;
+use crate::thermo::helmholtz::*;
+use crate::thermo::ideal_gas::*;
+use crate::thermo::refrigerant_constants::*;
+use crate::thermo::steam::property_diagrams::*;
+use crate::thermo::steam::*;
+use crate::thermo::*;
+
+// K
+const MAX_TEMPERATURE: f64 = 500f64;
+// Pa
+const MAX_PRESSURE: f64 = 20e6;
+// K
+const REFERENCE_STATE_TEMPERATURE: f64 = 273.15;
+// J/kg
+const REFERENCE_STATE_ENTHALPY: f64 = 200e3;
+// J/(kg * K)
+const REFERENCE_STATE_ENTROPY: f64 = 1e3;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Refrigerant {
+    R134a,
+    R410A,
+    R32,
+    Ammonia,
+    CarbonDioxide,
+}
+
+impl Refrigerant {
+    pub fn all() -> Vec<Refrigerant> {
+        vec![
+            Refrigerant::R134a,
+            Refrigerant::R410A,
+            Refrigerant::R32,
+            Refrigerant::Ammonia,
+            Refrigerant::CarbonDioxide,
+        ]
+    }
+
+    /// Critical temperature, critical pressure and acentric factor
+    pub fn get_critical_constants(&self) -> CriticalConstants {
+        self.get_data().critical
+    }
+
+    // kg/mol
+    pub fn get_molar_mass(&self) -> f64 {
+        self.get_data().molar_mass
+    }
+
+    /// Lowest temperature the properties are evaluated at, the triple point where known
+    pub fn get_min_temperature(&self) -> Temperature {
+        Temperature::K(K::new(self.get_data().min_temperature))
+    }
+
+    fn get_data(&self) -> RefrigerantData {
+        // Critical constants of each equation of state,
+        // R-410A is the 69.8 mole % R-32 and R-125 blend.
+        // The R-32 ideal gas heat capacity is a quadratic fit over 250 K to 350 K
+        match self {
+            Refrigerant::R134a => RefrigerantData {
+                critical: CriticalConstants {
+                    temperature: 374.18,
+                    pressure: 4.05629e6,
+                    acentric_factor: 0.32684,
+                },
+                molar_mass: 0.102032,
+                min_temperature: 169.85,
+                eos: RefrigerantEos::Helmholtz(&R134A),
+            },
+            Refrigerant::R410A => RefrigerantData {
+                critical: CriticalConstants {
+                    temperature: 344.494,
+                    pressure: 4.9012e6,
+                    acentric_factor: 0.296,
+                },
+                molar_mass: 0.0725854,
+                min_temperature: 200f64,
+                eos: RefrigerantEos::Helmholtz(&R410A),
+            },
+            Refrigerant::R32 => RefrigerantData {
+                critical: CriticalConstants {
+                    temperature: 351.255,
+                    pressure: 5.782e6,
+                    acentric_factor: 0.2769,
+                },
+                molar_mass: 0.052024,
+                min_temperature: 136.34,
+                eos: RefrigerantEos::PengRobinson {
+                    reference_liquid_density: 1055.3,
+                    ideal_gas_cp: [3.1017, 4.70e-3, 7.2e-6],
+                },
+            },
+            Refrigerant::Ammonia => RefrigerantData {
+                critical: CriticalConstants {
+                    temperature: 405.4,
+                    pressure: 11.339e6,
+                    acentric_factor: 0.256,
+                },
+                molar_mass: 0.01703026,
+                min_temperature: 195.495,
+                eos: RefrigerantEos::Helmholtz(&AMMONIA),
+            },
+            Refrigerant::CarbonDioxide => RefrigerantData {
+                critical: CriticalConstants {
+                    temperature: 304.1282,
+                    pressure: 7.3773e6,
+                    acentric_factor: 0.22394,
+                },
+                molar_mass: 0.0440098,
+                min_temperature: 216.592,
+                eos: RefrigerantEos::Helmholtz(&CARBON_DIOXIDE),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for Refrigerant {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Refrigerant::all()
+            .into_iter()
+            .find(|x| x.to_string() == value)
+            .ok_or(format!("Unknown Refrigerant \"{}\"", value))
+    }
+}
+
+impl From<Refrigerant> for String {
+    fn from(val: Refrigerant) -> Self {
+        val.to_string()
+    }
+}
+
+impl Display for Refrigerant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Refrigerant::R134a => "R-134a",
+                Refrigerant::R410A => "R-410A",
+                Refrigerant::R32 => "R-32",
+                Refrigerant::Ammonia => "R-717 (Ammonia)",
+                Refrigerant::CarbonDioxide => "R-744 (Carbon Dioxide)",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RefrigerantEos {
+    Helmholtz(&'static HelmholtzEos),
+    PengRobinson {
+        // Saturated liquid at 0 °C in kg/m3
+        reference_liquid_density: f64,
+        // cp / R = c0 + c1 * T + c2 * T^2
+        ideal_gas_cp: [f64; 3],
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RefrigerantData {
+    critical: CriticalConstants,
+    // kg/mol
+    molar_mass: f64,
+    // K
+    min_temperature: f64,
+    eos: RefrigerantEos,
+}
+
+/// Constants which fix the volume translation and the reference state
+struct RefrigerantModel {
+    data: RefrigerantData,
+    // m3/kg, subtracted from the equation of state's volume, zero for the Helmholtz equations
+    volume_shift: f64,
+    // J/kg
+    enthalpy_offset: f64,
+    // J/(kg * K)
+    entropy_offset: f64,
+}
+
+fn map_eos_err(err: EosErr) -> SteamQueryErr {
+    match err {
+        EosErr::PressureMustBePositive => SteamQueryErr::OutOfRange(OutOfRange::PressureLow),
+        EosErr::TemperatureMustBePositive | EosErr::IdealGasErr(IdealGasErr::TemperatureLow) => {
+            SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow)
+        }
+        EosErr::IdealGasErr(IdealGasErr::TemperatureHigh) => {
+            SteamQueryErr::OutOfRange(OutOfRange::TemperatureHigh)
+        }
+        EosErr::AboveCriticalTemperature => {
+            SteamQueryErr::OutOfRange(OutOfRange::AboveCriticalTemperature)
+        }
+        _ => SteamQueryErr::FailedToConverge(RootFinderErr::MaxIterationsReached),
+    }
+}
+
+fn get_ideal_gas_state([c0, c1, c2]: [f64; 3], temperature: f64, pressure: f64) -> IdealGasState {
+    let r = UNIVERSAL_GAS_CONSTANT;
+    let t = temperature;
+    IdealGasState {
+        cp: r * (c0 + c1 * t + c2 * t * t),
+        enthalpy: r * (c0 * t + c1 * t * t / 2f64 + c2 * t * t * t / 3f64),
+        entropy: r * (c0 * f64::ln(t) + c1 * t + c2 * t * t / 2f64)
+            - r * f64::ln(pressure / STANDARD_PRESSURE),
+    }
+}
+
+fn get_point(temperature: f64, pressure: f64) -> PtPoint {
+    PtPoint {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+    }
+}
+
+/// The liquid and vapor roots follow their branch of the isotherm past saturation
+/// up to the spinodal, as the cubic's roots do
+fn get_helmholtz_entry(
+    eos: &HelmholtzEos,
+    critical: CriticalConstants,
+    temperature: f64,
+    pressure: f64,
+    root: CubicRoot,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let liquid = PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid);
+    let vapor = PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor);
+    let (density, phase_region) = match root {
+        _ if temperature >= critical.temperature => (
+            eos.get_liquid_density(temperature, pressure),
+            if pressure >= critical.pressure {
+                PhaseRegion::SupercriticalFluid
+            } else {
+                PhaseRegion::Gas
+            },
+        ),
+        CubicRoot::Liquid => (eos.get_liquid_density(temperature, pressure), liquid),
+        CubicRoot::Vapor => (eos.get_vapor_density(temperature, pressure), vapor),
+        CubicRoot::Stable => {
+            if pressure >= eos.get_saturation_state(temperature)?.pressure {
+                (eos.get_liquid_density(temperature, pressure), liquid)
+            } else {
+                (eos.get_vapor_density(temperature, pressure), vapor)
+            }
+        }
+    };
+    let density = density.ok_or(SteamQueryErr::FailedToConverge(
+        RootFinderErr::MaxIterationsReached,
+    ))?;
+    Ok(eos.create_entry(
+        &eos.get_point(temperature, density),
+        temperature,
+        density,
+        phase_region,
+    ))
+}
+
+/// Equation of state entry before the volume translation and reference state are applied
+fn get_eos_entry(
+    data: &RefrigerantData,
+    temperature: f64,
+    pressure: f64,
+    root: CubicRoot,
+) -> Result<PtvEntry, SteamQueryErr> {
+    match data.eos {
+        RefrigerantEos::Helmholtz(eos) => {
+            get_helmholtz_entry(eos, data.critical, temperature, pressure, root)
+        }
+        RefrigerantEos::PengRobinson { ideal_gas_cp, .. } => get_cubic_entry(
+            CubicEos::PengRobinson,
+            &[(data.critical, 1f64)],
+            data.molar_mass,
+            get_ideal_gas_state(ideal_gas_cp, temperature, pressure),
+            get_point(temperature, pressure),
+            root,
+        )
+        .map_err(map_eos_err),
+    }
+}
+
+fn get_eos_saturation_pressure(
+    data: &RefrigerantData,
+    temperature: f64,
+) -> Result<f64, SteamQueryErr> {
+    match data.eos {
+        RefrigerantEos::Helmholtz(eos) => eos.get_saturation_state(temperature).map(|x| x.pressure),
+        RefrigerantEos::PengRobinson { .. } => get_saturation_pressure_from_constants(
+            CubicEos::PengRobinson,
+            data.critical,
+            Temperature::K(K::new(temperature)),
+        )
+        .map(|x| x.convert_to_si_unit().value)
+        .map_err(map_eos_err),
+    }
+}
+
+impl RefrigerantModel {
+    fn new(refrigerant: Refrigerant) -> Result<RefrigerantModel, SteamQueryErr> {
+        let data = refrigerant.get_data();
+        let pressure = get_eos_saturation_pressure(&data, REFERENCE_STATE_TEMPERATURE)?;
+        let liquid = get_eos_entry(
+            &data,
+            REFERENCE_STATE_TEMPERATURE,
+            pressure,
+            CubicRoot::Liquid,
+        )?;
+        let volume_shift = match data.eos {
+            RefrigerantEos::Helmholtz(_) => 0f64,
+            RefrigerantEos::PengRobinson {
+                reference_liquid_density,
+                ..
+            } => {
+                liquid.specific_volume.convert_to_si_unit().value - 1f64 / reference_liquid_density
+            }
+        };
+        Ok(RefrigerantModel {
+            data,
+            volume_shift,
+            enthalpy_offset: REFERENCE_STATE_ENTHALPY
+                - (liquid.enthalpy.convert_to_si_unit().value - volume_shift * pressure),
+            entropy_offset: REFERENCE_STATE_ENTROPY - liquid.entropy.convert_to_si_unit().value,
+        })
+    }
+
+    fn check_point(&self, temperature: f64, pressure: f64) -> Result<(), SteamQueryErr> {
+        if temperature.is_nan() || temperature < self.data.min_temperature {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow))
+        } else if temperature > MAX_TEMPERATURE {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureHigh))
+        } else if pressure.is_nan() || pressure <= 0f64 {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow))
+        } else if pressure > MAX_PRESSURE {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Translating the volume by a constant leaves the temperature derivatives
+    /// of the volume unchanged and keeps the saturation pressure
+    fn get_entry(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        root: CubicRoot,
+    ) -> Result<PtvEntry, SteamQueryErr> {
+        self.check_point(temperature, pressure)?;
+        let entry = get_eos_entry(&self.data, temperature, pressure, root)?;
+        let eos_volume = entry.specific_volume.convert_to_si_unit().value;
+        let specific_volume = eos_volume - self.volume_shift;
+        let volume_ratio = specific_volume / eos_volume;
+        let cp = entry.cp.convert_to_si_unit().value;
+        let enthalpy = entry.enthalpy.convert_to_si_unit().value - self.volume_shift * pressure
+            + self.enthalpy_offset;
+        let entropy = entry.entropy.convert_to_si_unit().value + self.entropy_offset;
+        let internal_energy = enthalpy - pressure * specific_volume;
+        Ok(PtvEntry {
+            internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+            entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+            speed_of_sound: Velocity::MPerSec(MPerSec::new(
+                entry.speed_of_sound.convert_to_si_unit().value * volume_ratio,
+            )),
+            specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+            density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+            gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy - temperature * entropy)),
+            helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(
+                internal_energy - temperature * entropy,
+            )),
+            isobaric_expansion: InverseTemperature::PerK(PerK::new(
+                entry.isobaric_expansion.convert_to_si_unit().value / volume_ratio,
+            )),
+            isothermal_compressibility: InversePressure::PerPa(PerPa::new(
+                entry.isothermal_compressibility.convert_to_si_unit().value / volume_ratio,
+            )),
+            joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(
+                entry.joule_thomson.convert_to_si_unit().value + self.volume_shift / cp,
+            )),
+            isentropic_exponent: entry.isentropic_exponent * volume_ratio,
+            ..entry
+        })
+    }
+
+    fn get_saturation_pressure(&self, temperature: f64) -> Result<f64, SteamQueryErr> {
+        if temperature.is_nan() || temperature < self.data.min_temperature {
+            return Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow));
+        }
+        get_eos_saturation_pressure(&self.data, temperature)
+    }
+
+    fn get_saturation_temperature(&self, pressure: f64) -> Result<f64, SteamQueryErr> {
+        let critical = self.data.critical;
+        if pressure >= critical.pressure {
+            return Err(SteamQueryErr::OutOfRange(OutOfRange::AboveCriticalPressure));
+        }
+        if pressure.is_nan()
+            || pressure < self.get_saturation_pressure(self.data.min_temperature)?
+        {
+            return Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow));
+        }
+        // Wilson's correlation as the first guess
+        let guess = critical.temperature
+            / (1f64
+                - f64::ln(pressure / critical.pressure)
+                    / (5.373 * (1f64 + critical.acentric_factor)));
+        let f = |temperature: f64| match self.get_saturation_pressure(temperature) {
+            Ok(saturation_pressure) => f64::ln(saturation_pressure / pressure),
+            Err(_) => f64::NAN,
+        };
+        secant_method(
+            f,
+            f64::clamp(
+                guess,
+                self.data.min_temperature,
+                critical.temperature - 1e-3,
+            ),
+            1e-12,
+        )
+        .map_err(SteamQueryErr::FailedToConverge)
+    }
+
+    fn get_sat_entry(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        phase_region: SteamNonCriticalPhaseRegion,
+    ) -> Result<PtvEntry, SteamQueryErr> {
+        let root = match phase_region {
+            SteamNonCriticalPhaseRegion::Liquid => CubicRoot::Liquid,
+            SteamNonCriticalPhaseRegion::Vapor => CubicRoot::Vapor,
+            // Solids are only stable below the triple point
+            SteamNonCriticalPhaseRegion::Solid => {
+                return Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow))
+            }
+        };
+        self.get_entry(temperature, pressure, root)
+            .map(|entry| PtvEntry {
+                phase_region: PhaseRegion::NonCritical(match phase_region {
+                    SteamNonCriticalPhaseRegion::Liquid => NonCriticalPhaseRegion::Liquid,
+                    _ => NonCriticalPhaseRegion::Vapor,
+                }),
+                ..entry
+            })
+    }
+
+    fn get_pt_entry(&self, temperature: f64, pressure: f64) -> Result<PtvEntry, SteamQueryErr> {
+        self.get_entry(temperature, pressure, CubicRoot::Stable)
+    }
+
+    /// Finds the state with the given property at a pressure,
+    /// a mixture of saturated liquid and vapor when the property lies between them
+    fn iterate_entry(
+        &self,
+        pressure: f64,
+        target_value: f64,
+        get_prop_value: fn(entry: &PtvEntry) -> f64,
+    ) -> Result<PtvEntry, SteamQueryErr> {
+        self.check_point(self.data.min_temperature, pressure)?;
+        let (guess, root) = if pressure < self.data.critical.pressure {
+            let temperature = self.get_saturation_temperature(pressure)?;
+            let liquid =
+                self.get_sat_entry(temperature, pressure, SteamNonCriticalPhaseRegion::Liquid)?;
+            let vapor =
+                self.get_sat_entry(temperature, pressure, SteamNonCriticalPhaseRegion::Vapor)?;
+            let (liquid_value, vapor_value) = (get_prop_value(&liquid), get_prop_value(&vapor));
+            if target_value < liquid_value {
+                (temperature - 5f64, CubicRoot::Liquid)
+            } else if target_value > vapor_value {
+                (temperature + 5f64, CubicRoot::Vapor)
+            } else {
+                let liq_frac = (vapor_value - target_value) / (vapor_value - liquid_value);
+                return mix_saturated_entries(&liquid, &vapor, liq_frac);
+            }
+        } else {
+            (self.data.critical.temperature, CubicRoot::Stable)
+        };
+        let f = |temperature| match self.get_entry(temperature, pressure, root) {
+            Ok(entry) => get_prop_value(&entry) - target_value,
+            Err(_) => f64::NAN,
+        };
+        secant_method(f, guess, 1e-5)
+            .map_err(SteamQueryErr::FailedToConverge)
+            .and_then(|temperature| self.get_entry(temperature, pressure, root))
+    }
+}
+
+// The derivative properties jump across the phase boundary so, as for water,
+// they are left as NaN for a liquid vapor mixture
+fn mix_saturated_entries(
+    liquid: &PtvEntry,
+    vapor: &PtvEntry,
+    liq_frac: f64,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let vap_frac = 1f64 - liq_frac;
+    let phase_region = LiquidVapor::new(liq_frac, vap_frac)
+        .map(|x| PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)))
+        .map_err(SteamQueryErr::CompositePhaseRegionErr)?;
+    let mix = |f: fn(e: &PtvEntry) -> f64| f(liquid) * liq_frac + f(vapor) * vap_frac;
+    let specific_volume = mix(|x| x.specific_volume.convert_to_si_unit().value);
+    Ok(PtvEntry {
+        phase_region,
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(mix(|x| {
+            x.internal_energy.convert_to_si_unit().value
+        }))),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(mix(|x| {
+            x.enthalpy.convert_to_si_unit().value
+        }))),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(mix(|x| {
+            x.entropy.convert_to_si_unit().value
+        }))),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(f64::NAN)),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(mix(|x| {
+            x.gibbs_free_energy.convert_to_si_unit().value
+        }))),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(mix(|x| {
+            x.helmholtz_free_energy.convert_to_si_unit().value
+        }))),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(f64::NAN)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(f64::NAN)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(f64::NAN)),
+        isentropic_exponent: f64::NAN,
+        ..liquid.clone()
+    })
+}
+
+/// Each refrigerant's model is built on its first query and shared by the queries after it
+fn get_model(refrigerant: Refrigerant) -> Result<&'static RefrigerantModel, SteamQueryErr> {
+    static MODELS: [OnceLock<Result<RefrigerantModel, SteamQueryErr>>; 5] =
+        [const { OnceLock::new() }; 5];
+    MODELS[refrigerant as usize]
+        .get_or_init(|| RefrigerantModel::new(refrigerant))
+        .as_ref()
+        .map_err(|x| x.clone())
+}
+
+impl SteamProperties for Refrigerant {
+    fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+        let model = get_model(*self)?;
+        match query {
+            SteamQuery::Pt(point) => model.get_pt_entry(
+                point.temperature.convert_to_si_unit().value,
+                point.pressure.convert_to_si_unit().value,
+            ),
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature,
+                phase_region,
+            }) => {
+                let temperature = temperature.convert_to_si_unit().value;
+                let pressure = model.get_saturation_pressure(temperature)?;
+                model.get_sat_entry(temperature, pressure, phase_region)
+            }
+            SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure,
+                phase_region,
+            }) => {
+                let pressure = pressure.convert_to_si_unit().value;
+                let temperature = model.get_saturation_temperature(pressure)?;
+                model.get_sat_entry(temperature, pressure, phase_region)
+            }
+            SteamQuery::EntropyP { entropy, pressure } => model.iterate_entry(
+                pressure.convert_to_si_unit().value,
+                entropy.convert_to_si_unit().value,
+                |entry| entry.entropy.convert_to_si_unit().value,
+            ),
+            SteamQuery::EnthalpyP { enthalpy, pressure } => model.iterate_entry(
+                pressure.convert_to_si_unit().value,
+                enthalpy.convert_to_si_unit().value,
+                |entry| entry.enthalpy.convert_to_si_unit().value,
+            ),
+        }
+    }
+}
+
+/// Saturation dome and constant quality lines of a refrigerant,
+/// from 55 % of the critical temperature or the minimum temperature up to the critical point
+pub fn get_refrigerant_diagram(refrigerant: Refrigerant, kind: PropertyDiagramKind) -> Diagram {
+    let (x_axis, y_axis) = get_axes(kind);
+    let critical = refrigerant.get_critical_constants();
+    let min_temperature = f64::max(
+        refrigerant.get_data().min_temperature,
+        0.55 * critical.temperature,
+    );
+    // Points crowd together near the critical point where the dome turns over
+    let temperatures = (0..40)
+        .map(|x| {
+            let frac = 1f64 - x as f64 / 40f64;
+            critical.temperature - (critical.temperature - min_temperature) * frac * frac
+        })
+        .collect::<Vec<f64>>();
+    let pairs = get_steam_table_entries(&refrigerant, &get_sat_t_queries(&temperatures))
+        .chunks(2)
+        .filter_map(|pair| match pair {
+            [Ok(liquid), Ok(vapor)] => Some((liquid.clone(), vapor.clone())),
+            _ => None,
+        })
+        .collect::<Vec<(PtvEntry, PtvEntry)>>();
+    // The last saturated vapor closes the dome if the critical point itself fails
+    let critical_point = refrigerant
+        .get_steam_table_entry(SteamQuery::Pt(get_point(
+            critical.temperature,
+            critical.pressure,
+        )))
+        .ok()
+        .or_else(|| pairs.last().map(|(_, vapor)| vapor.clone()));
+    let lines = match critical_point {
+        Some(critical_point) => std::iter::once(get_dome(kind, &pairs, &critical_point))
+            .chain(get_quality_lines(kind, &pairs, &critical_point))
+            .collect(),
+        None => vec![],
+    };
+    Diagram {
+        title: format!("{} Diagram of {}", kind, refrigerant),
+        x_axis,
+        y_axis,
+        lines,
+        points: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn sat_t(
+        refrigerant: Refrigerant,
+        temperature: f64,
+        phase: SteamNonCriticalPhaseRegion,
+    ) -> PtvEntry {
+        refrigerant
+            .get_steam_table_entry(SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::C(C::new(temperature)),
+                phase_region: phase,
+            }))
+            .unwrap()
+    }
+
+    macro_rules! saturation_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (refrigerant, °C, kPa, kJ/kg latent heat, kg/m3 liquid density, tolerance)
+                let (refrigerant, temperature, pressure, latent_heat, liquid_density, tolerance): (
+                    Refrigerant,
+                    f64,
+                    f64,
+                    f64,
+                    f64,
+                    f64,
+                ) = $value;
+                let liquid = sat_t(refrigerant, temperature, SteamNonCriticalPhaseRegion::Liquid);
+                let vapor = sat_t(refrigerant, temperature, SteamNonCriticalPhaseRegion::Vapor);
+                let actual_pressure = liquid.pressure.convert_to_si_unit().value / 1e3;
+                assert_approx_eq!(pressure, actual_pressure, tolerance * pressure);
+                let actual_latent_heat = (vapor.enthalpy.convert_to_si_unit().value
+                    - liquid.enthalpy.convert_to_si_unit().value)
+                    / 1e3;
+                assert_approx_eq!(latent_heat, actual_latent_heat, tolerance * latent_heat);
+                let actual_density = liquid.density.convert_to_si_unit().value;
+                assert_approx_eq!(liquid_density, actual_density, tolerance * liquid_density);
+            }
+        )*
+        }
+    }
+
+    // NIST Webbook saturation tables, R-32 is still on the Peng-Robinson equation
+    saturation_tests! {
+        r134a_0c: (Refrigerant::R134a, 0.0, 292.80, 198.60, 1294.78, 1e-3),
+        r134a_40c: (Refrigerant::R134a, 40.0, 1016.6, 163.02, 1146.7, 1e-3),
+        r410a_0c: (Refrigerant::R410A, 0.0, 798.7, 221.5, 1170.7, 1e-3),
+        r32_0c: (Refrigerant::R32, 0.0, 813.1, 315.9, 1055.3, 0.05),
+        ammonia_minus_30c: (Refrigerant::Ammonia, -30.0, 119.43, 1359.7, 677.8, 1e-3),
+        ammonia_0c: (Refrigerant::Ammonia, 0.0, 429.38, 1262.2, 638.57, 1e-3),
+        ammonia_30c: (Refrigerant::Ammonia, 30.0, 1167.2, 1144.0, 595.2, 1e-3),
+        carbon_dioxide_minus_20c: (Refrigerant::CarbonDioxide, -20.0, 1969.7, 282.4, 1031.7, 1e-3),
+        carbon_dioxide_0c: (Refrigerant::CarbonDioxide, 0.0, 3485.1, 230.89, 927.43, 1e-3),
+    }
+
+    #[test]
+    fn reference_state() {
+        for refrigerant in Refrigerant::all() {
+            let liquid = sat_t(refrigerant, 0.0, SteamNonCriticalPhaseRegion::Liquid);
+            assert_approx_eq!(200e3, liquid.enthalpy.convert_to_si_unit().value, 1e-6);
+            assert_approx_eq!(1e3, liquid.entropy.convert_to_si_unit().value, 1e-9);
+        }
+    }
+
+    #[test]
+    fn saturation_pressure_query_matches_temperature_query() {
+        let by_temperature = sat_t(
+            Refrigerant::R134a,
+            -10.0,
+            SteamNonCriticalPhaseRegion::Vapor,
+        );
+        let by_pressure = Refrigerant::R134a
+            .get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure: by_temperature.pressure,
+                phase_region: SteamNonCriticalPhaseRegion::Vapor,
+            }))
+            .unwrap();
+        assert_approx_eq!(
+            by_temperature.temperature.convert_to_si_unit().value,
+            by_pressure.temperature.convert_to_si_unit().value,
+            1e-6
+        );
+    }
+
+    #[test]
+    fn superheated_vapor() {
+        // R-134a at 0.5 MPa and 40 °C, h = 430.6 kJ/kg on the IIR reference state, v = 0.046456 m3/kg
+        let entry = Refrigerant::R134a
+            .get_steam_table_entry(SteamQuery::Pt(PtPoint {
+                pressure: Pressure::KPa(KPa::new(500.0)),
+                temperature: Temperature::C(C::new(40.0)),
+            }))
+            .unwrap();
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            entry.phase_region
+        );
+        assert_approx_eq!(430.6e3, entry.enthalpy.convert_to_si_unit().value, 430.6);
+        assert_approx_eq!(
+            0.046456,
+            entry.specific_volume.convert_to_si_unit().value,
+            0.046456e-3
+        );
+    }
+
+    macro_rules! round_trip_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (refrigerant, °C, kPa)
+                let (refrigerant, temperature, pressure): (Refrigerant, f64, f64) = $value;
+                let pressure = Pressure::KPa(KPa::new(pressure));
+                let expected = refrigerant
+                    .get_steam_table_entry(SteamQuery::Pt(PtPoint {
+                        pressure,
+                        temperature: Temperature::C(C::new(temperature)),
+                    }))
+                    .unwrap();
+                let by_enthalpy = refrigerant
+                    .get_steam_table_entry(SteamQuery::EnthalpyP {
+                        enthalpy: expected.enthalpy,
+                        pressure,
+                    })
+                    .unwrap();
+                let by_entropy = refrigerant
+                    .get_steam_table_entry(SteamQuery::EntropyP {
+                        entropy: expected.entropy,
+                        pressure,
+                    })
+                    .unwrap();
+                for actual in [by_enthalpy, by_entropy] {
+                    assert_eq!(expected.phase_region, actual.phase_region);
+                    assert_approx_eq!(
+                        expected.temperature.convert_to_si_unit().value,
+                        actual.temperature.convert_to_si_unit().value,
+                        1e-4
+                    );
+                }
+            }
+        )*
+        }
+    }
+
+    round_trip_tests! {
+        r134a_subcooled: (Refrigerant::R134a, 20.0, 1000.0),
+        r134a_superheated: (Refrigerant::R134a, 10.0, 200.0),
+        r410a_superheated: (Refrigerant::R410A, 60.0, 2500.0),
+        r32_subcooled: (Refrigerant::R32, -20.0, 1000.0),
+        ammonia_superheated: (Refrigerant::Ammonia, 80.0, 1500.0),
+        carbon_dioxide_supercritical: (Refrigerant::CarbonDioxide, 100.0, 10000.0),
+    }
+
+    #[test]
+    fn two_phase_enthalpy() {
+        let liquid = sat_t(
+            Refrigerant::Ammonia,
+            -10.0,
+            SteamNonCriticalPhaseRegion::Liquid,
+        );
+        let vapor = sat_t(
+            Refrigerant::Ammonia,
+            -10.0,
+            SteamNonCriticalPhaseRegion::Vapor,
+        );
+        let enthalpy = 0.25 * liquid.enthalpy.convert_to_si_unit().value
+            + 0.75 * vapor.enthalpy.convert_to_si_unit().value;
+        let entry = Refrigerant::Ammonia
+            .get_steam_table_entry(SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+                pressure: liquid.pressure,
+            })
+            .unwrap();
+        match entry.phase_region {
+            PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)) => {
+                assert_approx_eq!(0.75, x.get_vapor_frac(), 1e-9);
+            }
+            x => panic!("Expected a liquid vapor mixture, got {}", x),
+        }
+    }
+
+    #[test]
+    fn refrigerant_errors() {
+        let out_of_range = |query| match Refrigerant::CarbonDioxide.get_steam_table_entry(query) {
+            Err(SteamQueryErr::OutOfRange(x)) => x,
+            x => panic!("Expected out of range, got {:?}", x),
+        };
+        assert_eq!(
+            OutOfRange::AboveCriticalTemperature,
+            out_of_range(SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::C(C::new(35.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Liquid,
+            }))
+        );
+        assert_eq!(
+            OutOfRange::AboveCriticalPressure,
+            out_of_range(SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure: Pressure::KPa(KPa::new(8000.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Vapor,
+            }))
+        );
+        assert_eq!(
+            OutOfRange::TemperatureLow,
+            out_of_range(SteamQuery::Pt(PtPoint {
+                pressure: Pressure::KPa(KPa::new(100.0)),
+                temperature: Temperature::C(C::new(-80.0)),
+            }))
+        );
+        assert_eq!(
+            OutOfRange::PressureHigh,
+            out_of_range(SteamQuery::Pt(PtPoint {
+                pressure: Pressure::KPa(KPa::new(30000.0)),
+                temperature: Temperature::C(C::new(50.0)),
+            }))
+        );
+    }
+
+    #[test]
+    fn model_is_built_once() {
+        for refrigerant in Refrigerant::all() {
+            assert!(std::ptr::eq(
+                get_model(refrigerant).unwrap(),
+                get_model(refrigerant).unwrap()
+            ));
+        }
+    }
+
+    #[test]
+    fn refrigerant_names_round_trip() {
+        for refrigerant in Refrigerant::all() {
+            assert_eq!(
+                Ok(refrigerant),
+                Refrigerant::try_from(String::from(refrigerant))
+            );
+        }
+    }
+}
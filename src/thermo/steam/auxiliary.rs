@@ -0,0 +1,362 @@
+// IAPWS R1-76(2014) surface tension of ordinary water substance
+// IAPWS R8-97 static dielectric constant of ordinary water substance
+// IAPWS R9-97 refractive index of ordinary water substance
+use super::*;
+use crate::thermo::steam::iapws97::get_steam_table_entry;
+use crate::thermo::steam::iapws97_constants::IjnRegionPoint;
+use crate::thermo::steam::water_constants::*;
+
+static MOLAR_MASS: f64 = 0.018015268;
+static AVOGADRO_CONSTANT: f64 = 6.0221367e23;
+static BOLTZMANN_CONSTANT: f64 = 1.380658e-23;
+static PERMITTIVITY_OF_VACUUM: f64 = 8.854187817e-12;
+// Mean molecular polarizability (C² m² / J)
+static MOLECULAR_POLARIZABILITY: f64 = 1.636e-40;
+// Dipole moment of an isolated water molecule (C m)
+static DIPOLE_MOMENT: f64 = 6.138e-30;
+
+static DIELECTRIC_G_FACTOR: &[IjnRegionPoint] = &[
+    IjnRegionPoint {
+        i: 1.0,
+        j: 0.25,
+        n: 0.978224486826,
+    },
+    IjnRegionPoint {
+        i: 1.0,
+        j: 1.0,
+        n: -0.957771379375,
+    },
+    IjnRegionPoint {
+        i: 1.0,
+        j: 2.5,
+        n: 0.237511794148,
+    },
+    IjnRegionPoint {
+        i: 2.0,
+        j: 1.5,
+        n: 0.714692244396,
+    },
+    IjnRegionPoint {
+        i: 3.0,
+        j: 1.5,
+        n: -0.298217036956,
+    },
+    IjnRegionPoint {
+        i: 3.0,
+        j: 2.5,
+        n: -0.108863472196,
+    },
+    IjnRegionPoint {
+        i: 4.0,
+        j: 2.0,
+        n: 0.949327488264E-01,
+    },
+    IjnRegionPoint {
+        i: 5.0,
+        j: 2.0,
+        n: -0.980469816509E-02,
+    },
+    IjnRegionPoint {
+        i: 6.0,
+        j: 5.0,
+        n: 0.165167634970E-04,
+    },
+    IjnRegionPoint {
+        i: 7.0,
+        j: 0.5,
+        n: 0.937359795772E-04,
+    },
+    IjnRegionPoint {
+        i: 10.0,
+        j: 10.0,
+        n: -0.123179218720E-09,
+    },
+];
+static DIELECTRIC_N12: f64 = 0.196096504426E-02;
+
+// a0 through a7 of the refractive index formulation
+static REFRACTIVE_INDEX_A: &[f64] = &[
+    0.244257733,
+    9.74634476E-03,
+    -3.73234996E-03,
+    2.68678472E-04,
+    1.58920570E-03,
+    2.45934259E-03,
+    0.900704920,
+    -1.66626219E-02,
+];
+static REFRACTIVE_INDEX_LAMBDA_UV: f64 = 0.2292020;
+static REFRACTIVE_INDEX_LAMBDA_IR: f64 = 5.432937;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AuxiliaryEntry {
+    pub entry: PtvEntry,
+    // Only defined below the critical temperature
+    pub surface_tension: Option<SurfaceTension>,
+    // Only defined from 238 K to 1273 K
+    pub dielectric_constant: Option<f64>,
+    // Only defined from 261.15 K to 773.15 K, up to 1060 kg/m³ and for wavelengths from 0.2 μm to 1.1 μm
+    pub refractive_index: Option<f64>,
+}
+
+/// Surface tension of the vapor-liquid interface (IAPWS R1-76)
+pub fn get_surface_tension(temperature: Temperature) -> Result<SurfaceTension, OutOfRange> {
+    match temperature.convert_to_si_unit().value {
+        t if t < 248.15 => Err(OutOfRange::TemperatureLow),
+        t if t > CRITICAL_TEMPERATURE.value => Err(OutOfRange::AboveCriticalTemperature),
+        t => {
+            let tau = 1f64 - t / CRITICAL_TEMPERATURE.value;
+            let surface_tension = 235.8e-3 * f64::powf(tau, 1.256) * (1f64 - 0.625 * tau);
+            Ok(SurfaceTension::NPerM(NPerM::new(surface_tension)))
+        }
+    }
+}
+
+/// Static dielectric constant (IAPWS R8-97)
+pub fn get_dielectric_constant(
+    temperature: Temperature,
+    density: Density,
+) -> Result<f64, OutOfRange> {
+    let t = temperature.convert_to_si_unit().value;
+    let rho = density.convert_to_si_unit().value;
+    match (t, rho) {
+        (t, _) if t < 238.0 => Err(OutOfRange::TemperatureLow),
+        (t, _) if t > 1273.0 => Err(OutOfRange::TemperatureHigh),
+        (_, rho) if rho <= 0.0 => Err(OutOfRange::DensityLow),
+        (t, rho) => {
+            let delta = rho / CRITICAL_DENSITY.value;
+            let tau = CRITICAL_TEMPERATURE.value / t;
+            let mut g = 1f64 + DIELECTRIC_N12 * delta * f64::powf(t / 228.0 - 1f64, -1.2);
+            for point in DIELECTRIC_G_FACTOR.iter() {
+                g += point.n * f64::powf(delta, point.i) * f64::powf(tau, point.j);
+            }
+            let a = (AVOGADRO_CONSTANT * f64::powi(DIPOLE_MOMENT, 2) * rho * g)
+                / (MOLAR_MASS * PERMITTIVITY_OF_VACUUM * BOLTZMANN_CONSTANT * t);
+            let b = (AVOGADRO_CONSTANT * MOLECULAR_POLARIZABILITY * rho)
+                / (3f64 * MOLAR_MASS * PERMITTIVITY_OF_VACUUM);
+            Ok((1f64
+                + a
+                + 5f64 * b
+                + f64::sqrt(
+                    9f64 + 2f64 * a
+                        + 18f64 * b
+                        + f64::powi(a, 2)
+                        + 10f64 * a * b
+                        + 9f64 * f64::powi(b, 2),
+                ))
+                / (4f64 - 4f64 * b))
+        }
+    }
+}
+
+/// Refractive index relative to vacuum for light of the given wavelength (IAPWS R9-97)
+pub fn get_refractive_index(
+    temperature: Temperature,
+    density: Density,
+    wavelength: Length,
+) -> Result<f64, OutOfRange> {
+    let t = temperature.convert_to_si_unit().value;
+    let rho = density.convert_to_si_unit().value;
+    // in micrometers
+    let lambda = wavelength.convert_to_si_unit().value * 1e6;
+    match (t, rho, lambda) {
+        (t, _, _) if t < 261.15 => Err(OutOfRange::TemperatureLow),
+        (t, _, _) if t > 773.15 => Err(OutOfRange::TemperatureHigh),
+        (_, rho, _) if rho <= 0.0 => Err(OutOfRange::DensityLow),
+        (_, rho, _) if rho > 1060.0 => Err(OutOfRange::DensityHigh),
+        (_, _, lambda) if lambda < 0.2 => Err(OutOfRange::WavelengthLow),
+        (_, _, lambda) if lambda > 1.1 => Err(OutOfRange::WavelengthHigh),
+        (t, rho, lambda) => {
+            let a = REFRACTIVE_INDEX_A;
+            let t_bar = t / 273.15;
+            let rho_bar = rho / 1000.0;
+            let lambda_bar_2 = f64::powi(lambda / 0.589, 2);
+            let rhs = rho_bar
+                * (a[0]
+                    + a[1] * rho_bar
+                    + a[2] * t_bar
+                    + a[3] * lambda_bar_2 * t_bar
+                    + a[4] / lambda_bar_2
+                    + a[5] / (lambda_bar_2 - f64::powi(REFRACTIVE_INDEX_LAMBDA_UV, 2))
+                    + a[6] / (lambda_bar_2 - f64::powi(REFRACTIVE_INDEX_LAMBDA_IR, 2))
+                    + a[7] * f64::powi(rho_bar, 2));
+            // (n² - 1) / (n² + 2) = rhs
+            Ok(f64::sqrt((2f64 * rhs + 1f64) / (1f64 - rhs)))
+        }
+    }
+}
+
+/// Looks up the state with `get_steam_table_entry` then evaluates the auxiliary properties at it
+pub fn get_auxiliary_entry(
+    query: SteamQuery,
+    wavelength: Length,
+) -> Result<AuxiliaryEntry, SteamQueryErr> {
+    get_steam_table_entry(query).map(|entry| {
+        let density = Density::KgPerM3(KgPerM3::new(
+            1f64 / entry.specific_volume.convert_to_si_unit().value,
+        ));
+        let surface_tension = get_surface_tension(entry.temperature).ok();
+        let dielectric_constant = get_dielectric_constant(entry.temperature, density).ok();
+        let refractive_index = get_refractive_index(entry.temperature, density, wavelength).ok();
+        AuxiliaryEntry {
+            entry,
+            surface_tension,
+            dielectric_constant,
+            refractive_index,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! surface_tension_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, expected): (Temperature, Result<SurfaceTension, OutOfRange>) = $value;
+                let actual = get_surface_tension(temperature);
+                match (expected, actual) {
+                    (Ok(expected), Ok(actual)) => assert_approx_eq!(expected, actual, 1e-5),
+                    (x, y) => assert_eq!(x, y),
+                };
+            }
+        )*
+        }
+    }
+
+    surface_tension_tests! {
+        surface_tension_01: (
+            Temperature::C(C::new(0.01)),
+            Ok(SurfaceTension::NPerM(NPerM::new(75.65e-3)))
+        ),
+        surface_tension_02: (
+            Temperature::C(C::new(100.0)),
+            Ok(SurfaceTension::NPerM(NPerM::new(58.91e-3)))
+        ),
+        surface_tension_03: (
+            Temperature::C(C::new(200.0)),
+            Ok(SurfaceTension::NPerM(NPerM::new(37.67e-3)))
+        ),
+        surface_tension_04: (
+            Temperature::C(C::new(300.0)),
+            Ok(SurfaceTension::NPerM(NPerM::new(14.36e-3)))
+        ),
+        surface_tension_05: (
+            Temperature::K(K::new(240.0)),
+            Err(OutOfRange::TemperatureLow)
+        ),
+        surface_tension_06: (
+            Temperature::K(K::new(650.0)),
+            Err(OutOfRange::AboveCriticalTemperature)
+        ),
+    }
+
+    macro_rules! dielectric_constant_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, density, expected): (Temperature, Density, Result<f64, OutOfRange>) = $value;
+                let actual = get_dielectric_constant(temperature, density);
+                match (expected, actual) {
+                    (Ok(expected), Ok(actual)) => assert_approx_eq!(expected, actual, 1e-6),
+                    (x, y) => assert_eq!(x, y),
+                };
+            }
+        )*
+        }
+    }
+
+    dielectric_constant_tests! {
+        dielectric_constant_01: (
+            Temperature::K(K::new(298.15)),
+            Density::KgPerM3(KgPerM3::new(999.242866)),
+            Ok(78.5907250)
+        ),
+        dielectric_constant_02: (
+            Temperature::K(K::new(873.15)),
+            Density::KgPerM3(KgPerM3::new(26.0569558)),
+            Ok(1.12620970)
+        ),
+        dielectric_constant_03: (
+            Temperature::K(K::new(1300.0)),
+            Density::KgPerM3(KgPerM3::new(26.0569558)),
+            Err(OutOfRange::TemperatureHigh)
+        ),
+    }
+
+    macro_rules! refractive_index_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, density, wavelength, expected): (Temperature, Density, Length, Result<f64, OutOfRange>) = $value;
+                let actual = get_refractive_index(temperature, density, wavelength);
+                match (expected, actual) {
+                    (Ok(expected), Ok(actual)) => assert_approx_eq!(expected, actual, 1e-7),
+                    (x, y) => assert_eq!(x, y),
+                };
+            }
+        )*
+        }
+    }
+
+    refractive_index_tests! {
+        refractive_index_01: (
+            Temperature::K(K::new(298.15)),
+            Density::KgPerM3(KgPerM3::new(997.047435)),
+            Length::M(M::new(0.2265e-6)),
+            Ok(1.39277824)
+        ),
+        refractive_index_02: (
+            Temperature::K(K::new(773.15)),
+            Density::KgPerM3(KgPerM3::new(30.4758534)),
+            Length::M(M::new(0.5893e-6)),
+            Ok(1.00949307)
+        ),
+        refractive_index_03: (
+            Temperature::K(K::new(298.15)),
+            Density::KgPerM3(KgPerM3::new(997.047435)),
+            Length::M(M::new(1.2e-6)),
+            Err(OutOfRange::WavelengthHigh)
+        ),
+    }
+
+    #[test]
+    fn auxiliary_entry_uses_steam_table_state() {
+        let aux = get_auxiliary_entry(
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(298.15)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            }),
+            Length::M(M::new(0.589e-6)),
+        )
+        .unwrap();
+        assert_approx_eq!(
+            SurfaceTension::NPerM(NPerM::new(71.97e-3)),
+            aux.surface_tension.unwrap(),
+            1e-4
+        );
+        assert_approx_eq!(78.41, aux.dielectric_constant.unwrap(), 1e-2);
+        assert_approx_eq!(1.3329, aux.refractive_index.unwrap(), 1e-4);
+    }
+
+    #[test]
+    fn auxiliary_entry_outside_of_refractive_index_range() {
+        let aux = get_auxiliary_entry(
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::C(C::new(600.0)),
+                pressure: Pressure::Pa(Pa::new(10e6)),
+            }),
+            Length::M(M::new(0.589e-6)),
+        )
+        .unwrap();
+        assert_eq!(None, aux.surface_tension);
+        assert!(aux.dielectric_constant.is_some());
+        assert_eq!(None, aux.refractive_index);
+    }
+}
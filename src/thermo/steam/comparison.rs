@@ -0,0 +1,149 @@
+use super::*;
+
+/// Deviation of one formulation from a reference formulation at a single state.
+/// Volume, heat capacity and speed of sound are relative (other - reference) / reference
+/// while enthalpy and entropy are absolute differences.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BackendDeviation {
+    pub point: PtPoint,
+    pub specific_volume: f64,
+    pub enthalpy: EnergyPerMass,
+    pub entropy: EnergyPerMassTemperature,
+    pub cp: f64,
+    pub speed_of_sound: f64,
+}
+
+/// Largest absolute deviation of each property over a grid
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeviationSummary {
+    pub points_compared: usize,
+    pub points_failed: usize,
+    pub specific_volume: f64,
+    pub enthalpy: EnergyPerMass,
+    pub entropy: EnergyPerMassTemperature,
+    pub cp: f64,
+    pub speed_of_sound: f64,
+}
+
+fn get_relative_deviation(reference: f64, other: f64) -> f64 {
+    (other - reference) / reference
+}
+
+pub fn get_backend_deviation(
+    reference: &impl SteamProperties,
+    other: &impl SteamProperties,
+    point: PtPoint,
+) -> Result<BackendDeviation, SteamQueryErr> {
+    let reference_entry = reference.get_steam_table_entry(SteamQuery::Pt(point))?;
+    let other_entry = other.get_steam_table_entry(SteamQuery::Pt(point))?;
+    Ok(BackendDeviation {
+        point,
+        specific_volume: get_relative_deviation(
+            reference_entry.specific_volume.convert_to_si_unit().value,
+            other_entry.specific_volume.convert_to_si_unit().value,
+        ),
+        enthalpy: other_entry.enthalpy - reference_entry.enthalpy,
+        entropy: other_entry.entropy - reference_entry.entropy,
+        cp: get_relative_deviation(
+            reference_entry.cp.convert_to_si_unit().value,
+            other_entry.cp.convert_to_si_unit().value,
+        ),
+        speed_of_sound: get_relative_deviation(
+            reference_entry.speed_of_sound.convert_to_si_unit().value,
+            other_entry.speed_of_sound.convert_to_si_unit().value,
+        ),
+    })
+}
+
+/// Compares two formulations at every temperature and pressure combination of the grid
+pub fn compare_backends(
+    reference: &impl SteamProperties,
+    other: &impl SteamProperties,
+    temperatures: &[Temperature],
+    pressures: &[Pressure],
+) -> Vec<(PtPoint, Result<BackendDeviation, SteamQueryErr>)> {
+    let mut comparisons = Vec::with_capacity(temperatures.len() * pressures.len());
+    for pressure in pressures.iter() {
+        for temperature in temperatures.iter() {
+            let point = PtPoint {
+                pressure: *pressure,
+                temperature: *temperature,
+            };
+            comparisons.push((point, get_backend_deviation(reference, other, point)));
+        }
+    }
+    comparisons
+}
+
+pub fn summarize_deviations(
+    comparisons: &[(PtPoint, Result<BackendDeviation, SteamQueryErr>)],
+) -> DeviationSummary {
+    let deviations = comparisons
+        .iter()
+        .filter_map(|(_, x)| x.as_ref().ok())
+        .collect::<Vec<&BackendDeviation>>();
+    let get_max = |f: fn(&BackendDeviation) -> f64| {
+        deviations
+            .iter()
+            .map(|x| f64::abs(f(x)))
+            .fold(0f64, f64::max)
+    };
+    DeviationSummary {
+        points_compared: deviations.len(),
+        points_failed: comparisons.len() - deviations.len(),
+        specific_volume: get_max(|x| x.specific_volume),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(get_max(|x| {
+            x.enthalpy.convert_to_si_unit().value
+        }))),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(get_max(|x| {
+            x.entropy.convert_to_si_unit().value
+        }))),
+        cp: get_max(|x| x.cp),
+        speed_of_sound: get_max(|x| x.speed_of_sound),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws95::Iapws95;
+    use crate::thermo::steam::iapws97::Iapws97;
+
+    #[test]
+    fn if97_stays_close_to_iapws95() {
+        let temperatures = [300.0, 400.0, 500.0, 700.0, 900.0]
+            .iter()
+            .map(|x| Temperature::K(K::new(*x)))
+            .collect::<Vec<Temperature>>();
+        let pressures = [0.01e6, 1e6, 10e6, 50e6]
+            .iter()
+            .map(|x| Pressure::Pa(Pa::new(*x)))
+            .collect::<Vec<Pressure>>();
+        let comparisons = compare_backends(&Iapws95, &Iapws97, &temperatures, &pressures);
+        let summary = summarize_deviations(&comparisons);
+        assert_eq!(20, summary.points_compared);
+        assert_eq!(0, summary.points_failed);
+        assert!(summary.specific_volume < 1e-3);
+        assert!(summary.enthalpy.convert_to_si_unit().value < 1e3);
+        assert!(summary.entropy.convert_to_si_unit().value < 1.0);
+        assert!(summary.cp < 1e-2);
+        assert!(summary.speed_of_sound < 1e-2);
+    }
+
+    #[test]
+    fn failed_points_are_counted() {
+        let comparisons = compare_backends(
+            &Iapws95,
+            &Iapws97,
+            &[Temperature::K(K::new(300.0))],
+            &[Pressure::Pa(Pa::new(1e6)), Pressure::Pa(Pa::new(200e6))],
+        );
+        let summary = summarize_deviations(&comparisons);
+        assert_eq!(1, summary.points_compared);
+        assert_eq!(1, summary.points_failed);
+        assert_eq!(
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            comparisons[1].1
+        );
+    }
+}
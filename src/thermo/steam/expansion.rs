@@ -0,0 +1,516 @@
+use super::*;
+use crate::numerical_methods::optimizers::golden_section_search;
+use std::cell::RefCell;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ExpansionDevice {
+    Turbine,
+    // The flow leaves at the critical pressure once the nozzle chokes
+    ConvergingNozzle,
+    // The flow keeps expanding past the throat down to the outlet pressure
+    ConvergingDivergingNozzle,
+}
+
+impl TryFrom<String> for ExpansionDevice {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Turbine" => Ok(ExpansionDevice::Turbine),
+            "ConvergingNozzle" => Ok(ExpansionDevice::ConvergingNozzle),
+            "ConvergingDivergingNozzle" => Ok(ExpansionDevice::ConvergingDivergingNozzle),
+            _ => Err(format!("Unknown Device \"{}\"", value)),
+        }
+    }
+}
+
+impl From<ExpansionDevice> for String {
+    fn from(val: ExpansionDevice) -> Self {
+        match val {
+            ExpansionDevice::Turbine => "Turbine".to_owned(),
+            ExpansionDevice::ConvergingNozzle => "ConvergingNozzle".to_owned(),
+            ExpansionDevice::ConvergingDivergingNozzle => "ConvergingDivergingNozzle".to_owned(),
+        }
+    }
+}
+
+impl Display for ExpansionDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ExpansionDevice::Turbine => "Turbine Stage",
+                ExpansionDevice::ConvergingNozzle => "Converging Nozzle",
+                ExpansionDevice::ConvergingDivergingNozzle => "Converging-Diverging Nozzle",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ExpansionQuery {
+    pub device: ExpansionDevice,
+    // The inlet velocity is neglected so the inlet is also the stagnation state
+    pub inlet: PtPoint,
+    pub outlet_pressure: Pressure,
+    // Isentropic efficiency of a turbine or kinetic energy efficiency of a nozzle, between 0 and 1
+    pub efficiency: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NozzleChoking {
+    // Throat pressure over inlet pressure that maximizes the isentropic mass flux
+    pub critical_pressure_ratio: f64,
+    pub critical_pressure: Pressure,
+    // Isentropic velocity at the throat when the nozzle is choked
+    pub throat_velocity: Velocity,
+    pub is_choked: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Expansion {
+    pub inlet: PtvEntry,
+    pub isentropic_outlet: PtvEntry,
+    pub outlet: PtvEntry,
+    // Vapor fraction, None when the outlet is not a liquid vapor mixture
+    pub outlet_quality: Option<f64>,
+    pub isentropic_work: EnergyPerMass,
+    // Shaft work of a turbine or kinetic energy gained in a nozzle
+    pub specific_work: EnergyPerMass,
+    // Velocity reached if all of the specific work becomes kinetic energy
+    pub exit_velocity: Velocity,
+    // Only calculated for nozzles
+    pub choking: Option<NozzleChoking>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ExpansionErr {
+    InvalidEfficiency,
+    // The outlet pressure must be below the inlet pressure
+    OutletPressureHigh,
+    SteamQueryErr(SteamQueryErr),
+    FailedToConverge(RootFinderErr),
+    // The mass flux kept rising up to the edge of every pressure ratio bracket searched
+    CriticalPressureNotFound,
+}
+
+fn get_entry(backend: &impl SteamProperties, query: SteamQuery) -> Result<PtvEntry, ExpansionErr> {
+    backend
+        .get_steam_table_entry(query)
+        .map_err(ExpansionErr::SteamQueryErr)
+}
+
+fn get_isentropic_entry(
+    backend: &impl SteamProperties,
+    inlet: &PtvEntry,
+    pressure: f64,
+) -> Result<PtvEntry, ExpansionErr> {
+    get_entry(
+        backend,
+        SteamQuery::EntropyP {
+            entropy: inlet.entropy,
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        },
+    )
+}
+
+fn get_enthalpy_drop(inlet: &PtvEntry, outlet: &PtvEntry) -> f64 {
+    inlet.enthalpy.convert_to_si_unit().value - outlet.enthalpy.convert_to_si_unit().value
+}
+
+// Pressure ratio brackets for the throat, the wider one is only searched
+// when the maximum mass flux lands on a bound of the first
+static CRITICAL_PRESSURE_RATIO_BRACKETS: [(f64, f64); 2] = [(0.2, 0.95), (0.01, 0.999)];
+static CRITICAL_PRESSURE_RATIO_TOLERANCE: f64 = 1e-5;
+
+// The isentropic mass flux peaks at the throat of a choked nozzle
+fn get_choking(
+    backend: &impl SteamProperties,
+    inlet: &PtvEntry,
+    outlet_pressure: f64,
+) -> Result<NozzleChoking, ExpansionErr> {
+    let inlet_pressure = inlet.pressure.convert_to_si_unit().value;
+    // The search only takes numbers so the first failed query is kept and returned after it
+    let query_err = RefCell::new(None);
+    let get_mass_flux =
+        |ratio: f64| match get_isentropic_entry(backend, inlet, ratio * inlet_pressure) {
+            Ok(x) => {
+                let drop = f64::max(get_enthalpy_drop(inlet, &x), 0f64);
+                f64::sqrt(2f64 * drop) / x.specific_volume.convert_to_si_unit().value
+            }
+            Err(err) => {
+                query_err.borrow_mut().get_or_insert(err);
+                0f64
+            }
+        };
+    let tol = CRITICAL_PRESSURE_RATIO_TOLERANCE;
+    let mut critical_pressure_ratio = None;
+    for (lower, upper) in CRITICAL_PRESSURE_RATIO_BRACKETS {
+        let ratio = golden_section_search(|x| -get_mass_flux(x), lower, upper, tol)
+            .map_err(ExpansionErr::FailedToConverge)?;
+        if let Some(err) = query_err.borrow_mut().take() {
+            return Err(err);
+        }
+        if ratio - lower > tol && upper - ratio > tol {
+            critical_pressure_ratio = Some(ratio);
+            break;
+        }
+    }
+    let critical_pressure_ratio =
+        critical_pressure_ratio.ok_or(ExpansionErr::CriticalPressureNotFound)?;
+    let critical_pressure = critical_pressure_ratio * inlet_pressure;
+    let throat = get_isentropic_entry(backend, inlet, critical_pressure)?;
+    Ok(NozzleChoking {
+        critical_pressure_ratio,
+        critical_pressure: Pressure::Pa(Pa::new(critical_pressure)),
+        throat_velocity: Velocity::MPerSec(MPerSec::new(f64::sqrt(
+            2f64 * get_enthalpy_drop(inlet, &throat),
+        ))),
+        is_choked: outlet_pressure < critical_pressure,
+    })
+}
+
+/// Adiabatic expansion from the inlet state down to the outlet pressure.
+/// The actual enthalpy drop is the isentropic drop times the efficiency.
+pub fn get_expansion(
+    backend: &impl SteamProperties,
+    query: ExpansionQuery,
+) -> Result<Expansion, ExpansionErr> {
+    if query.efficiency <= 0f64 || query.efficiency > 1f64 {
+        return Err(ExpansionErr::InvalidEfficiency);
+    }
+    let inlet_pressure = query.inlet.pressure.convert_to_si_unit().value;
+    let outlet_pressure = query.outlet_pressure.convert_to_si_unit().value;
+    if outlet_pressure >= inlet_pressure {
+        return Err(ExpansionErr::OutletPressureHigh);
+    }
+    let inlet = get_entry(backend, SteamQuery::Pt(query.inlet))?;
+    let choking = match query.device {
+        ExpansionDevice::Turbine => None,
+        _ => Some(get_choking(backend, &inlet, outlet_pressure)?),
+    };
+    let exit_pressure = match (query.device, choking) {
+        (ExpansionDevice::ConvergingNozzle, Some(x)) if x.is_choked => {
+            x.critical_pressure.convert_to_si_unit().value
+        }
+        _ => outlet_pressure,
+    };
+    let isentropic_outlet = get_isentropic_entry(backend, &inlet, exit_pressure)?;
+    let isentropic_work = get_enthalpy_drop(&inlet, &isentropic_outlet);
+    let specific_work = isentropic_work * query.efficiency;
+    let outlet = get_entry(
+        backend,
+        SteamQuery::EnthalpyP {
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(
+                inlet.enthalpy.convert_to_si_unit().value - specific_work,
+            )),
+            pressure: Pressure::Pa(Pa::new(exit_pressure)),
+        },
+    )?;
+    let outlet_quality = match &outlet.phase_region {
+        PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)) => Some(x.get_vapor_frac()),
+        _ => None,
+    };
+    Ok(Expansion {
+        inlet,
+        isentropic_outlet,
+        outlet,
+        outlet_quality,
+        isentropic_work: EnergyPerMass::JPerKg(JPerKg::new(isentropic_work)),
+        specific_work: EnergyPerMass::JPerKg(JPerKg::new(specific_work)),
+        exit_velocity: Velocity::MPerSec(MPerSec::new(f64::sqrt(2f64 * specific_work))),
+        choking,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws97::Iapws97;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! expansion_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (
+                    query,
+                    expected_outlet_pressure,
+                    expected_isentropic_work,
+                    expected_quality_range,
+                    expected_is_choked,
+                ): (ExpansionQuery, Option<f64>, Option<f64>, Option<(f64, f64)>, Option<bool>) =
+                    $value;
+                let expansion = get_expansion(&Iapws97, query).unwrap();
+                let isentropic_work = expansion.isentropic_work.convert_to_si_unit().value;
+                assert_approx_eq!(
+                    expansion.inlet.entropy.convert_to_si_unit().value,
+                    expansion
+                        .isentropic_outlet
+                        .entropy
+                        .convert_to_si_unit()
+                        .value,
+                    1e-2
+                );
+                if let Some(work) = expected_isentropic_work {
+                    assert_approx_eq!(work, isentropic_work, 2e3);
+                }
+                assert_approx_eq!(
+                    query.efficiency * isentropic_work,
+                    expansion.specific_work.convert_to_si_unit().value,
+                    1e-6
+                );
+                assert_approx_eq!(
+                    f64::sqrt(2f64 * query.efficiency * isentropic_work),
+                    expansion.exit_velocity.convert_to_si_unit().value,
+                    1e-6
+                );
+                if let Some((lower, upper)) = expected_quality_range {
+                    let quality = expansion.outlet_quality.unwrap();
+                    assert!(quality > lower && quality < upper);
+                }
+                assert_eq!(expected_is_choked, expansion.choking.map(|x| x.is_choked));
+                // A choked converging nozzle exits at the critical pressure
+                let outlet_pressure = expected_outlet_pressure.unwrap_or_else(|| {
+                    expansion
+                        .choking
+                        .unwrap()
+                        .critical_pressure
+                        .convert_to_si_unit()
+                        .value
+                });
+                assert_approx_eq!(
+                    outlet_pressure,
+                    expansion.outlet.pressure.convert_to_si_unit().value,
+                    1e-6
+                );
+                // The throat velocity is the speed of sound at the throat
+                if let Some(choking) = expansion.choking {
+                    let throat = get_isentropic_entry(
+                        &Iapws97,
+                        &expansion.inlet,
+                        choking.critical_pressure.convert_to_si_unit().value,
+                    )
+                    .unwrap();
+                    assert_approx_eq!(
+                        throat.speed_of_sound.convert_to_si_unit().value,
+                        choking.throat_velocity.convert_to_si_unit().value,
+                        5f64
+                    );
+                }
+            }
+        )*
+        }
+    }
+
+    // Rows of (query, outlet pressure in Pa or None for the critical pressure,
+    // isentropic work in J/kg, outlet quality range, is choked)
+    expansion_tests! {
+        // 3 MPa and 400 °C expanding to 50 kPa, s = 6.9235 kJ/(kg · K) so
+        // x = (6.9235 - 1.0912) / 6.5019 and h = 340.54 + x 2304.7 kJ/kg
+        turbine_into_wet_region: (
+            ExpansionQuery {
+                device: ExpansionDevice::Turbine,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(3e6)),
+                    temperature: Temperature::C(C::new(400.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(50.0)),
+                efficiency: 0.85,
+            },
+            Some(50e3),
+            Some(3231.7e3 - 2407.8e3),
+            Some((0.897, 1.0)),
+            None,
+        ),
+        // A converging-diverging nozzle expands all the way to the outlet pressure
+        superheated_converging_diverging_nozzle: (
+            ExpansionQuery {
+                device: ExpansionDevice::ConvergingDivergingNozzle,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(300.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(200.0)),
+                efficiency: 1f64,
+            },
+            Some(200e3),
+            None,
+            None,
+            Some(true),
+        ),
+        converging_nozzle_exits_at_critical_pressure: (
+            ExpansionQuery {
+                device: ExpansionDevice::ConvergingNozzle,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(300.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(200.0)),
+                efficiency: 0.95,
+            },
+            None,
+            None,
+            None,
+            Some(true),
+        ),
+        converging_nozzle_above_critical_pressure: (
+            ExpansionQuery {
+                device: ExpansionDevice::ConvergingNozzle,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(300.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(800.0)),
+                efficiency: 0.95,
+            },
+            Some(800e3),
+            None,
+            None,
+            Some(false),
+        ),
+    }
+
+    macro_rules! critical_pressure_ratio_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (inlet, expected): (PtPoint, f64) = $value;
+                let query = ExpansionQuery {
+                    device: ExpansionDevice::ConvergingDivergingNozzle,
+                    inlet,
+                    outlet_pressure: Pressure::Pa(Pa::new(
+                        0.1 * inlet.pressure.convert_to_si_unit().value,
+                    )),
+                    efficiency: 1f64,
+                };
+                let choking = get_expansion(&Iapws97, query).unwrap().choking.unwrap();
+                assert_approx_eq!(expected, choking.critical_pressure_ratio, 0.01);
+                assert!(choking.is_choked);
+            }
+        )*
+        }
+    }
+
+    // Superheated steam is close to the ideal gas ratio for k = 1.3. Just above
+    // saturation the throat is wet and the ratio is close to the 0.577 of
+    // saturated steam with k = 1.135
+    critical_pressure_ratio_tests! {
+        critical_pressure_ratio_superheated: (PtPoint {
+            pressure: Pressure::Pa(Pa::new(1e6)),
+            temperature: Temperature::C(C::new(300.0)),
+        }, 0.546),
+        critical_pressure_ratio_near_saturated_1_mpa: (PtPoint {
+            pressure: Pressure::Pa(Pa::new(1e6)),
+            temperature: Temperature::C(C::new(180.0)),
+        }, 0.577),
+        critical_pressure_ratio_near_saturated_200_kpa: (PtPoint {
+            pressure: Pressure::KPa(KPa::new(200.0)),
+            temperature: Temperature::C(C::new(121.0)),
+        }, 0.577),
+    }
+
+    // Holds the isentropic volume fixed so the mass flux only rises as the pressure drops
+    struct ConstantVolume;
+
+    impl SteamProperties for ConstantVolume {
+        fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+            match query {
+                SteamQuery::EntropyP { .. } => {
+                    Iapws97.get_steam_table_entry(query).map(|x| PtvEntry {
+                        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.2)),
+                        ..x
+                    })
+                }
+                _ => Iapws97.get_steam_table_entry(query),
+            }
+        }
+    }
+
+    // Fails every isentropic query below 500 kPa
+    struct LowPressureLimit;
+
+    impl SteamProperties for LowPressureLimit {
+        fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+            match query {
+                SteamQuery::EntropyP { pressure, .. }
+                    if pressure.convert_to_si_unit().value < 500e3 =>
+                {
+                    Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow))
+                }
+                _ => Iapws97.get_steam_table_entry(query),
+            }
+        }
+    }
+
+    macro_rules! expansion_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (backend, query, expected): (_, ExpansionQuery, ExpansionErr) = $value;
+                assert_eq!(Err(expected), get_expansion(&backend, query));
+            }
+        )*
+        }
+    }
+
+    expansion_err_tests! {
+        efficiency_above_one: (
+            Iapws97,
+            ExpansionQuery {
+                device: ExpansionDevice::Turbine,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(3e6)),
+                    temperature: Temperature::C(C::new(400.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(50.0)),
+                efficiency: 1.2,
+            },
+            ExpansionErr::InvalidEfficiency,
+        ),
+        outlet_above_inlet_pressure: (
+            Iapws97,
+            ExpansionQuery {
+                device: ExpansionDevice::Turbine,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(3e6)),
+                    temperature: Temperature::C(C::new(400.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(5000.0)),
+                efficiency: 0.9,
+            },
+            ExpansionErr::OutletPressureHigh,
+        ),
+        critical_pressure_on_bracket_bound: (
+            ConstantVolume,
+            ExpansionQuery {
+                device: ExpansionDevice::ConvergingDivergingNozzle,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(300.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(200.0)),
+                efficiency: 1f64,
+            },
+            ExpansionErr::CriticalPressureNotFound,
+        ),
+        // The throat is above 500 kPa but the search for it passes through lower pressures
+        failed_choking_query: (
+            LowPressureLimit,
+            ExpansionQuery {
+                device: ExpansionDevice::ConvergingDivergingNozzle,
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(300.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(600.0)),
+                efficiency: 1f64,
+            },
+            ExpansionErr::SteamQueryErr(SteamQueryErr::OutOfRange(OutOfRange::PressureLow)),
+        ),
+    }
+}
@@ -0,0 +1,402 @@
+// IAPWS R6-95(2018) formulation for general and scientific use
+use super::*;
+use crate::numerical_methods::root_finders::newton_method;
+use crate::thermo::steam::iapws95_constants::*;
+use crate::thermo::steam::water_constants::*;
+
+fn get_supercritical_phase_region(temperature: f64, pressure: f64) -> Option<PhaseRegion> {
+    match (
+        temperature >= CRITICAL_TEMPERATURE.value,
+        pressure >= CRITICAL_PRESSURE.value,
+    ) {
+        (true, true) => Some(PhaseRegion::SupercriticalFluid),
+        (true, false) => Some(PhaseRegion::Gas),
+        _ => None,
+    }
+}
+
+/// Evaluates the formulation directly at its natural variables, temperature and density
+pub fn get_entry_from_density(temperature: Temperature, density: Density) -> PtvEntry {
+    let t = temperature.convert_to_si_unit().value;
+    let rho = density.convert_to_si_unit().value;
+    let point = IAPWS95.get_point(t, rho);
+    let pressure = IAPWS95.get_pressure(&point, t, rho);
+    let phase_region =
+        get_supercritical_phase_region(t, pressure).unwrap_or(if rho > CRITICAL_DENSITY.value {
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid)
+        } else {
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor)
+        });
+    IAPWS95.create_entry(&point, t, rho, phase_region)
+}
+
+fn get_saturation_temperature(pressure: f64) -> Result<f64, SteamQueryErr> {
+    if pressure > CRITICAL_PRESSURE.value {
+        return Err(SteamQueryErr::OutOfRange(OutOfRange::AboveCriticalPressure));
+    }
+    let seed = iapws97::get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region: SteamNonCriticalPhaseRegion::Vapor,
+    }))?
+    .temperature
+    .convert_to_si_unit()
+    .value;
+    let f = |t| {
+        IAPWS95
+            .get_saturation_state(t)
+            .map(|x| x.pressure / pressure - 1f64)
+            .unwrap_or(f64::NAN)
+    };
+    // dp/dT along the saturation curve from the Clausius-Clapeyron equation
+    let df = |t| {
+        IAPWS95
+            .get_saturation_state(t)
+            .map(|x| {
+                let s_l = IAPWS95.get_entropy(&IAPWS95.get_point(t, x.liquid_density));
+                let s_v = IAPWS95.get_entropy(&IAPWS95.get_point(t, x.vapor_density));
+                (s_v - s_l) / (1f64 / x.vapor_density - 1f64 / x.liquid_density) / pressure
+            })
+            .unwrap_or(f64::NAN)
+    };
+    newton_method(f, df, seed, 1e-9).map_err(SteamQueryErr::FailedToConverge)
+}
+
+fn get_saturated_entry(
+    temperature: f64,
+    phase_region: SteamNonCriticalPhaseRegion,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let state = IAPWS95.get_saturation_state(temperature)?;
+    let (density, phase) = match phase_region {
+        SteamNonCriticalPhaseRegion::Liquid => {
+            (state.liquid_density, NonCriticalPhaseRegion::Liquid)
+        }
+        SteamNonCriticalPhaseRegion::Vapor => (state.vapor_density, NonCriticalPhaseRegion::Vapor),
+        SteamNonCriticalPhaseRegion::Solid => {
+            return Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow))
+        }
+    };
+    let point = IAPWS95.get_point(temperature, density);
+    Ok(IAPWS95.create_entry(
+        &point,
+        temperature,
+        density,
+        PhaseRegion::NonCritical(phase),
+    ))
+}
+
+fn get_entry_from_pt_point(point: &PtPoint) -> Result<PtvEntry, SteamQueryErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    // IAPWS-IF97 is carried past 100 MPa and held at its lowest temperature to seed the solver
+    let if97_density = iapws97::get_steam_table_entry_with_options(
+        SteamQuery::Pt(PtPoint {
+            pressure: point.pressure,
+            temperature: Temperature::K(K::new(f64::max(temperature, 273.15))),
+        }),
+        SteamQueryOptions {
+            extrapolate: true,
+            ..SteamQueryOptions::default()
+        },
+    )
+    .map(|x| x.entry.density.convert_to_si_unit().value)
+    .unwrap_or(pressure / (IAPWS95.specific_gas_constant * temperature));
+    // Below the critical temperature the seed is kept on the stable side of the saturation curve
+    // so the solver does not land on a metastable root
+    let (seed, phase_region) = match get_supercritical_phase_region(temperature, pressure) {
+        Some(phase_region) => (if97_density, phase_region),
+        // Below the triple point the liquid only exists squeezed past the ice Ih melting curve
+        None if temperature < TRIPLE_POINT_TEMPERATURE.value => {
+            let sublimation_pressure = ice::get_sublimation_pressure(point.temperature)
+                .map_err(SteamQueryErr::OutOfRange)?
+                .convert_to_si_unit()
+                .value;
+            if pressure < sublimation_pressure {
+                return get_vapor_entry_below_triple_point(point);
+            }
+            (
+                if97_density,
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+            )
+        }
+        None => {
+            let state = IAPWS95.get_saturation_state(temperature)?;
+            if pressure >= state.pressure {
+                (
+                    f64::max(if97_density, state.liquid_density),
+                    PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+                )
+            } else {
+                (
+                    f64::min(if97_density, state.vapor_density),
+                    PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+                )
+            }
+        }
+    };
+    let f = |rho| {
+        IAPWS95.get_pressure(&IAPWS95.get_point(temperature, rho), temperature, rho) / pressure
+            - 1f64
+    };
+    let df = |rho| {
+        IAPWS95.get_pressure_density_derivative(&IAPWS95.get_point(temperature, rho), temperature)
+            / pressure
+    };
+    newton_method(f, df, seed, 1e-9)
+        .map_err(SteamQueryErr::FailedToConverge)
+        .map(|rho| {
+            IAPWS95.create_entry(
+                &IAPWS95.get_point(temperature, rho),
+                temperature,
+                rho,
+                phase_region,
+            )
+        })
+}
+
+// Vapor on the low pressure side of the sublimation curve, where the saturation
+// state used to pick the stable density root does not exist
+pub(crate) fn get_vapor_entry_below_triple_point(
+    point: &PtPoint,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    let seed = pressure / (IAPWS95.specific_gas_constant * temperature);
+    let f = |rho| {
+        IAPWS95.get_pressure(&IAPWS95.get_point(temperature, rho), temperature, rho) / pressure
+            - 1f64
+    };
+    let df = |rho| {
+        IAPWS95.get_pressure_density_derivative(&IAPWS95.get_point(temperature, rho), temperature)
+            / pressure
+    };
+    newton_method(f, df, seed, 1e-9)
+        .map_err(SteamQueryErr::FailedToConverge)
+        .map(|rho| {
+            IAPWS95.create_entry(
+                &IAPWS95.get_point(temperature, rho),
+                temperature,
+                rho,
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            )
+        })
+}
+
+// The stable fluid from the melting and sublimation curves up to 1273 K and 1000 MPa
+static VALIDITY_RANGE: ValidityRange = ValidityRange {
+    max_temperature: 1273.15,
+    max_pressure: 1000e6,
+    high_pressure_max_temperature: None,
+    stop_at_melting_line: true,
+};
+
+/// IAPWS-95 scientific formulation
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Iapws95;
+
+impl SteamProperties for Iapws95 {
+    fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+        get_steam_table_entry(query)
+    }
+}
+
+pub fn get_steam_table_entry(query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+    check_if_out_of_range(&query, &VALIDITY_RANGE)
+        .map_err(SteamQueryErr::OutOfRange)
+        .and_then(|_| match query {
+            SteamQuery::Pt(point) => get_entry_from_pt_point(&point),
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature,
+                phase_region,
+            }) => get_saturated_entry(temperature.convert_to_si_unit().value, phase_region),
+            SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure,
+                phase_region,
+            }) => get_saturation_temperature(pressure.convert_to_si_unit().value)
+                .and_then(|t| get_saturated_entry(t, phase_region)),
+            SteamQuery::EntropyP { entropy, pressure } => iterate_pt_entry_solution(
+                &Iapws95,
+                pressure.convert_to_si_unit(),
+                entropy.convert_to_si_unit().value,
+                |point| point.entropy.convert_to_si_unit().value,
+            ),
+            SteamQuery::EnthalpyP { enthalpy, pressure } => iterate_pt_entry_solution(
+                &Iapws95,
+                pressure.convert_to_si_unit(),
+                enthalpy.convert_to_si_unit().value,
+                |point| point.enthalpy.convert_to_si_unit().value,
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn helmholtz_derivatives_match_check_values() {
+        let point = IAPWS95.get_point(500.0, 838.025);
+        assert_approx_eq!(0.204797733e1, point.phi_o, 1e-8);
+        assert_approx_eq!(0.904611106e1, point.phi_o_tau, 1e-8);
+        assert_approx_eq!(-0.193249185e1, point.phi_o_tau_tau, 1e-8);
+        assert_approx_eq!(-0.342693206e1, point.phi_r, 1e-8);
+        assert_approx_eq!(-0.364366650, point.phi_r_delta, 1e-8);
+        assert_approx_eq!(0.856063701, point.phi_r_delta_delta, 1e-8);
+        assert_approx_eq!(-0.581403435e1, point.phi_r_tau, 1e-8);
+        assert_approx_eq!(-0.223440737e1, point.phi_r_tau_tau, 1e-8);
+        assert_approx_eq!(-0.112176915e1, point.phi_r_delta_tau, 1e-8);
+    }
+
+    macro_rules! density_entry_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let ((temperature, density), (pressure, cv, speed_of_sound, entropy)): ((f64, f64), (f64, f64, f64, f64)) = $value;
+                let entry = get_entry_from_density(
+                    Temperature::K(K::new(temperature)),
+                    Density::KgPerM3(KgPerM3::new(density)),
+                );
+                assert_approx_eq!(pressure, entry.pressure.convert_to_si_unit().value, pressure * 1e-8);
+                assert_approx_eq!(cv, entry.cv.convert_to_si_unit().value, 1e-4);
+                assert_approx_eq!(speed_of_sound, entry.speed_of_sound.convert_to_si_unit().value, 1e-4);
+                assert_approx_eq!(entropy, entry.entropy.convert_to_si_unit().value, 1e-4);
+            }
+        )*
+        }
+    }
+
+    density_entry_tests! {
+        density_entry_01: ((300.0, 0.9965560e3), (0.992418352e5, 0.413018112e4, 0.150151914e4, 0.393062643e3)),
+        density_entry_02: ((300.0, 0.1005308e4), (0.200022515e8, 0.406798347e4, 0.153492501e4, 0.387405401e3)),
+        density_entry_03: ((300.0, 0.1188202e4), (0.700004704e9, 0.346135580e4, 0.244357992e4, 0.132609616e3)),
+        density_entry_04: ((500.0, 0.4350000), (0.999679423e5, 0.150817541e4, 0.548314253e3, 0.794488271e4)),
+        density_entry_05: ((500.0, 0.4532000e1), (0.999938125e6, 0.166991025e4, 0.535739001e3, 0.682502725e4)),
+        density_entry_06: ((500.0, 0.8380250e3), (0.100003858e8, 0.322106219e4, 0.127128441e4, 0.256690919e4)),
+        density_entry_07: ((500.0, 0.1084564e4), (0.700000405e9, 0.307437693e4, 0.241200877e4, 0.203237509e4)),
+        density_entry_08: ((647.0, 0.3580000e3), (0.220384756e8, 0.618315728e4, 0.252145078e3, 0.432092307e4)),
+        density_entry_09: ((900.0, 0.2410000), (0.100062559e6, 0.175890657e4, 0.724027147e3, 0.916653194e4)),
+        density_entry_10: ((900.0, 0.5261500e2), (0.200000690e8, 0.193510526e4, 0.698445674e3, 0.659070225e4)),
+        density_entry_11: ((900.0, 0.8707690e3), (0.700000006e9, 0.266422350e4, 0.201933608e4, 0.417223802e4)),
+    }
+
+    macro_rules! saturation_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, (pressure, liquid_density, vapor_density)): (f64, (f64, f64, f64)) = $value;
+                let liquid = get_steam_table_entry(SteamQuery::Sat(SatQuery::SatTQuery {
+                    temperature: Temperature::K(K::new(temperature)),
+                    phase_region: SteamNonCriticalPhaseRegion::Liquid,
+                }))
+                .unwrap();
+                let vapor = get_steam_table_entry(SteamQuery::Sat(SatQuery::SatTQuery {
+                    temperature: Temperature::K(K::new(temperature)),
+                    phase_region: SteamNonCriticalPhaseRegion::Vapor,
+                }))
+                .unwrap();
+                assert_approx_eq!(pressure, liquid.pressure.convert_to_si_unit().value, pressure * 1e-8);
+                assert_approx_eq!(pressure, vapor.pressure.convert_to_si_unit().value, pressure * 1e-8);
+                assert_approx_eq!(liquid_density, liquid.density.convert_to_si_unit().value, liquid_density * 1e-8);
+                assert_approx_eq!(vapor_density, vapor.density.convert_to_si_unit().value, vapor_density * 1e-8);
+            }
+        )*
+        }
+    }
+
+    saturation_tests! {
+        saturation_01: (275.0, (0.698451167e3, 0.999887406e3, 0.550664919e-2)),
+        saturation_02: (450.0, (0.932203564e6, 0.890341250e3, 0.481200360e1)),
+        saturation_03: (625.0, (0.169082693e8, 0.567090385e3, 0.118290280e3)),
+    }
+
+    #[test]
+    fn pt_query_inverts_density() {
+        let entry = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            temperature: Temperature::K(K::new(500.0)),
+            pressure: Pressure::Pa(Pa::new(0.100003858e8)),
+        }))
+        .unwrap();
+        assert_approx_eq!(838.025, entry.density.convert_to_si_unit().value, 1e-3);
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+            entry.phase_region
+        );
+    }
+
+    #[test]
+    fn pt_query_at_atmospheric_pressure() {
+        let entry = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            temperature: Temperature::K(K::new(300.0)),
+            pressure: Pressure::Pa(Pa::new(0.992418352e5)),
+        }))
+        .unwrap();
+        assert_approx_eq!(996.556, entry.density.convert_to_si_unit().value, 1e-3);
+        let vapor = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            temperature: Temperature::K(K::new(500.0)),
+            pressure: Pressure::Pa(Pa::new(0.999679423e5)),
+        }))
+        .unwrap();
+        assert_approx_eq!(0.435, vapor.density.convert_to_si_unit().value, 1e-6);
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            vapor.phase_region
+        );
+    }
+
+    macro_rules! pt_query_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, pressure, expected): (f64, f64, Result<f64, OutOfRange>) = $value;
+                let actual = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+                    temperature: Temperature::K(K::new(temperature)),
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                }))
+                .map(|x| x.density.convert_to_si_unit().value);
+                match (expected, actual) {
+                    (Ok(expected), Ok(actual)) => assert_approx_eq!(expected, actual, expected * 1e-6),
+                    (expected, actual) => assert_eq!(expected.map_err(SteamQueryErr::OutOfRange), actual),
+                }
+            }
+        )*
+        }
+    }
+
+    pt_query_tests! {
+        // Table 7 of IAPWS R6-95 at 700 MPa
+        pt_query_compressed_liquid: (300.0, 0.700004704e9, Ok(0.1188202e4)),
+        pt_query_hot_compressed_liquid: (500.0, 0.700000405e9, Ok(0.1084564e4)),
+        pt_query_supercritical: (900.0, 0.700000006e9, Ok(0.8707690e3)),
+        // Liquid between the ice Ih and ice V melting curves and vapor below the sublimation curve
+        pt_query_liquid_below_triple_point: (260.0, 200e6, Ok(1086.2101)),
+        pt_query_vapor_below_triple_point: (260.0, 100.0, Ok(0.83347742e-3)),
+        pt_query_ice_ih: (260.0, 100e6, Err(OutOfRange::TemperatureLow)),
+        pt_query_ice_v: (270.0, 600e6, Err(OutOfRange::TemperatureLow)),
+        pt_query_ice_vi: (300.0, 1000e6, Err(OutOfRange::TemperatureLow)),
+        pt_query_pressure_high: (400.0, 1100e6, Err(OutOfRange::PressureHigh)),
+        pt_query_temperature_high: (1300.0, 100e6, Err(OutOfRange::TemperatureHigh)),
+    }
+
+    #[test]
+    fn sat_p_query_matches_sat_t_query() {
+        let entry = get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+            pressure: Pressure::Pa(Pa::new(0.932203564e6)),
+            phase_region: SteamNonCriticalPhaseRegion::Vapor,
+        }))
+        .unwrap();
+        assert_approx_eq!(450.0, entry.temperature.convert_to_si_unit().value, 1e-6);
+    }
+
+    #[test]
+    fn entropy_p_query_round_trips() {
+        let entry = get_steam_table_entry(SteamQuery::EntropyP {
+            entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(0.659070225e4)),
+            pressure: Pressure::Pa(Pa::new(0.200000690e8)),
+        })
+        .unwrap();
+        assert_approx_eq!(900.0, entry.temperature.convert_to_si_unit().value, 1e-3);
+    }
+}
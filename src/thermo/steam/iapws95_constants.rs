@@ -0,0 +1,405 @@
+// IAPWS R6-95(2018) revised release on the IAPWS formulation 1995 for the thermodynamic
+// properties of ordinary water substance for general and scientific use
+use crate::thermo::helmholtz::*;
+use crate::thermo::steam::water_constants::*;
+
+pub static IDEAL: &[IdealTerm] = &[
+    IdealTerm {
+        n: 0.012436,
+        gamma: 1.28728967,
+    },
+    IdealTerm {
+        n: 0.97315,
+        gamma: 3.53734222,
+    },
+    IdealTerm {
+        n: 1.2795,
+        gamma: 7.74073708,
+    },
+    IdealTerm {
+        n: 0.96956,
+        gamma: 9.24437796,
+    },
+    IdealTerm {
+        n: 0.24873,
+        gamma: 27.5075105,
+    },
+];
+
+// The first seven terms have no exponential so c is zero
+pub static RESIDUAL: &[ResidualTerm] = &[
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: -0.5,
+        n: 0.12533547935523E-01,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 0.875,
+        n: 0.78957634722828E+01,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 1.0,
+        t: 1.0,
+        n: -0.87803203303561E+01,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 0.5,
+        n: 0.31802509345418,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 2.0,
+        t: 0.75,
+        n: -0.26145533859358,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 3.0,
+        t: 0.375,
+        n: -0.78199751687981E-02,
+    },
+    ResidualTerm {
+        c: 0.0,
+        d: 4.0,
+        t: 1.0,
+        n: 0.88089493102134E-02,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 4.0,
+        n: -0.66856572307965,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 6.0,
+        n: 0.20433810950965,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 1.0,
+        t: 12.0,
+        n: -0.66212605039687E-04,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 1.0,
+        n: -0.19232721156002,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 2.0,
+        t: 5.0,
+        n: -0.25709043003438,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 3.0,
+        t: 4.0,
+        n: 0.16074868486251,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 4.0,
+        t: 2.0,
+        n: -0.40092828925870E-01,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 4.0,
+        t: 13.0,
+        n: 0.39343422603254E-06,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 5.0,
+        t: 9.0,
+        n: -0.75941377088144E-05,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 7.0,
+        t: 3.0,
+        n: 0.56250979351888E-03,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 9.0,
+        t: 4.0,
+        n: -0.15608652257135E-04,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 10.0,
+        t: 11.0,
+        n: 0.11537996422951E-08,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 11.0,
+        t: 4.0,
+        n: 0.36582165144204E-06,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 13.0,
+        t: 13.0,
+        n: -0.13251180074668E-11,
+    },
+    ResidualTerm {
+        c: 1.0,
+        d: 15.0,
+        t: 1.0,
+        n: -0.62639586912454E-09,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 1.0,
+        t: 7.0,
+        n: -0.10793600908932,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 1.0,
+        n: 0.17611491008752E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 9.0,
+        n: 0.22132295167546,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 2.0,
+        t: 10.0,
+        n: -0.40247669763528,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 3.0,
+        t: 10.0,
+        n: 0.58083399985759,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 3.0,
+        n: 0.49969146990806E-02,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 7.0,
+        n: -0.31358700712549E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 4.0,
+        t: 10.0,
+        n: -0.74315929710341,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 5.0,
+        t: 10.0,
+        n: 0.47807329915480,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 6.0,
+        t: 6.0,
+        n: 0.20527940895948E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 6.0,
+        t: 10.0,
+        n: -0.13636435110343,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 7.0,
+        t: 10.0,
+        n: 0.14180634400617E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 1.0,
+        n: 0.83326504880713E-02,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 2.0,
+        n: -0.29052336009585E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 3.0,
+        n: 0.38615085574206E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 4.0,
+        n: -0.20393486513704E-01,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 9.0,
+        t: 8.0,
+        n: -0.16554050063734E-02,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 10.0,
+        t: 6.0,
+        n: 0.19955571979541E-02,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 10.0,
+        t: 9.0,
+        n: 0.15870308324157E-03,
+    },
+    ResidualTerm {
+        c: 2.0,
+        d: 12.0,
+        t: 8.0,
+        n: -0.16388568342530E-04,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 3.0,
+        t: 16.0,
+        n: 0.43613615723811E-01,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 4.0,
+        t: 22.0,
+        n: 0.34994005463765E-01,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 4.0,
+        t: 23.0,
+        n: -0.76788197844621E-01,
+    },
+    ResidualTerm {
+        c: 3.0,
+        d: 5.0,
+        t: 23.0,
+        n: 0.22446277332006E-01,
+    },
+    ResidualTerm {
+        c: 4.0,
+        d: 14.0,
+        t: 10.0,
+        n: -0.62689710414685E-04,
+    },
+    ResidualTerm {
+        c: 6.0,
+        d: 3.0,
+        t: 50.0,
+        n: -0.55711118565645E-09,
+    },
+    ResidualTerm {
+        c: 6.0,
+        d: 6.0,
+        t: 44.0,
+        n: -0.19905718354408,
+    },
+    ResidualTerm {
+        c: 6.0,
+        d: 6.0,
+        t: 46.0,
+        n: 0.31777497330738,
+    },
+    ResidualTerm {
+        c: 6.0,
+        d: 6.0,
+        t: 50.0,
+        n: -0.11841182425981,
+    },
+];
+
+pub static GAUSSIAN: &[GaussianTerm] = &[
+    GaussianTerm {
+        d: 3.0,
+        t: 0.0,
+        n: -0.31306260323435E+02,
+        alpha: 20.0,
+        beta: 150.0,
+        gamma: 1.21,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 3.0,
+        t: 1.0,
+        n: 0.31546140237781E+02,
+        alpha: 20.0,
+        beta: 150.0,
+        gamma: 1.21,
+        epsilon: 1.0,
+    },
+    GaussianTerm {
+        d: 3.0,
+        t: 4.0,
+        n: -0.25213154341695E+04,
+        alpha: 20.0,
+        beta: 250.0,
+        gamma: 1.25,
+        epsilon: 1.0,
+    },
+];
+
+pub static NON_ANALYTIC: &[NonAnalyticTerm] = &[
+    NonAnalyticTerm {
+        a: 3.5,
+        b: 0.85,
+        big_b: 0.2,
+        n: -0.14874640856724,
+        big_c: 28.0,
+        big_d: 700.0,
+        big_a: 0.32,
+        beta: 0.3,
+    },
+    NonAnalyticTerm {
+        a: 3.5,
+        b: 0.95,
+        big_b: 0.2,
+        n: 0.31806110878444,
+        big_c: 32.0,
+        big_d: 800.0,
+        big_a: 0.32,
+        beta: 0.3,
+    },
+];
+
+pub static IAPWS95: HelmholtzEos = HelmholtzEos {
+    // IAPWS-95 uses a slightly different specific gas constant than IAPWS-IF97
+    specific_gas_constant: 461.51805,
+    reducing_temperature: CRITICAL_TEMPERATURE.value,
+    reducing_density: CRITICAL_DENSITY.value,
+    ideal_n1: -8.3204464837497,
+    ideal_n2: 6.6832105275932,
+    ideal_n3: 3.00632,
+    ideal_power: &[],
+    ideal: IDEAL,
+    residual: RESIDUAL,
+    gaussian: GAUSSIAN,
+    non_analytic: NON_ANALYTIC,
+};
@@ -1,16 +1,24 @@
 // https://github.com/jfkonecn/thermo/blob/feature/issue-42/thermo/steam_properties.py
 use super::*;
-use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::root_finders::{newton_method, secant_method};
+use crate::thermo::steam::iapws95::get_vapor_entry_below_triple_point;
 use crate::thermo::steam::iapws97_constants::{IjnRegionPoint, JnRegionPoint};
+use crate::thermo::steam::iapws97_region3_constants::*;
+use crate::thermo::steam::ice::*;
 use crate::thermo::steam::water_constants::*;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 enum Iapws97Region {
     Region1,
     Region2,
+    // Region 2 metastable-vapor equation for subcooled vapor
+    Region2Metastable,
     Region3,
     Region4,
     Region5,
+    Ice,
+    // Vapor below the lower temperature limit of region 2, evaluated with IAPWS-95
+    LowTemperatureVapor,
 }
 
 #[derive(Debug)]
@@ -81,9 +89,10 @@ fn get_sat_temperature(pressure: Pa) -> Result<K, OutOfRange> {
     }
 }
 
+// The B23 boundary between regions 2 and 3, which starts at 623.15 K on the saturation line
 fn get_boundary_34_pressure(temperature: K) -> Result<Pa, OutOfRange> {
     match temperature.value {
-        t if t < CRITICAL_TEMPERATURE.value => Err(OutOfRange::BelowCriticalTemperature),
+        t if t <= 623.15 => Err(OutOfRange::TemperatureLow),
         t => {
             let theta = t / 1.0;
             let pressure = (iapws97_constants::BOUNDARY_34[0].n
@@ -95,52 +104,30 @@ fn get_boundary_34_pressure(temperature: K) -> Result<Pa, OutOfRange> {
     }
 }
 
-fn extract_pressure(query: &SteamQuery) -> Option<Pressure> {
-    match query {
-        SteamQuery::Pt(PtPoint {
-            pressure: p,
-            temperature: _,
-        })
-        | SteamQuery::Sat(SatQuery::SatPQuery {
-            pressure: p,
-            phase_region: _,
-        })
-        | SteamQuery::EntropyP {
-            entropy: _,
-            pressure: p,
-        }
-        | SteamQuery::EnthalpyP {
-            enthalpy: _,
-            pressure: p,
-        } => Some(*p),
-        _ => None,
-    }
-}
+static VALIDITY_RANGE: ValidityRange = ValidityRange {
+    max_temperature: 2273.15,
+    max_pressure: 100e6,
+    high_pressure_max_temperature: Some((50e6, 1073.15)),
+    stop_at_melting_line: false,
+};
 
-fn extract_temperature(query: &SteamQuery) -> Option<Temperature> {
-    match query {
-        SteamQuery::Pt(PtPoint {
-            pressure: _,
-            temperature: t,
-        })
-        | SteamQuery::Sat(SatQuery::SatTQuery {
-            temperature: t,
-            phase_region: _,
-        }) => Some(*t),
-        _ => None,
-    }
-}
+// Region 1 and 2 equations carried to any pressure
+static EXTRAPOLATED_RANGE: ValidityRange = ValidityRange {
+    max_temperature: 2273.15,
+    max_pressure: f64::INFINITY,
+    high_pressure_max_temperature: None,
+    stop_at_melting_line: false,
+};
 
-fn check_if_out_of_range(query: &SteamQuery) -> Result<(), OutOfRange> {
-    let opt_p = extract_pressure(query).map(|x| x.convert_to_si_unit().value);
-    let opt_t = extract_temperature(query).map(|x| x.convert_to_si_unit().value);
-    match (opt_p, opt_t) {
-        (_, Some(t)) if t < 273.15 => Err(OutOfRange::TemperatureLow),
-        (_, Some(t)) if t > 2000.0 + 273.15 => Err(OutOfRange::TemperatureHigh),
-        (Some(p), Some(t)) if p > 50e6 && t > 800.0 + 273.15 => Err(OutOfRange::TemperatureHigh),
-        (Some(p), _) if p < 0.0 => Err(OutOfRange::PressureLow),
-        (Some(p), _) if p > 100e6 => Err(OutOfRange::PressureHigh),
-        _ => Ok(()),
+fn check_if_out_of_range(
+    query: &SteamQuery,
+    extrapolate: bool,
+) -> Result<Option<SteamWarning>, OutOfRange> {
+    match super::check_if_out_of_range(query, &VALIDITY_RANGE) {
+        Ok(()) => Ok(None),
+        Err(_) if extrapolate => super::check_if_out_of_range(query, &EXTRAPOLATED_RANGE)
+            .map(|_| Some(SteamWarning::PressureExtrapolated)),
+        Err(err) => Err(err),
     }
 }
 
@@ -151,48 +138,144 @@ fn get_region_from_pt_point(pt_point: &PtPoint) -> Result<Iapws97Region, OutOfRa
     let opt_boundary_result = get_boundary_34_pressure(t).map(|x| x.value);
     let t = t.value;
     match (opt_sat_p_result, opt_boundary_result) {
+        (_, _) if t < 273.15 => get_region_below_melting_point(t, p),
         (_, _) if t > 273.15 + 800.0 => Ok(Iapws97Region::Region5),
         (_, _) if t > 273.15 + 600.0 => Ok(Iapws97Region::Region2),
         (Ok(sat_p), _) if p == sat_p => Ok(Iapws97Region::Region4),
+        // Region 3 also covers the liquid and vapor above the boundary below the critical point
+        (_, Ok(boundary)) if p > boundary => Ok(Iapws97Region::Region3),
         (Ok(sat_p), _) if p < sat_p => Ok(Iapws97Region::Region2),
         (Ok(_), _) => Ok(Iapws97Region::Region1),
-        (_, Ok(boundary)) if p < boundary => Ok(Iapws97Region::Region2),
-        (_, Ok(_)) => Ok(Iapws97Region::Region3),
+        (_, Ok(_)) => Ok(Iapws97Region::Region2),
         (Err(err), _) => Err(err),
     }
 }
 
-fn get_region_from_sat_query(sat_query: &SatQuery) -> Result<(PtPoint, Iapws97Region), OutOfRange> {
-    let region = match sat_query {
-        SatQuery::SatTQuery {
-            temperature: _,
-            phase_region: r,
+// Below the lower temperature limit of region 1 water is either ice or vapor
+fn get_region_below_melting_point(
+    temperature: f64,
+    pressure: f64,
+) -> Result<Iapws97Region, OutOfRange> {
+    let temperature = Temperature::K(K::new(temperature));
+    let sublimation_pressure = match get_sublimation_pressure(temperature) {
+        Ok(p) => p.convert_to_si_unit().value,
+        // Ice is the only stable phase this cold
+        Err(OutOfRange::TemperatureLow) => 0f64,
+        Err(err) => return Err(err),
+    };
+    // The ice Ih melting curve ends at the ice III triple point
+    let melting_pressure = get_melting_pressure(temperature)
+        .map(|x| x.convert_to_si_unit().value)
+        .unwrap_or(MAX_ICE_PRESSURE.value);
+    match pressure {
+        p if p < sublimation_pressure => Ok(Iapws97Region::LowTemperatureVapor),
+        p if p > MAX_ICE_PRESSURE.value => Err(OutOfRange::PressureHigh),
+        p if p <= melting_pressure => Ok(Iapws97Region::Ice),
+        // Liquid below the lower temperature limit of region 1
+        _ => Err(OutOfRange::TemperatureLow),
+    }
+}
+
+// Swaps the stable region for the requested metastable one when the point is past the saturation line
+fn get_region_with_metastable_phase(
+    pt_point: &PtPoint,
+    metastable_phase: Option<SteamNonCriticalPhaseRegion>,
+) -> Result<(Iapws97Region, Option<SteamWarning>), OutOfRange> {
+    let t = pt_point.temperature.convert_to_si_unit().value;
+    let p = pt_point.pressure.convert_to_si_unit().value;
+    get_region_from_pt_point(pt_point).and_then(|region| match (metastable_phase, region) {
+        (Some(SteamNonCriticalPhaseRegion::Vapor), Iapws97Region::Region1) if p > 10e6 => {
+            Err(OutOfRange::PressureHigh)
         }
-        | SatQuery::SatPQuery {
-            pressure: _,
-            phase_region: r,
-        } => match *r {
-            SteamNonCriticalPhaseRegion::Liquid => Iapws97Region::Region1,
-            SteamNonCriticalPhaseRegion::Vapor => Iapws97Region::Region2,
-        },
+        (Some(SteamNonCriticalPhaseRegion::Vapor), Iapws97Region::Region1) => Ok((
+            Iapws97Region::Region2Metastable,
+            Some(SteamWarning::MetastableVapor),
+        )),
+        (Some(SteamNonCriticalPhaseRegion::Liquid), Iapws97Region::Region2) if t > 623.15 => {
+            Err(OutOfRange::TemperatureHigh)
+        }
+        // Region 3 has no metastable extension past the saturation line
+        (Some(phase), Iapws97Region::Region3) if t <= CRITICAL_TEMPERATURE.value => {
+            let sat_p = get_sat_pressure(K::new(t))?.value;
+            match phase {
+                SteamNonCriticalPhaseRegion::Liquid if p < sat_p => {
+                    Err(OutOfRange::TemperatureHigh)
+                }
+                SteamNonCriticalPhaseRegion::Vapor if p > sat_p => Err(OutOfRange::PressureHigh),
+                _ => Ok((Iapws97Region::Region3, None)),
+            }
+        }
+        (Some(SteamNonCriticalPhaseRegion::Liquid), Iapws97Region::Region2) => {
+            Ok((Iapws97Region::Region1, Some(SteamWarning::MetastableLiquid)))
+        }
+        (_, region) => Ok((region, None)),
+    })
+}
+
+fn get_region_from_sat_query(
+    sat_query: &SatQuery,
+) -> Result<(PtPoint, Iapws97Region), SteamQueryErr> {
+    let f = SteamQueryErr::OutOfRange;
+    let fluid_region = |t: f64, phase_region| match phase_region {
+        SteamNonCriticalPhaseRegion::Liquid => Iapws97Region::Region1,
+        SteamNonCriticalPhaseRegion::Vapor if t < 273.15 => Iapws97Region::LowTemperatureVapor,
+        SteamNonCriticalPhaseRegion::Vapor => Iapws97Region::Region2,
+        SteamNonCriticalPhaseRegion::Solid => Iapws97Region::Ice,
     };
-    let pt_result = match sat_query {
+    match *sat_query {
         SatQuery::SatTQuery {
-            temperature: t,
-            phase_region: _,
-        } => get_sat_pressure((*t).convert_to_si_unit()).map(|p| PtPoint {
-            pressure: Pressure::Pa(p),
-            temperature: *t,
-        }),
+            temperature,
+            phase_region,
+        } => {
+            let t = temperature.convert_to_si_unit().value;
+            let pressure = match phase_region {
+                SteamNonCriticalPhaseRegion::Liquid if t < 273.15 => {
+                    Err(OutOfRange::TemperatureLow)
+                }
+                SteamNonCriticalPhaseRegion::Solid => get_sublimation_pressure(temperature),
+                SteamNonCriticalPhaseRegion::Vapor if t < 273.15 => {
+                    get_sublimation_pressure(temperature)
+                }
+                _ => get_sat_pressure(temperature.convert_to_si_unit()).map(Pressure::Pa),
+            }
+            .map_err(f)?;
+            Ok((
+                PtPoint {
+                    pressure,
+                    temperature,
+                },
+                fluid_region(t, phase_region),
+            ))
+        }
         SatQuery::SatPQuery {
-            pressure: p,
-            phase_region: _,
-        } => get_sat_temperature((*p).convert_to_si_unit()).map(|t| PtPoint {
-            pressure: *p,
-            temperature: Temperature::K(t),
-        }),
-    };
-    pt_result.map(|pt| (pt, region))
+            pressure,
+            phase_region,
+        } => {
+            let below_triple_point =
+                pressure.convert_to_si_unit().value < TRIPLE_POINT_PRESSURE.value;
+            let temperature = match phase_region {
+                SteamNonCriticalPhaseRegion::Liquid if below_triple_point => {
+                    Err(f(OutOfRange::PressureLow))
+                }
+                SteamNonCriticalPhaseRegion::Solid | SteamNonCriticalPhaseRegion::Vapor
+                    if below_triple_point =>
+                {
+                    get_sublimation_temperature(pressure)
+                }
+                SteamNonCriticalPhaseRegion::Solid => get_melting_temperature(pressure),
+                _ => get_sat_temperature(pressure.convert_to_si_unit())
+                    .map(Temperature::K)
+                    .map_err(f),
+            }?;
+            Ok((
+                PtPoint {
+                    pressure,
+                    temperature,
+                },
+                fluid_region(temperature.convert_to_si_unit().value, phase_region),
+            ))
+        }
+    }
 }
 
 fn create_entry_from_region_point(
@@ -234,6 +317,12 @@ fn create_entry_from_region_point(
                     - gamma_pi_pi)),
     );
     let specific_volume = pi * (gamma_pi * GAS_CONSTANT.value * temperature) / pressure;
+    let gibbs_free_energy = GAS_CONSTANT.value * temperature * gamma;
+    let helmholtz_free_energy = gibbs_free_energy - pressure * specific_volume;
+    let isobaric_expansion = (1f64 - tau * gamma_pi_tau / gamma_pi) / temperature;
+    let isothermal_compressibility = -pi * gamma_pi_pi / gamma_pi / pressure;
+    let joule_thomson = specific_volume * (temperature * isobaric_expansion - 1f64) / cp;
+    let isentropic_exponent = f64::powi(speed_of_sound, 2) / (pressure * specific_volume);
     PtvEntry {
         temperature: Temperature::K(K::new(temperature)),
         pressure: Pressure::Pa(Pa::new(pressure)),
@@ -245,94 +334,155 @@ fn create_entry_from_region_point(
         cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
         speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
         specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(gibbs_free_energy)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(isobaric_expansion)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(isothermal_compressibility)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(joule_thomson)),
+        isentropic_exponent,
     }
 }
 
-fn gibbs_method(point: &PtPoint) -> PtvEntry {
-    let pressure = point.pressure.convert_to_si_unit().value;
-    let temperature = point.temperature.convert_to_si_unit().value;
-    let pi = pressure / 16.53e6;
+// Temperature dependent part of the Gibbs free energy equation of region 1, 2 or 5.
+// Every pressure at the same temperature shares it, so a batch only builds it once per temperature.
+struct TauTerms {
+    // Region 1 is written in 7.1 - pi with its own reducing pressure and has no ideal gas part
+    liquid: bool,
+    tau: f64,
+    // Ideal gas part and its first two tau derivatives
+    ideal: [f64; 3],
+    residual_points: &'static [IjnRegionPoint],
+    // (tau - shift)^j, ^(j - 1) and ^(j - 2) of each residual term
+    residual: Vec<[f64; 3]>,
+}
+
+fn get_power_terms(base: f64, exponent: f64) -> [f64; 3] {
+    [
+        f64::powf(base, exponent),
+        f64::powf(base, exponent - 1f64),
+        f64::powf(base, exponent - 2f64),
+    ]
+}
+
+fn get_region1_tau_terms(temperature: f64) -> TauTerms {
     let tau = 1386.0 / temperature;
-    let mut gamma = 0f64;
-    let mut gamma_pi = 0f64;
-    let mut gamma_pi_pi = 0f64;
-    let mut gamma_tau = 0f64;
-    let mut gamma_tau_tau = 0f64;
-    let mut gamma_pi_tau = 0f64;
-    let phase_info = PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid);
-    for region_point in iapws97_constants::REGION_1_AND_4.iter() {
+    let residual_points = iapws97_constants::REGION_1_AND_4;
+    TauTerms {
+        liquid: true,
+        tau,
+        ideal: [0f64; 3],
+        residual_points,
+        residual: residual_points
+            .iter()
+            .map(|x| get_power_terms(tau - 1.222, x.j))
+            .collect(),
+    }
+}
+
+fn get_vapor_tau_terms(
+    tau: f64,
+    tau_shift: f64,
+    ideal_points: &[JnRegionPoint],
+    residual_points: &'static [IjnRegionPoint],
+) -> TauTerms {
+    let mut ideal = [0f64; 3];
+    for region_point in ideal_points.iter() {
         let n = region_point.n;
-        let i = region_point.i;
         let j = region_point.j;
-        gamma += n * f64::powf(7.1 - pi, i) * f64::powf(tau - 1.222, j);
-        gamma_pi += -n * i * f64::powf(7.1 - pi, i - 1f64) * f64::powf(tau - 1.222, j);
-        gamma_pi_pi +=
-            n * i * (i - 1f64) * f64::powf(7.1 - pi, i - 2f64) * f64::powf(tau - 1.222, j);
-        gamma_tau += n * j * f64::powf(7.1 - pi, i) * f64::powf(tau - 1.222, j - 1f64);
-        gamma_tau_tau +=
-            n * j * (j - 1f64) * f64::powf(7.1 - pi, i) * f64::powf(tau - 1.222, j - 2f64);
-        gamma_pi_tau +=
-            -n * i * j * f64::powf(7.1 - pi, i - 1f64) * f64::powf(tau - 1.222, j - 1f64);
+        ideal[0] += n * f64::powf(tau, j);
+        ideal[1] += n * j * f64::powf(tau, j - 1f64);
+        ideal[2] += n * j * (j - 1f64) * f64::powf(tau, j - 2f64);
     }
-
-    let specific_region_point = SpecificRegionPoint {
-        point: *point,
+    TauTerms {
+        liquid: false,
         tau,
-        pi,
-        gamma,
-        gamma_pi,
-        gamma_pi_pi,
-        gamma_tau,
-        gamma_tau_tau,
-        gamma_pi_tau,
-    };
+        ideal,
+        residual_points,
+        residual: residual_points
+            .iter()
+            .map(|x| get_power_terms(tau - tau_shift, x.j))
+            .collect(),
+    }
+}
 
-    create_entry_from_region_point(specific_region_point, phase_info)
+// None for the regions without a Gibbs free energy equation in pressure and temperature
+fn get_tau_terms(region: Iapws97Region, temperature: f64) -> Option<TauTerms> {
+    match region {
+        Iapws97Region::Region1 | Iapws97Region::Region4 => Some(get_region1_tau_terms(temperature)),
+        Iapws97Region::Region2 => Some(get_vapor_tau_terms(
+            540f64 / temperature,
+            0.5,
+            iapws97_constants::REGION_2_IDEAL,
+            iapws97_constants::REGION_2_RESIDUAL,
+        )),
+        Iapws97Region::Region2Metastable => Some(get_vapor_tau_terms(
+            540f64 / temperature,
+            0.5,
+            iapws97_constants::REGION_2_METASTABLE_IDEAL,
+            iapws97_constants::REGION_2_METASTABLE_RESIDUAL,
+        )),
+        Iapws97Region::Region5 => Some(get_vapor_tau_terms(
+            1000f64 / temperature,
+            0f64,
+            iapws97_constants::REGION_5_IDEAL,
+            iapws97_constants::REGION_5_RESIDUAL,
+        )),
+        Iapws97Region::Region3 | Iapws97Region::Ice | Iapws97Region::LowTemperatureVapor => None,
+    }
 }
 
-fn vapor_method(
-    tau: f64,
-    tau_shift: f64,
-    point: &PtPoint,
-    ideal_points: &[JnRegionPoint],
-    residual_points: &[IjnRegionPoint],
-) -> PtvEntry {
+fn get_entry_from_tau_terms(point: &PtPoint, tau_terms: &TauTerms) -> PtvEntry {
     let pressure = point.pressure.convert_to_si_unit().value;
     let temperature = point.temperature.convert_to_si_unit().value;
-    let pi = pressure / 1.0e6;
-    let mut gamma = f64::ln(pi);
-    let mut gamma_pi = 1.0 / pi;
-    let mut gamma_pi_pi = -1.0 / f64::powi(pi, 2);
-    let mut gamma_tau = 0f64;
-    let mut gamma_tau_tau = 0f64;
+    let tau = tau_terms.tau;
+    let (pi, pi_base, pi_sign) = match tau_terms.liquid {
+        true => {
+            let pi = pressure / 16.53e6;
+            (pi, 7.1 - pi, -1f64)
+        }
+        false => {
+            let pi = pressure / 1.0e6;
+            (pi, pi, 1f64)
+        }
+    };
+    let (mut gamma, mut gamma_pi, mut gamma_pi_pi) = match tau_terms.liquid {
+        true => (0f64, 0f64, 0f64),
+        false => (
+            f64::ln(pi) + tau_terms.ideal[0],
+            1.0 / pi,
+            -1.0 / f64::powi(pi, 2),
+        ),
+    };
+    let mut gamma_tau = tau_terms.ideal[1];
+    let mut gamma_tau_tau = tau_terms.ideal[2];
     let mut gamma_pi_tau = 0f64;
     let phase_info = match (
+        tau_terms.liquid,
         temperature > CRITICAL_TEMPERATURE.value,
         pressure > CRITICAL_PRESSURE.value,
     ) {
-        (true, true) => PhaseRegion::SupercriticalFluid,
-        (true, false) => PhaseRegion::Gas,
+        (true, _, _) => PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+        (false, true, true) => PhaseRegion::SupercriticalFluid,
+        (false, true, false) => PhaseRegion::Gas,
         _ => PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
     };
 
-    for region_point in ideal_points.iter() {
-        let n = region_point.n;
-        let j = region_point.j;
-        gamma += n * f64::powf(tau, j);
-        gamma_tau += n * j * f64::powf(tau, j - 1f64);
-        gamma_tau_tau += n * j * (j - 1f64) * f64::powf(tau, j - 2f64);
-    }
-    for region_point in residual_points.iter() {
+    for (region_point, tau_powers) in tau_terms
+        .residual_points
+        .iter()
+        .zip(tau_terms.residual.iter())
+    {
         let n = region_point.n;
         let i = region_point.i;
         let j = region_point.j;
-        gamma += n * f64::powf(pi, i) * f64::powf(tau - tau_shift, j);
-        gamma_pi += n * i * f64::powf(pi, i - 1f64) * f64::powf(tau - tau_shift, j);
-        gamma_pi_pi += n * i * (i - 1f64) * f64::powf(pi, i - 2f64) * f64::powf(tau - tau_shift, j);
-        gamma_tau += n * f64::powf(pi, i) * j * f64::powf(tau - tau_shift, j - 1f64);
-        gamma_tau_tau +=
-            n * f64::powf(pi, i) * j * (j - 1f64) * f64::powf(tau - tau_shift, j - 2f64);
-        gamma_pi_tau += n * i * f64::powf(pi, i - 1f64) * j * f64::powf(tau - tau_shift, j - 1f64);
+        let pi_powers = get_power_terms(pi_base, i);
+        gamma += n * pi_powers[0] * tau_powers[0];
+        gamma_pi += pi_sign * n * i * pi_powers[1] * tau_powers[0];
+        gamma_pi_pi += n * i * (i - 1f64) * pi_powers[2] * tau_powers[0];
+        gamma_tau += n * j * pi_powers[0] * tau_powers[1];
+        gamma_tau_tau += n * j * (j - 1f64) * pi_powers[0] * tau_powers[2];
+        gamma_pi_tau += pi_sign * n * i * j * pi_powers[1] * tau_powers[1];
     }
 
     let specific_region_point = SpecificRegionPoint {
@@ -350,6 +500,11 @@ fn vapor_method(
     create_entry_from_region_point(specific_region_point, phase_info)
 }
 
+fn gibbs_method(point: &PtPoint) -> PtvEntry {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    get_entry_from_tau_terms(point, &get_region1_tau_terms(temperature))
+}
+
 fn region3_by_specific_volume(pt_point: &PtPoint, specific_volume: f64) -> PtvEntry {
     let density = 1f64 / specific_volume;
     let n1 = iapws97_constants::REGION_3_N1.n;
@@ -393,10 +548,30 @@ fn region3_by_specific_volume(pt_point: &PtPoint, specific_volume: f64) -> PtvEn
             * GAS_CONSTANT.value
             * temperature,
     );
+    let gibbs_free_energy = (phi + delta * phi_delta) * GAS_CONSTANT.value * temperature;
+    let helmholtz_free_energy = phi * GAS_CONSTANT.value * temperature;
+    let isobaric_expansion = (phi_delta - tau * phi_delta_tau)
+        / (2f64 * phi_delta + delta * phi_delta_delta)
+        / temperature;
+    let isothermal_compressibility = 1f64
+        / (density
+            * GAS_CONSTANT.value
+            * temperature
+            * delta
+            * (2f64 * phi_delta + delta * phi_delta_delta));
+    let joule_thomson = specific_volume * (temperature * isobaric_expansion - 1f64) / cp;
+    let isentropic_exponent = f64::powi(speed_of_sound, 2) / (pressure * specific_volume);
+    let phase_region = match temperature {
+        t if t >= CRITICAL_TEMPERATURE.value => PhaseRegion::SupercriticalFluid,
+        _ if density > CRITICAL_DENSITY.value => {
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid)
+        }
+        _ => PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+    };
     PtvEntry {
         temperature: Temperature::K(K::new(temperature)),
         pressure: Pressure::Pa(Pa::new(pressure)),
-        phase_region: PhaseRegion::SupercriticalFluid,
+        phase_region,
         internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
         enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
         entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
@@ -404,157 +579,583 @@ fn region3_by_specific_volume(pt_point: &PtPoint, specific_volume: f64) -> PtvEn
         cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
         speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
         specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(density)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(gibbs_free_energy)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(isobaric_expansion)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(isothermal_compressibility)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(joule_thomson)),
+        isentropic_exponent,
+    }
+}
+
+fn get_region3_boundary_temperature(pi: f64, boundary_points: &[InRegionPoint]) -> f64 {
+    boundary_points
+        .iter()
+        .map(|x| x.n * f64::powf(pi, x.i))
+        .sum()
+}
+
+fn get_region3_log_boundary_temperature(pi: f64, boundary_points: &[InRegionPoint]) -> f64 {
+    boundary_points
+        .iter()
+        .map(|x| x.n * f64::powf(f64::ln(pi), x.i))
+        .sum()
+}
+
+// Sub-region of the SR5-05 backward equations containing a region 3 point.
+// Close to the critical point, between the 3qu and 3rx boundaries below 22.5 MPa,
+// the auxiliary sub-regions 3u to 3z are used.
+fn get_region3_subregion(point: &PtPoint) -> &'static Region3Subregion {
+    let pi = point.pressure.convert_to_si_unit().value / 1e6;
+    let t = point.temperature.convert_to_si_unit().value;
+    let t_ab = get_region3_log_boundary_temperature(pi, BOUNDARY_3AB);
+    let t_cd = get_region3_boundary_temperature(pi, BOUNDARY_3CD);
+    let t_ef = 3.727888004 * (pi - 22.064) + 647.096;
+    let t_gh = get_region3_boundary_temperature(pi, BOUNDARY_3GH);
+    let t_ij = get_region3_boundary_temperature(pi, BOUNDARY_3IJ);
+    let t_jk = get_region3_boundary_temperature(pi, BOUNDARY_3JK);
+    let t_mn = get_region3_boundary_temperature(pi, BOUNDARY_3MN);
+    let t_op = get_region3_log_boundary_temperature(pi, BOUNDARY_3OP);
+    let t_qu = get_region3_boundary_temperature(pi, BOUNDARY_3QU);
+    let t_rx = get_region3_boundary_temperature(pi, BOUNDARY_3RX);
+    let t_uv = get_region3_boundary_temperature(pi, BOUNDARY_3UV);
+    let t_wx = get_region3_log_boundary_temperature(pi, BOUNDARY_3WX);
+    let get_t_sat = || {
+        get_sat_temperature(Pa::new(pi * 1e6))
+            .map(|x| x.value)
+            .unwrap_or(f64::NAN)
+    };
+    // get_sat_temperature stops at CRITICAL_PRESSURE, below the 22.064 MPa used by SR5-05,
+    // so the side of the saturation line is found from the pressure instead
+    let is_above_sat_temperature = || {
+        get_sat_pressure(K::new(t))
+            .map(|x| pi * 1e6 < x.value)
+            .unwrap_or(true)
+    };
+    let p_sat_643 = get_sat_pressure(K::new(643.15))
+        .map(|x| x.value / 1e6)
+        .unwrap_or(f64::NAN);
+    match pi {
+        pi if pi > 40.0 => match t {
+            t if t <= t_ab => REGION_3A,
+            _ => REGION_3B,
+        },
+        pi if pi > 25.0 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= t_ab => REGION_3D,
+            t if t <= t_ef => REGION_3E,
+            _ => REGION_3F,
+        },
+        pi if pi > 23.5 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= t_gh => REGION_3G,
+            t if t <= t_ef => REGION_3H,
+            t if t <= t_ij => REGION_3I,
+            t if t <= t_jk => REGION_3J,
+            _ => REGION_3K,
+        },
+        pi if pi > 23.0 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= t_gh => REGION_3L,
+            t if t <= t_ef => REGION_3H,
+            t if t <= t_ij => REGION_3I,
+            t if t <= t_jk => REGION_3J,
+            _ => REGION_3K,
+        },
+        pi if pi > 22.5 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= t_gh => REGION_3L,
+            t if t <= t_mn => REGION_3M,
+            t if t <= t_ef => REGION_3N,
+            t if t <= t_op => REGION_3O,
+            t if t <= t_ij => REGION_3P,
+            t if t <= t_jk => REGION_3J,
+            _ => REGION_3K,
+        },
+        pi if pi > p_sat_643 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= t_qu => REGION_3Q,
+            t if t <= t_rx => match pi {
+                pi if pi > 22.11 => match t {
+                    t if t <= t_uv => REGION_3U,
+                    t if t <= t_ef => REGION_3V,
+                    t if t <= t_wx => REGION_3W,
+                    _ => REGION_3X,
+                },
+                pi if pi > 22.064 => match t {
+                    t if t <= t_uv => REGION_3U,
+                    t if t <= t_ef => REGION_3Y,
+                    t if t <= t_wx => REGION_3Z,
+                    _ => REGION_3X,
+                },
+                _ if is_above_sat_temperature() => match t {
+                    t if pi > 21.90096265 && t <= t_wx => REGION_3Z,
+                    _ => REGION_3X,
+                },
+                _ => match t {
+                    t if pi > 21.93161551 && t > t_uv => REGION_3Y,
+                    _ => REGION_3U,
+                },
+            },
+            t if t <= t_jk => REGION_3R,
+            _ => REGION_3K,
+        },
+        pi if pi > 20.5 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= get_t_sat() => REGION_3S,
+            t if t <= t_jk => REGION_3R,
+            _ => REGION_3K,
+        },
+        pi if pi > 19.00881189173929 => match t {
+            t if t <= t_cd => REGION_3C,
+            t if t <= get_t_sat() => REGION_3S,
+            _ => REGION_3T,
+        },
+        _ => match t {
+            t if t <= get_t_sat() => REGION_3C,
+            _ => REGION_3T,
+        },
+    }
+}
+
+fn get_region3_backward_specific_volume(point: &PtPoint, subregion: &Region3Subregion) -> f64 {
+    let pi = point.pressure.convert_to_si_unit().value / (subregion.p_star * 1e6);
+    let theta = point.temperature.convert_to_si_unit().value / subregion.t_star;
+    let sum: f64 = subregion
+        .points
+        .iter()
+        .map(|x| {
+            x.n * f64::powf(pi - subregion.a, subregion.c * x.i)
+                * f64::powf(theta - subregion.b, subregion.d * x.j)
+        })
+        .sum();
+    if subregion.exponential {
+        f64::exp(sum) * subregion.v_star
+    } else {
+        f64::powf(sum, subregion.e) * subregion.v_star
     }
 }
 
+// Region 3 is explicit in density so the backward equation volume is polished with
+// Newton's method until the basic equation reproduces the pressure
 fn region3_method(point: &PtPoint) -> Result<PtvEntry, SteamQueryErr> {
+    let pressure = point.pressure.convert_to_si_unit().value;
+    let initial_volume = get_region3_backward_specific_volume(point, get_region3_subregion(point));
     let f = |x| {
         let entry = region3_by_specific_volume(point, x);
-        entry.pressure.convert_to_si_unit().value - point.pressure.convert_to_si_unit().value
+        entry.pressure.convert_to_si_unit().value / pressure - 1f64
+    };
+    let df = |x| {
+        let entry = region3_by_specific_volume(point, x);
+        let compressibility = entry.isothermal_compressibility.convert_to_si_unit().value;
+        -1f64 / (x * compressibility * pressure)
     };
-    secant_method(f, 1f64 / 500f64, 1e-4)
+    newton_method(f, df, initial_volume, 1e-10)
         .map(|x| region3_by_specific_volume(point, x))
         .map_err(SteamQueryErr::FailedToConverge)
 }
 
+// Evaluates the basic equation at the backward equation volume without polishing it,
+// so the pressure of the entry differs slightly from the requested pressure
+fn region3_backward_method(point: &PtPoint) -> Result<PtvEntry, SteamQueryErr> {
+    let subregion = get_region3_subregion(point);
+    Ok(region3_by_specific_volume(
+        point,
+        get_region3_backward_specific_volume(point, subregion),
+    ))
+}
+
 fn get_entry_from_pt_point(
     point: &PtPoint,
     region: Iapws97Region,
 ) -> Result<PtvEntry, SteamQueryErr> {
     let temperature = point.temperature.convert_to_si_unit().value;
-    match region {
-        Iapws97Region::Region1 | Iapws97Region::Region4 => Ok(gibbs_method(point)),
-        Iapws97Region::Region2 => Ok(vapor_method(
-            540f64 / temperature,
-            0.5,
-            point,
-            iapws97_constants::REGION_2_IDEAL,
-            iapws97_constants::REGION_2_RESIDUAL,
-        )),
-        Iapws97Region::Region3 => region3_method(point),
-        Iapws97Region::Region5 => Ok(vapor_method(
-            1000f64 / temperature,
-            0f64,
-            point,
-            iapws97_constants::REGION_5_IDEAL,
-            iapws97_constants::REGION_5_RESIDUAL,
-        )),
+    match get_tau_terms(region, temperature) {
+        Some(tau_terms) => Ok(get_entry_from_tau_terms(point, &tau_terms)),
+        None => match region {
+            Iapws97Region::Region3 => region3_method(point),
+            Iapws97Region::Ice => get_ice_entry(point),
+            _ => get_vapor_entry_below_triple_point(point),
+        },
     }
 }
 
-fn interpolate_entry(
-    liquid_entry: &PtvEntry,
-    vapor_entry: &PtvEntry,
-    liq_frac: f64,
-) -> Result<PtvEntry, SteamQueryErr> {
-    let vap_frac = 1.0 - liq_frac;
-    let interpolate_entry_property =
-        |f: fn(e: &PtvEntry) -> f64| (f(liquid_entry) * liq_frac) + (f(vapor_entry) * vap_frac);
-    let phase_info_result = LiquidVapor::new(liq_frac, vap_frac)
-        .map(|x| PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)))
-        .map_err(SteamQueryErr::CompositePhaseRegionErr);
-    let temperature = interpolate_entry_property(|x| x.temperature.convert_to_si_unit().value);
-    let pressure = interpolate_entry_property(|x| x.pressure.convert_to_si_unit().value);
-    let internal_energy =
-        interpolate_entry_property(|x| x.internal_energy.convert_to_si_unit().value);
-    let enthalpy = interpolate_entry_property(|x| x.enthalpy.convert_to_si_unit().value);
-    let entropy = interpolate_entry_property(|x| x.entropy.convert_to_si_unit().value);
-    let cv = interpolate_entry_property(|x| x.cv.convert_to_si_unit().value);
-    let cp = interpolate_entry_property(|x| x.cp.convert_to_si_unit().value);
-    let speed_of_sound =
-        interpolate_entry_property(|x| x.speed_of_sound.convert_to_si_unit().value);
-    let specific_volume =
-        1f64 / interpolate_entry_property(|x| 1f64 / x.specific_volume.convert_to_si_unit().value);
-    phase_info_result.map(|phase_region| PtvEntry {
-        temperature: Temperature::K(K::new(temperature)),
-        pressure: Pressure::Pa(Pa::new(pressure)),
-        phase_region,
-        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
-        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
-        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
-        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv)),
-        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
-        speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
-        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
-    })
+/// IAPWS-IF97 industrial formulation
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Iapws97;
+
+impl SteamProperties for Iapws97 {
+    fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+        get_steam_table_entry(query)
+    }
+
+    fn get_steam_table_entries(
+        &self,
+        queries: &[SteamQuery],
+    ) -> Vec<Result<PtvEntry, SteamQueryErr>> {
+        get_steam_table_entries(queries)
+    }
 }
 
-fn iterate_pt_entry_solution(
+fn iterate_entry_solution(
     pressure: Pa,
     target_value: f64,
     get_prop_value: fn(entry: &PtvEntry) -> f64,
+    extrapolate: bool,
 ) -> Result<PtvEntry, SteamQueryErr> {
-    let liquid_entry_result = get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
-        pressure: Pressure::Pa(pressure),
-        phase_region: SteamNonCriticalPhaseRegion::Liquid,
-    }));
-    let vapor_entry_result = get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
-        pressure: Pressure::Pa(pressure),
-        phase_region: SteamNonCriticalPhaseRegion::Vapor,
-    }));
-
-    match (liquid_entry_result, vapor_entry_result) {
-        (Ok(liquid_entry), Ok(vapor_entry))
-            if get_prop_value(&liquid_entry) <= target_value
-                && get_prop_value(&vapor_entry) >= target_value =>
-        {
-            let liq_frac = (get_prop_value(&vapor_entry) - target_value)
-                / (get_prop_value(&vapor_entry) - get_prop_value(&liquid_entry));
-            interpolate_entry(&liquid_entry, &vapor_entry, liq_frac)
+    match iterate_solid_entry_solution(pressure, target_value, get_prop_value) {
+        Some(result) => result,
+        None if extrapolate => {
+            iterate_pt_entry_solution(&ExtrapolatedIapws97, pressure, target_value, get_prop_value)
         }
-        _ => {
-            let f = |temperature| {
-                let query_result = get_steam_table_entry(SteamQuery::Pt(PtPoint {
-                    pressure: Pressure::Pa(pressure),
-                    temperature: Temperature::K(K::new(temperature)),
-                }));
-                if let Ok(entry) = query_result {
-                    get_prop_value(&entry) - target_value
-                } else {
-                    f64::NAN
-                }
-            };
-            secant_method(f, 310f64, 1e-5)
+        None => iterate_pt_entry_solution(&Iapws97, pressure, target_value, get_prop_value),
+    }
+}
+
+// Ice, or ice in equilibrium with vapor or liquid, when the target value lies below the
+// fluid side of the sublimation or melting curve. None when the state is a fluid.
+// Along the melting curve the liquid comes from region 1, which is extrapolated
+// slightly below 273.15 K above about 0.14 MPa.
+fn iterate_solid_entry_solution(
+    pressure: Pa,
+    target_value: f64,
+    get_prop_value: fn(entry: &PtvEntry) -> f64,
+) -> Option<Result<PtvEntry, SteamQueryErr>> {
+    let below_triple_point = pressure.value < TRIPLE_POINT_PRESSURE.value;
+    let equilibrium_temperature = if below_triple_point {
+        get_sublimation_temperature(Pressure::Pa(pressure))
+    } else {
+        get_melting_temperature(Pressure::Pa(pressure))
+    };
+    let point = match equilibrium_temperature {
+        Ok(temperature) => PtPoint {
+            pressure: Pressure::Pa(pressure),
+            temperature,
+        },
+        // No ice can exist at this pressure
+        Err(SteamQueryErr::OutOfRange(_)) => return None,
+        Err(err) => return Some(Err(err)),
+    };
+    let entries = get_ice_entry(&point).and_then(|solid_entry| {
+        if below_triple_point {
+            get_vapor_entry_below_triple_point(&point)
+        } else {
+            Ok(gibbs_method(&point))
+        }
+        .map(|fluid_entry| (solid_entry, fluid_entry))
+    });
+    let (solid_entry, fluid_entry) = match entries {
+        Ok(x) => x,
+        Err(err) => return Some(Err(err)),
+    };
+    let solid_value = get_prop_value(&solid_entry);
+    let fluid_value = get_prop_value(&fluid_entry);
+    if target_value > fluid_value {
+        None
+    } else if target_value >= solid_value {
+        let solid_frac = (fluid_value - target_value) / (fluid_value - solid_value);
+        let fluid_frac = 1.0 - solid_frac;
+        let phase_region_result = if below_triple_point {
+            SolidVapor::new(solid_frac, fluid_frac).map(CompositePhaseRegion::SolidVapor)
+        } else {
+            SolidLiquid::new(solid_frac, fluid_frac).map(CompositePhaseRegion::SolidLiquid)
+        };
+        Some(
+            phase_region_result
+                .map_err(SteamQueryErr::CompositePhaseRegionErr)
+                .map(|phase_region| {
+                    mix_entries(
+                        &[(&solid_entry, solid_frac), (&fluid_entry, fluid_frac)],
+                        PhaseRegion::Composite(phase_region),
+                    )
+                }),
+        )
+    } else {
+        let get_ice_point = |temperature| PtPoint {
+            pressure: Pressure::Pa(pressure),
+            temperature: Temperature::K(K::new(temperature)),
+        };
+        let f = |temperature| {
+            get_ice_entry(&get_ice_point(temperature))
+                .map(|entry| get_prop_value(&entry) - target_value)
+                .unwrap_or(f64::NAN)
+        };
+        let equilibrium_temperature = point.temperature.convert_to_si_unit().value;
+        Some(
+            secant_method(f, equilibrium_temperature - 1f64, 1e-5)
                 .map_err(SteamQueryErr::FailedToConverge)
-                .and_then(|temperature| {
-                    get_steam_table_entry(SteamQuery::Pt(PtPoint {
-                        pressure: Pressure::Pa(pressure),
-                        temperature: Temperature::K(K::new(temperature)),
-                    }))
-                })
+                .and_then(|temperature| get_ice_entry(&get_ice_point(temperature))),
+        )
+    }
+}
+
+/// Ice, liquid water and vapor in equilibrium at the triple point
+pub fn get_triple_point_entry(
+    solid_frac: f64,
+    liquid_frac: f64,
+    vapor_frac: f64,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let point = PtPoint {
+        pressure: Pressure::Pa(TRIPLE_POINT_PRESSURE),
+        temperature: Temperature::K(TRIPLE_POINT_TEMPERATURE),
+    };
+    let phase_region = SolidLiquidVapor::new(solid_frac, liquid_frac, vapor_frac)
+        .map_err(SteamQueryErr::CompositePhaseRegionErr)?;
+    let solid_entry = get_ice_entry(&point)?;
+    let liquid_entry = gibbs_method(&point);
+    let vapor_entry = get_entry_from_pt_point(&point, Iapws97Region::Region2)?;
+    Ok(mix_entries(
+        &[
+            (&solid_entry, solid_frac),
+            (&liquid_entry, liquid_frac),
+            (&vapor_entry, vapor_frac),
+        ],
+        PhaseRegion::Composite(CompositePhaseRegion::SolidLiquidVapor(phase_region)),
+    ))
+}
+
+/// Saturated liquid and vapor entries on the part of the saturation line inside region 3.
+/// The temperatures must be ascending and between 623.15 K and the critical temperature.
+/// Each solution seeds the next temperature so the solver stays on its own branch of the
+/// isotherm, the trace stops early if the two branches collapse onto one root.
+pub fn get_region3_saturation_line(temperatures: &[K]) -> Vec<(PtvEntry, PtvEntry)> {
+    let boundary_point = PtPoint {
+        pressure: Pressure::Pa(Pa::new(16.5292e6)),
+        temperature: Temperature::K(K::new(623.15)),
+    };
+    let get_volume = |entry: PtvEntry| entry.specific_volume.convert_to_si_unit().value;
+    let mut liquid_volume = get_volume(gibbs_method(&boundary_point));
+    let mut vapor_volume = match get_entry_from_pt_point(&boundary_point, Iapws97Region::Region2) {
+        Ok(entry) => get_volume(entry),
+        Err(_) => return vec![],
+    };
+    let critical_volume = 1f64 / CRITICAL_DENSITY.value;
+    let mut line = Vec::with_capacity(temperatures.len());
+    for temperature in temperatures {
+        let pressure = match get_sat_pressure(*temperature) {
+            Ok(pressure) => pressure,
+            Err(_) => break,
+        };
+        let point = PtPoint {
+            pressure: Pressure::Pa(pressure),
+            temperature: Temperature::K(*temperature),
+        };
+        let f = |x| {
+            region3_by_specific_volume(&point, x)
+                .pressure
+                .convert_to_si_unit()
+                .value
+                / pressure.value
+                - 1f64
+        };
+        let df = |x| {
+            let entry = region3_by_specific_volume(&point, x);
+            let compressibility = entry.isothermal_compressibility.convert_to_si_unit().value;
+            -1f64 / (x * compressibility * pressure.value)
+        };
+        match (
+            newton_method(f, df, liquid_volume, 1e-10),
+            newton_method(f, df, vapor_volume, 1e-10),
+        ) {
+            (Ok(liquid), Ok(vapor)) if liquid < critical_volume && vapor > critical_volume => {
+                liquid_volume = liquid;
+                vapor_volume = vapor;
+                let with_phase = |volume, phase_region| PtvEntry {
+                    phase_region: PhaseRegion::NonCritical(phase_region),
+                    ..region3_by_specific_volume(&point, volume)
+                };
+                line.push((
+                    with_phase(liquid, NonCriticalPhaseRegion::Liquid),
+                    with_phase(vapor, NonCriticalPhaseRegion::Vapor),
+                ));
+            }
+            _ => break,
         }
     }
+    line
+}
+
+/// The region 3 basic equation at the critical temperature and density
+pub fn get_critical_point_entry() -> PtvEntry {
+    let point = PtPoint {
+        pressure: Pressure::Pa(CRITICAL_PRESSURE),
+        temperature: Temperature::K(CRITICAL_TEMPERATURE),
+    };
+    region3_by_specific_volume(&point, 1f64 / CRITICAL_DENSITY.value)
+}
+
+// Lets the property iteration reach pressures above 100 MPa
+struct ExtrapolatedIapws97;
+
+impl SteamProperties for ExtrapolatedIapws97 {
+    fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
+        get_steam_table_entry_with_options(
+            query,
+            SteamQueryOptions {
+                extrapolate: true,
+                ..Default::default()
+            },
+        )
+        .map(|x| x.entry)
+    }
+}
+
+/// Vaporization pressure from the region 4 saturation equation, liquid water only
+pub fn get_saturation_pressure(temperature: Temperature) -> Result<Pressure, OutOfRange> {
+    let temperature = temperature.convert_to_si_unit();
+    if temperature.value < TRIPLE_POINT_TEMPERATURE.value {
+        return Err(OutOfRange::TemperatureLow);
+    }
+    get_sat_pressure(temperature).map(Pressure::Pa)
+}
+
+/// Vaporization temperature from the region 4 saturation equation, liquid water only
+pub fn get_saturation_temperature(pressure: Pressure) -> Result<Temperature, OutOfRange> {
+    let pressure = pressure.convert_to_si_unit();
+    if pressure.value < TRIPLE_POINT_PRESSURE.value {
+        return Err(OutOfRange::PressureLow);
+    }
+    get_sat_temperature(pressure).map(Temperature::K)
 }
 
 pub fn get_steam_table_entry(query: SteamQuery) -> Result<PtvEntry, SteamQueryErr> {
-    let f = SteamQueryErr::OutOfRange;
+    get_steam_table_entry_with_options(query, SteamQueryOptions::default()).map(|x| x.entry)
+}
 
-    check_if_out_of_range(&query)
-        .map_err(f)
-        .and_then(|_| match query {
-            SteamQuery::Pt(point) => get_region_from_pt_point(&point)
-                .map_err(f)
-                .and_then(|r| get_entry_from_pt_point(&point, r)),
-            SteamQuery::Sat(sat_query) => get_region_from_sat_query(&sat_query)
-                .map_err(f)
-                .and_then(|(p, r)| get_entry_from_pt_point(&p, r)),
-            SteamQuery::EntropyP {
-                pressure: p,
-                entropy: e,
-            } => iterate_pt_entry_solution(
+/// Like get_steam_table_entry but can return metastable and extrapolated states.
+/// Every state outside of the stable, validated range is flagged in the warnings.
+pub fn get_steam_table_entry_with_options(
+    query: SteamQuery,
+    options: SteamQueryOptions,
+) -> Result<SteamEntry, SteamQueryErr> {
+    let f = SteamQueryErr::OutOfRange;
+    let range_warning = check_if_out_of_range(&query, options.extrapolate).map_err(f)?;
+    let (entry, phase_warning) = match query {
+        SteamQuery::Pt(point) => {
+            let (region, warning) =
+                get_region_with_metastable_phase(&point, options.metastable_phase).map_err(f)?;
+            let entry = match region {
+                Iapws97Region::Region3 if options.region3_backward_only => {
+                    region3_backward_method(&point)
+                }
+                region => get_entry_from_pt_point(&point, region),
+            }?;
+            (entry, warning)
+        }
+        SteamQuery::Sat(sat_query) => {
+            let (point, region) = get_region_from_sat_query(&sat_query)?;
+            (get_entry_from_pt_point(&point, region)?, None)
+        }
+        SteamQuery::EntropyP {
+            pressure: p,
+            entropy: e,
+        } => (
+            iterate_entry_solution(
                 p.convert_to_si_unit(),
                 e.convert_to_si_unit().value,
                 |point| point.entropy.convert_to_si_unit().value,
-            ),
-            SteamQuery::EnthalpyP {
-                pressure: p,
-                enthalpy: e,
-            } => iterate_pt_entry_solution(
+                options.extrapolate,
+            )?,
+            None,
+        ),
+        SteamQuery::EnthalpyP {
+            pressure: p,
+            enthalpy: e,
+        } => (
+            iterate_entry_solution(
                 p.convert_to_si_unit(),
                 e.convert_to_si_unit().value,
                 |point| point.enthalpy.convert_to_si_unit().value,
-            ),
-        })
+                options.extrapolate,
+            )?,
+            None,
+        ),
+    };
+    Ok(SteamEntry {
+        entry,
+        warnings: range_warning.into_iter().chain(phase_warning).collect(),
+    })
+}
+
+// A pressure-temperature query converted to SI with its region found
+#[derive(Debug, Clone, Copy)]
+struct BatchPoint {
+    index: usize,
+    region: Iapws97Region,
+    temperature: f64,
+    point: PtPoint,
+}
+
+/// Batch form of get_steam_table_entry, see steam::get_steam_table_entries.
+/// Pressure-temperature queries are converted to SI and placed in their region once, then
+/// evaluated sorted by region and temperature so that the temperature dependent terms of each
+/// equation are shared by every pressure at that temperature.
+pub fn get_steam_table_entries(queries: &[SteamQuery]) -> Vec<Result<PtvEntry, SteamQueryErr>> {
+    let mut results = Vec::with_capacity(queries.len());
+    let mut batch_points = Vec::with_capacity(queries.len());
+    for (index, query) in queries.iter().enumerate() {
+        let point = match query {
+            SteamQuery::Pt(point) => PtPoint {
+                pressure: Pressure::Pa(point.pressure.convert_to_si_unit()),
+                temperature: Temperature::K(point.temperature.convert_to_si_unit()),
+            },
+            _ => {
+                results.push((index, get_steam_table_entry(*query)));
+                continue;
+            }
+        };
+        let region = check_if_out_of_range(query, false)
+            .and_then(|_| get_region_from_pt_point(&point))
+            .map_err(SteamQueryErr::OutOfRange);
+        match region {
+            Ok(region) => batch_points.push(BatchPoint {
+                index,
+                region,
+                temperature: point.temperature.convert_to_si_unit().value,
+                point,
+            }),
+            Err(err) => results.push((index, Err(err))),
+        }
+    }
+    batch_points.sort_by(|a, b| {
+        a.region
+            .cmp(&b.region)
+            .then(a.temperature.total_cmp(&b.temperature))
+    });
+    let runs: Vec<&[BatchPoint]> = batch_points
+        .chunk_by(|a, b| a.region == b.region && a.temperature == b.temperature)
+        .collect();
+    for run in map_items(&runs, get_run_entries) {
+        results.extend(run);
+    }
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// Every point in a run has the same region and temperature
+fn get_run_entries(run: &[BatchPoint]) -> Vec<(usize, Result<PtvEntry, SteamQueryErr>)> {
+    let first = &run[0];
+    match get_tau_terms(first.region, first.temperature) {
+        Some(tau_terms) => run
+            .iter()
+            .map(|x| (x.index, Ok(get_entry_from_tau_terms(&x.point, &tau_terms))))
+            .collect(),
+        None => run
+            .iter()
+            .map(|x| (x.index, get_entry_from_pt_point(&x.point, x.region)))
+            .collect(),
+    }
+}
+
+/// Batch form of get_steam_table_entry_with_options with the same options for every query
+pub fn get_steam_table_entries_with_options(
+    queries: &[SteamQuery],
+    options: SteamQueryOptions,
+) -> Vec<Result<SteamEntry, SteamQueryErr>> {
+    map_items(queries, |query| {
+        get_steam_table_entry_with_options(query, options)
+    })
 }
 
 #[cfg(test)]
@@ -563,6 +1164,78 @@ mod tests {
     use super::*;
     use assert_approx_eq::assert_approx_eq;
 
+    // NaN marks a property which is undefined for the state, such as cp of wet steam
+    fn assert_derived_eq(expected: f64, actual: f64, eps: f64) {
+        if expected.is_nan() {
+            assert!(actual.is_nan(), "Expected NaN, got {}", actual);
+        } else {
+            assert_approx_eq!(expected, actual, eps);
+        }
+    }
+
+    macro_rules! wet_specific_volume_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (kPa, quality, IF97 saturated h_f and h_g in kJ/kg, v_f and v_g in m3/kg)
+                let (pressure, quality, (h_f, h_g), (v_f, v_g)): (f64, f64, (f64, f64), (f64, f64)) =
+                    $value;
+                let entry = get_steam_table_entry(SteamQuery::EnthalpyP {
+                    enthalpy: EnergyPerMass::JPerKg(JPerKg::new(1e3 * (h_f + quality * (h_g - h_f)))),
+                    pressure: Pressure::KPa(KPa::new(pressure)),
+                })
+                .unwrap();
+                let expected = v_f + quality * (v_g - v_f);
+                let actual = entry.specific_volume.convert_to_si_unit().value;
+                assert_approx_eq!(expected, actual, expected * 1e-3);
+                let density = entry.density.convert_to_si_unit().value;
+                assert_approx_eq!(1.0 / expected, density, 1e-3 / expected);
+                assert!(entry.cp.convert_to_si_unit().value.is_nan());
+            }
+        )*
+        }
+    }
+
+    wet_specific_volume_tests! {
+        wet_specific_volume_10_kpa: (10.0, 0.9, (191.81, 2583.9), (0.001010, 14.670)),
+        wet_specific_volume_100_kpa: (100.0, 0.5, (417.51, 2675.0), (0.001043, 1.6941)),
+        wet_specific_volume_1_mpa: (1000.0, 0.1, (762.51, 2777.1), (0.001127, 0.19436)),
+    }
+
+    macro_rules! entropy_p_near_saturation_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                // (kPa, K) just off the saturation line, found again from its entropy
+                let (pressure, temperature): (f64, f64) = $value;
+                let pressure = Pressure::KPa(KPa::new(pressure));
+                let entropy = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+                    pressure,
+                    temperature: Temperature::K(K::new(temperature)),
+                }))
+                .unwrap()
+                .entropy;
+                let query = SteamQuery::EntropyP { entropy, pressure };
+                let entry = get_steam_table_entry(query).unwrap();
+                assert_approx_eq!(temperature, entry.temperature.convert_to_si_unit().value, 1e-3);
+            }
+        )*
+        }
+    }
+
+    entropy_p_near_saturation_tests! {
+        entropy_p_near_saturation_vapor_200_kpa: (200.0, 393.5),
+        entropy_p_near_saturation_vapor_1_mpa: (1000.0, 453.2),
+        entropy_p_near_saturation_liquid_200_kpa: (200.0, 393.2),
+        entropy_p_near_saturation_liquid_10_mpa: (10000.0, 584.0),
+    }
+
+    // The state properties are IF97 check values. The free energies, expansion coefficient,
+    // compressibility and Joule-Thomson coefficient are IAPWS-95 values at the same state to
+    // 5 significant figures, at the IF97 density for the 2000 K point beyond IAPWS-95's range.
+    // IF97 agrees with IAPWS-95 to within about 0.5% in these derivatives.
     macro_rules! get_steam_table_valid_entry_tests {
         ($($name:ident: $value:expr,)*) => {
         $(
@@ -574,7 +1247,7 @@ mod tests {
                     (Ok(expected), Ok(actual)) => {
                         assert_approx_eq!(expected.pressure, actual.pressure, 10f64);
                         assert_approx_eq!(expected.temperature, actual.temperature, 1f64);
-                        match (expected.phase_region, actual.phase_region) {
+                        match (&expected.phase_region, &actual.phase_region) {
                             (
                                 PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(exp)),
                                 PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(act))
@@ -587,10 +1260,26 @@ mod tests {
                         assert_approx_eq!(expected.internal_energy, actual.internal_energy, 1f64);
                         assert_approx_eq!(expected.enthalpy, actual.enthalpy, 150f64);
                         assert_approx_eq!(expected.entropy, actual.entropy, 150f64);
-                        assert_approx_eq!(expected.cv, actual.cv, 1e-2);
-                        assert_approx_eq!(expected.cp, actual.cp, 1e-2);
-                        assert_approx_eq!(expected.speed_of_sound, actual.speed_of_sound, 1e-2);
+                        let assert_derived = |f: fn(&PtvEntry) -> f64, eps: f64| {
+                            assert_derived_eq(f(&expected), f(&actual), eps)
+                        };
+                        assert_derived(|x| x.cv.convert_to_si_unit().value, 1e-2);
+                        assert_derived(|x| x.cp.convert_to_si_unit().value, 1e-2);
+                        assert_derived(|x| x.speed_of_sound.convert_to_si_unit().value, 1e-2);
                         assert_approx_eq!(expected.specific_volume, actual.specific_volume, 1e-2);
+                        assert_approx_eq!(expected.density, actual.density, 1e-2);
+                        let assert_relative = |f: fn(&PtvEntry) -> f64, eps: f64| {
+                            assert_derived_eq(f(&expected), f(&actual), eps * f(&expected).abs())
+                        };
+                        assert_relative(|x| x.gibbs_free_energy.convert_to_si_unit().value, 1e-3);
+                        assert_relative(|x| x.helmholtz_free_energy.convert_to_si_unit().value, 1e-3);
+                        assert_relative(|x| x.isobaric_expansion.convert_to_si_unit().value, 1e-2);
+                        assert_relative(
+                            |x| x.isothermal_compressibility.convert_to_si_unit().value,
+                            1e-2,
+                        );
+                        assert_relative(|x| x.joule_thomson.convert_to_si_unit().value, 1e-2);
+                        assert_derived(|x| x.isentropic_exponent, 1e-2);
                     },
                     (x, y) => assert_eq!(x, y),
                 };
@@ -615,6 +1304,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(6.341653594791e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(760.696040876798)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(1.0 / 500.0)),
+                density: Density::KgPerM3(KgPerM3::new(500.0)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.09365e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.25029e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(4.41551e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(8.08993e-9)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(7.30079e-7)),
+                isentropic_exponent: 3.694685793548577,
             })
         ),
         steam_table_02: (
@@ -633,6 +1329,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(4.315767590903e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1457.418351596083)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.001122406088)),
+                density: Density::KgPerM3(KgPerM3::new(890.943136081778)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.06644e5)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.51540e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.15491e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(6.92646e-10)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(-1.17901e-7)),
+                isentropic_exponent: 47.31060073261657,
             })
         ),
         steam_table_03: (
@@ -651,6 +1354,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.885698818781e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1067.369478777425)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.03113852187)),
+                density: Density::KgPerM3(KgPerM3::new(32.11456228317108)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.05017e7)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.14359e7)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(5.08877e-4)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(3.29194e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(1.91484e-7)),
+                isentropic_exponent: 1.2195800526241571,
             })
         ),
         steam_table_04: (
@@ -669,6 +1379,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.666558503968e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(666.050616844223)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.024763222774)),
+                density: Density::KgPerM3(KgPerM3::new(40.38246593048236)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.94286e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.28953e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.72866e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(7.86397e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(3.93002e-6)),
+                isentropic_exponent: 1.2796148438330484,
             })
         ),
         steam_table_05: (
@@ -687,6 +1404,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(4246.73524917536)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1520.69128792808)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.00106051840643552)),
+                density: Density::KgPerM3(KgPerM3::new(942.9350720663804)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.72052e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.74173e4)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(8.58900e-4)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(5.31780e-10)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(-1.65466e-7)),
+                isentropic_exponent: 10902.696167965869,
             })
         ),
         steam_table_06: (
@@ -705,6 +1429,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2175.22318865273)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(481.883535821489)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.885735065081644)),
+                density: Density::KgPerM3(KgPerM3::new(1.1290057709387664)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.72052e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.74342e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(2.87215e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(5.13449e-6)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(5.27754e-5)),
+                isentropic_exponent: 1.3108419845296277,
             })
         ),
         steam_table_07: (
@@ -723,6 +1454,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(4246.73524917536)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1520.69128792808)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.00106051840643552)),
+                density: Density::KgPerM3(KgPerM3::new(942.9350720663804)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.72052e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.74173e4)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(8.58900e-4)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(5.31780e-10)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(-1.65466e-7)),
+                isentropic_exponent: 10902.696167965869,
             })
         ),
         steam_table_08: (
@@ -741,6 +1479,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2175.22318865273)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(481.883535821489)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.885735065081644)),
+                density: Density::KgPerM3(KgPerM3::new(1.1290057709387664)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-9.72052e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.74342e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(2.87215e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(5.13449e-6)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(5.27754e-5)),
+                isentropic_exponent: 1.3108419845296277,
             })
         ),
         steam_table_09: (
@@ -759,6 +1504,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(6.341653594791e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(760.696040876798)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(1.0 / 500.0)),
+                density: Density::KgPerM3(KgPerM3::new(500.0)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.09365e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.25029e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(4.41551e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(8.08993e-9)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(7.30079e-7)),
+                isentropic_exponent: 3.694685793548577,
             })
         ),
         steam_table_10: (
@@ -777,6 +1529,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(4.315767590903e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1457.418351596083)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.001122406088)),
+                density: Density::KgPerM3(KgPerM3::new(890.943136081778)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.06644e5)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.51540e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.15491e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(6.92646e-10)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(-1.17901e-7)),
+                isentropic_exponent: 47.31060073261657,
             })
         ),
         steam_table_11: (
@@ -795,6 +1554,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.885698818781e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1067.369478777425)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.03113852187)),
+                density: Density::KgPerM3(KgPerM3::new(32.11456228317108)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.05017e7)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.14359e7)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(5.08877e-4)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(3.29194e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(1.91484e-7)),
+                isentropic_exponent: 1.2195800526241571,
             })
         ),
         steam_table_12: (
@@ -813,6 +1579,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.666558503968e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(666.050616844223)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.024763222774)),
+                density: Density::KgPerM3(KgPerM3::new(40.38246593048236)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.94286e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.28953e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.72866e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(7.86397e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(3.93002e-6)),
+                isentropic_exponent: 1.2796148438330484,
             })
         ),
         steam_table_13: (
@@ -831,10 +1604,19 @@ mod tests {
                 internal_energy: EnergyPerMass::JPerKg(JPerKg::new(1999135.82661328)),
                 enthalpy: EnergyPerMass::JPerKg(JPerKg::new(2117222.94886314)),
                 entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(6.6858e3)),
-                cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(1966.28009225455)),
-                cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2377.86300751001)),
-                speed_of_sound: Velocity::MPerSec(MPerSec::new(655.005141924186)),
-                specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(1.0 / 193.16103883)),
+                // Derivative properties are undefined across the phase boundary
+                cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+                cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+                speed_of_sound: Velocity::MPerSec(MPerSec::new(f64::NAN)),
+                // v_f + x (v_g - v_f) with v_f = 0.001010 and v_g = 14.670 m3/kg at 10 kPa
+                specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(11.808)),
+                density: Density::KgPerM3(KgPerM3::new(1.0 / 11.808)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.52591e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.33343e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(f64::NAN)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(f64::NAN)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(f64::NAN)),
+                isentropic_exponent: f64::NAN,
             })
         ),
         steam_table_14: (
@@ -853,6 +1635,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(6.341653594791e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(760.696040876798)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(1.0 / 500.0)),
+                density: Density::KgPerM3(KgPerM3::new(500.0)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.09365e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.25029e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(4.41551e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(8.08993e-9)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(7.30079e-7)),
+                isentropic_exponent: 3.694685793548577,
             })
         ),
         steam_table_15: (
@@ -871,6 +1660,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(4.315767590903e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1457.418351596083)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.001122406088)),
+                density: Density::KgPerM3(KgPerM3::new(890.943136081778)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.06644e5)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.51540e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.15491e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(6.92646e-10)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(-1.17901e-7)),
+                isentropic_exponent: 47.31060073261657,
             })
         ),
         steam_table_16: (
@@ -889,6 +1685,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.885698818781e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(1067.369478777425)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.03113852187)),
+                density: Density::KgPerM3(KgPerM3::new(32.11456228317108)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.05017e7)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.14359e7)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(5.08877e-4)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(3.29194e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(1.91484e-7)),
+                isentropic_exponent: 1.2195800526241571,
             })
         ),
         steam_table_17: (
@@ -907,6 +1710,13 @@ mod tests {
                 cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2.666558503968e3)),
                 speed_of_sound: Velocity::MPerSec(MPerSec::new(666.050616844223)),
                 specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(0.024763222774)),
+                density: Density::KgPerM3(KgPerM3::new(40.38246593048236)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.94286e6)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-2.28953e6)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(1.72866e-3)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(7.86397e-8)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(3.93002e-6)),
+                isentropic_exponent: 1.2796148438330484,
             })
         ),
         steam_table_18: (
@@ -925,10 +1735,19 @@ mod tests {
                 internal_energy: EnergyPerMass::JPerKg(JPerKg::new(1999135.82661328)),
                 enthalpy: EnergyPerMass::JPerKg(JPerKg::new(2117222.94886314)),
                 entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(6.6858e3)),
-                cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(1966.28009225455)),
-                cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(2377.86300751001)),
-                speed_of_sound: Velocity::MPerSec(MPerSec::new(655.005141924186)),
-                specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(1.0 / 193.16103883)),
+                // Derivative properties are undefined across the phase boundary
+                cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+                cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+                speed_of_sound: Velocity::MPerSec(MPerSec::new(f64::NAN)),
+                // v_f + x (v_g - v_f) with v_f = 0.001010 and v_g = 14.670 m3/kg at 10 kPa
+                specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(11.808)),
+                density: Density::KgPerM3(KgPerM3::new(1.0 / 11.808)),
+                gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.52591e4)),
+                helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(-1.33343e5)),
+                isobaric_expansion: InverseTemperature::PerK(PerK::new(f64::NAN)),
+                isothermal_compressibility: InversePressure::PerPa(PerPa::new(f64::NAN)),
+                joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(f64::NAN)),
+                isentropic_exponent: f64::NAN,
             })
         ),
         steam_table_19: (
@@ -988,4 +1807,546 @@ mod tests {
             Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh))
         ),
     }
+
+    // Expected values are [v, h, s, cp, w] in SI units, compared with a relative tolerance
+    macro_rules! get_steam_table_entry_with_options_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (input, options, expected_result, tol): (
+                    SteamQuery,
+                    SteamQueryOptions,
+                    Result<([f64; 5], Vec<SteamWarning>), SteamQueryErr>,
+                    f64,
+                ) = $value;
+                let actual_result = get_steam_table_entry_with_options(input, options);
+                match (expected_result, actual_result) {
+                    (Ok((expected, expected_warnings)), Ok(actual)) => {
+                        let actual_values = [
+                            actual.entry.specific_volume.convert_to_si_unit().value,
+                            actual.entry.enthalpy.convert_to_si_unit().value,
+                            actual.entry.entropy.convert_to_si_unit().value,
+                            actual.entry.cp.convert_to_si_unit().value,
+                            actual.entry.speed_of_sound.convert_to_si_unit().value,
+                        ];
+                        for (e, a) in expected.iter().zip(actual_values.iter()) {
+                            assert_approx_eq!(e, a, f64::abs(e * tol));
+                        }
+                        assert_eq!(expected_warnings, actual.warnings);
+                    },
+                    (Err(x), Err(y)) => assert_eq!(x, y),
+                    (x, y) => panic!("expected {:?} but got {:?}", x, y),
+                };
+            }
+        )*
+        }
+    }
+
+    get_steam_table_entry_with_options_tests! {
+        metastable_vapor_01: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(450.0)),
+                pressure: Pressure::Pa(Pa::new(1e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Vapor),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Ok(([0.192516540, 2768.81115e3, 6.56660377e3, 2.76349265e3, 498.408101], vec![SteamWarning::MetastableVapor])),
+            1e-8
+        ),
+        metastable_vapor_02: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(440.0)),
+                pressure: Pressure::Pa(Pa::new(1e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Vapor),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Ok(([0.186212297, 2740.15123e3, 6.50218759e3, 2.98166443e3, 489.363295], vec![SteamWarning::MetastableVapor])),
+            1e-8
+        ),
+        metastable_vapor_03: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(450.0)),
+                pressure: Pressure::Pa(Pa::new(1.5e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Vapor),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Ok(([0.121685206, 2721.34539e3, 6.29170440e3, 3.62795578e3, 481.941819], vec![SteamWarning::MetastableVapor])),
+            1e-8
+        ),
+        metastable_vapor_04: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(300.0)),
+                pressure: Pressure::Pa(Pa::new(3.5e3)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Vapor),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Ok(([39.4913866, 2549.91145e3, 8.52238967e3, 1.91300162e3, 427.920172], vec![])),
+            1e-8
+        ),
+        metastable_vapor_05: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(400.0)),
+                pressure: Pressure::Pa(Pa::new(11e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Vapor),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            0.0
+        ),
+        metastable_liquid_01: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(400.0)),
+                pressure: Pressure::Pa(Pa::new(0.1e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Liquid),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            // IAPWS-95
+            Ok(([0.00106676786, 532.852917e3, 1.60140438e3, 4.25590372e3, 1509.11373], vec![SteamWarning::MetastableLiquid])),
+            1e-3
+        ),
+        metastable_liquid_02: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(700.0)),
+                pressure: Pressure::Pa(Pa::new(0.1e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: Some(SteamNonCriticalPhaseRegion::Liquid),
+                extrapolate: false,
+                region3_backward_only: false,
+            },
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureHigh)),
+            0.0
+        ),
+        extrapolated_01: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(300.0)),
+                pressure: Pressure::Pa(Pa::new(200e6)),
+            }),
+            SteamQueryOptions {
+                metastable_phase: None,
+                extrapolate: true,
+                region3_backward_only: false,
+            },
+            // IAPWS-95
+            Ok(([0.000933617577, 285.281507e3, 325.087377, 3.88278187e3, 1827.86848], vec![SteamWarning::PressureExtrapolated])),
+            1e-2
+        ),
+        extrapolated_02: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(300.0)),
+                pressure: Pressure::Pa(Pa::new(200e6)),
+            }),
+            SteamQueryOptions::default(),
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            0.0
+        ),
+        extrapolated_03: (
+            SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(1043.28426e3)),
+                pressure: Pressure::Pa(Pa::new(150e6)),
+            },
+            SteamQueryOptions {
+                metastable_phase: None,
+                extrapolate: true,
+                region3_backward_only: false,
+            },
+            // IAPWS-95 at 500 K
+            Ok(([0.00108197065, 1043.28426e3, 2.38273912e3, 4.08240805e3, 1670.99009], vec![SteamWarning::PressureExtrapolated])),
+            3e-2
+        ),
+    }
+
+    // Expected values are [T, p, h, s] in SI units, compared with a relative tolerance
+    macro_rules! get_steam_table_solid_entry_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (input, expected_result): (
+                    SteamQuery,
+                    Result<(PhaseRegion, [f64; 4]), SteamQueryErr>,
+                ) = $value;
+                match (expected_result, get_steam_table_entry(input)) {
+                    (Ok((expected_phase_region, expected)), Ok(actual)) => {
+                        let actual_values = [
+                            actual.temperature.convert_to_si_unit().value,
+                            actual.pressure.convert_to_si_unit().value,
+                            actual.enthalpy.convert_to_si_unit().value,
+                            actual.entropy.convert_to_si_unit().value,
+                        ];
+                        for (e, a) in expected.iter().zip(actual_values.iter()) {
+                            assert_approx_eq!(e, a, f64::abs(e * 1e-6));
+                        }
+                        match (expected_phase_region, actual.phase_region) {
+                            (
+                                PhaseRegion::Composite(CompositePhaseRegion::SolidLiquid(exp)),
+                                PhaseRegion::Composite(CompositePhaseRegion::SolidLiquid(act))
+                            ) => {
+                                assert_approx_eq!(exp.get_solid_frac(), act.get_solid_frac(), 1e-3);
+                                assert_approx_eq!(exp.get_liquid_frac(), act.get_liquid_frac(), 1e-3);
+                            }
+                            (
+                                PhaseRegion::Composite(CompositePhaseRegion::SolidVapor(exp)),
+                                PhaseRegion::Composite(CompositePhaseRegion::SolidVapor(act))
+                            ) => {
+                                assert_approx_eq!(exp.get_solid_frac(), act.get_solid_frac(), 1e-3);
+                                assert_approx_eq!(exp.get_vapor_frac(), act.get_vapor_frac(), 1e-3);
+                            }
+                            (x, y) => assert_eq!(x, y),
+                        }
+                    },
+                    (x, y) => assert_eq!(x.map(|_| ()), y.map(|_| ())),
+                };
+            }
+        )*
+        }
+    }
+
+    get_steam_table_solid_entry_tests! {
+        solid_entry_01: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(100.0)),
+                pressure: Pressure::Pa(Pa::new(100e6)),
+            }),
+            Ok((
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid),
+                [100.0, 100e6, -0.483491635676e6, -0.261195122589e4],
+            ))
+        ),
+        solid_entry_02: (
+            SteamQuery::Pt(PtPoint {
+                temperature: Temperature::K(K::new(230.0)),
+                pressure: Pressure::Pa(Pa::new(5.0)),
+            }),
+            Ok((
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+                [230.0, 5.0, 2421.35e3, 11.05643e3],
+            ))
+        ),
+        solid_entry_03: (
+            SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Solid,
+            }),
+            Ok((
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid),
+                [273.152519, 101325.0, -0.333354873637e6, -0.122076932550e4],
+            ))
+        ),
+        solid_entry_04: (
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::K(K::new(230.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Solid,
+            }),
+            Ok((
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid),
+                [230.0, 8.94735, -417.118434e3, -1.55332977e3],
+            ))
+        ),
+        solid_entry_05: (
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::K(K::new(230.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Liquid,
+            }),
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow))
+        ),
+        solid_entry_06: (
+            SteamQuery::Sat(SatQuery::SatPQuery {
+                pressure: Pressure::Pa(Pa::new(100.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Liquid,
+            }),
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow))
+        ),
+        solid_entry_07: (
+            SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(-200e3)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            },
+            Ok((
+                PhaseRegion::Composite(CompositePhaseRegion::SolidLiquid(
+                    SolidLiquid::new(0.6, 0.4).unwrap(),
+                )),
+                [273.152519, 101325.0, -200e3, -732.562710],
+            ))
+        ),
+        solid_entry_08: (
+            SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(1000e3)),
+                pressure: Pressure::Pa(Pa::new(100.0)),
+            },
+            Ok((
+                PhaseRegion::Composite(CompositePhaseRegion::SolidVapor(
+                    SolidVapor::new(0.5157, 0.4843).unwrap(),
+                )),
+                [252.818306, 100.0, 1000e3, 4059.92313],
+            ))
+        ),
+        solid_entry_09: (
+            SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(-400e3)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            },
+            Ok((
+                PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid),
+                [239.369873, 101325.0, -400e3, -1480.85516],
+            ))
+        ),
+    }
+
+    #[test]
+    fn triple_point_entry() {
+        let entry = get_triple_point_entry(0.2, 0.3, 0.5).unwrap();
+        assert_eq!(
+            PhaseRegion::Composite(CompositePhaseRegion::SolidLiquidVapor(
+                SolidLiquidVapor::new(0.2, 0.3, 0.5).unwrap()
+            )),
+            entry.phase_region
+        );
+        assert_approx_eq!(273.16, entry.temperature.convert_to_si_unit().value, 1e-9);
+        assert_approx_eq!(611.657, entry.pressure.convert_to_si_unit().value, 1e-9);
+        // 0.2 of ice at -333.444 kJ/kg and 0.5 of vapor at 2500.91 kJ/kg
+        assert_approx_eq!(1183.77e3, entry.enthalpy.convert_to_si_unit().value, 10f64);
+        assert_eq!(
+            Err(SteamQueryErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::FractionsDoNotAddUpToOne
+            )),
+            get_triple_point_entry(0.2, 0.3, 0.6)
+        );
+    }
+
+    // Check values from table 5 of SR5-05, as [p, T, v] in SI units
+    macro_rules! get_region3_backward_specific_volume_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (pressure, temperature, expected): (f64, f64, f64) = $value;
+                let point = PtPoint {
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                    temperature: Temperature::K(K::new(temperature)),
+                };
+                let subregion = get_region3_subregion(&point);
+                let actual = get_region3_backward_specific_volume(&point, subregion);
+                assert_approx_eq!(expected, actual, expected * 1e-9);
+            }
+        )*
+        }
+    }
+
+    get_region3_backward_specific_volume_tests! {
+        region3a_01: (50.0e6, 630.0, 1.470853100e-03),
+        region3a_02: (80.0e6, 670.0, 1.503831359e-03),
+        region3b_01: (50.0e6, 710.0, 2.204728587e-03),
+        region3b_02: (80.0e6, 750.0, 1.973692940e-03),
+        region3c_01: (20.0e6, 630.0, 1.761696406e-03),
+        region3c_02: (30.0e6, 650.0, 1.819560617e-03),
+        region3d_01: (26.0e6, 656.0, 2.245587720e-03),
+        region3d_02: (30.0e6, 670.0, 2.506897702e-03),
+        region3e_01: (26.0e6, 661.0, 2.970225962e-03),
+        region3e_02: (30.0e6, 675.0, 3.004627086e-03),
+        region3f_01: (26.0e6, 671.0, 5.019029401e-03),
+        region3f_02: (30.0e6, 690.0, 4.656470142e-03),
+        region3g_01: (23.6e6, 649.0, 2.163198378e-03),
+        region3g_02: (24.0e6, 650.0, 2.166044161e-03),
+        region3h_01: (23.6e6, 652.0, 2.651081407e-03),
+        region3h_02: (24.0e6, 654.0, 2.967802335e-03),
+        region3i_01: (23.6e6, 653.0, 3.273916816e-03),
+        region3i_02: (24.0e6, 655.0, 3.550329864e-03),
+        region3j_01: (23.5e6, 655.0, 4.545001142e-03),
+        region3j_02: (24.0e6, 660.0, 5.100267704e-03),
+        region3k_01: (23.0e6, 660.0, 6.109525997e-03),
+        region3k_02: (24.0e6, 670.0, 6.427325645e-03),
+        region3l_01: (22.6e6, 646.0, 2.117860851e-03),
+        region3l_02: (23.0e6, 646.0, 2.062374674e-03),
+        region3m_01: (22.6e6, 648.6, 2.533063780e-03),
+        region3m_02: (22.8e6, 649.3, 2.572971781e-03),
+        region3n_01: (22.6e6, 649.0, 2.923432711e-03),
+        region3n_02: (22.8e6, 649.7, 2.913311494e-03),
+        region3o_01: (22.6e6, 649.1, 3.131208996e-03),
+        region3o_02: (22.8e6, 649.9, 3.221160278e-03),
+        region3p_01: (22.6e6, 649.4, 3.715596186e-03),
+        region3p_02: (22.8e6, 650.2, 3.664754790e-03),
+        region3q_01: (21.1e6, 640.0, 1.970999272e-03),
+        region3q_02: (21.8e6, 643.0, 2.043919161e-03),
+        region3r_01: (21.1e6, 644.0, 5.251009921e-03),
+        region3r_02: (21.8e6, 648.0, 5.256844741e-03),
+        region3s_01: (19.1e6, 635.0, 1.932829079e-03),
+        region3s_02: (20.0e6, 638.0, 1.985387227e-03),
+        region3t_01: (17.0e6, 626.0, 8.483262001e-03),
+        region3t_02: (20.0e6, 640.0, 6.227528101e-03),
+        region3u_01: (21.5e6, 644.6, 2.268366647e-03),
+        region3u_02: (22.0e6, 646.1, 2.296350553e-03),
+        region3v_01: (22.5e6, 648.6, 2.832373260e-03),
+        region3v_02: (22.3e6, 647.9, 2.811424405e-03),
+        region3w_01: (22.15e6, 647.5, 3.694032281e-03),
+        region3w_02: (22.3e6, 648.1, 3.622226305e-03),
+        region3x_01: (22.11e6, 648.0, 4.528072649e-03),
+        region3y_01: (22.0e6, 646.84, 2.698354719e-03),
+        region3y_02: (22.064e6, 647.05, 2.717655648e-03),
+        region3z_01: (22.0e6, 646.89, 3.798732962e-03),
+        region3z_02: (22.064e6, 647.15, 3.701940010e-03),
+    }
+
+    // SR5-05 table 5 check points queried by pressure and temperature, as [p, T, v] in SI
+    // units with the relative tolerance. The basic equation volume is within 1e-3 of the
+    // backward equations, except next to the critical point where the auxiliary 3y and 3z
+    // equations are up to 1% off.
+    macro_rules! region3_pt_query_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (pressure, temperature, expected, eps): (f64, f64, f64, f64) = $value;
+                let entry = get_steam_table_entry(SteamQuery::Pt(PtPoint {
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                    temperature: Temperature::K(K::new(temperature)),
+                }))
+                .unwrap();
+                let actual = entry.specific_volume.convert_to_si_unit().value;
+                assert_approx_eq!(pressure, entry.pressure.convert_to_si_unit().value, 1e-3);
+                assert_approx_eq!(expected, actual, expected * eps);
+            }
+        )*
+        }
+    }
+
+    region3_pt_query_tests! {
+        region3_pt_query_3r_below_critical: (21.1e6, 644.0, 5.251009921e-03, 1e-3),
+        region3_pt_query_3s_below_critical: (20.0e6, 638.0, 1.985387227e-03, 1e-3),
+        region3_pt_query_3t_below_critical: (17.0e6, 626.0, 8.483262001e-03, 1e-3),
+        region3_pt_query_3u_below_critical: (22.0e6, 646.1, 2.296350553e-03, 1e-3),
+        region3_pt_query_3v: (22.3e6, 647.9, 2.811424405e-03, 1e-3),
+        region3_pt_query_3w: (22.15e6, 647.5, 3.694032281e-03, 1e-3),
+        region3_pt_query_3y_below_critical: (22.0e6, 646.84, 2.698354719e-03, 1e-2),
+        region3_pt_query_3z_below_critical: (22.0e6, 646.89, 3.798732962e-03, 1e-2),
+        region3_pt_query_3z_critical_pressure: (22.064e6, 647.15, 3.701940010e-03, 1e-2),
+    }
+
+    #[test]
+    fn region3_polish_reproduces_pressure() {
+        let point = PtPoint {
+            pressure: Pressure::Pa(Pa::new(23.6e6)),
+            temperature: Temperature::K(K::new(652.0)),
+        };
+        let polished = get_entry_from_pt_point(&point, Iapws97Region::Region3).unwrap();
+        let backward = get_steam_table_entry_with_options(
+            SteamQuery::Pt(point),
+            SteamQueryOptions {
+                region3_backward_only: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .entry;
+        assert_approx_eq!(
+            23.6e6,
+            polished.pressure.convert_to_si_unit().value,
+            23.6e6 * 1e-9
+        );
+        let polished_volume = polished.specific_volume.convert_to_si_unit().value;
+        let backward_volume = backward.specific_volume.convert_to_si_unit().value;
+        assert_approx_eq!(2.651081407e-3, backward_volume, 2.651081407e-3 * 1e-9);
+        assert_approx_eq!(polished_volume, backward_volume, polished_volume * 1e-4);
+    }
+
+    #[test]
+    fn batch_matches_scalar_queries() {
+        let queries = [
+            SteamQuery::Pt(PtPoint {
+                pressure: Pressure::Pa(Pa::new(3e6)),
+                temperature: Temperature::K(K::new(300.0)),
+            }),
+            SteamQuery::Pt(PtPoint {
+                pressure: Pressure::Pa(Pa::new(200e6)),
+                temperature: Temperature::K(K::new(300.0)),
+            }),
+            SteamQuery::Sat(SatQuery::SatTQuery {
+                temperature: Temperature::K(K::new(400.0)),
+                phase_region: SteamNonCriticalPhaseRegion::Vapor,
+            }),
+            SteamQuery::EnthalpyP {
+                enthalpy: EnergyPerMass::JPerKg(JPerKg::new(3e6)),
+                pressure: Pressure::Pa(Pa::new(1e6)),
+            },
+            SteamQuery::Pt(PtPoint {
+                pressure: Pressure::Pa(Pa::new(25e6)),
+                temperature: Temperature::K(K::new(650.0)),
+            }),
+        ];
+        // Shared temperatures in regions 1, 2 and 5 with converted units, then ice
+        let grid = [0.001e6, 0.1e6, 3e6]
+            .iter()
+            .flat_map(|pressure| {
+                [300.0, 500.0, 1500.0, 250.0].map(|temperature| {
+                    SteamQuery::Pt(PtPoint {
+                        pressure: Pressure::KPa(KPa::new(pressure / 1e3)),
+                        temperature: Temperature::C(C::new(temperature - 273.15)),
+                    })
+                })
+            })
+            .collect::<Vec<SteamQuery>>();
+        let queries = [queries.as_slice(), grid.as_slice()].concat();
+        let expected = queries
+            .iter()
+            .map(|x| get_steam_table_entry(*x))
+            .collect::<Vec<Result<PtvEntry, SteamQueryErr>>>();
+        assert_eq!(expected, get_steam_table_entries(&queries));
+        assert_eq!(expected, steam::get_steam_table_entries(&Iapws97, &queries));
+        let with_options = get_steam_table_entries_with_options(&queries, Default::default())
+            .into_iter()
+            .map(|x| x.map(|y| y.entry))
+            .collect::<Vec<Result<PtvEntry, SteamQueryErr>>>();
+        assert_eq!(expected, with_options);
+    }
+
+    #[test]
+    fn region3_saturation_line() {
+        let temperatures = (1..=40)
+            .map(|i| K::new(647.096 - 23.946 * f64::powi(1f64 - i as f64 / 41f64, 2)))
+            .collect::<Vec<K>>();
+        let line = get_region3_saturation_line(&temperatures);
+        let at_640 = get_region3_saturation_line(&[K::new(630.0), K::new(640.0)]);
+        let (liquid, vapor) = &at_640[1];
+        // IAPWS-95 saturated densities at 640 K
+        assert_approx_eq!(481.53, liquid.density.convert_to_si_unit().value, 1.0);
+        assert_approx_eq!(177.15, vapor.density.convert_to_si_unit().value, 1.0);
+        assert_approx_eq!(20.265e6, vapor.pressure.convert_to_si_unit().value, 1e4);
+        assert_eq!(temperatures.len(), line.len());
+    }
+
+    #[test]
+    fn saturation_pressure_and_temperature() {
+        // IF97 region 4 verification values
+        let pressure = get_saturation_pressure(Temperature::K(K::new(300.0))).unwrap();
+        assert_approx_eq!(3.53658941e3, pressure.convert_to_si_unit().value, 1e-5);
+        let temperature = get_saturation_temperature(Pressure::Pa(Pa::new(0.1e6))).unwrap();
+        assert_approx_eq!(372.755919, temperature.convert_to_si_unit().value, 1e-5);
+        assert_eq!(
+            Err(OutOfRange::TemperatureLow),
+            get_saturation_pressure(Temperature::C(C::new(-5.0)))
+        );
+        assert_eq!(
+            Err(OutOfRange::PressureLow),
+            get_saturation_temperature(Pressure::Pa(Pa::new(100.0)))
+        );
+    }
 }
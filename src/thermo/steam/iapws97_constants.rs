@@ -443,6 +443,113 @@ pub static REGION_2_RESIDUAL: &[IjnRegionPoint] = &[
     },
 ];
 
+pub static REGION_2_METASTABLE_IDEAL: &[JnRegionPoint] = &[
+    JnRegionPoint {
+        j: 0.0,
+        n: -9.6937268393049E+00,
+    },
+    JnRegionPoint {
+        j: 1.0,
+        n: 1.0087275970006E+01,
+    },
+    JnRegionPoint {
+        j: -5.0,
+        n: -5.6087911283020E-03,
+    },
+    JnRegionPoint {
+        j: -4.0,
+        n: 7.1452738081455E-02,
+    },
+    JnRegionPoint {
+        j: -3.0,
+        n: -4.0710498223928E-01,
+    },
+    JnRegionPoint {
+        j: -2.0,
+        n: 1.4240819171444E+00,
+    },
+    JnRegionPoint {
+        j: -1.0,
+        n: -4.3839511319450E+00,
+    },
+    JnRegionPoint {
+        j: 2.0,
+        n: -2.8408632460772E-01,
+    },
+    JnRegionPoint {
+        j: 3.0,
+        n: 2.1268463753307E-02,
+    },
+];
+
+pub static REGION_2_METASTABLE_RESIDUAL: &[IjnRegionPoint] = &[
+    IjnRegionPoint {
+        i: 1.0,
+        j: 0.0,
+        n: -7.3362260186506E-03,
+    },
+    IjnRegionPoint {
+        i: 1.0,
+        j: 2.0,
+        n: -8.8223831943146E-02,
+    },
+    IjnRegionPoint {
+        i: 1.0,
+        j: 5.0,
+        n: -7.2334555213245E-02,
+    },
+    IjnRegionPoint {
+        i: 1.0,
+        j: 11.0,
+        n: -4.0813178534455E-03,
+    },
+    IjnRegionPoint {
+        i: 2.0,
+        j: 1.0,
+        n: 2.0097803380207E-03,
+    },
+    IjnRegionPoint {
+        i: 2.0,
+        j: 7.0,
+        n: -5.3045921898642E-02,
+    },
+    IjnRegionPoint {
+        i: 2.0,
+        j: 16.0,
+        n: -7.6190409086970E-03,
+    },
+    IjnRegionPoint {
+        i: 3.0,
+        j: 4.0,
+        n: -6.3498037657313E-03,
+    },
+    IjnRegionPoint {
+        i: 3.0,
+        j: 16.0,
+        n: -8.6043093028588E-02,
+    },
+    IjnRegionPoint {
+        i: 4.0,
+        j: 7.0,
+        n: 7.5321581522770E-03,
+    },
+    IjnRegionPoint {
+        i: 4.0,
+        j: 10.0,
+        n: -7.9238375446139E-03,
+    },
+    IjnRegionPoint {
+        i: 5.0,
+        j: 9.0,
+        n: -2.2888160778447E-04,
+    },
+    IjnRegionPoint {
+        i: 5.0,
+        j: 10.0,
+        n: -2.6456501482810E-03,
+    },
+];
+
 pub static REGION_3_N1: &NRegionPoint = &NRegionPoint {
     n: 1.0658070028513E+00,
 };
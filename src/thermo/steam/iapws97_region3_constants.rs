@@ -0,0 +1,4899 @@
+// IAPWS SR5-05(2016), Revised Supplementary Release on Backward Equations for Specific Volume
+// as a Function of Pressure and Temperature v(p,T) for Region 3 of the IAPWS Industrial
+// Formulation 1997 for the Thermodynamic Properties of Water and Steam
+use crate::thermo::steam::iapws97_constants::IjnRegionPoint;
+
+pub struct InRegionPoint {
+    pub i: f64,
+    pub n: f64,
+}
+
+/// Reducing parameters and coefficients of one region 3 sub-region backward equation
+pub struct Region3Subregion {
+    // m3/kg
+    pub v_star: f64,
+    // MPa
+    pub p_star: f64,
+    // K
+    pub t_star: f64,
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    // ln(v/v*) is given by the sum instead of (v/v*)^(1/e)
+    pub exponential: bool,
+    pub points: &'static [IjnRegionPoint],
+}
+
+pub static BOUNDARY_3AB: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 1.54793642129415E+3,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: -1.87661219490113E+2,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 2.13144632222113E+1,
+    },
+    InRegionPoint {
+        i: -1.0,
+        n: -1.91887498864292E+3,
+    },
+    InRegionPoint {
+        i: -2.0,
+        n: 9.18419702359447E+2,
+    },
+];
+
+pub static BOUNDARY_3CD: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.85276966696349E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 2.78233532206915,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: -1.27283549295878E-2,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: 1.59090746562729E-4,
+    },
+];
+
+pub static BOUNDARY_3GH: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: -2.49284240900418E+4,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 4.28143584791546E+3,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: -2.69029173140130E+2,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: 7.51608051114157,
+    },
+    InRegionPoint {
+        i: 4.0,
+        n: -7.87105249910383E-2,
+    },
+];
+
+pub static BOUNDARY_3IJ: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.84814781649163E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: -6.16179320924617E-1,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 2.60763050899562E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: -5.87071076864459E-3,
+    },
+    InRegionPoint {
+        i: 4.0,
+        n: 5.15308185433082E-5,
+    },
+];
+
+pub static BOUNDARY_3JK: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 6.17229772068439E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: -7.70600270141675,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 6.97072596851896E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: -1.57391839848015E-2,
+    },
+    InRegionPoint {
+        i: 4.0,
+        n: 1.37897492684194E-4,
+    },
+];
+
+pub static BOUNDARY_3MN: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.35339483742384E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 7.61978122720128,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: -1.58365725441648E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: 1.92871054508108E-3,
+    },
+];
+
+pub static BOUNDARY_3OP: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 9.69461372400213E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: -3.32500170441278E+2,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 6.42859598466067E+1,
+    },
+    InRegionPoint {
+        i: -1.0,
+        n: 7.73845935768222E+2,
+    },
+    InRegionPoint {
+        i: -2.0,
+        n: -1.52313732937084E+3,
+    },
+];
+
+pub static BOUNDARY_3QU: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.65603648239126E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 5.29062258221222,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: -1.02020639611016E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: 1.22240301070145E-3,
+    },
+];
+
+pub static BOUNDARY_3RX: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.84561202520006E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: -1.02961025163669,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 2.43293362700452E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: -2.94905044740799E-3,
+    },
+];
+
+pub static BOUNDARY_3UV: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 5.28199646263062E+2,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 8.90579602135307,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: -2.22814134903755E-1,
+    },
+    InRegionPoint {
+        i: 3.0,
+        n: 2.86791682263697E-3,
+    },
+];
+
+pub static BOUNDARY_3WX: &[InRegionPoint] = &[
+    InRegionPoint {
+        i: 0.0,
+        n: 7.28052609145380,
+    },
+    InRegionPoint {
+        i: 1.0,
+        n: 9.73505869861952E+1,
+    },
+    InRegionPoint {
+        i: 2.0,
+        n: 1.47370491183191E+1,
+    },
+    InRegionPoint {
+        i: -1.0,
+        n: 3.29196213998375E+2,
+    },
+    InRegionPoint {
+        i: -2.0,
+        n: 8.73371668682417E+2,
+    },
+];
+
+pub static REGION_3A: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0024,
+    p_star: 100.0,
+    t_star: 760.0,
+    a: 0.085,
+    b: 0.817,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 5.0,
+            n: 1.10879558823853E-3,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 10.0,
+            n: 5.72616740810616E+2,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: -7.67051948380852E+4,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 5.0,
+            n: -2.53321069529674E-2,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: 6.28008049345689E+3,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 12.0,
+            n: 2.34105654131876E+5,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 5.0,
+            n: 2.16867826045856E-1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: -1.56237904341963E+2,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: -2.69893956176613E+4,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 1.0,
+            n: -1.80407100085505E-4,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 1.0,
+            n: 1.16732227668261E-3,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 5.0,
+            n: 2.66987040856040E+1,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 10.0,
+            n: 2.82776617243286E+4,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 8.0,
+            n: -2.42431520029523E+3,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 0.0,
+            n: 4.35217323022733E-4,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 1.0,
+            n: -1.22494831387441E-2,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: 1.79357604019989,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 6.0,
+            n: 4.42729521058314E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -5.93223489018342E-3,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: 4.53186261685774E-1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 3.0,
+            n: 1.35825703129140,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 4.08748415856745E-2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 4.74686397863312E-1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 1.18646814997915,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 5.46987265727549E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.95266770452643E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -5.02268790869663E-2,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: -3.69645308193377E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 6.33828037528420E-3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 2.0,
+            n: 7.97441793901017E-2,
+        },
+    ],
+};
+
+pub static REGION_3B: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0041,
+    p_star: 100.0,
+    t_star: 860.0,
+    a: 0.28,
+    b: 0.779,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 10.0,
+            n: -8.27670470003621E-2,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: 4.16887126010565E+1,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: 4.83651982197059E-2,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: -2.91032084950276E+4,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: -1.11422582236948E+2,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 5.0,
+            n: -2.02300083904014E-2,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: 2.94002509338515E+2,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: 1.40244997609658E+2,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 5.0,
+            n: -3.44384158811459E+2,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 8.0,
+            n: 3.61182452612149E+2,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 10.0,
+            n: -1.40699677420738E+3,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 2.0,
+            n: -2.02023902676481E-3,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 4.0,
+            n: 1.71346792457471E+2,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 5.0,
+            n: -4.25597804058632,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 0.0,
+            n: 6.91346085000334E-6,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 1.0,
+            n: 1.51140509678925E-3,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 2.0,
+            n: -4.16375290166236E-2,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: -4.13754957011042E+1,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 5.0,
+            n: -5.06673295721637E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -5.72212965569023E-4,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: 6.08817368401785,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 5.0,
+            n: 2.39600660256161E+1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 1.22261479925384E-2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 2.16356057692938,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 3.98198903368642E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: -1.16892827834085E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -1.02845919373532E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: -4.92676637589284E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 6.55540456406790E-2,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 2.0,
+            n: -2.40462535078530E-1,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 0.0,
+            n: -2.69798180310075E-2,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 1.0,
+            n: 1.28369435967012E-1,
+        },
+    ],
+};
+
+pub static REGION_3C: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0022,
+    p_star: 40.0,
+    t_star: 690.0,
+    a: 0.259,
+    b: 0.903,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 6.0,
+            n: 3.11967788763030,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 8.0,
+            n: 2.76713458847564E+4,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 10.0,
+            n: 3.22583103403269E+7,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 6.0,
+            n: -3.42416065095363E+2,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: -8.99732529907377E+5,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: -7.93892049821251E+7,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 5.0,
+            n: 9.53193003217388E+1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 6.0,
+            n: 2.29784742345072E+3,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 7.0,
+            n: 1.75336675322499E+5,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: 7.91214365222792E+6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 1.0,
+            n: 3.19933345844209E-5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 4.0,
+            n: -6.59508863555767E+1,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 7.0,
+            n: -8.33426563212851E+5,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 2.0,
+            n: 6.45734680583292E-2,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 8.0,
+            n: -3.82031020570813E+6,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 0.0,
+            n: 4.06398848470079E-5,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: 3.10327498492008E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -8.92996718483724E-4,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 4.0,
+            n: 2.34604891591616E+2,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 5.0,
+            n: 3.77515668966951E+3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 1.58646812591361E-2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 7.07906336241843E-1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 1.26016225146570E+1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 7.36143655772152E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 6.76544268999101E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: -1.78100588189137E+1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -1.56531975531713E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: 1.17707430048158E+1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 8.40143653860447E-2,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 1.0,
+            n: -1.86442467471949E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 3.0,
+            n: -4.40170203949645E+1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 7.0,
+            n: 1.23290423502494E+6,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: -2.40650039730845E-2,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 7.0,
+            n: -1.07077716660869E+6,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 1.0,
+            n: 4.38319858566475E-2,
+        },
+    ],
+};
+
+pub static REGION_3D: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0029,
+    p_star: 40.0,
+    t_star: 690.0,
+    a: 0.559,
+    b: 0.939,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 4.0,
+            n: -4.52484847171645E-10,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 6.0,
+            n: 3.15210389538801E-5,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 7.0,
+            n: -2.14991352047545E-3,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 10.0,
+            n: 5.08058874808345E+2,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: -1.27123036845932E+7,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 16.0,
+            n: 1.15371133120497E+12,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 0.0,
+            n: -1.97805728776273E-16,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 2.0,
+            n: 2.41554806033972E-11,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 4.0,
+            n: -1.56481703640525E-6,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 6.0,
+            n: 2.77211346836625E-3,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: -2.03578994462286E+1,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: 1.44369489909053E+6,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: -4.11254217946539E+10,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 3.0,
+            n: 6.23449786243773E-6,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 7.0,
+            n: -2.21774281146038E+1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: -6.89315087933158E+4,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: -1.95419525060713E+7,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: 3.16373510564015E+3,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: 2.24040754426988E+6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 1.0,
+            n: -4.36701347922356E-6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 2.0,
+            n: -4.04213852833996E-4,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 5.0,
+            n: -3.48153203414663E+2,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 7.0,
+            n: -3.85294213555289E+5,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 0.0,
+            n: 1.35203700099403E-7,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 1.0,
+            n: 1.34648383271089E-4,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 7.0,
+            n: 1.25031835351736E+5,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 2.0,
+            n: 9.68123678455841E-2,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 4.0,
+            n: 2.25660517512438E+2,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -1.90102435341872E-4,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 1.0,
+            n: -2.99628410819229E-2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 5.00833915372121E-3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 3.87842482998411E-1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 5.0,
+            n: -1.38535367777182E+3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 8.70745245971773E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: 1.71946252068742,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -3.26650121426383E-2,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 6.0,
+            n: 4.98044171727877E+3,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: 5.51478022765087E-3,
+        },
+    ],
+};
+
+pub static REGION_3E: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0032,
+    p_star: 40.0,
+    t_star: 710.0,
+    a: 0.587,
+    b: 0.918,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 14.0,
+            n: 7.15815808404721E+8,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 16.0,
+            n: -1.14328360753449E+11,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 3.0,
+            n: 3.76531002015720E-12,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 6.0,
+            n: -9.03983668691157E-5,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: 6.65695908836252E+5,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: 5.35364174960127E+9,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 16.0,
+            n: 7.94977402335603E+10,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 7.0,
+            n: 9.22230563421437E+1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: -1.42586073991215E+5,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: -1.11796381424162E+6,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: 8.96121629640760E+3,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 6.0,
+            n: -6.69989239070491E+3,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 2.0,
+            n: 4.51242538486834E-3,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 4.0,
+            n: -3.39731325977713E+1,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 2.0,
+            n: -1.20523111552278,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 6.0,
+            n: 4.75992667717124E+4,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 7.0,
+            n: -2.66627750390341E+5,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -1.53314954386524E-4,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 1.0,
+            n: 3.05638404828265E-1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 3.0,
+            n: 1.23654999499486E+2,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 4.0,
+            n: -1.04390794213011E+3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: -1.57496516174308E-2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 6.85331118940253E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.78373462873903,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -5.44674124878910E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 4.0,
+            n: 2.04529931318843E+3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 6.0,
+            n: -2.28342359328752E+4,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 4.13197481515899E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 2.0,
+            n: -3.41931835910405E+1,
+        },
+    ],
+};
+
+pub static REGION_3F: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0064,
+    p_star: 40.0,
+    t_star: 730.0,
+    a: 0.587,
+    b: 0.891,
+    c: 0.5,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -3.0,
+            n: -2.51756547792325E-8,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -2.0,
+            n: 6.01307193668763E-6,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -1.00615977450049E-3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 9.99969140252192E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 2.14107759236486,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: -1.65175571959086E+1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -1.0,
+            n: -1.41987303638727E-3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: 2.69251915156554,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: 3.49741815858722E+1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 3.0,
+            n: -3.00208695771783E+1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: -1.31546288252539,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 1.0,
+            n: -8.39091277286169,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -5.0,
+            n: 1.81545608337015E-10,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -2.0,
+            n: -5.91099206478909E-4,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: 1.52115067087106,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -3.0,
+            n: 2.52956470663225E-5,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -8.0,
+            n: 1.00726265203786E-15,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 1.0,
+            n: -1.49774533860650,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -6.0,
+            n: -7.93940970562969E-10,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -4.0,
+            n: -1.50290891264717E-4,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 1.0,
+            n: 1.51205531275133,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -6.0,
+            n: 4.70942606221652E-6,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -10.0,
+            n: 1.95049710391712E-13,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -8.0,
+            n: -9.11627886266077E-9,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -4.0,
+            n: 6.04374640201265E-4,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: -2.25132933900136E-16,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: 6.10916973582981E-12,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: -3.03063908043404E-7,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -6.0,
+            n: -1.37796070798409E-5,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -4.0,
+            n: -9.19296736666106E-4,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: -10.0,
+            n: 6.39288223132545E-10,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: -8.0,
+            n: 7.53259479898699E-7,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -12.0,
+            n: -4.00321478682929E-13,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -10.0,
+            n: 7.56140294351614E-9,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -12.0,
+            n: -9.12082054034891E-12,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -10.0,
+            n: -2.37612381140539E-8,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -6.0,
+            n: 2.69586010591874E-5,
+        },
+        IjnRegionPoint {
+            i: 22.0,
+            j: -12.0,
+            n: -7.32828135157839E-11,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -12.0,
+            n: 2.41995578306660E-10,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -4.0,
+            n: -4.05735532730322E-4,
+        },
+        IjnRegionPoint {
+            i: 28.0,
+            j: -12.0,
+            n: 1.89424143498011E-10,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: -12.0,
+            n: -4.86632965074563E-10,
+        },
+    ],
+};
+
+pub static REGION_3G: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0027,
+    p_star: 25.0,
+    t_star: 660.0,
+    a: 0.872,
+    b: 0.971,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 7.0,
+            n: 4.12209020652996E-5,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: -1.14987238280587E+6,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 14.0,
+            n: 9.48180885032080E+9,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 18.0,
+            n: -1.95788865718971E+17,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 22.0,
+            n: 4.96250704871300E+24,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 24.0,
+            n: -1.05549884548496E+28,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: -7.58642165988278E+11,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 20.0,
+            n: -9.22172769596101E+22,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 24.0,
+            n: 7.25379072059348E+29,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 7.0,
+            n: -6.17718249205859E+1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: 1.07555033344858E+4,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: -3.79545802336487E+7,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 12.0,
+            n: 2.28646846221831E+11,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -4.99741093010619E+6,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 22.0,
+            n: -2.80214310054101E+30,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 7.0,
+            n: 1.04915406769586E+6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 20.0,
+            n: 6.13754229168619E+27,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 22.0,
+            n: 8.02056715528378E+31,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 7.0,
+            n: -2.98617819828065E+7,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 3.0,
+            n: -9.10782540134681E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 5.0,
+            n: 1.35033227281565E+5,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 14.0,
+            n: -7.12949383408211E+18,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 24.0,
+            n: -1.04578785289542E+36,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 3.04331584444093E+1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 8.0,
+            n: 5.93250797959445E+9,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 18.0,
+            n: -3.64174062110798E+27,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 9.21791403532461E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: -3.37693609657471E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: -7.24644143758508E+1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -1.10480239272601E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: 5.36516031875059,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 3.0,
+            n: -2.91441872156205E+3,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 24.0,
+            n: 6.16338176535305E+39,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 22.0,
+            n: -1.20889175861180E+38,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 12.0,
+            n: 8.18396024524612E+22,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 3.0,
+            n: 9.40781944835829E+8,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 0.0,
+            n: -3.67279669545448E+4,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 6.0,
+            n: -8.37513931798655E+15,
+        },
+    ],
+};
+
+pub static REGION_3H: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0032,
+    p_star: 25.0,
+    t_star: 660.0,
+    a: 0.898,
+    b: 0.983,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 8.0,
+            n: 5.61379678887577E-2,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: 7.74135421587083E+9,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 4.0,
+            n: 1.11482975877938E-9,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 6.0,
+            n: -1.43987128208183E-3,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: 1.93696558764920E+3,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: -6.05971823585005E+8,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: 1.71951568124337E+13,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 16.0,
+            n: -1.85461154985145E+16,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 0.0,
+            n: 3.87851168078010E-17,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 1.0,
+            n: -3.95464327846105E-14,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 6.0,
+            n: -1.70875935679023E+2,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 7.0,
+            n: -2.12010620701220E+3,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: 1.77683337348191E+7,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 4.0,
+            n: 1.10177443629575E+1,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: -2.34396091693313E+5,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -6.56174421999594E+6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 2.0,
+            n: 1.56362212977396E-5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 3.0,
+            n: -2.12946257021400,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 4.0,
+            n: 1.35249306374858E+1,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 2.0,
+            n: 1.77189164145813E-1,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 4.0,
+            n: 1.39499167345464E+3,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 1.0,
+            n: -7.03670932036388E-3,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 2.0,
+            n: -1.52011044389648E-1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: 9.81916922991113E-5,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 1.47199658618076E-3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 2.02618487025578E+1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 8.99345518944240E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -2.11346402240858E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: 2.49971752957491E+1,
+        },
+    ],
+};
+
+pub static REGION_3I: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0041,
+    p_star: 25.0,
+    t_star: 660.0,
+    a: 0.91,
+    b: 0.984,
+    c: 0.5,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 1.06905684359136,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: -1.48620857922333,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 10.0,
+            n: 2.59862256980408E+14,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -4.0,
+            n: -4.46352055678749E-12,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -2.0,
+            n: -5.66620757170032E-7,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -1.0,
+            n: -2.35302885736849E-3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -2.69226321968839E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 9.22024992944392,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -5.0,
+            n: 3.57633505503772E-12,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: -1.73942565562222E+1,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -3.0,
+            n: 7.00681785556229E-6,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -2.0,
+            n: -2.67050351075768E-4,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -1.0,
+            n: -2.31779669675624,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -6.0,
+            n: -7.53533046979752E-13,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -1.0,
+            n: 4.81337131452891,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 12.0,
+            n: -2.23286270422356E+21,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -4.0,
+            n: -1.18746004987383E-5,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -3.0,
+            n: 6.46412934136496E-3,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -6.0,
+            n: -4.10588536330937E-10,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 10.0,
+            n: 4.22739537057241E+19,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -8.0,
+            n: 3.13698180473812E-13,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -12.0,
+            n: 1.64395334345040E-24,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -6.0,
+            n: -3.39823323754373E-6,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -4.0,
+            n: -1.35268639905021E-2,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: -7.23252514211625E-15,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: 1.84386437538366E-9,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -4.0,
+            n: -4.63959533752385E-2,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 5.0,
+            n: -9.92263100376750E+13,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -12.0,
+            n: 6.88169154439335E-17,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -10.0,
+            n: -2.22620998452197E-11,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -8.0,
+            n: -5.40843018624083E-8,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -6.0,
+            n: 3.45570606200257E-3,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: 2.0,
+            n: 4.22275800304086E+10,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -12.0,
+            n: -1.26974478770487E-15,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -10.0,
+            n: 9.27237985153679E-10,
+        },
+        IjnRegionPoint {
+            i: 22.0,
+            j: -12.0,
+            n: 6.12670812016489E-14,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -12.0,
+            n: -7.22693924063497E-12,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -8.0,
+            n: -3.83669502636822E-4,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: -10.0,
+            n: 3.74684572410204E-4,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: -5.0,
+            n: -9.31976897511086E+4,
+        },
+        IjnRegionPoint {
+            i: 36.0,
+            j: -10.0,
+            n: -2.47690616026922E-2,
+        },
+        IjnRegionPoint {
+            i: 36.0,
+            j: -8.0,
+            n: 6.58110546759474E+1,
+        },
+    ],
+};
+
+pub static REGION_3J: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0054,
+    p_star: 25.0,
+    t_star: 670.0,
+    a: 0.875,
+    b: 0.964,
+    c: 0.5,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -1.11371317395540E-4,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 1.00342892423685,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 5.30615581928979,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -2.0,
+            n: 1.79058760078792E-6,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -1.0,
+            n: -7.28541958464774E-4,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: -1.87576133371704E+1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -1.0,
+            n: 1.99060874071849E-3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 1.0,
+            n: 2.43574755377290E+1,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -2.0,
+            n: -1.77040785499444E-4,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -2.0,
+            n: -2.59680385227130E-3,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 2.0,
+            n: -1.98704578406823E+2,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -3.0,
+            n: 7.38627790224287E-5,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -2.0,
+            n: -2.36264692844138E-3,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 0.0,
+            n: -1.61023121314333,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 3.0,
+            n: 6.22322971786473E+3,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -6.0,
+            n: -9.60754116701669E-9,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -8.0,
+            n: -5.10572269720488E-11,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -3.0,
+            n: 7.67373781404211E-3,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: 6.63855469485254E-15,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: -7.17590735526745E-10,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -5.0,
+            n: 1.46564542926508E-5,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: -10.0,
+            n: 3.09029474277013E-12,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -12.0,
+            n: -4.64216300971708E-16,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -12.0,
+            n: -3.90499637961161E-14,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -10.0,
+            n: -2.36716126781431E-10,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -12.0,
+            n: 4.54652854268717E-12,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -6.0,
+            n: -4.22271787482497E-3,
+        },
+        IjnRegionPoint {
+            i: 28.0,
+            j: -12.0,
+            n: 2.83911742354706E-11,
+        },
+        IjnRegionPoint {
+            i: 28.0,
+            j: -5.0,
+            n: 2.70929002720228,
+        },
+    ],
+};
+
+pub static REGION_3K: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0077,
+    p_star: 25.0,
+    t_star: 680.0,
+    a: 0.802,
+    b: 0.935,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -2.0,
+            j: 10.0,
+            n: -4.01215699576099E+8,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 12.0,
+            n: 4.84501478318406E+10,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -5.0,
+            n: 3.94721471363678E-15,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 6.0,
+            n: 3.72629967374147E+4,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -12.0,
+            n: -3.69794374168666E-30,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -6.0,
+            n: -3.80436407012452E-15,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -2.0,
+            n: 4.75361629970233E-7,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -8.79148916140706E-4,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 8.44317863844331E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.22433162656600E+1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: -1.04529634830279E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 3.0,
+            n: 5.89702771277429E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 14.0,
+            n: -2.91026851164444E+13,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -3.0,
+            n: 1.70343072841850E-6,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -2.0,
+            n: -2.77617606975748E-4,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -3.44709605486686,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: 2.21333862447095E+1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 2.0,
+            n: -1.94646110037079E+2,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -8.0,
+            n: 8.08354639772825E-16,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -6.0,
+            n: -1.80845209145470E-11,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -3.0,
+            n: -6.96664158132412E-6,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -2.0,
+            n: -1.81057560300994E-3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 2.55830298579027,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 4.0,
+            n: 3.28913873658481E+3,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -12.0,
+            n: -1.73270241249904E-19,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -6.0,
+            n: -6.61876792558034E-7,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -3.0,
+            n: -3.95688923421250E-3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -12.0,
+            n: 6.04203299819132E-18,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -10.0,
+            n: -4.00879935920517E-14,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -8.0,
+            n: 1.60751107464958E-9,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -5.0,
+            n: 3.83719409025556E-5,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -12.0,
+            n: -6.49565446702457E-15,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -12.0,
+            n: -1.49095328506000E-12,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -10.0,
+            n: 5.41449377329581E-9,
+        },
+    ],
+};
+
+pub static REGION_3L: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0026,
+    p_star: 24.0,
+    t_star: 650.0,
+    a: 0.908,
+    b: 0.989,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 14.0,
+            n: 2.60702058647537E+9,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 16.0,
+            n: -1.88277213604704E+14,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 18.0,
+            n: 5.54923870289667E+18,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 20.0,
+            n: -7.58966946387758E+22,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 22.0,
+            n: 4.13865186848908E+26,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: -8.15038000738060E+11,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 24.0,
+            n: -3.81458260489955E+32,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 6.0,
+            n: -1.23239564600519E-2,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: 2.26095631437174E+7,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 12.0,
+            n: -4.95017809506720E+11,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: 5.29482996422863E+15,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 18.0,
+            n: -4.44359478746295E+22,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 24.0,
+            n: 5.21635864527315E+34,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 36.0,
+            n: -4.87095672740742E+54,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -7.14430209937547E+5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 4.0,
+            n: 1.27868634615495E-1,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 5.0,
+            n: -1.00752127917598E+1,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 7.0,
+            n: 7.77451437960990E+6,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 16.0,
+            n: -1.08105480796471E+24,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 1.0,
+            n: -3.57578581169659E-6,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: -2.12857169423484,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 18.0,
+            n: 2.70706111085238E+29,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 20.0,
+            n: -6.95953622348829E+32,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: 1.10609027472280E-1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 3.0,
+            n: 7.21559163361354E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 10.0,
+            n: -3.06367307532219E+14,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 2.65839618885530E-5,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 2.53392392889754E-2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 3.0,
+            n: -2.14443041836579E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 9.37846601489667E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 2.23184043101700,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: 3.38401222509191E+1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 12.0,
+            n: 4.94237237179718E+20,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -1.98068404154428E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 16.0,
+            n: -1.41415349881140E+30,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 1.0,
+            n: -9.93862421613651E+1,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 0.0,
+            n: 1.25070534142731E+2,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 0.0,
+            n: -9.96473529004439E+2,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 1.0,
+            n: 4.73137909872765E+4,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 14.0,
+            n: 1.16662121219322E+32,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 4.0,
+            n: -3.15874976271533E+15,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 12.0,
+            n: -4.45703369196945E+32,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 10.0,
+            n: 6.42794932373694E+32,
+        },
+    ],
+};
+
+pub static REGION_3M: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0028,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 1.0,
+    b: 0.997,
+    c: 1.0,
+    d: 0.25,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 8.11384363481847E-1,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: -5.68199310990094E+3,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 0.0,
+            n: -1.78657198172556E+10,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: 2.0,
+            n: 7.95537657613427E+31,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 5.0,
+            n: -8.14568209346872E+4,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 5.0,
+            n: -6.59774567602874E+7,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 5.0,
+            n: -1.52861148659302E+10,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 5.0,
+            n: -5.60165667510446E+11,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 6.0,
+            n: 4.58384828593949E+5,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 6.0,
+            n: -3.85754000383848E+13,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 7.0,
+            n: 4.53735800004273E+7,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 8.0,
+            n: 9.39454935735563E+11,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 8.0,
+            n: 2.66572856432938E+27,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 10.0,
+            n: -5.47578313899097E+9,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 10.0,
+            n: 2.00725701112386E+14,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 12.0,
+            n: 1.85007245563239E+12,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 14.0,
+            n: 1.85135446828337E+8,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 14.0,
+            n: -1.70451090076385E+11,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 18.0,
+            n: 1.57890366037614E+14,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 20.0,
+            n: -2.02530509748774E+15,
+        },
+        IjnRegionPoint {
+            i: 28.0,
+            j: 20.0,
+            n: 3.68193926183570E+59,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 22.0,
+            n: 1.70215539458936E+17,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: 22.0,
+            n: 6.39234909918741E+41,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 24.0,
+            n: -8.21698160721956E+14,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 24.0,
+            n: -7.95260241872306E+23,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 28.0,
+            n: 2.33415869478510E+17,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 28.0,
+            n: -6.00079934586803E+22,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 28.0,
+            n: 5.94584382273384E+24,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: 28.0,
+            n: 1.89461279349492E+39,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: 28.0,
+            n: -8.10093428842645E+45,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 32.0,
+            n: 1.88813911076809E+21,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 32.0,
+            n: 1.11052244098768E+35,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 32.0,
+            n: 2.91133958602503E+45,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 36.0,
+            n: -3.29421923951460E+21,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 36.0,
+            n: -1.37570282536696E+25,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 36.0,
+            n: 1.81508996303902E+27,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 36.0,
+            n: -3.46865122768353E+29,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 36.0,
+            n: -2.11961148774260E+37,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 36.0,
+            n: -1.28617899887675E+48,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: 36.0,
+            n: 4.79817895699239E+64,
+        },
+    ],
+};
+
+pub static REGION_3N: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0031,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.976,
+    b: 0.997,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: true,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -12.0,
+            n: 2.80967799943151E-39,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -12.0,
+            n: 6.14869006573609E-31,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -12.0,
+            n: 5.82238667048942E-28,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -12.0,
+            n: 3.90628369238462E-23,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -12.0,
+            n: 8.21445758255119E-21,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -12.0,
+            n: 4.02137961842776E-15,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -12.0,
+            n: 6.51718171878301E-13,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: -2.11773355803058E-8,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -12.0,
+            n: 2.64953354380072E-3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -10.0,
+            n: -1.35031446451331E-32,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -10.0,
+            n: -6.07246643970893E-24,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -10.0,
+            n: -4.02352115234494E-19,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -10.0,
+            n: -7.44938506925544E-17,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -10.0,
+            n: 1.89917206526237E-13,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -10.0,
+            n: 3.64975183508473E-6,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -8.0,
+            n: 1.77274872361946E-26,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -8.0,
+            n: -3.34952758812999E-19,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -8.0,
+            n: -4.21537726098389E-9,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -8.0,
+            n: -3.91048167929649E-2,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -6.0,
+            n: 5.41276911564176E-14,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -6.0,
+            n: 7.05412100773699E-12,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -6.0,
+            n: 2.58585887897486E-9,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -5.0,
+            n: -4.93111362030162E-11,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -5.0,
+            n: -1.58649699894543E-6,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -5.0,
+            n: -5.25037427886100E-1,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -4.0,
+            n: 2.20019901729615E-3,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -3.0,
+            n: -6.43064132636925E-3,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -3.0,
+            n: 6.29154149015048E+1,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -3.0,
+            n: 1.35147318617061E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -2.0,
+            n: 2.40560808321713E-7,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -8.90763306701305E-4,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -1.0,
+            n: -4.40209599407714E+3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -3.02807107747776E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.59158748314599E+3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: 2.32534272709876E+5,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: -7.92681207132600E+5,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 4.0,
+            n: -8.69871364662769E+10,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 5.0,
+            n: 3.54542769185671E+11,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 6.0,
+            n: 4.00849240129329E+14,
+        },
+    ],
+};
+
+pub static REGION_3O: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0034,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.974,
+    b: 0.996,
+    c: 0.5,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -12.0,
+            n: 1.28746023979718E-35,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -4.0,
+            n: -7.35234770382342E-12,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: 2.89078692149150E-3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -1.0,
+            n: 2.44482731907223E-1,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -10.0,
+            n: 1.41733492030985E-24,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -12.0,
+            n: -3.54533853059476E-29,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -8.0,
+            n: -5.94539202901431E-18,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -5.0,
+            n: -5.85188401782779E-9,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -4.0,
+            n: 2.01377325411803E-6,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -1.0,
+            n: 1.38647388209306,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -4.0,
+            n: -1.73959365084772E-5,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -3.0,
+            n: 1.37680878349369E-3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -8.0,
+            n: 8.14897605805513E-15,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -12.0,
+            n: 4.25596631351839E-26,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -10.0,
+            n: -3.87449113787755E-18,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -8.0,
+            n: 1.39814747930240E-13,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -4.0,
+            n: -1.71849638951521E-3,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -12.0,
+            n: 6.41890529513296E-22,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -8.0,
+            n: 1.18960578072018E-11,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: -1.55282762571611E-18,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: 2.33907907347507E-8,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -12.0,
+            n: -1.74093247766213E-13,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -10.0,
+            n: 3.77682649089149E-9,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -12.0,
+            n: -5.16720236575302E-11,
+        },
+    ],
+};
+
+pub static REGION_3P: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0041,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.972,
+    b: 0.997,
+    c: 0.5,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -9.82825342010366E-5,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 1.05145700850612,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.16033094095084E+2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 2.0,
+            n: 3.24664750281543E+3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: -1.23592348610137E+3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -1.0,
+            n: -5.61403450013495E-2,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -3.0,
+            n: 8.56677401640869E-8,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: 2.36313425393924E+2,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -2.0,
+            n: 9.72503292350109E-3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -2.0,
+            n: -1.03001994531927,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -5.0,
+            n: -1.49653706199162E-9,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: -4.0,
+            n: -2.15743778861592E-5,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -2.0,
+            n: -8.34452198291445,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -3.0,
+            n: 5.86602660564988E-1,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -12.0,
+            n: 3.43480022104968E-26,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -6.0,
+            n: 8.16256095947021E-6,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -5.0,
+            n: 2.94985697916798E-3,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: 7.11730466276584E-17,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: 4.00954763806941E-10,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -3.0,
+            n: 1.07766027032853E+1,
+        },
+        IjnRegionPoint {
+            i: 16.0,
+            j: -8.0,
+            n: -4.09449599138182E-7,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: -8.0,
+            n: -7.29121307758902E-6,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: -10.0,
+            n: 6.77107970938909E-9,
+        },
+        IjnRegionPoint {
+            i: 22.0,
+            j: -10.0,
+            n: 6.02745973022975E-8,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -12.0,
+            n: -3.82323011855257E-11,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: -8.0,
+            n: 1.79946628317437E-3,
+        },
+        IjnRegionPoint {
+            i: 36.0,
+            j: -12.0,
+            n: -3.45042834640005E-4,
+        },
+    ],
+};
+
+pub static REGION_3Q: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0022,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.848,
+    b: 0.983,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 10.0,
+            n: -8.20433843259950E+4,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 12.0,
+            n: 4.73271518461586E+10,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 6.0,
+            n: -8.05950021005413E-2,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 7.0,
+            n: 3.28600025435980E+1,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: -3.56617029982490E+3,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: -1.72985781433335E+9,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: 3.51769232729192E+7,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: -7.75489259985144E+5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 2.0,
+            n: 7.10346691966018E-5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 5.0,
+            n: 9.93499883820274E+4,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 3.0,
+            n: -6.42094171904570E-1,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 4.0,
+            n: -6.12842816820083E+3,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: 2.32808472983776E+2,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 0.0,
+            n: -1.42808220416837E-5,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 1.0,
+            n: -6.43596060678456E-3,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: -4.28577227475614,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 4.0,
+            n: 2.25689939161918E+3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 1.00355651721510E-3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 3.33491455143516E-1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 1.09697576888873,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 9.61917379376452E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -8.38165632204598E-2,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: 2.47795908411492,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 3.0,
+            n: -3.19114969006533E+3,
+        },
+    ],
+};
+
+pub static REGION_3R: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0054,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.874,
+    b: 0.982,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -8.0,
+            j: 6.0,
+            n: 1.44165955660863E-3,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: -7.01438599628258E+12,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: -3.0,
+            n: -8.30946716459219E-17,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: 2.61975135368109E-1,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 4.0,
+            n: 3.93097214706245E+2,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 5.0,
+            n: -1.04334030654021E+4,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 8.0,
+            n: 4.90112654154211E+8,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -1.0,
+            n: -1.47104222772069E-4,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 1.03602748043408,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 3.05308890065089,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 5.0,
+            n: -3.99745276971264E+6,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -6.0,
+            n: 5.69233719593750E-12,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -2.0,
+            n: -4.64923504407778E-2,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -12.0,
+            n: -5.35400396512906E-18,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -10.0,
+            n: 3.99988795693162E-13,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -8.0,
+            n: -5.36479560201811E-7,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -5.0,
+            n: 1.59536722411202E-2,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -12.0,
+            n: 2.70303248860217E-15,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -10.0,
+            n: 2.44247453858506E-8,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -8.0,
+            n: -9.83430636716454E-6,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -6.0,
+            n: 6.63513144224454E-2,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -5.0,
+            n: -9.93456957845006,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -4.0,
+            n: 5.46491323528491E+2,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -3.0,
+            n: -1.43365406393758E+4,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -2.0,
+            n: 1.50764974125511E+5,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -12.0,
+            n: -3.37209709340105E-10,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: 3.77501980025469E-9,
+        },
+    ],
+};
+
+pub static REGION_3S: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0022,
+    p_star: 21.0,
+    t_star: 640.0,
+    a: 0.886,
+    b: 0.99,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 20.0,
+            n: -5.32466612140254E+22,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 24.0,
+            n: 1.00415480000824E+31,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 22.0,
+            n: -1.91540001821367E+29,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: 1.05618377808847E+16,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 36.0,
+            n: 2.02281884477061E+58,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 8.0,
+            n: 8.84585472596134E+7,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 16.0,
+            n: 1.66540181638363E+22,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 6.0,
+            n: -3.13563197669111E+5,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 32.0,
+            n: -1.85662327545324E+53,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: -6.24942093918942E-2,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 8.0,
+            n: -5.04160724132590E+9,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 4.0,
+            n: 1.87514491833092E+4,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: 1.21399979993217E-3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 2.0,
+            n: 1.88317043049455,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 3.0,
+            n: -1.67073503962060E+3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 9.65961650599775E-1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 2.94885696802488,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 4.0,
+            n: -6.53915627346115E+4,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 28.0,
+            n: 6.04012200163444E+49,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -1.98339358557937E-1,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 32.0,
+            n: -1.75984090163501E+57,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: 3.56314881403987,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 1.0,
+            n: -5.75991255144384E+2,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 2.0,
+            n: 4.56213415338071E+4,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 3.0,
+            n: -1.09174044987829E+7,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 18.0,
+            n: 4.37796099975134E+33,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 24.0,
+            n: -6.16552611135792E+45,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 4.0,
+            n: 1.93568768917797E+9,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 24.0,
+            n: 9.50898170425042E+53,
+        },
+    ],
+};
+
+pub static REGION_3T: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0088,
+    p_star: 20.0,
+    t_star: 650.0,
+    a: 0.803,
+    b: 1.02,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 1.55287249586268,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 6.64235115009031,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 4.0,
+            n: -2.89366236727210E+3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 12.0,
+            n: -3.85923202309848E+12,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 0.0,
+            n: -2.91002915783761,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 10.0,
+            n: -8.29088246858083E+11,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 0.0,
+            n: 1.76814899675218,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 6.0,
+            n: -5.34686695713469E+8,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 14.0,
+            n: 1.60464608687834E+17,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 3.0,
+            n: 1.96435366560186E+5,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 8.0,
+            n: 1.56637427541729E+12,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 0.0,
+            n: -1.78154560260006,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 10.0,
+            n: -2.29746237623692E+15,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 3.0,
+            n: 3.85659001648006E+7,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 4.0,
+            n: 1.10554446790543E+9,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 7.0,
+            n: -6.77073830687349E+13,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 20.0,
+            n: -3.27910592086523E+30,
+        },
+        IjnRegionPoint {
+            i: 7.0,
+            j: 36.0,
+            n: -3.41552040860644E+50,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 10.0,
+            n: -5.27251339709047E+20,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 12.0,
+            n: 2.45375640937055E+23,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 14.0,
+            n: -1.68776617209269E+26,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 16.0,
+            n: 3.58958955867578E+28,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: 22.0,
+            n: -6.56475280339411E+35,
+        },
+        IjnRegionPoint {
+            i: 18.0,
+            j: 18.0,
+            n: 3.55286045512301E+38,
+        },
+        IjnRegionPoint {
+            i: 20.0,
+            j: 32.0,
+            n: 5.69021454413270E+57,
+        },
+        IjnRegionPoint {
+            i: 22.0,
+            j: 22.0,
+            n: -7.00584546433113E+47,
+        },
+        IjnRegionPoint {
+            i: 22.0,
+            j: 36.0,
+            n: -7.05772623326374E+64,
+        },
+        IjnRegionPoint {
+            i: 24.0,
+            j: 24.0,
+            n: 1.66861176200148E+52,
+        },
+        IjnRegionPoint {
+            i: 28.0,
+            j: 28.0,
+            n: -3.00475129680486E+60,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: 22.0,
+            n: -6.68481295196808E+50,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: 32.0,
+            n: 4.28432338620678E+68,
+        },
+        IjnRegionPoint {
+            i: 32.0,
+            j: 36.0,
+            n: -4.44227367758304E+71,
+        },
+        IjnRegionPoint {
+            i: 36.0,
+            j: 36.0,
+            n: -2.81396013562745E+76,
+        },
+    ],
+};
+
+pub static REGION_3U: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0026,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.902,
+    b: 0.988,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 14.0,
+            n: 1.22088349258355E+17,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 10.0,
+            n: 1.04216468608488E+9,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 12.0,
+            n: -8.82666931564652E+15,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 14.0,
+            n: 2.59929510849499E+19,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 10.0,
+            n: 2.22612779142211E+14,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 12.0,
+            n: -8.78473585050085E+17,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: -3.14432577551552E+21,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -2.16934916996285E+12,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 12.0,
+            n: 1.59079648196849E+20,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 4.0,
+            n: -3.39567617303423E+2,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 8.0,
+            n: 8.84387651337836E+12,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 12.0,
+            n: -8.43405926846418E+20,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 2.0,
+            n: 1.14178193518022E+1,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -1.0,
+            n: -1.22708229235641E-4,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: -1.06201671767107E+2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 12.0,
+            n: 9.03443213959313E+24,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 14.0,
+            n: -6.93996270370852E+27,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -3.0,
+            n: 6.48916718965575E-9,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 7.18957567127851E+3,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -2.0,
+            n: 1.05581745346187E-3,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 5.0,
+            n: -6.51903203602581E+14,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 10.0,
+            n: -1.60116813274676E+24,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -5.0,
+            n: -5.10254294237837E-9,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -4.0,
+            n: -1.52355388953402E-1,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 2.0,
+            n: 6.77143292290144E+11,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 3.0,
+            n: 2.76378438378930E+14,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -5.0,
+            n: 1.16862983141686E-2,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 2.0,
+            n: -3.01426947980171E+13,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -8.0,
+            n: 1.69719813884840E-8,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 8.0,
+            n: 1.04674840020929E+26,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -4.0,
+            n: -1.08016904560140E+4,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -12.0,
+            n: -9.90623601934295E-13,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -4.0,
+            n: 5.36116483602738E+6,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: 4.0,
+            n: 2.26145963747881E+21,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: -4.88731565776210E-10,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: 1.51001548880670E-5,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -6.0,
+            n: -2.27700464643920E+4,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 6.0,
+            n: -7.81754507698846E+27,
+        },
+    ],
+};
+
+pub static REGION_3V: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0031,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.96,
+    b: 0.995,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -10.0,
+            j: -8.0,
+            n: -4.15652812061591E-55,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: -12.0,
+            n: 1.77441742924043E-61,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: -12.0,
+            n: -3.57078668203377E-55,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: -3.0,
+            n: 3.59252213604114E-26,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 5.0,
+            n: -2.59123736380269E+1,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: 5.94619766193460E+4,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -6.24184007103158E+10,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 10.0,
+            n: 3.13080299915944E+16,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 1.0,
+            n: 1.05006446192036E-9,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 2.0,
+            n: -1.92824336984852E-6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 6.0,
+            n: 6.54144373749937E+5,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 8.0,
+            n: 5.13117462865044E+12,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 10.0,
+            n: -6.97595750347391E+18,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 14.0,
+            n: -1.03977184454767E+28,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: -12.0,
+            n: 1.19563135540666E-48,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: -10.0,
+            n: -4.36677034051655E-42,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: -6.0,
+            n: 9.26990036530639E-30,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 10.0,
+            n: 5.87793105620748E+20,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: -3.0,
+            n: 2.80375725094731E-18,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 10.0,
+            n: -1.92359972440634E+22,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 12.0,
+            n: 7.42705723302738E+26,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: -5.17429682450605E+1,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 4.0,
+            n: 8.20612048645469E+6,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -2.0,
+            n: -1.88214882341448E-9,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 0.0,
+            n: 1.84587261114837E-2,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -2.0,
+            n: -1.35830407782663E-6,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 6.0,
+            n: -7.23681885626348E+16,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 10.0,
+            n: -2.23449194054124E+26,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -12.0,
+            n: -1.11526741826431E-35,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -10.0,
+            n: 2.76032601145151E-29,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 3.0,
+            n: 1.34856491567853E+14,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -6.0,
+            n: 6.52440293345860E-10,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 3.0,
+            n: 5.10655119774360E+16,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 10.0,
+            n: -4.68138358908732E+31,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 2.0,
+            n: -7.60667491183279E+15,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -12.0,
+            n: -4.17247986986821E-19,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -2.0,
+            n: 3.12545677756104E+13,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -3.0,
+            n: -1.00375333864186E+14,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: 1.0,
+            n: 2.47761392329058E+26,
+        },
+    ],
+};
+
+pub static REGION_3W: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0039,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.959,
+    b: 0.995,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -12.0,
+            j: 8.0,
+            n: -5.86219133817016E-8,
+        },
+        IjnRegionPoint {
+            i: -12.0,
+            j: 14.0,
+            n: -8.94460355005526E+10,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: -1.0,
+            n: 5.31168037519774E-31,
+        },
+        IjnRegionPoint {
+            i: -10.0,
+            j: 8.0,
+            n: 1.09892402329239E-1,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 6.0,
+            n: -5.75368389425212E-2,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 8.0,
+            n: 2.28276853990249E+4,
+        },
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: -1.58548609655002E+18,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: -4.0,
+            n: 3.29865748576503E-28,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: -3.0,
+            n: -6.34987981190669E-25,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 2.0,
+            n: 6.15762068640611E-9,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 8.0,
+            n: -9.61109240985747E+7,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: -10.0,
+            n: -4.06274286652625E-45,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: -1.0,
+            n: -4.71103725498077E-13,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 3.0,
+            n: 7.25937724828145E-1,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: -10.0,
+            n: 1.87768525763682E-39,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 3.0,
+            n: -1.03308436323771E+3,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 1.0,
+            n: -6.62552816342168E-2,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: 5.79514041765710E+2,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -8.0,
+            n: 2.37416732616644E-27,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -4.0,
+            n: 2.71700235739893E-15,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 1.0,
+            n: -9.07886213483600E+1,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: -12.0,
+            n: -1.71242509570207E-37,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 1.56792067854621E+2,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -1.0,
+            n: 9.23261357901470E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -1.0,
+            n: -5.97865988422577,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 2.0,
+            n: 3.21988767636389E+6,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -12.0,
+            n: -3.99441390042203E-30,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -5.0,
+            n: 4.93429086046981E-8,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -10.0,
+            n: 8.12036983370565E-20,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -8.0,
+            n: -2.07610284654137E-12,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -6.0,
+            n: -3.40821291419719E-7,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -12.0,
+            n: 5.42000573372233E-18,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -10.0,
+            n: -8.56711586510214E-13,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -12.0,
+            n: 2.66170454405981E-14,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -8.0,
+            n: 8.58133791857099E-6,
+        },
+    ],
+};
+
+pub static REGION_3X: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0049,
+    p_star: 23.0,
+    t_star: 650.0,
+    a: 0.91,
+    b: 0.988,
+    c: 1.0,
+    d: 1.0,
+    e: 1.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -8.0,
+            j: 14.0,
+            n: 3.77373741298151E+18,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 10.0,
+            n: -5.07100883722913E+12,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 10.0,
+            n: -1.03363225598860E+15,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 1.0,
+            n: 1.84790814320773E-6,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 2.0,
+            n: -9.24729378390945E-4,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 14.0,
+            n: -4.25999562292738E+23,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: -2.0,
+            n: -4.62307771873973E-13,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 12.0,
+            n: 1.07319065855767E+21,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: 5.0,
+            n: 6.48662492280682E+10,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 0.0,
+            n: 2.44200600688281,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 4.0,
+            n: -8.51535733484258E+9,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 10.0,
+            n: 1.69894481433592E+21,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -10.0,
+            n: 2.15780222509020E-27,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: -1.0,
+            n: -3.20850551367334E-1,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 6.0,
+            n: -3.82642448458610E+16,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -12.0,
+            n: -2.75386077674421E-29,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 0.0,
+            n: -5.63199253391666E+5,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 8.0,
+            n: -3.26068646279314E+20,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 3.0,
+            n: 3.97949001553184E+13,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -6.0,
+            n: 1.00824008584757E-7,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -2.0,
+            n: 1.62234569738433E+4,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 1.0,
+            n: -4.32355225319745E+10,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: 1.0,
+            n: -5.92874245598610E+11,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -6.0,
+            n: 1.33061647281106,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -3.0,
+            n: 1.57338197797544E+6,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 1.0,
+            n: 2.58189614270853E+13,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: 8.0,
+            n: 2.62413209706358E+24,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -8.0,
+            n: -9.20011937431142E-2,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -10.0,
+            n: 2.20213765905426E-3,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -8.0,
+            n: -1.10433759109547E+1,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -5.0,
+            n: 8.47004870612087E+6,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -4.0,
+            n: -5.92910695762536E+8,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -12.0,
+            n: -1.83027173269660E-5,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -10.0,
+            n: 1.81339603516302E-1,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -8.0,
+            n: -1.19228759669889E+3,
+        },
+        IjnRegionPoint {
+            i: 14.0,
+            j: -6.0,
+            n: 4.30867658061468E+6,
+        },
+    ],
+};
+
+pub static REGION_3Y: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0031,
+    p_star: 22.0,
+    t_star: 650.0,
+    a: 0.996,
+    b: 0.994,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: 0.0,
+            j: -3.0,
+            n: -5.25597995024633E-10,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 1.0,
+            n: 5.83441305228407E+3,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 5.0,
+            n: -1.34778968457925E+16,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 8.0,
+            n: 1.18973500934212E+25,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 8.0,
+            n: -1.59096490904708E+26,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -4.0,
+            n: -3.15839902302021E-7,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: -1.0,
+            n: 4.96212197158239E+2,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 4.0,
+            n: 3.27777227273171E+18,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 5.0,
+            n: -5.27114657850696E+21,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -8.0,
+            n: 2.10017506281863E-17,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 4.0,
+            n: 7.05106224399834E+20,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: 8.0,
+            n: -2.66713136106469E+30,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: -6.0,
+            n: -1.45370512554562E-8,
+        },
+        IjnRegionPoint {
+            i: 4.0,
+            j: 6.0,
+            n: 1.49333917053130E+27,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: -2.0,
+            n: -1.49795620287641E+7,
+        },
+        IjnRegionPoint {
+            i: 5.0,
+            j: 1.0,
+            n: -3.81881906271100E+15,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -8.0,
+            n: 7.24660165585797E-5,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -2.0,
+            n: -9.37808169550193E+13,
+        },
+        IjnRegionPoint {
+            i: 10.0,
+            j: -5.0,
+            n: 5.14411468376383E+9,
+        },
+        IjnRegionPoint {
+            i: 12.0,
+            j: -8.0,
+            n: -8.28198594040141E+4,
+        },
+    ],
+};
+
+pub static REGION_3Z: &Region3Subregion = &Region3Subregion {
+    v_star: 0.0038,
+    p_star: 22.0,
+    t_star: 650.0,
+    a: 0.993,
+    b: 0.994,
+    c: 1.0,
+    d: 1.0,
+    e: 4.0,
+    exponential: false,
+    points: &[
+        IjnRegionPoint {
+            i: -8.0,
+            j: 3.0,
+            n: 2.44007892290650E-11,
+        },
+        IjnRegionPoint {
+            i: -6.0,
+            j: 6.0,
+            n: -4.63057430331242E+6,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 6.0,
+            n: 7.28803274777712E+9,
+        },
+        IjnRegionPoint {
+            i: -5.0,
+            j: 8.0,
+            n: 3.27776302858856E+15,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 5.0,
+            n: -1.10598170118409E+9,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 6.0,
+            n: -3.23899915729957E+12,
+        },
+        IjnRegionPoint {
+            i: -4.0,
+            j: 8.0,
+            n: 9.23814007023245E+15,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: -2.0,
+            n: 8.42250080413712E-13,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 5.0,
+            n: 6.63221436245506E+11,
+        },
+        IjnRegionPoint {
+            i: -3.0,
+            j: 6.0,
+            n: -1.67170186672139E+14,
+        },
+        IjnRegionPoint {
+            i: -2.0,
+            j: 2.0,
+            n: 2.53749358701391E+3,
+        },
+        IjnRegionPoint {
+            i: -1.0,
+            j: -6.0,
+            n: -8.19731559610523E-21,
+        },
+        IjnRegionPoint {
+            i: 0.0,
+            j: 3.0,
+            n: 3.28380587890663E+11,
+        },
+        IjnRegionPoint {
+            i: 1.0,
+            j: 1.0,
+            n: -6.25004791171543E+7,
+        },
+        IjnRegionPoint {
+            i: 2.0,
+            j: 6.0,
+            n: 8.03197957462023E+20,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -6.0,
+            n: -2.04397011338353E-11,
+        },
+        IjnRegionPoint {
+            i: 3.0,
+            j: -2.0,
+            n: -3.78391047055938E+3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -6.0,
+            n: 9.72876545938620E-3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -5.0,
+            n: 1.54355721681459E+1,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -4.0,
+            n: -3.73962862928643E+3,
+        },
+        IjnRegionPoint {
+            i: 6.0,
+            j: -1.0,
+            n: -6.82859011374572E+10,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -8.0,
+            n: -2.48488015614543E-4,
+        },
+        IjnRegionPoint {
+            i: 8.0,
+            j: -4.0,
+            n: 3.94536049497068E+6,
+        },
+    ],
+};
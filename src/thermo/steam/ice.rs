@@ -0,0 +1,577 @@
+// IAPWS R10-06(2009) equation of state for ice Ih and
+// IAPWS R14-08(2011) melting and sublimation pressures of ordinary water substance
+use std::ops::{Add, Mul, Sub};
+
+use super::*;
+use crate::numerical_methods::root_finders::newton_method;
+use crate::thermo::steam::water_constants::*;
+
+static NORMAL_PRESSURE: Pa = Pa { value: 101325.0 };
+// Ice Ih - ice III - liquid triple point which bounds the ice Ih melting curve
+static ICE_III_TRIPLE_POINT_TEMPERATURE: K = K { value: 251.165 };
+pub static MAX_ICE_PRESSURE: Pa = Pa { value: 210e6 };
+static MIN_SUBLIMATION_TEMPERATURE: K = K { value: 50.0 };
+// Triple points where the ice III, V, VI and VII melting curves start, (K, Pa)
+static ICE_III_TRIPLE_POINT: (f64, f64) = (251.165, 208.566e6);
+static ICE_V_TRIPLE_POINT: (f64, f64) = (256.164, 350.100e6);
+static ICE_VI_TRIPLE_POINT: (f64, f64) = (273.31, 632.400e6);
+static ICE_VII_TRIPLE_POINT: (f64, f64) = (355.0, 2216.000e6);
+static MAX_ICE_VII_MELTING_TEMPERATURE: K = K { value: 715.0 };
+
+static G0: [f64; 5] = [
+    -0.632020233335886e6,
+    0.655022213658955,
+    -0.189369929326131e-7,
+    0.339746123271053e-14,
+    -0.556464869058991e-21,
+];
+// Chosen so the entropy of liquid water at the triple point is zero as in IAPWS-95
+static S0: f64 = -0.332733756492168e4;
+static T1: Complex = Complex::new(0.368017112855051e-1, 0.510878114959572e-1);
+static R1: Complex = Complex::new(0.447050716285388e2, 0.656876847463481e2);
+static T2: Complex = Complex::new(0.337315741065416, 0.335449415919309);
+static R2: [Complex; 3] = [
+    Complex::new(-0.725974574329220e2, -0.781008427112870e2),
+    Complex::new(-0.557107698030123e-4, 0.464578634580806e-4),
+    Complex::new(0.234801409215913e-10, -0.285651142904972e-10),
+];
+
+static MELTING_A: [f64; 3] = [0.119539337e7, 0.808183159e5, 0.333826860e4];
+static MELTING_B: [f64; 3] = [0.300000e1, 0.257500e2, 0.103750e3];
+static SUBLIMATION_A: [f64; 3] = [-0.212144006e2, 0.273203819e2, -0.610598130e1];
+static SUBLIMATION_B: [f64; 3] = [0.333333333e-2, 0.120666667e1, 0.170333333e1];
+
+#[derive(Debug, Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Complex {
+    const fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    fn real(re: f64) -> Complex {
+        Complex::new(re, 0f64)
+    }
+
+    fn ln(self) -> Complex {
+        Complex::new(
+            f64::ln(f64::hypot(self.re, self.im)),
+            f64::atan2(self.im, self.re),
+        )
+    }
+
+    fn inv(self) -> Complex {
+        let d = self.re * self.re + self.im * self.im;
+        Complex::new(self.re / d, -self.im / d)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: f64) -> Complex {
+        Complex::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+// Derivatives of the specific Gibbs free energy in SI units
+#[derive(Debug)]
+struct IceGibbsPoint {
+    g: f64,
+    g_t: f64,
+    g_p: f64,
+    g_tt: f64,
+    g_tp: f64,
+    g_pp: f64,
+}
+
+// The three temperature dependent bracket terms of the release for one (t, r) pair
+fn get_bracket_terms(t: Complex, tau: f64) -> (Complex, Complex, Complex) {
+    let tau_c = Complex::real(tau);
+    let minus = t - tau_c;
+    let plus = t + tau_c;
+    let a = minus * minus.ln() + plus * plus.ln()
+        - t * t.ln() * 2f64
+        - Complex::real(tau * tau) * t.inv();
+    let b = plus.ln() - minus.ln() - Complex::real(2f64 * tau) * t.inv();
+    let c = minus.inv() + plus.inv() - t.inv() * 2f64;
+    (a, b, c)
+}
+
+fn get_gibbs_point(temperature: f64, pressure: f64) -> IceGibbsPoint {
+    let tt = TRIPLE_POINT_TEMPERATURE.value;
+    let pt = TRIPLE_POINT_PRESSURE.value;
+    let tau = temperature / tt;
+    let dp = (pressure - NORMAL_PRESSURE.value) / pt;
+
+    let mut g0 = 0f64;
+    let mut g0_p = 0f64;
+    let mut g0_pp = 0f64;
+    for (k, g0k) in G0.iter().enumerate() {
+        let k = k as i32;
+        g0 += g0k * f64::powi(dp, k);
+        if k >= 1 {
+            g0_p += g0k * k as f64 * f64::powi(dp, k - 1) / pt;
+        }
+        if k >= 2 {
+            g0_pp += g0k * (k * (k - 1)) as f64 * f64::powi(dp, k - 2) / f64::powi(pt, 2);
+        }
+    }
+    let r2 = R2[0] + R2[1] * dp + R2[2] * f64::powi(dp, 2);
+    let r2_p = (R2[1] + R2[2] * (2f64 * dp)) * (1f64 / pt);
+    let r2_pp = R2[2] * (2f64 / f64::powi(pt, 2));
+
+    let (a1, b1, c1) = get_bracket_terms(T1, tau);
+    let (a2, b2, c2) = get_bracket_terms(T2, tau);
+    IceGibbsPoint {
+        g: g0 - S0 * tt * tau + tt * (R1 * a1 + r2 * a2).re,
+        g_t: -S0 + (R1 * b1 + r2 * b2).re,
+        g_p: g0_p + tt * (r2_p * a2).re,
+        g_tt: (R1 * c1 + r2 * c2).re / tt,
+        g_tp: (r2_p * b2).re,
+        g_pp: g0_pp + tt * (r2_pp * a2).re,
+    }
+}
+
+/// Ice Ih at the given temperature and pressure.
+/// The speed of sound is the bulk value from the isentropic compressibility.
+pub fn get_ice_entry(point: &PtPoint) -> Result<PtvEntry, SteamQueryErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    match (temperature, pressure) {
+        (t, _) if t <= 0f64 => Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureLow)),
+        (t, _) if t > TRIPLE_POINT_TEMPERATURE.value => {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureHigh))
+        }
+        (_, p) if p <= 0f64 => Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow)),
+        (_, p) if p > MAX_ICE_PRESSURE.value => {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh))
+        }
+        _ => Ok(create_entry_from_gibbs_point(
+            &get_gibbs_point(temperature, pressure),
+            temperature,
+            pressure,
+        )),
+    }
+}
+
+fn create_entry_from_gibbs_point(
+    point: &IceGibbsPoint,
+    temperature: f64,
+    pressure: f64,
+) -> PtvEntry {
+    let specific_volume = point.g_p;
+    let entropy = -point.g_t;
+    let cp = -temperature * point.g_tt;
+    let enthalpy = point.g + temperature * entropy;
+    let internal_energy = enthalpy - pressure * specific_volume;
+    let helmholtz_free_energy = point.g - pressure * specific_volume;
+    let isobaric_expansion = point.g_tp / point.g_p;
+    let isothermal_compressibility = -point.g_pp / point.g_p;
+    let isentropic_compressibility =
+        (f64::powi(point.g_tp, 2) - point.g_tt * point.g_pp) / (point.g_p * point.g_tt);
+    let cv = cp
+        - temperature * specific_volume * f64::powi(isobaric_expansion, 2)
+            / isothermal_compressibility;
+    let speed_of_sound = f64::sqrt(specific_volume / isentropic_compressibility);
+    let joule_thomson = specific_volume * (temperature * isobaric_expansion - 1f64) / cp;
+    let isentropic_exponent = f64::powi(speed_of_sound, 2) / (pressure * specific_volume);
+    PtvEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region: PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid),
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cv)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(cp)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(speed_of_sound)),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(point.g)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(isobaric_expansion)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(isothermal_compressibility)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(joule_thomson)),
+        isentropic_exponent,
+    }
+}
+
+/// Pressure where ice Ih and liquid water coexist
+pub fn get_melting_pressure(temperature: Temperature) -> Result<Pressure, OutOfRange> {
+    match temperature.convert_to_si_unit().value {
+        t if t < ICE_III_TRIPLE_POINT_TEMPERATURE.value => Err(OutOfRange::TemperatureLow),
+        t if t > TRIPLE_POINT_TEMPERATURE.value => Err(OutOfRange::TemperatureHigh),
+        t => {
+            let theta = t / TRIPLE_POINT_TEMPERATURE.value;
+            let pi = 1f64
+                + MELTING_A
+                    .iter()
+                    .zip(MELTING_B.iter())
+                    .map(|(a, b)| a * (1f64 - f64::powf(theta, *b)))
+                    .sum::<f64>();
+            Ok(Pressure::Pa(Pa::new(pi * TRIPLE_POINT_PRESSURE.value)))
+        }
+    }
+}
+
+/// Pressure where liquid water coexists with ice III, V, VI or VII,
+/// the highest pressure the liquid can reach at the temperature
+pub fn get_high_pressure_melting_pressure(
+    temperature: Temperature,
+) -> Result<Pressure, OutOfRange> {
+    let t = temperature.convert_to_si_unit().value;
+    let (triple_point_temperature, triple_point_pressure) = match t {
+        t if t < ICE_III_TRIPLE_POINT.0 => return Err(OutOfRange::TemperatureLow),
+        t if t <= ICE_V_TRIPLE_POINT.0 => ICE_III_TRIPLE_POINT,
+        t if t <= ICE_VI_TRIPLE_POINT.0 => ICE_V_TRIPLE_POINT,
+        t if t <= ICE_VII_TRIPLE_POINT.0 => ICE_VI_TRIPLE_POINT,
+        t if t <= MAX_ICE_VII_MELTING_TEMPERATURE.value => ICE_VII_TRIPLE_POINT,
+        _ => return Err(OutOfRange::TemperatureHigh),
+    };
+    let theta = t / triple_point_temperature;
+    let pi = match t {
+        t if t <= ICE_V_TRIPLE_POINT.0 => 1f64 - 0.299948 * (1f64 - f64::powi(theta, 60)),
+        t if t <= ICE_VI_TRIPLE_POINT.0 => 1f64 - 1.18721 * (1f64 - f64::powi(theta, 8)),
+        t if t <= ICE_VII_TRIPLE_POINT.0 => 1f64 - 1.07476 * (1f64 - f64::powf(theta, 4.6)),
+        _ => f64::exp(
+            1.73683 * (1f64 - 1f64 / theta) - 0.0544606 * (1f64 - f64::powi(theta, 5))
+                + 0.806106e-7 * (1f64 - f64::powi(theta, 22)),
+        ),
+    };
+    Ok(Pressure::Pa(Pa::new(pi * triple_point_pressure)))
+}
+
+/// Whether ice rather than liquid water or vapor is the stable phase
+pub fn is_frozen(point: &PtPoint) -> bool {
+    let temperature = point.temperature;
+    let p = point.pressure.convert_to_si_unit().value;
+    let below = |x: Result<Pressure, OutOfRange>| x.map(|x| p < x.convert_to_si_unit().value);
+    // Vapor on the low pressure side of the sublimation curve
+    if let Ok(true) = below(get_sublimation_pressure(temperature)) {
+        return false;
+    }
+    let above_high_pressure_curves = below(get_high_pressure_melting_pressure(temperature))
+        .map(|x| !x)
+        .unwrap_or(temperature.convert_to_si_unit().value < ICE_III_TRIPLE_POINT_TEMPERATURE.value);
+    match below(get_melting_pressure(temperature)) {
+        Ok(below_ice_ih) => below_ice_ih || above_high_pressure_curves,
+        // Above the ice Ih melting curve's temperatures only the high pressure ices remain
+        Err(OutOfRange::TemperatureHigh) => above_high_pressure_curves,
+        Err(_) => true,
+    }
+}
+
+/// Pressure where ice Ih and water vapor coexist
+pub fn get_sublimation_pressure(temperature: Temperature) -> Result<Pressure, OutOfRange> {
+    match temperature.convert_to_si_unit().value {
+        t if t < MIN_SUBLIMATION_TEMPERATURE.value => Err(OutOfRange::TemperatureLow),
+        t if t > TRIPLE_POINT_TEMPERATURE.value => Err(OutOfRange::TemperatureHigh),
+        t => {
+            let theta = t / TRIPLE_POINT_TEMPERATURE.value;
+            let ln_pi = SUBLIMATION_A
+                .iter()
+                .zip(SUBLIMATION_B.iter())
+                .map(|(a, b)| a * f64::powf(theta, *b))
+                .sum::<f64>()
+                / theta;
+            Ok(Pressure::Pa(Pa::new(
+                f64::exp(ln_pi) * TRIPLE_POINT_PRESSURE.value,
+            )))
+        }
+    }
+}
+
+/// Temperature where ice Ih melts at the given pressure
+pub fn get_melting_temperature(pressure: Pressure) -> Result<Temperature, SteamQueryErr> {
+    let p = pressure.convert_to_si_unit().value;
+    let max_pressure = get_melting_pressure(Temperature::K(ICE_III_TRIPLE_POINT_TEMPERATURE))
+        .map_err(SteamQueryErr::OutOfRange)?
+        .convert_to_si_unit()
+        .value;
+    match p {
+        p if p < TRIPLE_POINT_PRESSURE.value => {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow))
+        }
+        p if p > max_pressure => Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+        p => {
+            let f = |t: f64| {
+                get_melting_pressure(Temperature::K(K::new(t)))
+                    .map(|x| x.convert_to_si_unit().value / p - 1f64)
+                    .unwrap_or(f64::NAN)
+            };
+            let df = |t: f64| {
+                let theta = t / TRIPLE_POINT_TEMPERATURE.value;
+                -MELTING_A
+                    .iter()
+                    .zip(MELTING_B.iter())
+                    .map(|(a, b)| a * b * f64::powf(theta, b - 1f64))
+                    .sum::<f64>()
+                    * TRIPLE_POINT_PRESSURE.value
+                    / TRIPLE_POINT_TEMPERATURE.value
+                    / p
+            };
+            // The melting curve is concave so Newton steps from the triple point never overshoot
+            newton_method(f, df, TRIPLE_POINT_TEMPERATURE.value, 1e-9)
+                .map(|t| Temperature::K(K::new(t)))
+                .map_err(SteamQueryErr::FailedToConverge)
+        }
+    }
+}
+
+/// Temperature where ice Ih sublimates at the given pressure
+pub fn get_sublimation_temperature(pressure: Pressure) -> Result<Temperature, SteamQueryErr> {
+    let p = pressure.convert_to_si_unit().value;
+    let min_pressure = get_sublimation_pressure(Temperature::K(MIN_SUBLIMATION_TEMPERATURE))
+        .map_err(SteamQueryErr::OutOfRange)?
+        .convert_to_si_unit()
+        .value;
+    match p {
+        p if p < min_pressure => Err(SteamQueryErr::OutOfRange(OutOfRange::PressureLow)),
+        p if p > TRIPLE_POINT_PRESSURE.value => {
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh))
+        }
+        p => {
+            let ln_pi = f64::ln(p / TRIPLE_POINT_PRESSURE.value);
+            // ln(p / pt) as a function of 1 / theta
+            let f = |x: f64| {
+                SUBLIMATION_A
+                    .iter()
+                    .zip(SUBLIMATION_B.iter())
+                    .map(|(a, b)| a * f64::powf(x, 1f64 - b))
+                    .sum::<f64>()
+                    - ln_pi
+            };
+            let df = |x: f64| {
+                SUBLIMATION_A
+                    .iter()
+                    .zip(SUBLIMATION_B.iter())
+                    .map(|(a, b)| a * (1f64 - b) * f64::powf(x, -b))
+                    .sum::<f64>()
+            };
+            // Clausius-Clapeyron with a constant heat of sublimation as the seed
+            let seed = 1f64 - ln_pi * GAS_CONSTANT.value * TRIPLE_POINT_TEMPERATURE.value / 2.834e6;
+            newton_method(f, df, seed, 1e-12)
+                .map(|x| Temperature::K(K::new(TRIPLE_POINT_TEMPERATURE.value / x)))
+                .map_err(SteamQueryErr::FailedToConverge)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! get_ice_entry_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let ((temperature, pressure), (g, v, h, u, s, cp, alpha, kappa)): ((f64, f64), (f64, f64, f64, f64, f64, f64, f64, f64)) = $value;
+                let entry = get_ice_entry(&PtPoint {
+                    temperature: Temperature::K(K::new(temperature)),
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                })
+                .unwrap();
+                assert_eq!(PhaseRegion::NonCritical(NonCriticalPhaseRegion::Solid), entry.phase_region);
+                assert_approx_eq!(g, entry.gibbs_free_energy.convert_to_si_unit().value, f64::abs(g) * 1e-8 + 1e-6);
+                assert_approx_eq!(v, entry.specific_volume.convert_to_si_unit().value, v * 1e-9);
+                assert_approx_eq!(h, entry.enthalpy.convert_to_si_unit().value, f64::abs(h) * 1e-9);
+                assert_approx_eq!(u, entry.internal_energy.convert_to_si_unit().value, f64::abs(u) * 1e-9);
+                assert_approx_eq!(s, entry.entropy.convert_to_si_unit().value, f64::abs(s) * 1e-9);
+                assert_approx_eq!(cp, entry.cp.convert_to_si_unit().value, cp * 1e-9);
+                assert_approx_eq!(alpha, entry.isobaric_expansion.convert_to_si_unit().value, f64::abs(alpha) * 1e-8);
+                assert_approx_eq!(kappa, entry.isothermal_compressibility.convert_to_si_unit().value, kappa * 1e-8);
+            }
+        )*
+        }
+    }
+
+    get_ice_entry_tests! {
+        ice_entry_01: (
+            (273.16, 611.657),
+            (
+                0.611784135,
+                0.109085812737e-2,
+                -0.333444253966e6,
+                -0.333444921197e6,
+                -0.122069433940e4,
+                0.209678431622e4,
+                0.159863102566e-3,
+                0.117793449348e-9,
+            )
+        ),
+        ice_entry_02: (
+            (273.152519, 101325.0),
+            (
+                0.10134274069e3,
+                0.109084388214e-2,
+                -0.333354873637e6,
+                -0.333465403393e6,
+                -0.122076932550e4,
+                0.209671391024e4,
+                0.159841589458e-3,
+                0.117785291765e-9,
+            )
+        ),
+        ice_entry_03: (
+            (100.0, 100e6),
+            (
+                -0.222296513088e6,
+                0.106193389260e-2,
+                -0.483491635676e6,
+                -0.589685024936e6,
+                -0.261195122589e4,
+                0.866333195517e3,
+                0.258495528207e-4,
+                0.886880048115e-10,
+            )
+        ),
+    }
+
+    #[test]
+    fn ice_entry_out_of_range() {
+        assert_eq!(
+            Err(SteamQueryErr::OutOfRange(OutOfRange::TemperatureHigh)),
+            get_ice_entry(&PtPoint {
+                temperature: Temperature::K(K::new(280.0)),
+                pressure: Pressure::Pa(Pa::new(101325.0)),
+            })
+        );
+        assert_eq!(
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            get_ice_entry(&PtPoint {
+                temperature: Temperature::K(K::new(250.0)),
+                pressure: Pressure::Pa(Pa::new(250e6)),
+            })
+        );
+    }
+
+    macro_rules! phase_curve_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (get_pressure, get_temperature, temperature, pressure): (
+                    fn(Temperature) -> Result<Pressure, OutOfRange>,
+                    fn(Pressure) -> Result<Temperature, SteamQueryErr>,
+                    f64,
+                    f64,
+                ) = $value;
+                let actual_pressure = get_pressure(Temperature::K(K::new(temperature)))
+                    .unwrap()
+                    .convert_to_si_unit()
+                    .value;
+                assert_approx_eq!(pressure, actual_pressure, pressure * 1e-5);
+                let actual_temperature = get_temperature(Pressure::Pa(Pa::new(actual_pressure)))
+                    .unwrap()
+                    .convert_to_si_unit()
+                    .value;
+                assert_approx_eq!(temperature, actual_temperature, 1e-7);
+            }
+        )*
+        }
+    }
+
+    phase_curve_tests! {
+        phase_curve_01: (get_melting_pressure, get_melting_temperature, 260.0, 138.268e6),
+        phase_curve_02: (get_melting_pressure, get_melting_temperature, 273.16, 611.657),
+        phase_curve_03: (get_sublimation_pressure, get_sublimation_temperature, 230.0, 8.94735),
+        phase_curve_04: (get_sublimation_pressure, get_sublimation_temperature, 273.16, 611.657),
+    }
+
+    macro_rules! high_pressure_melting_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (temperature, pressure): (f64, f64) = $value;
+                let actual = get_high_pressure_melting_pressure(Temperature::K(K::new(temperature)))
+                    .unwrap()
+                    .convert_to_si_unit()
+                    .value;
+                assert_approx_eq!(pressure, actual, pressure * 1e-5);
+            }
+        )*
+        }
+    }
+
+    // Check values of table 3 in IAPWS R14-08
+    high_pressure_melting_tests! {
+        ice_iii_melting: (254.0, 268.685e6),
+        ice_v_melting: (265.0, 479.640e6),
+        ice_vi_melting: (320.0, 1356.76e6),
+        ice_vii_melting: (550.0, 6308.71e6),
+    }
+
+    #[test]
+    fn frozen_points() {
+        let is_frozen_at = |t: f64, p: f64| {
+            is_frozen(&PtPoint {
+                pressure: Pressure::Pa(Pa::new(p)),
+                temperature: Temperature::K(K::new(t)),
+            })
+        };
+        // Ice Ih, liquid squeezed past its melting curve and ice V above it
+        assert!(is_frozen_at(260.0, 100e6));
+        assert!(!is_frozen_at(260.0, 200e6));
+        assert!(is_frozen_at(260.0, 450e6));
+        // Vapor below the sublimation curve
+        assert!(!is_frozen_at(260.0, 100.0));
+        assert!(is_frozen_at(240.0, 200e6));
+        // Ice VI at room temperature
+        assert!(!is_frozen_at(300.0, 900e6));
+        assert!(is_frozen_at(300.0, 1000e6));
+    }
+
+    #[test]
+    fn phase_curves_out_of_range() {
+        assert_eq!(
+            Err(OutOfRange::TemperatureLow),
+            get_melting_pressure(Temperature::K(K::new(250.0)))
+        );
+        assert_eq!(
+            Err(OutOfRange::TemperatureHigh),
+            get_sublimation_pressure(Temperature::K(K::new(274.0)))
+        );
+        assert_eq!(
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            get_melting_temperature(Pressure::Pa(Pa::new(300e6)))
+        );
+        assert_eq!(
+            Err(SteamQueryErr::OutOfRange(OutOfRange::PressureHigh)),
+            get_sublimation_temperature(Pressure::Pa(Pa::new(1000.0)))
+        );
+    }
+}
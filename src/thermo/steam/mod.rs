@@ -1,10 +1,21 @@
 use std::fmt::Display;
 
+use crate::numerical_methods::root_finders::secant_method;
 use crate::numerical_methods::*;
 use crate::thermo::*;
 
+pub mod auxiliary;
+pub mod comparison;
+pub mod expansion;
+pub mod iapws95;
+mod iapws95_constants;
 pub mod iapws97;
 mod iapws97_constants;
+mod iapws97_region3_constants;
+pub mod ice;
+pub mod process_units;
+pub mod property_diagrams;
+pub mod tables;
 mod water_constants;
 
 #[derive(Copy, PartialEq, Clone, Debug)]
@@ -19,6 +30,8 @@ pub enum SteamNonCriticalPhaseRegion {
     Vapor,
     // Pressure is above the vaporization curve and the temperature is greater than the fusion curve and less than the critical temperature
     Liquid,
+    // Pressure is above the sublimation curve and temperature is less than the fusion curve
+    Solid,
 }
 
 impl TryFrom<String> for SteamNonCriticalPhaseRegion {
@@ -28,6 +41,7 @@ impl TryFrom<String> for SteamNonCriticalPhaseRegion {
         match value.as_str() {
             "Vapor" => Ok(SteamNonCriticalPhaseRegion::Vapor),
             "Liquid" => Ok(SteamNonCriticalPhaseRegion::Liquid),
+            "Solid" => Ok(SteamNonCriticalPhaseRegion::Solid),
             _ => Err(format!("Unknown Phase \"{}\"", value)),
         }
     }
@@ -38,6 +52,7 @@ impl From<SteamNonCriticalPhaseRegion> for String {
         match val {
             SteamNonCriticalPhaseRegion::Vapor => "Vapor".to_owned(),
             SteamNonCriticalPhaseRegion::Liquid => "Liquid".to_owned(),
+            SteamNonCriticalPhaseRegion::Solid => "Solid".to_owned(),
         }
     }
 }
@@ -50,6 +65,7 @@ impl Display for SteamNonCriticalPhaseRegion {
             match self {
                 SteamNonCriticalPhaseRegion::Vapor => "Vapor",
                 SteamNonCriticalPhaseRegion::Liquid => "Liquid",
+                SteamNonCriticalPhaseRegion::Solid => "Solid",
             }
         )
     }
@@ -99,4 +115,269 @@ pub enum OutOfRange {
     AboveCriticalTemperature,
     BelowCriticalTemperature,
     AboveCriticalPressure,
+    DensityLow,
+    DensityHigh,
+    WavelengthLow,
+    WavelengthHigh,
+}
+
+/// Opt-in behavior beyond the stable, validated range of a formulation
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SteamQueryOptions {
+    // Keep a pressure temperature query in this phase past the saturation line
+    // instead of switching to the stable phase
+    pub metastable_phase: Option<SteamNonCriticalPhaseRegion>,
+    // Evaluate pressures above 100 MPa instead of rejecting the query
+    pub extrapolate: bool,
+    // Use the region 3 backward equation volume as is instead of polishing it against
+    // the basic equation, trading a small pressure error for speed
+    pub region3_backward_only: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SteamWarning {
+    // Subcooled vapor evaluated with the region 2 metastable-vapor equation
+    MetastableVapor,
+    // Superheated liquid evaluated with the region 1 equation past the saturation line
+    MetastableLiquid,
+    // Pressure is above the range the formulation was fitted to
+    PressureExtrapolated,
+}
+
+/// A steam table entry which may lie outside of the stable, validated range
+#[derive(Debug, PartialEq, Clone)]
+pub struct SteamEntry {
+    pub entry: PtvEntry,
+    pub warnings: Vec<SteamWarning>,
+}
+
+/// Temperature and pressure range a formulation was fitted to, in K and Pa
+pub(crate) struct ValidityRange {
+    pub max_temperature: f64,
+    pub max_pressure: f64,
+    // Above the first pressure the temperature only goes up to the second
+    pub high_pressure_max_temperature: Option<(f64, f64)>,
+    // Reject points where ice is stable instead of leaving them to the formulation
+    pub stop_at_melting_line: bool,
+}
+
+fn extract_pressure(query: &SteamQuery) -> Option<f64> {
+    match query {
+        SteamQuery::Pt(PtPoint { pressure: p, .. })
+        | SteamQuery::Sat(SatQuery::SatPQuery { pressure: p, .. })
+        | SteamQuery::EntropyP { pressure: p, .. }
+        | SteamQuery::EnthalpyP { pressure: p, .. } => Some(p.convert_to_si_unit().value),
+        _ => None,
+    }
+}
+
+fn extract_temperature(query: &SteamQuery) -> Option<f64> {
+    match query {
+        SteamQuery::Pt(PtPoint { temperature: t, .. })
+        | SteamQuery::Sat(SatQuery::SatTQuery { temperature: t, .. }) => {
+            Some(t.convert_to_si_unit().value)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn check_if_out_of_range(
+    query: &SteamQuery,
+    range: &ValidityRange,
+) -> Result<(), OutOfRange> {
+    let opt_p = extract_pressure(query);
+    let opt_t = extract_temperature(query);
+    match (opt_p, opt_t) {
+        (_, Some(t)) if t <= 0.0 => Err(OutOfRange::TemperatureLow),
+        (_, Some(t)) if t > range.max_temperature => Err(OutOfRange::TemperatureHigh),
+        (Some(p), Some(t))
+            if range
+                .high_pressure_max_temperature
+                .map(|(max_p, max_t)| p > max_p && t > max_t)
+                .unwrap_or(false) =>
+        {
+            Err(OutOfRange::TemperatureHigh)
+        }
+        (Some(p), _) if p <= 0.0 => Err(OutOfRange::PressureLow),
+        (Some(p), _) if p > range.max_pressure => Err(OutOfRange::PressureHigh),
+        (Some(p), Some(t))
+            if range.stop_at_melting_line
+                && ice::is_frozen(&PtPoint {
+                    pressure: Pressure::Pa(Pa::new(p)),
+                    temperature: Temperature::K(K::new(t)),
+                }) =>
+        {
+            Err(OutOfRange::TemperatureLow)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A formulation of the thermodynamic properties of water, or another pure fluid,
+/// which can answer steam table queries
+pub trait SteamProperties {
+    fn get_steam_table_entry(&self, query: SteamQuery) -> Result<PtvEntry, SteamQueryErr>;
+
+    /// Answers every query in order. Backends override this to share work between queries.
+    fn get_steam_table_entries(
+        &self,
+        queries: &[SteamQuery],
+    ) -> Vec<Result<PtvEntry, SteamQueryErr>>
+    where
+        Self: Sync,
+    {
+        map_items(queries, |query| self.get_steam_table_entry(query))
+    }
+}
+
+/// Answers every query with one allocation for the results, which keep the order of the queries.
+/// Queries are spread over threads on native targets when the rayon feature is enabled.
+pub fn get_steam_table_entries(
+    backend: &(impl SteamProperties + Sync),
+    queries: &[SteamQuery],
+) -> Vec<Result<PtvEntry, SteamQueryErr>> {
+    backend.get_steam_table_entries(queries)
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+pub(crate) fn map_items<I: Copy + Sync, T: Send>(
+    items: &[I],
+    f: impl Fn(I) -> T + Sync + Send,
+) -> Vec<T> {
+    use rayon::prelude::*;
+    let mut results = Vec::with_capacity(items.len());
+    items
+        .par_iter()
+        .map(|x| f(*x))
+        .collect_into_vec(&mut results);
+    results
+}
+
+#[cfg(not(all(feature = "rayon", not(target_arch = "wasm32"))))]
+pub(crate) fn map_items<I: Copy + Sync, T: Send>(
+    items: &[I],
+    f: impl Fn(I) -> T + Sync + Send,
+) -> Vec<T> {
+    let mut results = Vec::with_capacity(items.len());
+    results.extend(items.iter().map(|x| f(*x)));
+    results
+}
+
+fn interpolate_entry(
+    liquid_entry: &PtvEntry,
+    vapor_entry: &PtvEntry,
+    liq_frac: f64,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let vap_frac = 1.0 - liq_frac;
+    LiquidVapor::new(liq_frac, vap_frac)
+        .map(|x| PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)))
+        .map_err(SteamQueryErr::CompositePhaseRegionErr)
+        .map(|phase_region| {
+            mix_entries(
+                &[(liquid_entry, liq_frac), (vapor_entry, vap_frac)],
+                phase_region,
+            )
+        })
+}
+
+// Mass fraction weighted average of phases in equilibrium with each other.
+// The derivative properties (cv, cp, speed of sound, expansion, compressibility,
+// Joule-Thomson and isentropic exponent) jump across the phase boundary
+// and are not a mass average of the phases so they are left as NaN
+fn mix_entries(entries: &[(&PtvEntry, f64)], phase_region: PhaseRegion) -> PtvEntry {
+    let interpolate_entry_property = |f: fn(e: &PtvEntry) -> f64| {
+        entries
+            .iter()
+            .map(|(entry, frac)| f(entry) * frac)
+            .sum::<f64>()
+    };
+    let temperature = interpolate_entry_property(|x| x.temperature.convert_to_si_unit().value);
+    let pressure = interpolate_entry_property(|x| x.pressure.convert_to_si_unit().value);
+    let internal_energy =
+        interpolate_entry_property(|x| x.internal_energy.convert_to_si_unit().value);
+    let enthalpy = interpolate_entry_property(|x| x.enthalpy.convert_to_si_unit().value);
+    let entropy = interpolate_entry_property(|x| x.entropy.convert_to_si_unit().value);
+    let specific_volume =
+        interpolate_entry_property(|x| x.specific_volume.convert_to_si_unit().value);
+    let gibbs_free_energy =
+        interpolate_entry_property(|x| x.gibbs_free_energy.convert_to_si_unit().value);
+    let helmholtz_free_energy =
+        interpolate_entry_property(|x| x.helmholtz_free_energy.convert_to_si_unit().value);
+    PtvEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region,
+        internal_energy: EnergyPerMass::JPerKg(JPerKg::new(internal_energy)),
+        enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+        entropy: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(entropy)),
+        cv: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+        cp: EnergyPerMassTemperature::JPerKgK(JPerKgK::new(f64::NAN)),
+        speed_of_sound: Velocity::MPerSec(MPerSec::new(f64::NAN)),
+        specific_volume: SpecificVolume::M3PerKg(M3PerKg::new(specific_volume)),
+        density: Density::KgPerM3(KgPerM3::new(1f64 / specific_volume)),
+        gibbs_free_energy: EnergyPerMass::JPerKg(JPerKg::new(gibbs_free_energy)),
+        helmholtz_free_energy: EnergyPerMass::JPerKg(JPerKg::new(helmholtz_free_energy)),
+        isobaric_expansion: InverseTemperature::PerK(PerK::new(f64::NAN)),
+        isothermal_compressibility: InversePressure::PerPa(PerPa::new(f64::NAN)),
+        joule_thomson: TemperaturePerPressure::KPerPa(KPerPa::new(f64::NAN)),
+        isentropic_exponent: f64::NAN,
+    }
+}
+
+fn iterate_pt_entry_solution(
+    backend: &impl SteamProperties,
+    pressure: Pa,
+    target_value: f64,
+    get_prop_value: fn(entry: &PtvEntry) -> f64,
+) -> Result<PtvEntry, SteamQueryErr> {
+    let liquid_entry_result = backend.get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+        pressure: Pressure::Pa(pressure),
+        phase_region: SteamNonCriticalPhaseRegion::Liquid,
+    }));
+    let vapor_entry_result = backend.get_steam_table_entry(SteamQuery::Sat(SatQuery::SatPQuery {
+        pressure: Pressure::Pa(pressure),
+        phase_region: SteamNonCriticalPhaseRegion::Vapor,
+    }));
+
+    let sat_temperature = |entry: &PtvEntry| entry.temperature.convert_to_si_unit().value;
+    // Outside of the dome the temperature is kept on the side of the saturation line the
+    // target is on so the secant steps never cross the jump in properties at saturation
+    let (guess, min_temperature, max_temperature) = match (liquid_entry_result, vapor_entry_result)
+    {
+        (Ok(liquid_entry), Ok(vapor_entry))
+            if get_prop_value(&liquid_entry) <= target_value
+                && get_prop_value(&vapor_entry) >= target_value =>
+        {
+            let liq_frac = (get_prop_value(&vapor_entry) - target_value)
+                / (get_prop_value(&vapor_entry) - get_prop_value(&liquid_entry));
+            return interpolate_entry(&liquid_entry, &vapor_entry, liq_frac);
+        }
+        (_, Ok(vapor_entry)) if get_prop_value(&vapor_entry) < target_value => {
+            let temperature = sat_temperature(&vapor_entry);
+            (temperature + 1f64, temperature, f64::INFINITY)
+        }
+        (Ok(liquid_entry), _) if get_prop_value(&liquid_entry) > target_value => {
+            let temperature = sat_temperature(&liquid_entry);
+            (f64::min(310f64, temperature - 1f64), 0f64, temperature)
+        }
+        _ => (310f64, 0f64, f64::INFINITY),
+    };
+    let get_entry = |temperature: f64| {
+        backend.get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            pressure: Pressure::Pa(pressure),
+            temperature: Temperature::K(K::new(
+                temperature.clamp(min_temperature, max_temperature),
+            )),
+        }))
+    };
+    let f = |temperature| {
+        if let Ok(entry) = get_entry(temperature) {
+            get_prop_value(&entry) - target_value
+        } else {
+            f64::NAN
+        }
+    };
+    secant_method(f, guess, 1e-5)
+        .map_err(SteamQueryErr::FailedToConverge)
+        .and_then(get_entry)
 }
@@ -0,0 +1,729 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SteamStream {
+    pub state: PtPoint,
+    pub mass_flow: MassFlowRate,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ThrottleQuery {
+    pub inlet: PtPoint,
+    pub outlet_pressure: Pressure,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Throttle {
+    pub inlet: PtvEntry,
+    pub outlet: PtvEntry,
+    // Vapor fraction, None when the outlet is not a liquid vapor mixture
+    pub outlet_quality: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DesuperheaterQuery {
+    pub steam: SteamStream,
+    // Must be at or above the steam pressure to be sprayed in
+    pub spray_water: PtPoint,
+    // The outlet stays at the steam pressure and must still be superheated
+    pub outlet_temperature: Temperature,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Desuperheater {
+    pub steam: PtvEntry,
+    pub spray_water: PtvEntry,
+    pub outlet: PtvEntry,
+    pub spray_water_flow: MassFlowRate,
+    pub outlet_flow: MassFlowRate,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MixingQuery {
+    // The outlet leaves at the lowest stream pressure
+    pub streams: Vec<SteamStream>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Mixing {
+    pub streams: Vec<PtvEntry>,
+    pub outlet: PtvEntry,
+    // Vapor fraction, None when the outlet is not a liquid vapor mixture
+    pub outlet_quality: Option<f64>,
+    pub outlet_flow: MassFlowRate,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FlashQuery {
+    pub condensate_pressure: Pressure,
+    // None for saturated condensate
+    pub condensate_temperature: Option<Temperature>,
+    pub condensate_flow: MassFlowRate,
+    pub flash_pressure: Pressure,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Flash {
+    pub condensate: PtvEntry,
+    // Saturated vapor leaving the flash vessel
+    pub flash_steam: PtvEntry,
+    // Liquid leaving the flash vessel, saturated unless the condensate is too cold to flash
+    pub flash_liquid: PtvEntry,
+    // Vapor mass over condensate mass, 0 when the condensate is too cold to flash
+    pub flash_fraction: f64,
+    pub vapor_flow: MassFlowRate,
+    pub liquid_flow: MassFlowRate,
+    // Latent heat given up when the flash steam is condensed by a user at the flash pressure
+    pub energy_recovered: Power,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProcessUnitErr {
+    // The outlet pressure must be below the inlet pressure
+    OutletPressureHigh,
+    NoStreams,
+    // Mass flows must be above 0
+    InvalidMassFlow,
+    // The target temperature must be below the steam temperature
+    OutletTemperatureHigh,
+    // The target temperature must be above the saturation temperature
+    OutletNotSuperheated,
+    SprayWaterPressureLow,
+    // The spray water must be colder than the outlet
+    SprayWaterTooHot,
+    // The condensate temperature must be at or below the saturation temperature
+    CondensateNotLiquid,
+    SteamQueryErr(SteamQueryErr),
+}
+
+fn get_entry(
+    backend: &impl SteamProperties,
+    query: SteamQuery,
+) -> Result<PtvEntry, ProcessUnitErr> {
+    backend
+        .get_steam_table_entry(query)
+        .map_err(ProcessUnitErr::SteamQueryErr)
+}
+
+fn get_enthalpy_p_entry(
+    backend: &impl SteamProperties,
+    enthalpy: f64,
+    pressure: f64,
+) -> Result<PtvEntry, ProcessUnitErr> {
+    get_entry(
+        backend,
+        SteamQuery::EnthalpyP {
+            enthalpy: EnergyPerMass::JPerKg(JPerKg::new(enthalpy)),
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        },
+    )
+}
+
+fn get_quality(entry: &PtvEntry) -> Option<f64> {
+    match &entry.phase_region {
+        PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)) => Some(x.get_vapor_frac()),
+        _ => None,
+    }
+}
+
+fn get_mass_flow(stream: &SteamStream) -> Result<f64, ProcessUnitErr> {
+    let mass_flow = stream.mass_flow.convert_to_si_unit().value;
+    if mass_flow > 0f64 {
+        Ok(mass_flow)
+    } else {
+        Err(ProcessUnitErr::InvalidMassFlow)
+    }
+}
+
+/// Isenthalpic expansion through a valve
+pub fn get_throttle(
+    backend: &impl SteamProperties,
+    query: ThrottleQuery,
+) -> Result<Throttle, ProcessUnitErr> {
+    let outlet_pressure = query.outlet_pressure.convert_to_si_unit().value;
+    if outlet_pressure >= query.inlet.pressure.convert_to_si_unit().value {
+        return Err(ProcessUnitErr::OutletPressureHigh);
+    }
+    let inlet = get_entry(backend, SteamQuery::Pt(query.inlet))?;
+    let outlet = get_enthalpy_p_entry(
+        backend,
+        inlet.enthalpy.convert_to_si_unit().value,
+        outlet_pressure,
+    )?;
+    Ok(Throttle {
+        outlet_quality: get_quality(&outlet),
+        inlet,
+        outlet,
+    })
+}
+
+/// Spray water flow needed to cool superheated steam to the outlet temperature
+pub fn get_desuperheater(
+    backend: &impl SteamProperties,
+    query: DesuperheaterQuery,
+) -> Result<Desuperheater, ProcessUnitErr> {
+    let steam_flow = get_mass_flow(&query.steam)?;
+    let pressure = query.steam.state.pressure;
+    if query.spray_water.pressure.convert_to_si_unit().value < pressure.convert_to_si_unit().value {
+        return Err(ProcessUnitErr::SprayWaterPressureLow);
+    }
+    if query.outlet_temperature.convert_to_si_unit().value
+        >= query.steam.state.temperature.convert_to_si_unit().value
+    {
+        return Err(ProcessUnitErr::OutletTemperatureHigh);
+    }
+    let steam = get_entry(backend, SteamQuery::Pt(query.steam.state))?;
+    let spray_water = get_entry(backend, SteamQuery::Pt(query.spray_water))?;
+    let outlet = get_entry(
+        backend,
+        SteamQuery::Pt(PtPoint {
+            pressure,
+            temperature: query.outlet_temperature,
+        }),
+    )?;
+    match outlet.phase_region {
+        PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor)
+        | PhaseRegion::SupercriticalFluid
+        | PhaseRegion::Gas => {}
+        _ => return Err(ProcessUnitErr::OutletNotSuperheated),
+    }
+    let steam_enthalpy = steam.enthalpy.convert_to_si_unit().value;
+    let water_enthalpy = spray_water.enthalpy.convert_to_si_unit().value;
+    let outlet_enthalpy = outlet.enthalpy.convert_to_si_unit().value;
+    if water_enthalpy >= outlet_enthalpy {
+        return Err(ProcessUnitErr::SprayWaterTooHot);
+    }
+    let spray_water_flow =
+        steam_flow * (steam_enthalpy - outlet_enthalpy) / (outlet_enthalpy - water_enthalpy);
+    Ok(Desuperheater {
+        steam,
+        spray_water,
+        outlet,
+        spray_water_flow: MassFlowRate::KgPerSec(KgPerSec::new(spray_water_flow)),
+        outlet_flow: MassFlowRate::KgPerSec(KgPerSec::new(steam_flow + spray_water_flow)),
+    })
+}
+
+/// Adiabatic mixing of streams into one outlet
+pub fn get_mixing(
+    backend: &impl SteamProperties,
+    query: &MixingQuery,
+) -> Result<Mixing, ProcessUnitErr> {
+    if query.streams.is_empty() {
+        return Err(ProcessUnitErr::NoStreams);
+    }
+    let mass_flows = query
+        .streams
+        .iter()
+        .map(get_mass_flow)
+        .collect::<Result<Vec<f64>, ProcessUnitErr>>()?;
+    let streams = query
+        .streams
+        .iter()
+        .map(|x| get_entry(backend, SteamQuery::Pt(x.state)))
+        .collect::<Result<Vec<PtvEntry>, ProcessUnitErr>>()?;
+    let outlet_flow: f64 = mass_flows.iter().sum();
+    let outlet_enthalpy = streams
+        .iter()
+        .zip(mass_flows.iter())
+        .map(|(entry, mass_flow)| entry.enthalpy.convert_to_si_unit().value * mass_flow)
+        .sum::<f64>()
+        / outlet_flow;
+    let outlet_pressure = streams
+        .iter()
+        .map(|x| x.pressure.convert_to_si_unit().value)
+        .fold(f64::INFINITY, f64::min);
+    let outlet = get_enthalpy_p_entry(backend, outlet_enthalpy, outlet_pressure)?;
+    Ok(Mixing {
+        outlet_quality: get_quality(&outlet),
+        streams,
+        outlet,
+        outlet_flow: MassFlowRate::KgPerSec(KgPerSec::new(outlet_flow)),
+    })
+}
+
+fn get_sat_p_entry(
+    backend: &impl SteamProperties,
+    pressure: Pressure,
+    phase_region: SteamNonCriticalPhaseRegion,
+) -> Result<PtvEntry, ProcessUnitErr> {
+    get_entry(
+        backend,
+        SteamQuery::Sat(SatQuery::SatPQuery {
+            pressure,
+            phase_region,
+        }),
+    )
+}
+
+/// Condensate dropping to the flash vessel pressure splits into saturated vapor and liquid
+pub fn get_flash(
+    backend: &impl SteamProperties,
+    query: FlashQuery,
+) -> Result<Flash, ProcessUnitErr> {
+    let condensate_flow = query.condensate_flow.convert_to_si_unit().value;
+    if condensate_flow <= 0f64 {
+        return Err(ProcessUnitErr::InvalidMassFlow);
+    }
+    if query.flash_pressure.convert_to_si_unit().value
+        >= query.condensate_pressure.convert_to_si_unit().value
+    {
+        return Err(ProcessUnitErr::OutletPressureHigh);
+    }
+    let condensate = match query.condensate_temperature {
+        Some(temperature) => get_entry(
+            backend,
+            SteamQuery::Pt(PtPoint {
+                pressure: query.condensate_pressure,
+                temperature,
+            }),
+        )?,
+        None => get_sat_p_entry(
+            backend,
+            query.condensate_pressure,
+            SteamNonCriticalPhaseRegion::Liquid,
+        )?,
+    };
+    if condensate.phase_region != PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid) {
+        return Err(ProcessUnitErr::CondensateNotLiquid);
+    }
+    let flash_steam = get_sat_p_entry(
+        backend,
+        query.flash_pressure,
+        SteamNonCriticalPhaseRegion::Vapor,
+    )?;
+    let saturated_liquid = get_sat_p_entry(
+        backend,
+        query.flash_pressure,
+        SteamNonCriticalPhaseRegion::Liquid,
+    )?;
+    let condensate_enthalpy = condensate.enthalpy.convert_to_si_unit().value;
+    let liquid_enthalpy = saturated_liquid.enthalpy.convert_to_si_unit().value;
+    let latent_heat = flash_steam.enthalpy.convert_to_si_unit().value - liquid_enthalpy;
+    let flash_fraction = f64::max((condensate_enthalpy - liquid_enthalpy) / latent_heat, 0f64);
+    // Too cold to flash so the condensate is only throttled and leaves subcooled
+    let flash_liquid = if flash_fraction > 0f64 {
+        saturated_liquid
+    } else {
+        get_enthalpy_p_entry(
+            backend,
+            condensate_enthalpy,
+            query.flash_pressure.convert_to_si_unit().value,
+        )?
+    };
+    let vapor_flow = flash_fraction * condensate_flow;
+    Ok(Flash {
+        condensate,
+        flash_steam,
+        flash_liquid,
+        flash_fraction,
+        vapor_flow: MassFlowRate::KgPerSec(KgPerSec::new(vapor_flow)),
+        liquid_flow: MassFlowRate::KgPerSec(KgPerSec::new(condensate_flow - vapor_flow)),
+        energy_recovered: Power::W(W::new(vapor_flow * latent_heat)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws97::Iapws97;
+    use assert_approx_eq::assert_approx_eq;
+
+    macro_rules! throttle_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (query, expected_quality, (lower_temperature, upper_temperature)): (
+                    ThrottleQuery,
+                    Option<f64>,
+                    (f64, f64),
+                ) = $value;
+                let throttle = get_throttle(&Iapws97, query).unwrap();
+                assert_approx_eq!(
+                    throttle.inlet.enthalpy.convert_to_si_unit().value,
+                    throttle.outlet.enthalpy.convert_to_si_unit().value,
+                    1e-3
+                );
+                match expected_quality {
+                    Some(quality) => {
+                        assert_approx_eq!(quality, throttle.outlet_quality.unwrap(), 1e-3)
+                    }
+                    None => assert_eq!(None, throttle.outlet_quality),
+                }
+                let outlet_temperature = throttle.outlet.temperature.convert_to_si_unit().value;
+                assert!(outlet_temperature > lower_temperature);
+                assert!(outlet_temperature < upper_temperature);
+            }
+        )*
+        }
+    }
+
+    // Rows of (query, outlet quality, outlet temperature range in K)
+    throttle_tests! {
+        // x = (632.5 - 417.5) / 2257.5 at the 372.76 K saturation temperature
+        throttle_compressed_liquid: (
+            ThrottleQuery {
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(1e6)),
+                    temperature: Temperature::C(C::new(150.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(100.0)),
+            },
+            Some(0.0952),
+            (372.71, 372.81),
+        ),
+        // Joule-Thomson cooling
+        throttle_superheated_steam: (
+            ThrottleQuery {
+                inlet: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(5e6)),
+                    temperature: Temperature::C(C::new(400.0)),
+                },
+                outlet_pressure: Pressure::KPa(KPa::new(500.0)),
+            },
+            None,
+            (600.0, 673.15),
+        ),
+    }
+
+    macro_rules! desuperheater_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (query, expected_spray_water_flow): (DesuperheaterQuery, f64) = $value;
+                let desuperheater = get_desuperheater(&Iapws97, query).unwrap();
+                let spray_water_flow = desuperheater.spray_water_flow.convert_to_si_unit().value;
+                assert_approx_eq!(expected_spray_water_flow, spray_water_flow, 5e-3);
+                assert_approx_eq!(
+                    desuperheater.outlet_flow.convert_to_si_unit().value,
+                    query.steam.mass_flow.convert_to_si_unit().value + spray_water_flow,
+                    1e-9
+                );
+            }
+        )*
+        }
+    }
+
+    // Steam at 4 MPa and 450 °C with h = 3331.2 kJ/kg cooled by water at 5 MPa
+    // and 100 °C with h = 422.7 kJ/kg
+    desuperheater_tests! {
+        // 10 (3331.2 - 2961.7) / (2961.7 - 422.7)
+        desuperheater_spray_water_flow: (
+            DesuperheaterQuery {
+                steam: SteamStream {
+                    state: PtPoint {
+                        pressure: Pressure::Pa(Pa::new(4e6)),
+                        temperature: Temperature::C(C::new(450.0)),
+                    },
+                    mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(10.0)),
+                },
+                spray_water: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(5e6)),
+                    temperature: Temperature::C(C::new(100.0)),
+                },
+                outlet_temperature: Temperature::C(C::new(300.0)),
+            },
+            1.4553,
+        ),
+        // 10 (3331.2 - 3093.3) / (3093.3 - 422.7)
+        desuperheater_spray_water_flow_to_350_c: (
+            DesuperheaterQuery {
+                steam: SteamStream {
+                    state: PtPoint {
+                        pressure: Pressure::Pa(Pa::new(4e6)),
+                        temperature: Temperature::C(C::new(450.0)),
+                    },
+                    mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(10.0)),
+                },
+                spray_water: PtPoint {
+                    pressure: Pressure::Pa(Pa::new(5e6)),
+                    temperature: Temperature::C(C::new(100.0)),
+                },
+                outlet_temperature: Temperature::C(C::new(350.0)),
+            },
+            0.8908,
+        ),
+    }
+
+    macro_rules! desuperheater_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (steam_kg_per_sec, spray_water_mpa, outlet_c, expected): (
+                    f64,
+                    f64,
+                    f64,
+                    ProcessUnitErr,
+                ) = $value;
+                let query = DesuperheaterQuery {
+                    steam: SteamStream {
+                        state: PtPoint {
+                            pressure: Pressure::Pa(Pa::new(4e6)),
+                            temperature: Temperature::C(C::new(450.0)),
+                        },
+                        mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(steam_kg_per_sec)),
+                    },
+                    spray_water: PtPoint {
+                        pressure: Pressure::Pa(Pa::new(spray_water_mpa * 1e6)),
+                        temperature: Temperature::C(C::new(100.0)),
+                    },
+                    outlet_temperature: Temperature::C(C::new(outlet_c)),
+                };
+                assert_eq!(Err(expected), get_desuperheater(&Iapws97, query));
+            }
+        )*
+        }
+    }
+
+    // Steam at 4 MPa and 450 °C cooled by water at 100 °C, rows of
+    // (steam flow in kg/s, spray water pressure in MPa, outlet temperature in °C, error)
+    desuperheater_err_tests! {
+        desuperheater_outlet_hotter_than_steam: (
+            10.0,
+            5.0,
+            500.0,
+            ProcessUnitErr::OutletTemperatureHigh,
+        ),
+        desuperheater_outlet_not_superheated: (
+            10.0,
+            5.0,
+            200.0,
+            ProcessUnitErr::OutletNotSuperheated,
+        ),
+        desuperheater_spray_water_pressure_low: (
+            10.0,
+            1.0,
+            300.0,
+            ProcessUnitErr::SprayWaterPressureLow,
+        ),
+        desuperheater_no_steam_flow: (
+            0.0,
+            5.0,
+            300.0,
+            ProcessUnitErr::InvalidMassFlow,
+        ),
+    }
+
+    macro_rules! mixing_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (query, expected_pressure, expected_quality): (MixingQuery, f64, Option<f64>) =
+                    $value;
+                let mixing = get_mixing(&Iapws97, &query).unwrap();
+                let flows = query
+                    .streams
+                    .iter()
+                    .map(|x| x.mass_flow.convert_to_si_unit().value)
+                    .collect::<Vec<f64>>();
+                let total_flow = flows.iter().sum::<f64>();
+                let expected_enthalpy = mixing
+                    .streams
+                    .iter()
+                    .zip(flows.iter())
+                    .map(|(x, flow)| x.enthalpy.convert_to_si_unit().value * flow)
+                    .sum::<f64>()
+                    / total_flow;
+                assert_approx_eq!(
+                    expected_pressure,
+                    mixing.outlet.pressure.convert_to_si_unit().value,
+                    1e-6
+                );
+                assert_approx_eq!(total_flow, mixing.outlet_flow.convert_to_si_unit().value, 1e-9);
+                assert_approx_eq!(
+                    expected_enthalpy,
+                    mixing.outlet.enthalpy.convert_to_si_unit().value,
+                    1e-3
+                );
+                match expected_quality {
+                    Some(quality) => {
+                        assert_approx_eq!(quality, mixing.outlet_quality.unwrap(), 2e-3)
+                    }
+                    None => assert_eq!(None, mixing.outlet_quality),
+                }
+            }
+        )*
+        }
+    }
+
+    // Rows of (query, outlet pressure in Pa, outlet quality), the outlet is at the
+    // lowest stream pressure
+    mixing_tests! {
+        // h = (84.8 + 3038.2) / 2 and x = (1561.5 - 762.5) / 2014.6
+        mixing_water_and_steam: (
+            MixingQuery {
+                streams: vec![
+                    SteamStream {
+                        state: PtPoint {
+                            pressure: Pressure::Pa(Pa::new(1e6)),
+                            temperature: Temperature::C(C::new(20.0)),
+                        },
+                        mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(1.0)),
+                    },
+                    SteamStream {
+                        state: PtPoint {
+                            pressure: Pressure::Pa(Pa::new(1.5e6)),
+                            temperature: Temperature::C(C::new(300.0)),
+                        },
+                        mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(1.0)),
+                    },
+                ],
+            },
+            1e6,
+            Some(0.3966),
+        ),
+        mixing_liquid_streams: (
+            MixingQuery {
+                streams: vec![
+                    SteamStream {
+                        state: PtPoint {
+                            pressure: Pressure::Pa(Pa::new(1e6)),
+                            temperature: Temperature::C(C::new(20.0)),
+                        },
+                        mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(3.0)),
+                    },
+                    SteamStream {
+                        state: PtPoint {
+                            pressure: Pressure::KPa(KPa::new(500.0)),
+                            temperature: Temperature::C(C::new(80.0)),
+                        },
+                        mass_flow: MassFlowRate::KgPerSec(KgPerSec::new(1.0)),
+                    },
+                ],
+            },
+            500e3,
+            None,
+        ),
+    }
+
+    macro_rules! process_unit_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (result, expected): (Result<(), ProcessUnitErr>, ProcessUnitErr) = $value;
+                assert_eq!(Err(expected), result);
+            }
+        )*
+        }
+    }
+
+    process_unit_err_tests! {
+        throttle_outlet_above_inlet_pressure: (
+            get_throttle(
+                &Iapws97,
+                ThrottleQuery {
+                    inlet: PtPoint {
+                        pressure: Pressure::Pa(Pa::new(5e6)),
+                        temperature: Temperature::C(C::new(400.0)),
+                    },
+                    outlet_pressure: Pressure::Pa(Pa::new(6e6)),
+                },
+            )
+            .map(|_| ()),
+            ProcessUnitErr::OutletPressureHigh,
+        ),
+        mixing_no_streams: (
+            get_mixing(&Iapws97, &MixingQuery { streams: vec![] }).map(|_| ()),
+            ProcessUnitErr::NoStreams,
+        ),
+    }
+
+    macro_rules! flash_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (condensate_c, expected_fraction, expected_liquid_c): (Option<f64>, f64, f64) =
+                    $value;
+                let flash = get_flash(
+                    &Iapws97,
+                    FlashQuery {
+                        condensate_pressure: Pressure::Pa(Pa::new(1e6)),
+                        condensate_temperature: condensate_c.map(|x| Temperature::C(C::new(x))),
+                        condensate_flow: MassFlowRate::KgPerSec(KgPerSec::new(10.0)),
+                        flash_pressure: Pressure::KPa(KPa::new(100.0)),
+                    },
+                )
+                .unwrap();
+                assert_approx_eq!(expected_fraction, flash.flash_fraction, 1e-3);
+                let vapor_flow = flash.vapor_flow.convert_to_si_unit().value;
+                assert_approx_eq!(10.0 * expected_fraction, vapor_flow, 1e-2);
+                assert_approx_eq!(
+                    10.0 - vapor_flow,
+                    flash.liquid_flow.convert_to_si_unit().value,
+                    1e-9
+                );
+                // Every joule the condensate loses dropping to the flash liquid enthalpy
+                let released = 10.0
+                    * (flash.condensate.enthalpy.convert_to_si_unit().value
+                        - flash.flash_liquid.enthalpy.convert_to_si_unit().value);
+                assert_approx_eq!(
+                    f64::max(released, 0.0),
+                    flash.energy_recovered.convert_to_si_unit().value,
+                    1e-3
+                );
+                // The vessel neither gains nor loses energy
+                assert_approx_eq!(
+                    10.0 * flash.condensate.enthalpy.convert_to_si_unit().value,
+                    vapor_flow * flash.flash_steam.enthalpy.convert_to_si_unit().value
+                        + (10.0 - vapor_flow) * flash.flash_liquid.enthalpy.convert_to_si_unit().value,
+                    1.0
+                );
+                assert_approx_eq!(
+                    expected_liquid_c,
+                    flash.flash_liquid.temperature.convert_to_si_unit().value - 273.15,
+                    0.1
+                );
+            }
+        )*
+        }
+    }
+
+    flash_tests! {
+        // (762.5 - 417.5) / 2257.5
+        flash_saturated_condensate: (None, 0.1528, 99.61),
+        // (632.5 - 417.5) / 2257.5
+        flash_subcooled_condensate: (Some(150.0), 0.0952, 99.61),
+        // Leaves subcooled, throttling warms the liquid slightly
+        flash_cold_condensate: (Some(90.0), 0.0, 90.17),
+    }
+
+    macro_rules! flash_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (query, expected): (FlashQuery, ProcessUnitErr) = $value;
+                assert_eq!(Err(expected), get_flash(&Iapws97, query));
+            }
+        )*
+        }
+    }
+
+    flash_err_tests! {
+        // Superheated at 1 MPa
+        flash_condensate_not_liquid: (
+            FlashQuery {
+                condensate_pressure: Pressure::Pa(Pa::new(1e6)),
+                condensate_temperature: Some(Temperature::C(C::new(250.0))),
+                condensate_flow: MassFlowRate::KgPerSec(KgPerSec::new(10.0)),
+                flash_pressure: Pressure::KPa(KPa::new(100.0)),
+            },
+            ProcessUnitErr::CondensateNotLiquid,
+        ),
+        flash_above_condensate_pressure: (
+            FlashQuery {
+                condensate_pressure: Pressure::Pa(Pa::new(1e6)),
+                condensate_temperature: None,
+                condensate_flow: MassFlowRate::KgPerSec(KgPerSec::new(10.0)),
+                flash_pressure: Pressure::Pa(Pa::new(2e6)),
+            },
+            ProcessUnitErr::OutletPressureHigh,
+        ),
+    }
+}
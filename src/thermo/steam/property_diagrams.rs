@@ -0,0 +1,391 @@
+use super::iapws97::{
+    get_critical_point_entry, get_region3_saturation_line, get_steam_table_entries,
+};
+use super::water_constants::*;
+use super::*;
+use crate::thermo::diagrams::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PropertyDiagramKind {
+    TemperatureEntropy,
+    // Mollier diagram
+    EnthalpyEntropy,
+    PressureEnthalpy,
+    PressureVolume,
+}
+
+impl TryFrom<String> for PropertyDiagramKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "TemperatureEntropy" => Ok(PropertyDiagramKind::TemperatureEntropy),
+            "EnthalpyEntropy" => Ok(PropertyDiagramKind::EnthalpyEntropy),
+            "PressureEnthalpy" => Ok(PropertyDiagramKind::PressureEnthalpy),
+            "PressureVolume" => Ok(PropertyDiagramKind::PressureVolume),
+            _ => Err(format!("Unknown Diagram \"{}\"", value)),
+        }
+    }
+}
+
+impl From<PropertyDiagramKind> for String {
+    fn from(val: PropertyDiagramKind) -> Self {
+        match val {
+            PropertyDiagramKind::TemperatureEntropy => "TemperatureEntropy".to_owned(),
+            PropertyDiagramKind::EnthalpyEntropy => "EnthalpyEntropy".to_owned(),
+            PropertyDiagramKind::PressureEnthalpy => "PressureEnthalpy".to_owned(),
+            PropertyDiagramKind::PressureVolume => "PressureVolume".to_owned(),
+        }
+    }
+}
+
+impl Display for PropertyDiagramKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PropertyDiagramKind::TemperatureEntropy => "Temperature-Entropy (T-s)",
+                PropertyDiagramKind::EnthalpyEntropy => "Enthalpy-Entropy (h-s Mollier)",
+                PropertyDiagramKind::PressureEnthalpy => "Pressure-Enthalpy (P-h)",
+                PropertyDiagramKind::PressureVolume => "Pressure-Volume (P-v)",
+            }
+        )
+    }
+}
+
+// MPa
+const ISOBARS: [f64; 7] = [0.01, 0.1, 1.0, 5.0, 10.0, 30.0, 50.0];
+// °C
+const ISOTHERMS: [f64; 6] = [100.0, 200.0, 300.0, 400.0, 500.0, 600.0];
+const QUALITIES: [f64; 4] = [0.2, 0.4, 0.6, 0.8];
+const SWEEP_POINTS: usize = 60;
+
+/// x and y axes of a diagram kind, the same for every fluid
+pub fn get_axes(kind: PropertyDiagramKind) -> (Axis, Axis) {
+    let linear = |label: &str| Axis {
+        label: label.to_owned(),
+        scale: AxisScale::Linear,
+    };
+    let log = |label: &str| Axis {
+        label: label.to_owned(),
+        scale: AxisScale::Log,
+    };
+    match kind {
+        PropertyDiagramKind::TemperatureEntropy => (linear("s (kJ/(kg · K))"), linear("T (°C)")),
+        PropertyDiagramKind::EnthalpyEntropy => (linear("s (kJ/(kg · K))"), linear("h (kJ/kg)")),
+        PropertyDiagramKind::PressureEnthalpy => (linear("h (kJ/kg)"), log("P (MPa)")),
+        PropertyDiagramKind::PressureVolume => (log("v (m³/kg)"), log("P (MPa)")),
+    }
+}
+
+/// Where an entry lands on a diagram in the units of get_property_diagram's axes
+pub fn get_diagram_coordinates(kind: PropertyDiagramKind, entry: &PtvEntry) -> (f64, f64) {
+    let temperature = entry.temperature.convert_to_si_unit().value - 273.15;
+    let pressure = entry.pressure.convert_to_si_unit().value / 1e6;
+    let enthalpy = entry.enthalpy.convert_to_si_unit().value / 1e3;
+    let entropy = entry.entropy.convert_to_si_unit().value / 1e3;
+    let specific_volume = entry.specific_volume.convert_to_si_unit().value;
+    match kind {
+        PropertyDiagramKind::TemperatureEntropy => (entropy, temperature),
+        PropertyDiagramKind::EnthalpyEntropy => (entropy, enthalpy),
+        PropertyDiagramKind::PressureEnthalpy => (enthalpy, pressure),
+        PropertyDiagramKind::PressureVolume => (specific_volume, pressure),
+    }
+}
+
+/// Saturated liquid and vapor queries at each temperature in K, in that order
+pub fn get_sat_t_queries(temperatures: &[f64]) -> Vec<SteamQuery> {
+    temperatures
+        .iter()
+        .flat_map(|t| {
+            [
+                SteamNonCriticalPhaseRegion::Liquid,
+                SteamNonCriticalPhaseRegion::Vapor,
+            ]
+            .map(|phase_region| {
+                SteamQuery::Sat(SatQuery::SatTQuery {
+                    temperature: Temperature::K(K::new(*t)),
+                    phase_region,
+                })
+            })
+        })
+        .collect()
+}
+
+// Saturated liquid and vapor pairs from the triple point up to the critical temperature,
+// region 4 below 623.15 K and region 3 above it
+fn get_saturation_pairs() -> Vec<(PtvEntry, PtvEntry)> {
+    let temperatures = tables::get_linear_range(273.16, 623.15, 50);
+    let entries = get_steam_table_entries(&get_sat_t_queries(&temperatures));
+    let region3_temperatures = (1..30)
+        .map(|x| {
+            let frac = 1f64 - x as f64 / 30f64;
+            K::new(CRITICAL_TEMPERATURE.value - (CRITICAL_TEMPERATURE.value - 623.15) * frac * frac)
+        })
+        .collect::<Vec<K>>();
+    entries
+        .chunks(2)
+        .filter_map(|pair| match pair {
+            [Ok(liquid), Ok(vapor)] => Some((liquid.clone(), vapor.clone())),
+            _ => None,
+        })
+        .chain(get_region3_saturation_line(&region3_temperatures))
+        .collect()
+}
+
+/// Saturated liquid line up to the critical point and back down the saturated vapor line
+pub fn get_dome(
+    kind: PropertyDiagramKind,
+    pairs: &[(PtvEntry, PtvEntry)],
+    critical_point: &PtvEntry,
+) -> DiagramLine {
+    let points = pairs
+        .iter()
+        .map(|(liquid, _)| liquid)
+        .chain(std::iter::once(critical_point))
+        .chain(pairs.iter().rev().map(|(_, vapor)| vapor))
+        .map(|x| get_diagram_coordinates(kind, x))
+        .collect();
+    DiagramLine {
+        label: "Saturation".to_owned(),
+        style: LineStyle::Saturation,
+        points,
+    }
+}
+
+/// Properties are linear in quality inside the dome, which meets at the critical point
+pub fn get_quality_lines(
+    kind: PropertyDiagramKind,
+    pairs: &[(PtvEntry, PtvEntry)],
+    critical_point: &PtvEntry,
+) -> Vec<DiagramLine> {
+    let critical_point = get_diagram_coordinates(kind, critical_point);
+    QUALITIES
+        .iter()
+        .map(|quality| {
+            let points = pairs
+                .iter()
+                .map(|(liquid, vapor)| {
+                    let (x_liquid, y_liquid) = get_diagram_coordinates(kind, liquid);
+                    let (x_vapor, y_vapor) = get_diagram_coordinates(kind, vapor);
+                    (
+                        x_liquid + quality * (x_vapor - x_liquid),
+                        y_liquid + quality * (y_vapor - y_liquid),
+                    )
+                })
+                .chain(std::iter::once(critical_point))
+                .collect();
+            DiagramLine {
+                label: format!("x = {}", quality),
+                style: LineStyle::Quality,
+                points,
+            }
+        })
+        .collect()
+}
+
+// Single phase sweep with the saturated states spliced in where the line crosses the dome
+fn get_iso_line(
+    kind: PropertyDiagramKind,
+    sweep: Vec<SteamQuery>,
+    saturation: Vec<SteamQuery>,
+    get_sweep_value: fn(&PtvEntry) -> f64,
+) -> Vec<(f64, f64)> {
+    let sat_entries = get_steam_table_entries(&saturation)
+        .into_iter()
+        .filter_map(|x| x.ok())
+        .collect::<Vec<PtvEntry>>();
+    let sat_values = sat_entries
+        .iter()
+        .map(get_sweep_value)
+        .collect::<Vec<f64>>();
+    let sat_min = sat_values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let sat_max = sat_values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mut entries = get_steam_table_entries(&sweep)
+        .into_iter()
+        .filter_map(|x| x.ok())
+        .filter(|x| {
+            let value = get_sweep_value(x);
+            !(sat_min..=sat_max).contains(&value)
+        })
+        .chain(sat_entries.iter().cloned())
+        .collect::<Vec<PtvEntry>>();
+    entries.sort_by(|a, b| {
+        get_sweep_value(a)
+            .partial_cmp(&get_sweep_value(b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    entries
+        .iter()
+        .map(|x| get_diagram_coordinates(kind, x))
+        .collect()
+}
+
+fn get_isobars(kind: PropertyDiagramKind) -> Vec<DiagramLine> {
+    let temperatures = tables::get_linear_range(273.16, 1073.15, SWEEP_POINTS);
+    ISOBARS
+        .iter()
+        .map(|mpa| {
+            let pressure = Pressure::Pa(Pa::new(mpa * 1e6));
+            let sweep = temperatures
+                .iter()
+                .map(|t| {
+                    SteamQuery::Pt(PtPoint {
+                        pressure,
+                        temperature: Temperature::K(K::new(*t)),
+                    })
+                })
+                .collect();
+            let saturation = match pressure.convert_to_si_unit().value < CRITICAL_PRESSURE.value {
+                true => [
+                    SteamNonCriticalPhaseRegion::Liquid,
+                    SteamNonCriticalPhaseRegion::Vapor,
+                ]
+                .map(|phase_region| {
+                    SteamQuery::Sat(SatQuery::SatPQuery {
+                        pressure,
+                        phase_region,
+                    })
+                })
+                .to_vec(),
+                false => vec![],
+            };
+            DiagramLine {
+                label: format!("{} MPa", mpa),
+                style: LineStyle::Isobar,
+                points: get_iso_line(kind, sweep, saturation, |x| {
+                    x.entropy.convert_to_si_unit().value
+                }),
+            }
+        })
+        .collect()
+}
+
+fn get_isotherms(kind: PropertyDiagramKind) -> Vec<DiagramLine> {
+    let log_pressures = tables::get_linear_range(3f64, 8f64, SWEEP_POINTS);
+    ISOTHERMS
+        .iter()
+        .map(|celsius| {
+            let temperature = Temperature::K(K::new(celsius + 273.15));
+            let sweep = log_pressures
+                .iter()
+                .map(|x| {
+                    SteamQuery::Pt(PtPoint {
+                        pressure: Pressure::Pa(Pa::new(f64::powf(10f64, *x))),
+                        temperature,
+                    })
+                })
+                .collect();
+            let saturation =
+                match temperature.convert_to_si_unit().value < CRITICAL_TEMPERATURE.value {
+                    true => get_sat_t_queries(&[temperature.convert_to_si_unit().value]),
+                    false => vec![],
+                };
+            DiagramLine {
+                label: format!("{} °C", celsius),
+                style: LineStyle::Isotherm,
+                // Ordered by decreasing entropy, which runs from the vapor to the liquid
+                points: get_iso_line(kind, sweep, saturation, |x| {
+                    -x.entropy.convert_to_si_unit().value
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Saturation dome, constant quality lines and the isobars or isotherms that are not flat on
+/// the chosen diagram, computed with IAPWS-IF97. The state, if any, is marked on top.
+pub fn get_property_diagram(kind: PropertyDiagramKind, state: Option<&PtvEntry>) -> Diagram {
+    let (x_axis, y_axis) = get_axes(kind);
+    let pairs = get_saturation_pairs();
+    let critical_point = get_critical_point_entry();
+    let mut lines = vec![get_dome(kind, &pairs, &critical_point)];
+    lines.extend(get_quality_lines(kind, &pairs, &critical_point));
+    if kind != PropertyDiagramKind::PressureEnthalpy && kind != PropertyDiagramKind::PressureVolume
+    {
+        lines.extend(get_isobars(kind));
+    }
+    if kind != PropertyDiagramKind::TemperatureEntropy {
+        lines.extend(get_isotherms(kind));
+    }
+    let points = state
+        .map(|entry| {
+            let (x, y) = get_diagram_coordinates(kind, entry);
+            DiagramPoint {
+                label: "State".to_owned(),
+                x,
+                y,
+            }
+        })
+        .into_iter()
+        .collect();
+    Diagram {
+        title: format!("{} Diagram of Water", kind),
+        x_axis,
+        y_axis,
+        lines,
+        points,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn dome_meets_at_critical_point() {
+        let diagram = get_property_diagram(PropertyDiagramKind::TemperatureEntropy, None);
+        let dome = &diagram.lines[0];
+        let (s_triple_liquid, t_triple_liquid) = dome.points[0];
+        let (s_triple_vapor, t_triple_vapor) = dome.points[dome.points.len() - 1];
+        assert_approx_eq!(0.01, t_triple_liquid, 1e-9);
+        assert_approx_eq!(0.01, t_triple_vapor, 1e-9);
+        assert_approx_eq!(0.0, s_triple_liquid, 1e-3);
+        assert_approx_eq!(9.1555, s_triple_vapor, 1e-3);
+        let critical_index = (dome.points.len() - 1) / 2;
+        let (s_critical, t_critical) = dome.points[critical_index];
+        assert_approx_eq!(373.946, t_critical, 1e-9);
+        assert_approx_eq!(4.412, s_critical, 1e-2);
+        // The temperature rises up the liquid side and falls down the vapor side
+        assert!(dome.points[..=critical_index]
+            .windows(2)
+            .all(|x| x[0].1 < x[1].1));
+        assert!(dome.points[critical_index..]
+            .windows(2)
+            .all(|x| x[0].1 > x[1].1));
+    }
+
+    #[test]
+    fn isobar_crosses_dome_at_saturation() {
+        let diagram = get_property_diagram(PropertyDiagramKind::TemperatureEntropy, None);
+        let isobar = diagram.lines.iter().find(|x| x.label == "1 MPa").unwrap();
+        // 1 MPa boils at 179.88 °C from s_f = 2.1381 to s_g = 6.5850 kJ/(kg · K)
+        let boiling = isobar
+            .points
+            .iter()
+            .filter(|x| f64::abs(x.1 - 179.88) < 1e-2)
+            .collect::<Vec<_>>();
+        assert_eq!(2, boiling.len());
+        assert_approx_eq!(2.1381, boiling[0].0, 1e-3);
+        assert_approx_eq!(6.5850, boiling[1].0, 1e-3);
+        assert!(diagram.lines.iter().all(|x| x.style != LineStyle::Isotherm));
+    }
+
+    #[test]
+    fn state_point_overlay() {
+        let entry = iapws97::get_steam_table_entry(SteamQuery::Pt(PtPoint {
+            pressure: Pressure::Pa(Pa::new(3e6)),
+            temperature: Temperature::K(K::new(573.15)),
+        }))
+        .unwrap();
+        let diagram = get_property_diagram(PropertyDiagramKind::PressureEnthalpy, Some(&entry));
+        let point = &diagram.points[0];
+        assert_approx_eq!(2994.35, point.x, 0.1);
+        assert_approx_eq!(3.0, point.y, 1e-9);
+        let svg = diagram.to_svg(800, 600);
+        assert!(svg.contains("<title>State</title>"));
+        assert!(svg.contains("<title>x = 0.4</title>"));
+    }
+}
@@ -0,0 +1,591 @@
+use super::*;
+
+/// Unit abbreviations used for the columns of a generated steam table
+#[derive(Debug, PartialEq, Clone)]
+pub struct SteamTableUnits {
+    pub temperature: String,
+    pub pressure: String,
+    pub specific_volume: String,
+    // Used for internal energy and enthalpy
+    pub energy_per_mass: String,
+    pub entropy: String,
+}
+
+impl Default for SteamTableUnits {
+    fn default() -> Self {
+        SteamTableUnits {
+            temperature: Temperature::get_si_unit_label().abbreviation,
+            pressure: Pressure::get_si_unit_label().abbreviation,
+            specific_volume: SpecificVolume::get_si_unit_label().abbreviation,
+            energy_per_mass: EnergyPerMass::get_si_unit_label().abbreviation,
+            entropy: EnergyPerMassTemperature::get_si_unit_label().abbreviation,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SteamTableErr {
+    EmptyRange,
+    ParseUnitError(ParseUnitError),
+}
+
+/// Formatted cells of a steam table, ready to be exported or rendered
+#[derive(Debug, PartialEq, Clone)]
+pub struct SteamTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Evenly spaced values from start to end, both included
+pub fn get_linear_range(start: f64, end: f64, points: usize) -> Vec<f64> {
+    match points {
+        0 => vec![],
+        1 => vec![start],
+        _ => (0..points)
+            .map(|x| start + (end - start) * x as f64 / (points - 1) as f64)
+            .collect(),
+    }
+}
+
+// At least six significant figures, switching to scientific notation for very large or small values
+pub(crate) fn format_value(value: f64) -> String {
+    let magnitude = f64::abs(value);
+    if value == 0f64 {
+        "0".to_owned()
+    } else if !(1e-4..1e9).contains(&magnitude) {
+        format!("{:.5e}", value)
+    } else {
+        let decimals = 5 - f64::floor(f64::log10(magnitude)) as i32;
+        format!("{:.*}", i32::max(decimals, 0) as usize, value)
+    }
+}
+
+pub(crate) fn format_unit<T: Unit>(value: T, unit: &str) -> Result<String, SteamTableErr> {
+    value
+        .try_convert(unit.to_owned())
+        .map(|x| format_value(x.get_value()))
+        .map_err(SteamTableErr::ParseUnitError)
+}
+
+pub(crate) fn get_header(symbol: &str, unit: &str) -> String {
+    format!("{} ({})", symbol, unit)
+}
+
+// Failed points keep their row, with the error in place of the first property,
+// so a table never silently skips part of the requested range
+fn get_error_row(
+    first: String,
+    second: String,
+    err: &SteamQueryErr,
+    columns: usize,
+) -> Vec<String> {
+    let message = match err {
+        SteamQueryErr::OutOfRange(_) => "Out of range",
+        SteamQueryErr::CompositePhaseRegionErr(_) => "Phase error",
+        SteamQueryErr::FailedToConverge(_) => "Failed to converge",
+    };
+    let mut row = vec![first, second, message.to_owned()];
+    row.resize(columns, String::new());
+    row
+}
+
+fn check_units(units: &SteamTableUnits) -> Result<(), SteamTableErr> {
+    format_unit(Temperature::K(K::new(0f64)), &units.temperature)?;
+    format_unit(Pressure::Pa(Pa::new(0f64)), &units.pressure)?;
+    format_unit(
+        SpecificVolume::M3PerKg(M3PerKg::new(0f64)),
+        &units.specific_volume,
+    )?;
+    format_unit(
+        EnergyPerMass::JPerKg(JPerKg::new(0f64)),
+        &units.energy_per_mass,
+    )?;
+    format_unit(
+        EnergyPerMassTemperature::JPerKgK(JPerKgK::new(0f64)),
+        &units.entropy,
+    )?;
+    Ok(())
+}
+
+fn get_saturation_headers(units: &SteamTableUnits, by_temperature: bool) -> Vec<String> {
+    let temperature = get_header("T", &units.temperature);
+    let pressure = get_header("P", &units.pressure);
+    let (first, second) = if by_temperature {
+        (temperature, pressure)
+    } else {
+        (pressure, temperature)
+    };
+    vec![
+        first,
+        second,
+        get_header("v_f", &units.specific_volume),
+        get_header("v_g", &units.specific_volume),
+        get_header("u_f", &units.energy_per_mass),
+        get_header("u_g", &units.energy_per_mass),
+        get_header("h_f", &units.energy_per_mass),
+        get_header("h_fg", &units.energy_per_mass),
+        get_header("h_g", &units.energy_per_mass),
+        get_header("s_f", &units.entropy),
+        get_header("s_fg", &units.entropy),
+        get_header("s_g", &units.entropy),
+    ]
+}
+
+fn get_saturation_row(
+    liquid: &PtvEntry,
+    vapor: &PtvEntry,
+    units: &SteamTableUnits,
+    by_temperature: bool,
+) -> Result<Vec<String>, SteamTableErr> {
+    let temperature = format_unit(liquid.temperature, &units.temperature)?;
+    let pressure = format_unit(liquid.pressure, &units.pressure)?;
+    let (first, second) = if by_temperature {
+        (temperature, pressure)
+    } else {
+        (pressure, temperature)
+    };
+    Ok(vec![
+        first,
+        second,
+        format_unit(liquid.specific_volume, &units.specific_volume)?,
+        format_unit(vapor.specific_volume, &units.specific_volume)?,
+        format_unit(liquid.internal_energy, &units.energy_per_mass)?,
+        format_unit(vapor.internal_energy, &units.energy_per_mass)?,
+        format_unit(liquid.enthalpy, &units.energy_per_mass)?,
+        format_unit(vapor.enthalpy - liquid.enthalpy, &units.energy_per_mass)?,
+        format_unit(vapor.enthalpy, &units.energy_per_mass)?,
+        format_unit(liquid.entropy, &units.entropy)?,
+        format_unit(vapor.entropy - liquid.entropy, &units.entropy)?,
+        format_unit(vapor.entropy, &units.entropy)?,
+    ])
+}
+
+// Queries are laid out as liquid then vapor for each saturation point.
+// Points where either phase cannot be evaluated, such as above the critical point, get an error row.
+fn get_saturation_table(
+    backend: &(impl SteamProperties + Sync),
+    queries: &[SteamQuery],
+    units: &SteamTableUnits,
+    by_temperature: bool,
+) -> Result<SteamTable, SteamTableErr> {
+    if queries.is_empty() {
+        return Err(SteamTableErr::EmptyRange);
+    }
+    check_units(units)?;
+    let headers = get_saturation_headers(units, by_temperature);
+    let entries = get_steam_table_entries(backend, queries);
+    let mut rows = Vec::with_capacity(entries.len() / 2);
+    for (pair, query) in entries.chunks(2).zip(queries.iter().step_by(2)) {
+        match pair {
+            [Ok(liquid), Ok(vapor)] => {
+                rows.push(get_saturation_row(liquid, vapor, units, by_temperature)?)
+            }
+            [Err(err), _] | [_, Err(err)] => {
+                let first = match query {
+                    SteamQuery::Sat(SatQuery::SatTQuery { temperature, .. }) => {
+                        format_unit(*temperature, &units.temperature)?
+                    }
+                    SteamQuery::Sat(SatQuery::SatPQuery { pressure, .. }) => {
+                        format_unit(*pressure, &units.pressure)?
+                    }
+                    _ => String::new(),
+                };
+                rows.push(get_error_row(first, String::new(), err, headers.len()));
+            }
+            _ => {}
+        }
+    }
+    Ok(SteamTable { headers, rows })
+}
+
+/// Saturated liquid and vapor properties at each temperature
+pub fn get_saturation_table_by_temperature(
+    backend: &(impl SteamProperties + Sync),
+    temperatures: &[Temperature],
+    units: &SteamTableUnits,
+) -> Result<SteamTable, SteamTableErr> {
+    let queries = temperatures
+        .iter()
+        .flat_map(|temperature| {
+            [
+                SteamNonCriticalPhaseRegion::Liquid,
+                SteamNonCriticalPhaseRegion::Vapor,
+            ]
+            .map(|phase_region| {
+                SteamQuery::Sat(SatQuery::SatTQuery {
+                    temperature: *temperature,
+                    phase_region,
+                })
+            })
+        })
+        .collect::<Vec<SteamQuery>>();
+    get_saturation_table(backend, &queries, units, true)
+}
+
+/// Saturated liquid and vapor properties at each pressure
+pub fn get_saturation_table_by_pressure(
+    backend: &(impl SteamProperties + Sync),
+    pressures: &[Pressure],
+    units: &SteamTableUnits,
+) -> Result<SteamTable, SteamTableErr> {
+    let queries = pressures
+        .iter()
+        .flat_map(|pressure| {
+            [
+                SteamNonCriticalPhaseRegion::Liquid,
+                SteamNonCriticalPhaseRegion::Vapor,
+            ]
+            .map(|phase_region| {
+                SteamQuery::Sat(SatQuery::SatPQuery {
+                    pressure: *pressure,
+                    phase_region,
+                })
+            })
+        })
+        .collect::<Vec<SteamQuery>>();
+    get_saturation_table(backend, &queries, units, false)
+}
+
+/// Compressed liquid, superheated vapor and supercritical properties at every
+/// pressure and temperature combination. Points outside of the formulation get an error row.
+pub fn get_pressure_temperature_table(
+    backend: &(impl SteamProperties + Sync),
+    pressures: &[Pressure],
+    temperatures: &[Temperature],
+    units: &SteamTableUnits,
+) -> Result<SteamTable, SteamTableErr> {
+    if pressures.is_empty() || temperatures.is_empty() {
+        return Err(SteamTableErr::EmptyRange);
+    }
+    check_units(units)?;
+    let queries = pressures
+        .iter()
+        .flat_map(|pressure| {
+            temperatures.iter().map(|temperature| {
+                SteamQuery::Pt(PtPoint {
+                    pressure: *pressure,
+                    temperature: *temperature,
+                })
+            })
+        })
+        .collect::<Vec<SteamQuery>>();
+    let headers = vec![
+        get_header("P", &units.pressure),
+        get_header("T", &units.temperature),
+        "Phase".to_owned(),
+        get_header("v", &units.specific_volume),
+        get_header("u", &units.energy_per_mass),
+        get_header("h", &units.energy_per_mass),
+        get_header("s", &units.entropy),
+    ];
+    let mut rows = Vec::with_capacity(queries.len());
+    for (result, query) in get_steam_table_entries(backend, &queries)
+        .iter()
+        .zip(queries.iter())
+    {
+        match (result, query) {
+            (Ok(entry), _) => rows.push(vec![
+                format_unit(entry.pressure, &units.pressure)?,
+                format_unit(entry.temperature, &units.temperature)?,
+                entry.phase_region.to_string(),
+                format_unit(entry.specific_volume, &units.specific_volume)?,
+                format_unit(entry.internal_energy, &units.energy_per_mass)?,
+                format_unit(entry.enthalpy, &units.energy_per_mass)?,
+                format_unit(entry.entropy, &units.entropy)?,
+            ]),
+            (Err(err), SteamQuery::Pt(point)) => rows.push(get_error_row(
+                format_unit(point.pressure, &units.pressure)?,
+                format_unit(point.temperature, &units.temperature)?,
+                err,
+                headers.len(),
+            )),
+            _ => {}
+        }
+    }
+    Ok(SteamTable { headers, rows })
+}
+
+fn escape_csv(cell: &str) -> String {
+    if cell.contains([',', '"', '\n']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
+
+fn escape_html(cell: &str) -> String {
+    cell.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl SteamTable {
+    pub fn to_csv(&self) -> String {
+        std::iter::once(&self.headers)
+            .chain(self.rows.iter())
+            .map(|row| {
+                let cells = row.iter().map(|x| escape_csv(x)).collect::<Vec<String>>();
+                format!("{}\n", cells.join(","))
+            })
+            .collect()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let to_line = |row: &Vec<String>| {
+            let cells = row
+                .iter()
+                .map(|x| x.replace('|', "\\|"))
+                .collect::<Vec<String>>();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        let divider = format!("|{}\n", " --- |".repeat(self.headers.len()));
+        to_line(&self.headers) + &divider + &self.rows.iter().map(to_line).collect::<String>()
+    }
+
+    pub fn to_html(&self) -> String {
+        let to_line = |row: &Vec<String>, tag: &str| {
+            let cells = row
+                .iter()
+                .map(|x| format!("<{0}>{1}</{0}>", tag, escape_html(x)))
+                .collect::<String>();
+            format!("<tr>{}</tr>\n", cells)
+        };
+        format!(
+            "<table>\n<thead>\n{}</thead>\n<tbody>\n{}</tbody>\n</table>\n",
+            to_line(&self.headers, "th"),
+            self.rows
+                .iter()
+                .map(|x| to_line(x, "td"))
+                .collect::<String>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thermo::steam::iapws97::Iapws97;
+
+    macro_rules! linear_range_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (start, end, count, expected): (f64, f64, usize, Vec<f64>) = $value;
+                assert_eq!(expected, get_linear_range(start, end, count));
+            }
+        )*
+        }
+    }
+
+    linear_range_tests! {
+        linear_range_three_points: (1.0, 2.0, 3, vec![1.0, 1.5, 2.0]),
+        linear_range_one_point: (1.0, 2.0, 1, vec![1.0]),
+        linear_range_empty: (1.0, 2.0, 0, vec![]),
+    }
+
+    macro_rules! format_value_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (value, expected): (f64, &str) = $value;
+                assert_eq!(expected, format_value(value));
+            }
+        )*
+        }
+    }
+
+    format_value_tests! {
+        format_value_fixed: (2675.5712, "2675.57"),
+        format_value_small: (0.0010434556, "0.00104346"),
+        format_value_integer: (101325.0, "101325"),
+        format_value_scientific: (2.2064e9, "2.20640e9"),
+        format_value_zero: (0.0, "0"),
+    }
+
+    macro_rules! steam_table_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (table, row_count, headers, row, cells): (
+                    Result<SteamTable, SteamTableErr>,
+                    usize,
+                    &[(usize, &str)],
+                    usize,
+                    &[(usize, &str)],
+                ) = $value;
+                let table = table.unwrap();
+                assert_eq!(row_count, table.rows.len());
+                for (column, expected) in headers {
+                    assert_eq!(*expected, table.headers[*column]);
+                }
+                for (column, expected) in cells {
+                    assert_eq!(*expected, table.rows[row][*column]);
+                }
+            }
+        )*
+        }
+    }
+
+    steam_table_tests! {
+        saturation_table_by_temperature: (
+            get_saturation_table_by_temperature(
+                &Iapws97,
+                &[Temperature::C(C::new(100.0)), Temperature::C(C::new(200.0))],
+                &SteamTableUnits {
+                    temperature: "°C".to_owned(),
+                    pressure: "kPa".to_owned(),
+                    ..Default::default()
+                },
+            ),
+            2,
+            &[(0, "T (°C)"), (7, "h_fg (J/kg)")],
+            0,
+            &[(0, "100.000"), (1, "101.418"), (3, "1.67186"), (7, "2256473")],
+        ),
+        // 700 °C is above the critical point
+        saturation_table_above_critical_temperature: (
+            get_saturation_table_by_temperature(
+                &Iapws97,
+                &[Temperature::C(C::new(100.0)), Temperature::C(C::new(700.0))],
+                &SteamTableUnits {
+                    temperature: "°C".to_owned(),
+                    ..Default::default()
+                },
+            ),
+            2,
+            &[],
+            1,
+            &[(0, "700.000"), (2, "Out of range"), (11, "")],
+        ),
+        saturation_table_by_pressure: (
+            get_saturation_table_by_pressure(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(1e6))],
+                &Default::default(),
+            ),
+            1,
+            &[(0, "P (Pa)")],
+            0,
+            &[(0, "1000000"), (1, "453.036")],
+        ),
+        pressure_temperature_table_liquid: (
+            get_pressure_temperature_table(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(3e6))],
+                &[Temperature::K(K::new(300.0)), Temperature::K(K::new(500.0))],
+                &Default::default(),
+            ),
+            2,
+            &[],
+            0,
+            &[
+                (0, "3000000"),
+                (1, "300.000"),
+                (2, "Liquid"),
+                (3, "0.00100215"),
+                (4, "112325"),
+                (5, "115331"),
+                (6, "392.295"),
+            ],
+        ),
+        pressure_temperature_table_hot_liquid: (
+            get_pressure_temperature_table(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(3e6))],
+                &[Temperature::K(K::new(300.0)), Temperature::K(K::new(500.0))],
+                &Default::default(),
+            ),
+            2,
+            &[],
+            1,
+            &[(1, "500.000"), (2, "Liquid")],
+        ),
+        // 200 MPa is outside of IF97
+        pressure_temperature_table_out_of_range: (
+            get_pressure_temperature_table(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(200e6))],
+                &[Temperature::K(K::new(300.0))],
+                &Default::default(),
+            ),
+            1,
+            &[],
+            0,
+            &[(0, "200000000"), (2, "Out of range"), (3, ""), (4, ""), (5, ""), (6, "")],
+        ),
+    }
+
+    macro_rules! steam_table_err_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (table, expected): (Result<SteamTable, SteamTableErr>, SteamTableErr) = $value;
+                assert_eq!(Err(expected), table);
+            }
+        )*
+        }
+    }
+
+    steam_table_err_tests! {
+        table_unknown_unit: (
+            get_saturation_table_by_pressure(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(1e6))],
+                &SteamTableUnits {
+                    pressure: "furlongs".to_owned(),
+                    ..Default::default()
+                },
+            ),
+            SteamTableErr::ParseUnitError(ParseUnitError::UnknownUnit("furlongs".to_owned())),
+        ),
+        table_empty_range: (
+            get_pressure_temperature_table(
+                &Iapws97,
+                &[Pressure::Pa(Pa::new(1e6))],
+                &[],
+                &Default::default(),
+            ),
+            SteamTableErr::EmptyRange,
+        ),
+    }
+
+    macro_rules! export_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (phase, export, expected): (&str, fn(&SteamTable) -> String, &str) = $value;
+                let table = SteamTable {
+                    headers: vec!["T (K)".to_owned(), "Phase".to_owned()],
+                    rows: vec![vec!["300".to_owned(), phase.to_owned()]],
+                };
+                assert_eq!(expected, export(&table));
+            }
+        )*
+        }
+    }
+
+    export_tests! {
+        export_csv: (
+            "Solid, \"Liquid\"",
+            SteamTable::to_csv,
+            "T (K),Phase\n300,\"Solid, \"\"Liquid\"\"\"\n",
+        ),
+        export_markdown: (
+            "Solid 1.00%, Liquid <99%",
+            SteamTable::to_markdown,
+            "| T (K) | Phase |\n| --- | --- |\n| 300 | Solid 1.00%, Liquid <99% |\n",
+        ),
+        export_html: (
+            "Solid 1.00%, Liquid <99%",
+            SteamTable::to_html,
+            "<table>\n<thead>\n<tr><th>T (K)</th><th>Phase</th></tr>\n</thead>\n<tbody>\n\
+            <tr><td>300</td><td>Solid 1.00%, Liquid &lt;99%</td></tr>\n</tbody>\n</table>\n",
+        ),
+    }
+}
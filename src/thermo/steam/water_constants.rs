@@ -2,4 +2,7 @@ use crate::units::*;
 
 pub static CRITICAL_TEMPERATURE: K = K { value: 647.096 };
 pub static CRITICAL_PRESSURE: Pa = Pa { value: 22.06e6 };
+pub static CRITICAL_DENSITY: KgPerM3 = KgPerM3 { value: 322.0 };
 pub static GAS_CONSTANT: JPerKgK = JPerKgK { value: 461.526 };
+pub static TRIPLE_POINT_TEMPERATURE: K = K { value: 273.16 };
+pub static TRIPLE_POINT_PRESSURE: Pa = Pa { value: 611.657 };
@@ -0,0 +1,870 @@
+// Rachford and Rice (1952) for the phase split,
+// Michelsen and Mollerup (2007) chapters 9 and 12 for the saturation points and the flash
+use std::fmt::Display;
+
+use crate::numerical_methods::multivariate::newton_raphson_system;
+use crate::numerical_methods::root_finders::secant_method;
+use crate::numerical_methods::RootFinderErr;
+use crate::thermo::eos::*;
+use crate::thermo::ideal_gas::{Mixture, Species};
+use crate::thermo::steam::PtPoint;
+use crate::thermo::*;
+
+// Pa per mmHg
+static MMHG: f64 = 133.322368;
+static ZERO_CELSIUS: f64 = 273.15;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AntoineConstants {
+    // log10(P / mmHg) = a - b / (T / °C + c)
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl AntoineConstants {
+    /// Pa, temperature in K
+    pub fn get_vapor_pressure(&self, temperature: f64) -> f64 {
+        MMHG * f64::powf(
+            10f64,
+            self.a - self.b / (temperature - ZERO_CELSIUS + self.c),
+        )
+    }
+
+    /// K, pressure in Pa
+    pub fn get_saturation_temperature(&self, pressure: f64) -> f64 {
+        self.b / (self.a - f64::log10(pressure / MMHG)) - self.c + ZERO_CELSIUS
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AntoineComponent {
+    Acetone,
+    Benzene,
+    Ethanol,
+    Methanol,
+    NHeptane,
+    NHexane,
+    NPentane,
+    Toluene,
+    Water,
+}
+
+impl AntoineComponent {
+    pub fn all() -> Vec<AntoineComponent> {
+        vec![
+            AntoineComponent::Acetone,
+            AntoineComponent::Benzene,
+            AntoineComponent::Ethanol,
+            AntoineComponent::Methanol,
+            AntoineComponent::NHeptane,
+            AntoineComponent::NHexane,
+            AntoineComponent::NPentane,
+            AntoineComponent::Toluene,
+            AntoineComponent::Water,
+        ]
+    }
+
+    /// Felder and Rousseau (2005) table B.4
+    pub fn get_antoine_constants(&self) -> AntoineConstants {
+        let (a, b, c) = match self {
+            AntoineComponent::Acetone => (7.11714, 1210.595, 229.664),
+            AntoineComponent::Benzene => (6.90565, 1211.033, 220.79),
+            AntoineComponent::Ethanol => (8.1122, 1592.864, 226.184),
+            AntoineComponent::Methanol => (7.87863, 1473.11, 230.0),
+            AntoineComponent::NHeptane => (6.89677, 1264.9, 216.544),
+            AntoineComponent::NHexane => (6.87601, 1171.17, 224.408),
+            AntoineComponent::NPentane => (6.87632, 1075.78, 233.205),
+            AntoineComponent::Toluene => (6.95464, 1344.8, 219.482),
+            AntoineComponent::Water => (8.07131, 1730.63, 233.426),
+        };
+        AntoineConstants { a, b, c }
+    }
+}
+
+impl TryFrom<String> for AntoineComponent {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        AntoineComponent::all()
+            .into_iter()
+            .find(|x| x.to_string() == value)
+            .ok_or(format!("Unknown Component \"{}\"", value))
+    }
+}
+
+impl From<AntoineComponent> for String {
+    fn from(val: AntoineComponent) -> Self {
+        val.to_string()
+    }
+}
+
+impl Display for AntoineComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                AntoineComponent::Acetone => "Acetone",
+                AntoineComponent::Benzene => "Benzene",
+                AntoineComponent::Ethanol => "Ethanol",
+                AntoineComponent::Methanol => "Methanol",
+                AntoineComponent::NHeptane => "n-Heptane",
+                AntoineComponent::NHexane => "n-Hexane",
+                AntoineComponent::NPentane => "n-Pentane",
+                AntoineComponent::Toluene => "Toluene",
+                AntoineComponent::Water => "Water",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum VleModel {
+    // Ideal liquid and vapor with Antoine vapor pressures
+    RaoultsLaw(Vec<AntoineComponent>),
+    // Both phases from a cubic equation of state
+    CubicEos(CubicEos, Vec<Species>),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum VleQuery {
+    BubblePointPressure(Temperature),
+    BubblePointTemperature(Pressure),
+    DewPointPressure(Temperature),
+    DewPointTemperature(Pressure),
+    // Isothermal flash at a fixed temperature and pressure
+    Flash(PtPoint),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct VleEntry {
+    pub temperature: Temperature,
+    pub pressure: Pressure,
+    // Two phase results hold mole based phase fractions and compositions
+    pub phase_region: PhaseRegion,
+    // y / x of each component
+    pub k_values: Vec<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum VleErr {
+    NoComponents,
+    DuplicateComponent,
+    // The equation of state only found one phase
+    TrivialSolution,
+    CompositePhaseRegionErr(CompositePhaseRegionErr),
+    EosErr(EosErr),
+    RootFinderErr(RootFinderErr),
+}
+
+fn normalize(composition: &[f64]) -> Vec<f64> {
+    let total: f64 = composition.iter().sum();
+    composition.iter().map(|x| x / total).collect()
+}
+
+impl VleModel {
+    fn get_component_count(&self) -> usize {
+        match self {
+            VleModel::RaoultsLaw(components) => components.len(),
+            VleModel::CubicEos(_, species) => species.len(),
+        }
+    }
+
+    fn validate(&self, feed: &[f64]) -> Result<(), VleErr> {
+        let count = self.get_component_count();
+        if count == 0 {
+            return Err(VleErr::NoComponents);
+        }
+        let has_duplicates = match self {
+            VleModel::RaoultsLaw(components) => components
+                .iter()
+                .enumerate()
+                .any(|(i, x)| components[..i].contains(x)),
+            VleModel::CubicEos(_, species) => species
+                .iter()
+                .enumerate()
+                .any(|(i, x)| species[..i].contains(x)),
+        };
+        if has_duplicates {
+            return Err(VleErr::DuplicateComponent);
+        }
+        if feed.len() != count {
+            return Err(VleErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::CompositionLengthMismatch,
+            ));
+        }
+        if feed.iter().any(|x| x.is_nan() || *x < 0f64) {
+            return Err(VleErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::FractionsMustBePositive,
+            ));
+        }
+        if f64::abs(feed.iter().sum::<f64>() - 1f64) > 1e-6 {
+            return Err(VleErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::FractionsDoNotAddUpToOne,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pure component vapor pressures in Pa from Antoine or Wilson's correlation
+    fn get_ideal_vapor_pressures(&self, temperature: f64) -> Result<Vec<f64>, VleErr> {
+        match self {
+            VleModel::RaoultsLaw(components) => Ok(components
+                .iter()
+                .map(|x| x.get_antoine_constants().get_vapor_pressure(temperature))
+                .collect()),
+            VleModel::CubicEos(_, species) => species
+                .iter()
+                .map(|x| {
+                    let critical = get_critical_constants(*x).map_err(VleErr::EosErr)?;
+                    Ok(critical.pressure
+                        * f64::exp(
+                            5.373
+                                * (1f64 + critical.acentric_factor)
+                                * (1f64 - critical.temperature / temperature),
+                        ))
+                })
+                .collect(),
+        }
+    }
+
+    /// Pure component saturation temperatures in K, the inverse of the ideal vapor pressures
+    fn get_ideal_saturation_temperatures(&self, pressure: f64) -> Result<Vec<f64>, VleErr> {
+        match self {
+            VleModel::RaoultsLaw(components) => Ok(components
+                .iter()
+                .map(|x| {
+                    x.get_antoine_constants()
+                        .get_saturation_temperature(pressure)
+                })
+                .collect()),
+            VleModel::CubicEos(_, species) => species
+                .iter()
+                .map(|x| {
+                    let critical = get_critical_constants(*x).map_err(VleErr::EosErr)?;
+                    Ok(critical.temperature
+                        / (1f64
+                            - f64::ln(pressure / critical.pressure)
+                                / (5.373 * (1f64 + critical.acentric_factor))))
+                })
+                .collect(),
+        }
+    }
+
+    fn get_eos_phase(
+        eos: CubicEos,
+        species: &[Species],
+        composition: &[f64],
+        point: PtPoint,
+        is_liquid: bool,
+    ) -> Result<(f64, Vec<f64>), VleErr> {
+        let mixture = Mixture::from_mole_fractions(
+            species
+                .iter()
+                .copied()
+                .zip(normalize(composition))
+                .collect(),
+        )
+        .map_err(|x| VleErr::EosErr(EosErr::IdealGasErr(x)))?;
+        let roots = get_compressibility_roots(eos, &mixture, point).map_err(VleErr::EosErr)?;
+        let z = if is_liquid {
+            roots.first()
+        } else {
+            roots.last()
+        }
+        .copied()
+        .ok_or(VleErr::EosErr(EosErr::FailedToConverge))?;
+        let phi = get_fugacity_coefficients(eos, &mixture, point, z).map_err(VleErr::EosErr)?;
+        Ok((z, phi))
+    }
+
+    /// ln(K) for the given phase compositions, temperature in K and pressure in Pa
+    fn get_ln_k_values(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        liquid: &[f64],
+        vapor: &[f64],
+    ) -> Result<Vec<f64>, VleErr> {
+        match self {
+            VleModel::RaoultsLaw(_) => Ok(self
+                .get_ideal_vapor_pressures(temperature)?
+                .into_iter()
+                .map(|x| f64::ln(x / pressure))
+                .collect()),
+            VleModel::CubicEos(eos, species) => {
+                let point = PtPoint {
+                    temperature: Temperature::K(K::new(temperature)),
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                };
+                let (_, phi_liquid) = VleModel::get_eos_phase(*eos, species, liquid, point, true)?;
+                let (_, phi_vapor) = VleModel::get_eos_phase(*eos, species, vapor, point, false)?;
+                Ok(phi_liquid
+                    .iter()
+                    .zip(phi_vapor)
+                    .map(|(l, v)| f64::ln(l / v))
+                    .collect())
+            }
+        }
+    }
+
+    /// Both phases fell on the same root of the equation of state
+    fn is_trivial(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        liquid: &[f64],
+        vapor: &[f64],
+    ) -> Result<bool, VleErr> {
+        match self {
+            VleModel::RaoultsLaw(_) => Ok(false),
+            VleModel::CubicEos(eos, species) => {
+                let point = PtPoint {
+                    temperature: Temperature::K(K::new(temperature)),
+                    pressure: Pressure::Pa(Pa::new(pressure)),
+                };
+                let (z_liquid, _) = VleModel::get_eos_phase(*eos, species, liquid, point, true)?;
+                let (z_vapor, _) = VleModel::get_eos_phase(*eos, species, vapor, point, false)?;
+                let same_composition = liquid
+                    .iter()
+                    .zip(vapor)
+                    .all(|(x, y)| f64::abs(x - y) < 1e-6);
+                Ok(same_composition && f64::abs(z_liquid - z_vapor) < 1e-6)
+            }
+        }
+    }
+}
+
+/// Vapor fraction that splits the feed with the given K values,
+/// 0 when the feed is a subcooled liquid and 1 when it is a superheated vapor
+pub fn solve_rachford_rice(feed: &[f64], k_values: &[f64]) -> f64 {
+    let f = |beta: f64| -> f64 {
+        feed.iter()
+            .zip(k_values)
+            .map(|(z, k)| z * (k - 1f64) / (1f64 + beta * (k - 1f64)))
+            .sum()
+    };
+    let df = |beta: f64| -> f64 {
+        feed.iter()
+            .zip(k_values)
+            .map(|(z, k)| -z * f64::powi((k - 1f64) / (1f64 + beta * (k - 1f64)), 2))
+            .sum()
+    };
+    if f(0f64) <= 0f64 {
+        return 0f64;
+    }
+    if f(1f64) >= 0f64 {
+        return 1f64;
+    }
+    // f decreases monotonically so Newton steps outside of the bracket fall back to bisection
+    let (mut lower, mut upper) = (0f64, 1f64);
+    let mut beta = 0.5;
+    for _ in 0..200 {
+        let y = f(beta);
+        if y.abs() < 1e-15 {
+            break;
+        }
+        if y > 0f64 {
+            lower = beta;
+        } else {
+            upper = beta;
+        }
+        let newton = beta - y / df(beta);
+        beta = if newton > lower && newton < upper {
+            newton
+        } else {
+            (lower + upper) / 2f64
+        };
+        if upper - lower < 1e-15 {
+            break;
+        }
+    }
+    beta
+}
+
+fn get_phase_compositions(feed: &[f64], k_values: &[f64], beta: f64) -> (Vec<f64>, Vec<f64>) {
+    let liquid: Vec<f64> = feed
+        .iter()
+        .zip(k_values)
+        .map(|(z, k)| z / (1f64 + beta * (k - 1f64)))
+        .collect();
+    let vapor: Vec<f64> = liquid.iter().zip(k_values).map(|(x, k)| x * k).collect();
+    (normalize(&liquid), normalize(&vapor))
+}
+
+fn create_entry(
+    temperature: f64,
+    pressure: f64,
+    phase_region: PhaseRegion,
+    k_values: Vec<f64>,
+) -> VleEntry {
+    VleEntry {
+        temperature: Temperature::K(K::new(temperature)),
+        pressure: Pressure::Pa(Pa::new(pressure)),
+        phase_region,
+        k_values,
+    }
+}
+
+/// Solves ln(K) together with the unknown temperature or pressure using Newton-Raphson
+fn get_saturation_point(
+    model: &VleModel,
+    feed: &[f64],
+    is_bubble_point: bool,
+    temperature: Option<f64>,
+    pressure: Option<f64>,
+) -> Result<VleEntry, VleErr> {
+    // Ideal solution as the first guess
+    let sum_ideal = |temperature: f64, pressure: f64| -> Result<f64, VleErr> {
+        let vapor_pressures = model.get_ideal_vapor_pressures(temperature)?;
+        Ok(feed
+            .iter()
+            .zip(vapor_pressures)
+            .map(|(z, p_sat)| {
+                if is_bubble_point {
+                    z * p_sat / pressure
+                } else {
+                    z * pressure / p_sat
+                }
+            })
+            .sum())
+    };
+    let (temperature_0, pressure_0) = match (temperature, pressure) {
+        (Some(temperature), _) => {
+            let sum = sum_ideal(temperature, 1f64)?;
+            let pressure = if is_bubble_point { sum } else { 1f64 / sum };
+            (temperature, pressure)
+        }
+        (None, Some(pressure)) => {
+            let guess: f64 = feed
+                .iter()
+                .zip(model.get_ideal_saturation_temperatures(pressure)?)
+                .map(|(z, t)| z * t)
+                .sum();
+            let temperature = secant_method(
+                |t| match sum_ideal(t, pressure) {
+                    Ok(sum) => f64::ln(sum),
+                    Err(_) => f64::NAN,
+                },
+                guess,
+                1e-12,
+            )
+            .map_err(VleErr::RootFinderErr)?;
+            (temperature, pressure)
+        }
+        (None, None) => return Err(VleErr::NoComponents),
+    };
+
+    let get_state = |u: &[f64]| -> (f64, f64, Vec<f64>, Vec<f64>, f64) {
+        let n = feed.len();
+        let k_values: Vec<f64> = u[..n].iter().map(|x| f64::exp(*x)).collect();
+        let t = temperature.unwrap_or_else(|| f64::exp(u[n]));
+        let p = pressure.unwrap_or_else(|| f64::exp(u[n]));
+        let (liquid, vapor, sum) = if is_bubble_point {
+            let vapor: Vec<f64> = feed.iter().zip(&k_values).map(|(x, k)| x * k).collect();
+            let sum: f64 = vapor.iter().sum();
+            (feed.to_vec(), normalize(&vapor), sum)
+        } else {
+            let liquid: Vec<f64> = feed.iter().zip(&k_values).map(|(y, k)| y / k).collect();
+            let sum: f64 = liquid.iter().sum();
+            (normalize(&liquid), feed.to_vec(), sum)
+        };
+        (t, p, liquid, vapor, sum)
+    };
+
+    let mut u0: Vec<f64> = model
+        .get_ideal_vapor_pressures(temperature_0)?
+        .into_iter()
+        .map(|p_sat| f64::ln(p_sat / pressure_0))
+        .collect();
+    u0.push(f64::ln(if temperature.is_some() {
+        pressure_0
+    } else {
+        temperature_0
+    }));
+
+    let u = newton_raphson_system(
+        |u| {
+            let (t, p, liquid, vapor, sum) = get_state(u);
+            match model.get_ln_k_values(t, p, &liquid, &vapor) {
+                Ok(ln_k) => {
+                    let mut residual: Vec<f64> =
+                        u.iter().zip(ln_k).map(|(u, ln_k)| u - ln_k).collect();
+                    residual.push(sum - 1f64);
+                    residual
+                }
+                Err(_) => vec![f64::NAN; u.len()],
+            }
+        },
+        &u0,
+        1e-10,
+    )
+    .map_err(VleErr::RootFinderErr)?;
+
+    let (t, p, liquid, vapor, _) = get_state(&u);
+    if model.is_trivial(t, p, &liquid, &vapor)? {
+        return Err(VleErr::TrivialSolution);
+    }
+    let k_values = u[..feed.len()].iter().map(|x| f64::exp(*x)).collect();
+    let (liquid_frac, vapor_frac) = if is_bubble_point {
+        (1f64, 0f64)
+    } else {
+        (0f64, 1f64)
+    };
+    let phase_region = PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(
+        LiquidVapor::with_compositions(liquid_frac, vapor_frac, liquid, vapor)
+            .map_err(VleErr::CompositePhaseRegionErr)?,
+    ));
+    Ok(create_entry(t, p, phase_region, k_values))
+}
+
+/// Successive substitution on the K values with a Rachford-Rice split each iteration
+fn get_flash(model: &VleModel, feed: &[f64], point: PtPoint) -> Result<VleEntry, VleErr> {
+    let temperature = point.temperature.convert_to_si_unit().value;
+    let pressure = point.pressure.convert_to_si_unit().value;
+    let mut ln_k: Vec<f64> = model
+        .get_ideal_vapor_pressures(temperature)?
+        .into_iter()
+        .map(|p_sat| f64::ln(p_sat / pressure))
+        .collect();
+    for _ in 0..500 {
+        let k_values: Vec<f64> = ln_k.iter().map(|x| f64::exp(*x)).collect();
+        let beta = solve_rachford_rice(feed, &k_values);
+        let (liquid, vapor) = get_phase_compositions(feed, &k_values, beta);
+        let next = model.get_ln_k_values(temperature, pressure, &liquid, &vapor)?;
+        let change = next
+            .iter()
+            .zip(&ln_k)
+            .map(|(x, y)| f64::abs(x - y))
+            .fold(0f64, f64::max);
+        ln_k = next;
+        if change >= 1e-10 {
+            continue;
+        }
+
+        let k_values: Vec<f64> = ln_k.iter().map(|x| f64::exp(*x)).collect();
+        let beta = solve_rachford_rice(feed, &k_values);
+        let phase_region = if ln_k.iter().all(|x| x.abs() < 1e-4) {
+            // Both phases collapsed onto one root so the single phase decides
+            match model {
+                VleModel::CubicEos(eos, species) => {
+                    let mixture = Mixture::from_mole_fractions(
+                        species.iter().copied().zip(feed.iter().copied()).collect(),
+                    )
+                    .map_err(|x| VleErr::EosErr(EosErr::IdealGasErr(x)))?;
+                    get_eos_entry(*eos, &mixture, point)
+                        .map_err(VleErr::EosErr)?
+                        .phase_region
+                }
+                VleModel::RaoultsLaw(_) => return Err(VleErr::TrivialSolution),
+            }
+        } else if beta <= 0f64 {
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid)
+        } else if beta >= 1f64 {
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor)
+        } else {
+            let (liquid, vapor) = get_phase_compositions(feed, &k_values, beta);
+            PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(
+                LiquidVapor::with_compositions(1f64 - beta, beta, liquid, vapor)
+                    .map_err(VleErr::CompositePhaseRegionErr)?,
+            ))
+        };
+        return Ok(create_entry(temperature, pressure, phase_region, k_values));
+    }
+    Err(VleErr::RootFinderErr(RootFinderErr::MaxIterationsReached))
+}
+
+/// Feed is the mole fraction of each of the model's components in the same order
+pub fn get_vle_entry(model: &VleModel, feed: &[f64], query: VleQuery) -> Result<VleEntry, VleErr> {
+    model.validate(feed)?;
+    let feed = normalize(feed);
+    match query {
+        VleQuery::BubblePointPressure(temperature) => get_saturation_point(
+            model,
+            &feed,
+            true,
+            Some(temperature.convert_to_si_unit().value),
+            None,
+        ),
+        VleQuery::BubblePointTemperature(pressure) => get_saturation_point(
+            model,
+            &feed,
+            true,
+            None,
+            Some(pressure.convert_to_si_unit().value),
+        ),
+        VleQuery::DewPointPressure(temperature) => get_saturation_point(
+            model,
+            &feed,
+            false,
+            Some(temperature.convert_to_si_unit().value),
+            None,
+        ),
+        VleQuery::DewPointTemperature(pressure) => get_saturation_point(
+            model,
+            &feed,
+            false,
+            None,
+            Some(pressure.convert_to_si_unit().value),
+        ),
+        VleQuery::Flash(point) => get_flash(model, &feed, point),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    fn get_liquid_vapor(entry: &VleEntry) -> LiquidVapor {
+        match &entry.phase_region {
+            PhaseRegion::Composite(CompositePhaseRegion::LiquidVapor(x)) => x.clone(),
+            x => panic!("Expected two phases but got {}", x),
+        }
+    }
+
+    fn benzene_toluene() -> VleModel {
+        VleModel::RaoultsLaw(vec![AntoineComponent::Benzene, AntoineComponent::Toluene])
+    }
+
+    fn celsius(temperature: f64) -> Temperature {
+        Temperature::C(C::new(temperature))
+    }
+
+    fn kpa(pressure: f64) -> Pressure {
+        Pressure::KPa(KPa::new(pressure))
+    }
+
+    #[test]
+    fn antoine_normal_boiling_points() {
+        for (component, expected) in [
+            (AntoineComponent::Benzene, 80.1),
+            (AntoineComponent::Toluene, 110.6),
+            (AntoineComponent::Water, 100.0),
+            (AntoineComponent::Ethanol, 78.3),
+            (AntoineComponent::NHexane, 68.7),
+        ] {
+            let actual = component
+                .get_antoine_constants()
+                .get_saturation_temperature(101325.0)
+                - ZERO_CELSIUS;
+            assert_approx_eq!(expected, actual, 0.1);
+        }
+    }
+
+    #[test]
+    fn rachford_rice() {
+        assert_approx_eq!(0.5, solve_rachford_rice(&[0.5, 0.5], &[2.0, 0.5]), 1e-12);
+        assert_eq!(0.0, solve_rachford_rice(&[0.5, 0.5], &[1.2, 0.5]));
+        assert_eq!(1.0, solve_rachford_rice(&[0.5, 0.5], &[3.0, 0.9]));
+    }
+
+    #[test]
+    fn raoult_bubble_point_pressure() {
+        // Psat benzene = 1350.6 mmHg and Psat toluene = 556.3 mmHg at 100 °C
+        let entry = get_vle_entry(
+            &benzene_toluene(),
+            &[0.5, 0.5],
+            VleQuery::BubblePointPressure(celsius(100.0)),
+        )
+        .unwrap();
+        let phases = get_liquid_vapor(&entry);
+        assert_approx_eq!(127.12e3, entry.pressure.convert_to_si_unit().value, 50.0);
+        assert_approx_eq!(0.7083, phases.get_vapor_composition()[0], 1e-3);
+        assert_eq!(0.0, phases.get_vapor_frac());
+    }
+
+    macro_rules! raoult_round_trip_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (feed, temperature) = $value;
+                let model = benzene_toluene();
+                let bubble =
+                    get_vle_entry(&model, &feed, VleQuery::BubblePointPressure(celsius(temperature)))
+                        .unwrap();
+                let bubble_temperature = get_vle_entry(
+                    &model,
+                    &feed,
+                    VleQuery::BubblePointTemperature(bubble.pressure),
+                )
+                .unwrap();
+                assert_approx_eq!(
+                    temperature + ZERO_CELSIUS,
+                    bubble_temperature.temperature.convert_to_si_unit().value,
+                    1e-6
+                );
+
+                // The incipient vapor at the bubble point condenses back to the feed at its dew point
+                let vapor = get_liquid_vapor(&bubble).get_vapor_composition().to_vec();
+                let dew =
+                    get_vle_entry(&model, &vapor, VleQuery::DewPointTemperature(bubble.pressure))
+                        .unwrap();
+                assert_approx_eq!(
+                    temperature + ZERO_CELSIUS,
+                    dew.temperature.convert_to_si_unit().value,
+                    1e-6
+                );
+                for (expected, actual) in feed
+                    .iter()
+                    .zip(get_liquid_vapor(&dew).get_liquid_composition())
+                {
+                    assert_approx_eq!(expected, actual, 1e-8);
+                }
+            }
+        )*
+        }
+    }
+
+    raoult_round_trip_tests! {
+        raoult_round_trip_equimolar: ([0.5, 0.5], 100.0),
+        raoult_round_trip_benzene_rich: ([0.9, 0.1], 85.0),
+        raoult_round_trip_toluene_rich: ([0.1, 0.9], 105.0),
+    }
+
+    #[test]
+    fn raoult_flash() {
+        let model = benzene_toluene();
+        let feed = [0.5, 0.5];
+        let point = |pressure| PtPoint {
+            temperature: celsius(100.0),
+            pressure: kpa(pressure),
+        };
+        let entry = get_vle_entry(&model, &feed, VleQuery::Flash(point(110.0))).unwrap();
+        let phases = get_liquid_vapor(&entry);
+        let beta = phases.get_vapor_frac();
+        assert!(beta > 0.0 && beta < 1.0);
+        for (i, z) in feed.iter().enumerate() {
+            let x = phases.get_liquid_composition()[i];
+            let y = phases.get_vapor_composition()[i];
+            assert_approx_eq!(z, (1.0 - beta) * x + beta * y, 1e-10);
+            assert_approx_eq!(entry.k_values[i] * x, y, 1e-10);
+        }
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+            get_vle_entry(&model, &feed, VleQuery::Flash(point(150.0)))
+                .unwrap()
+                .phase_region
+        );
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            get_vle_entry(&model, &feed, VleQuery::Flash(point(70.0)))
+                .unwrap()
+                .phase_region
+        );
+    }
+
+    #[test]
+    fn eos_pure_bubble_point_is_saturation_pressure() {
+        let eos = CubicEos::PengRobinson;
+        let temperature = Temperature::K(K::new(300.0));
+        let entry = get_vle_entry(
+            &VleModel::CubicEos(eos, vec![Species::C3H8]),
+            &[1.0],
+            VleQuery::BubblePointPressure(temperature),
+        )
+        .unwrap();
+        let expected = get_saturation_pressure(eos, Species::C3H8, temperature)
+            .unwrap()
+            .convert_to_si_unit()
+            .value;
+        assert_approx_eq!(expected, entry.pressure.convert_to_si_unit().value, 1.0);
+    }
+
+    #[test]
+    fn eos_flash() {
+        let model = VleModel::CubicEos(CubicEos::PengRobinson, vec![Species::CH4, Species::C3H8]);
+        let feed = [0.2, 0.8];
+        let temperature = Temperature::K(K::new(250.0));
+        let bubble = get_vle_entry(&model, &feed, VleQuery::BubblePointPressure(temperature))
+            .unwrap()
+            .pressure
+            .convert_to_si_unit()
+            .value;
+        let dew = get_vle_entry(&model, &feed, VleQuery::DewPointPressure(temperature))
+            .unwrap()
+            .pressure
+            .convert_to_si_unit()
+            .value;
+        assert!(dew < bubble);
+
+        let point = |pressure| PtPoint {
+            temperature,
+            pressure: Pressure::Pa(Pa::new(pressure)),
+        };
+        let middle = point((bubble + dew) / 2.0);
+        let entry = get_vle_entry(&model, &feed, VleQuery::Flash(middle)).unwrap();
+        let phases = get_liquid_vapor(&entry);
+        let beta = phases.get_vapor_frac();
+        assert!(beta > 0.0 && beta < 1.0);
+        let liquid = phases.get_liquid_composition();
+        let vapor = phases.get_vapor_composition();
+        for i in 0..2 {
+            assert_approx_eq!(feed[i], (1.0 - beta) * liquid[i] + beta * vapor[i], 1e-8);
+        }
+        // Equal fugacities in both phases
+        let ln_k = model
+            .get_ln_k_values(250.0, (bubble + dew) / 2.0, liquid, vapor)
+            .unwrap();
+        for i in 0..2 {
+            assert_approx_eq!(f64::ln(vapor[i] / liquid[i]), ln_k[i], 1e-8);
+        }
+
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Liquid),
+            get_vle_entry(&model, &feed, VleQuery::Flash(point(bubble * 1.05)))
+                .unwrap()
+                .phase_region
+        );
+        assert_eq!(
+            PhaseRegion::NonCritical(NonCriticalPhaseRegion::Vapor),
+            get_vle_entry(&model, &feed, VleQuery::Flash(point(dew * 0.95)))
+                .unwrap()
+                .phase_region
+        );
+    }
+
+    #[test]
+    fn vle_errors() {
+        let flash = VleQuery::Flash(PtPoint {
+            temperature: celsius(100.0),
+            pressure: kpa(100.0),
+        });
+        assert_eq!(
+            Err(VleErr::NoComponents),
+            get_vle_entry(&VleModel::RaoultsLaw(vec![]), &[], flash)
+        );
+        assert_eq!(
+            Err(VleErr::DuplicateComponent),
+            get_vle_entry(
+                &VleModel::RaoultsLaw(vec![AntoineComponent::Water, AntoineComponent::Water]),
+                &[0.5, 0.5],
+                flash
+            )
+        );
+        assert_eq!(
+            Err(VleErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::CompositionLengthMismatch
+            )),
+            get_vle_entry(&benzene_toluene(), &[1.0], flash)
+        );
+        assert_eq!(
+            Err(VleErr::CompositePhaseRegionErr(
+                CompositePhaseRegionErr::FractionsDoNotAddUpToOne
+            )),
+            get_vle_entry(&benzene_toluene(), &[0.5, 0.6], flash)
+        );
+        assert_eq!(
+            Err(VleErr::EosErr(EosErr::MissingCriticalConstants(
+                Species::OH
+            ))),
+            get_vle_entry(
+                &VleModel::CubicEos(CubicEos::PengRobinson, vec![Species::OH]),
+                &[1.0],
+                flash
+            )
+        );
+    }
+}
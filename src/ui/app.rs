@@ -1,9 +1,24 @@
 #![allow(clippy::let_unit_value, unused_variables)]
 use super::assets::svg::*;
+use super::fluids::compressor_form::CompressorForm;
 use super::fluids::orifice_plate_form::OrificePlateForm;
+use super::fluids::pump_form::PumpForm;
+use super::heat_transfer::exchanger_form::ExchangerForm;
 use super::logo::*;
 use super::shared::search_button::*;
 use super::splash::Splash;
+use super::thermo::combustion::combustion_form::*;
+use super::thermo::cycles::rankine_cycle_form::*;
+use super::thermo::cycles::vapor_compression_form::*;
+use super::thermo::eos::eos_form::*;
+use super::thermo::psychrometrics::psychrometric_form::*;
+use super::thermo::refrigerants::refrigerant_table_form::*;
+use super::thermo::steam_devices::desuperheater_form::*;
+use super::thermo::steam_devices::expansion_form::*;
+use super::thermo::steam_devices::flash_form::*;
+use super::thermo::steam_devices::mixing_form::*;
+use super::thermo::steam_devices::throttle_form::*;
+use super::thermo::steam_table::printable_steam_table_form::*;
 use super::thermo::steam_table::steam_table_form::*;
 use strum_macros::EnumIter;
 use yew::prelude::*;
@@ -21,6 +36,10 @@ pub enum MainRoute {
     FluidsRoot,
     #[at("/Fluids/*")]
     Fluids,
+    #[at("/HeatTransfer")]
+    HeatTransferRoot,
+    #[at("/HeatTransfer/*")]
+    HeatTransfer,
     #[not_found]
     #[at("/*")]
     NotFound,
@@ -30,6 +49,30 @@ pub enum MainRoute {
 pub enum ThermoRoute {
     #[at("/Thermo/SteamTable")]
     SteamTable,
+    #[at("/Thermo/SteamTables")]
+    PrintableSteamTable,
+    #[at("/Thermo/RankineCycle")]
+    RankineCycle,
+    #[at("/Thermo/SteamExpansion")]
+    SteamExpansion,
+    #[at("/Thermo/SteamThrottling")]
+    SteamThrottling,
+    #[at("/Thermo/Desuperheater")]
+    Desuperheater,
+    #[at("/Thermo/SteamMixing")]
+    SteamMixing,
+    #[at("/Thermo/FlashSteam")]
+    FlashSteam,
+    #[at("/Thermo/Psychrometrics")]
+    Psychrometrics,
+    #[at("/Thermo/CubicEos")]
+    CubicEos,
+    #[at("/Thermo/RefrigerantTable")]
+    RefrigerantTable,
+    #[at("/Thermo/VaporCompressionCycle")]
+    VaporCompressionCycle,
+    #[at("/Thermo/Combustion")]
+    Combustion,
     #[not_found]
     #[at("/*")]
     NotFound,
@@ -39,6 +82,19 @@ pub enum ThermoRoute {
 pub enum FluidsRoute {
     #[at("/Fluids/OrificePlate")]
     OrificePlate,
+    #[at("/Fluids/Pump")]
+    Pump,
+    #[at("/Fluids/Compressor")]
+    Compressor,
+    #[not_found]
+    #[at("/*")]
+    NotFound,
+}
+
+#[derive(Clone, Copy, Routable, PartialEq, Debug, EnumIter)]
+pub enum HeatTransferRoute {
+    #[at("/HeatTransfer/HeatExchanger")]
+    HeatExchanger,
     #[not_found]
     #[at("/*")]
     NotFound,
@@ -55,6 +111,42 @@ fn switch_thermo(route: &ThermoRoute) -> Html {
         ThermoRoute::SteamTable => html! {
             <SteamTableForm/>
         },
+        ThermoRoute::PrintableSteamTable => html! {
+            <PrintableSteamTableForm/>
+        },
+        ThermoRoute::RankineCycle => html! {
+            <RankineCycleForm/>
+        },
+        ThermoRoute::SteamExpansion => html! {
+            <ExpansionForm/>
+        },
+        ThermoRoute::SteamThrottling => html! {
+            <ThrottleForm/>
+        },
+        ThermoRoute::Desuperheater => html! {
+            <DesuperheaterForm/>
+        },
+        ThermoRoute::SteamMixing => html! {
+            <MixingForm/>
+        },
+        ThermoRoute::FlashSteam => html! {
+            <FlashForm/>
+        },
+        ThermoRoute::Psychrometrics => html! {
+            <PsychrometricForm/>
+        },
+        ThermoRoute::CubicEos => html! {
+            <EosForm/>
+        },
+        ThermoRoute::RefrigerantTable => html! {
+            <RefrigerantTableForm/>
+        },
+        ThermoRoute::VaporCompressionCycle => html! {
+            <VaporCompressionForm/>
+        },
+        ThermoRoute::Combustion => html! {
+            <CombustionForm/>
+        },
         ThermoRoute::NotFound => html! {
             <NotFound/>
         },
@@ -66,12 +158,29 @@ fn switch_fluids(route: &FluidsRoute) -> Html {
         FluidsRoute::OrificePlate => html! {
             <OrificePlateForm />
         },
+        FluidsRoute::Pump => html! {
+            <PumpForm />
+        },
+        FluidsRoute::Compressor => html! {
+            <CompressorForm />
+        },
         FluidsRoute::NotFound => html! {
             <NotFound/>
         },
     }
 }
 
+fn switch_heat_transfer(route: &HeatTransferRoute) -> Html {
+    match route {
+        HeatTransferRoute::HeatExchanger => html! {
+            <ExchangerForm />
+        },
+        HeatTransferRoute::NotFound => html! {
+            <NotFound/>
+        },
+    }
+}
+
 fn switch_main(route: &MainRoute) -> Html {
     html! {
         <AppShell>
@@ -86,6 +195,9 @@ fn switch_main(route: &MainRoute) -> Html {
                 MainRoute::FluidsRoot | MainRoute::Fluids => html! {
                     <Switch<FluidsRoute> render={Switch::render(switch_fluids)} />
                 },
+                MainRoute::HeatTransferRoot | MainRoute::HeatTransfer => html! {
+                    <Switch<HeatTransferRoute> render={Switch::render(switch_heat_transfer)} />
+                },
                 MainRoute::NotFound => html! {
                     <NotFound/>
                 },
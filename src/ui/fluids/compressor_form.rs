@@ -0,0 +1,372 @@
+use super::pump_form::get_machine_err_message;
+use crate::fluids::machines::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::units::*;
+use std::fmt::Display;
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiCompressorEfficiency {
+    Isentropic,
+    Polytropic,
+}
+
+impl TryFrom<String> for UiCompressorEfficiency {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Isentropic" => Ok(UiCompressorEfficiency::Isentropic),
+            "Polytropic" => Ok(UiCompressorEfficiency::Polytropic),
+            _ => Err(format!("Unknown Efficiency \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiCompressorEfficiency> for String {
+    fn from(val: UiCompressorEfficiency) -> Self {
+        match val {
+            UiCompressorEfficiency::Isentropic => "Isentropic".to_owned(),
+            UiCompressorEfficiency::Polytropic => "Polytropic".to_owned(),
+        }
+    }
+}
+
+impl Display for UiCompressorEfficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiCompressorEfficiency::Isentropic => "Isentropic Efficiency",
+                UiCompressorEfficiency::Polytropic => "Polytropic Efficiency",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct CompressorQuery {
+    flow_rate: VolumetricFlowRate,
+    inlet_pressure: Pressure,
+    inlet_temperature: Temperature,
+    discharge_pressure: Pressure,
+    // g/mol
+    molar_mass: f64,
+    isentropic_exponent: f64,
+    compressibility_factor: f64,
+    efficiency_kind: UiCompressorEfficiency,
+    efficiency: f64,
+    stages: f64,
+}
+
+fn get_compressor_result(query: &CompressorQuery) -> Result<CompressorPower, MachineErr> {
+    let efficiency = match query.efficiency_kind {
+        UiCompressorEfficiency::Isentropic => CompressorEfficiency::Isentropic(query.efficiency),
+        UiCompressorEfficiency::Polytropic => CompressorEfficiency::Polytropic(query.efficiency),
+    };
+    get_compressor_power(&Compressor {
+        flow_rate: query.flow_rate,
+        inlet_pressure: query.inlet_pressure,
+        inlet_temperature: query.inlet_temperature,
+        discharge_pressure: query.discharge_pressure,
+        molar_mass: query.molar_mass / 1000f64,
+        isentropic_exponent: query.isentropic_exponent,
+        compressibility_factor: query.compressibility_factor,
+        efficiency,
+        stages: query.stages.round() as u32,
+    })
+}
+
+#[derive(Properties, PartialEq)]
+struct CompressorInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<CompressorQuery>>,
+}
+
+#[function_component(CompressorInput)]
+fn compressor_input(CompressorInputProps { onchange }: &CompressorInputProps) -> Html {
+    let flow_rate_opt = use_state(|| -> Option<VolumetricFlowRate> { None });
+    let on_flow_rate_change = {
+        let flow_rate_opt = flow_rate_opt.clone();
+        Callback::from(move |val| {
+            flow_rate_opt.set(val);
+        })
+    };
+
+    let inlet_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_inlet_pressure_change = {
+        let inlet_pressure_opt = inlet_pressure_opt.clone();
+        Callback::from(move |val| {
+            inlet_pressure_opt.set(val);
+        })
+    };
+
+    let inlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_inlet_temperature_change = {
+        let inlet_temperature_opt = inlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            inlet_temperature_opt.set(val);
+        })
+    };
+
+    let discharge_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_discharge_pressure_change = {
+        let discharge_pressure_opt = discharge_pressure_opt.clone();
+        Callback::from(move |val| {
+            discharge_pressure_opt.set(val);
+        })
+    };
+
+    let molar_mass_opt = use_state(|| -> Option<f64> { None });
+    let on_molar_mass_change = {
+        let molar_mass_opt = molar_mass_opt.clone();
+        Callback::from(move |val| {
+            molar_mass_opt.set(val);
+        })
+    };
+
+    let isentropic_exponent_opt = use_state(|| -> Option<f64> { None });
+    let on_isentropic_exponent_change = {
+        let isentropic_exponent_opt = isentropic_exponent_opt.clone();
+        Callback::from(move |val| {
+            isentropic_exponent_opt.set(val);
+        })
+    };
+
+    let compressibility_factor_opt = use_state(|| -> Option<f64> { None });
+    let on_compressibility_factor_change = {
+        let compressibility_factor_opt = compressibility_factor_opt.clone();
+        Callback::from(move |val| {
+            compressibility_factor_opt.set(val);
+        })
+    };
+
+    let efficiency_kind_opt = use_state(|| -> Option<UiCompressorEfficiency> {
+        Some(UiCompressorEfficiency::Isentropic)
+    });
+    let on_efficiency_kind_change = {
+        let efficiency_kind_opt = efficiency_kind_opt.clone();
+        Callback::from(move |val| {
+            efficiency_kind_opt.set(val);
+        })
+    };
+
+    let efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_efficiency_change = {
+        let efficiency_opt = efficiency_opt.clone();
+        Callback::from(move |val| {
+            efficiency_opt.set(val);
+        })
+    };
+
+    let stages_opt = use_state(|| -> Option<f64> { None });
+    let on_stages_change = {
+        let stages_opt = stages_opt.clone();
+        Callback::from(move |val| {
+            stages_opt.set(val);
+        })
+    };
+
+    {
+        let flow_rate_opt = *flow_rate_opt;
+        let inlet_pressure_opt = *inlet_pressure_opt;
+        let inlet_temperature_opt = *inlet_temperature_opt;
+        let discharge_pressure_opt = *discharge_pressure_opt;
+        let molar_mass_opt = *molar_mass_opt;
+        let isentropic_exponent_opt = *isentropic_exponent_opt;
+        let compressibility_factor_opt = *compressibility_factor_opt;
+        let efficiency_kind_opt = *efficiency_kind_opt;
+        let efficiency_opt = *efficiency_opt;
+        let stages_opt = *stages_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                flow_rate_opt,
+                inlet_pressure_opt,
+                inlet_temperature_opt,
+                discharge_pressure_opt,
+                molar_mass_opt,
+                isentropic_exponent_opt,
+                compressibility_factor_opt,
+                efficiency_kind_opt,
+                efficiency_opt,
+                stages_opt,
+            ) {
+                (
+                    Some(flow_rate),
+                    Some(inlet_pressure),
+                    Some(inlet_temperature),
+                    Some(discharge_pressure),
+                    Some(molar_mass),
+                    Some(isentropic_exponent),
+                    Some(compressibility_factor),
+                    Some(efficiency_kind),
+                    Some(efficiency),
+                    Some(stages),
+                ) => Some(CompressorQuery {
+                    flow_rate,
+                    inlet_pressure,
+                    inlet_temperature,
+                    discharge_pressure,
+                    molar_mass,
+                    isentropic_exponent,
+                    compressibility_factor,
+                    efficiency_kind,
+                    efficiency,
+                    stages,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <UnitInput<VolumetricFlowRate> id={"flow_rate"} label={"Inlet Flow Rate"} onchange={on_flow_rate_change}/>
+        <UnitInput<Pressure> id={"inlet_pressure"} label={"Inlet Pressure"} onchange={on_inlet_pressure_change}/>
+        <UnitInput<Temperature> id={"inlet_temperature"} label={"Inlet Temperature"} onchange={on_inlet_temperature_change}/>
+        <UnitInput<Pressure> id={"discharge_pressure"} label={"Discharge Pressure"} onchange={on_discharge_pressure_change}/>
+        <NumberInput id={"molar_mass"} label={"Molar Mass in g/mol"} onchange={on_molar_mass_change}/>
+        <NumberInput id={"isentropic_exponent"} label={"Isentropic Exponent (cp/cv)"} onchange={on_isentropic_exponent_change}/>
+        <NumberInput id={"compressibility_factor"} label={"Compressibility Factor"} onchange={on_compressibility_factor_change}/>
+        <SelectInput<UiCompressorEfficiency>
+            id="efficiency_kind"
+            label="Known Efficiency"
+            onchange={on_efficiency_kind_change}
+            value={*efficiency_kind_opt}
+            options={vec![UiCompressorEfficiency::Isentropic, UiCompressorEfficiency::Polytropic]}
+        />
+        <NumberInput
+            id={"efficiency"}
+            label={(*efficiency_kind_opt)
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| String::from("Isentropic Efficiency"))}
+            onchange={on_efficiency_change}
+        />
+        <NumberInput id={"stages"} label={"Intercooled Stages"} onchange={on_stages_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CompressorOutputProps {
+    result_opt: Option<Result<CompressorPower, MachineErr>>,
+}
+
+#[function_component(CompressorOutput)]
+fn compressor_output(CompressorOutputProps { result_opt }: &CompressorOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<Power>
+                    id={"gas_power_output"}
+                    label={"Gas Power"}
+                    value={result.gas_power}
+                />
+                <UnitOutput<Temperature>
+                    id={"discharge_temperature_output"}
+                    label={"Stage Discharge Temperature"}
+                    value={result.discharge_temperature}
+                />
+                <UnitOutput<Head>
+                    id={"isentropic_head_output"}
+                    label={"Isentropic Head"}
+                    value={result.isentropic_head}
+                />
+                <UnitOutput<Head>
+                    id={"polytropic_head_output"}
+                    label={"Polytropic Head"}
+                    value={result.polytropic_head}
+                />
+                <NumberOutput
+                    id={"isentropic_efficiency_output"}
+                    label={"Isentropic Efficiency"}
+                    value={result.isentropic_efficiency}
+                />
+                <NumberOutput
+                    id={"polytropic_efficiency_output"}
+                    label={"Polytropic Efficiency"}
+                    value={result.polytropic_efficiency}
+                />
+                <NumberOutput
+                    id={"polytropic_exponent_output"}
+                    label={"Polytropic Exponent"}
+                    value={result.polytropic_exponent}
+                />
+                <NumberOutput
+                    id={"stage_pressure_ratio_output"}
+                    label={"Stage Pressure Ratio"}
+                    value={result.stage_pressure_ratio}
+                />
+                <UnitOutput<MassFlowRate>
+                    id={"mass_flow_rate_output"}
+                    label={"Mass Flow Rate"}
+                    value={result.mass_flow_rate}
+                />
+                <UnitOutput<Power>
+                    id={"intercooler_duty_output"}
+                    label={"Intercooler Duty"}
+                    value={result.intercooler_duty}
+                />
+            </>
+        },
+        Some(Err(err)) => {
+            let (label, err_msg) = get_machine_err_message(err);
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CompressorFormProps {}
+
+#[function_component(CompressorForm)]
+pub fn compressor_form(CompressorFormProps {}: &CompressorFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<CompressorQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<CompressorPower, MachineErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <CompressorInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = &*query_opt {
+                            let result = get_compressor_result(query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <CompressorOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -1 +1,3 @@
+pub mod compressor_form;
 pub mod orifice_plate_form;
+pub mod pump_form;
@@ -0,0 +1,183 @@
+use crate::fluids::machines::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::units::*;
+use yew::prelude::*;
+
+pub fn get_machine_err_message(err: &MachineErr) -> (String, String) {
+    match err {
+        MachineErr::EfficiencyLow => (
+            String::from("Efficiency Error"),
+            String::from("Efficiency must be above 0"),
+        ),
+        MachineErr::EfficiencyHigh => (
+            String::from("Efficiency Error"),
+            String::from("Efficiency can not be above 1"),
+        ),
+        MachineErr::PressureOutOfOrder => (
+            String::from("Pressure Error"),
+            String::from("Discharge pressure must be above the inlet pressure"),
+        ),
+        MachineErr::IsentropicExponentLow => (
+            String::from("Isentropic Exponent Error"),
+            String::from("Isentropic exponent must be above 1"),
+        ),
+        MachineErr::NoStages => (
+            String::from("Stages Error"),
+            String::from("Compressor needs at least one stage"),
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PumpInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<Pump>>,
+}
+
+#[function_component(PumpInput)]
+fn pump_input(PumpInputProps { onchange }: &PumpInputProps) -> Html {
+    let flow_rate_opt = use_state(|| -> Option<VolumetricFlowRate> { None });
+    let on_flow_rate_change = {
+        let flow_rate_opt = flow_rate_opt.clone();
+        Callback::from(move |val| {
+            flow_rate_opt.set(val);
+        })
+    };
+
+    let head_opt = use_state(|| -> Option<Head> { None });
+    let on_head_change = {
+        let head_opt = head_opt.clone();
+        Callback::from(move |val| {
+            head_opt.set(val);
+        })
+    };
+
+    let density_opt = use_state(|| -> Option<Density> { None });
+    let on_density_change = {
+        let density_opt = density_opt.clone();
+        Callback::from(move |val| {
+            density_opt.set(val);
+        })
+    };
+
+    let efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_efficiency_change = {
+        let efficiency_opt = efficiency_opt.clone();
+        Callback::from(move |val| {
+            efficiency_opt.set(val);
+        })
+    };
+
+    {
+        let flow_rate_opt = *flow_rate_opt;
+        let head_opt = *head_opt;
+        let density_opt = *density_opt;
+        let efficiency_opt = *efficiency_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (flow_rate_opt, head_opt, density_opt, efficiency_opt) {
+                (Some(flow_rate), Some(head), Some(density), Some(efficiency)) => Some(Pump {
+                    flow_rate,
+                    head,
+                    density,
+                    efficiency,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <UnitInput<VolumetricFlowRate> id={"flow_rate"} label={"Flow Rate"} onchange={on_flow_rate_change}/>
+        <UnitInput<Head> id={"head"} label={"Total Head"} onchange={on_head_change}/>
+        <UnitInput<Density> id={"density"} label={"Liquid Density"} onchange={on_density_change}/>
+        <NumberInput id={"efficiency"} label={"Pump Efficiency"} onchange={on_efficiency_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PumpOutputProps {
+    result_opt: Option<Result<PumpPower, MachineErr>>,
+}
+
+#[function_component(PumpOutput)]
+fn pump_output(PumpOutputProps { result_opt }: &PumpOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<Power>
+                    id={"hydraulic_power_output"}
+                    label={"Hydraulic Power"}
+                    value={result.hydraulic_power}
+                />
+                <UnitOutput<Power>
+                    id={"shaft_power_output"}
+                    label={"Shaft Power"}
+                    value={result.shaft_power}
+                />
+                <UnitOutput<Pressure>
+                    id={"pressure_rise_output"}
+                    label={"Pressure Rise"}
+                    value={result.pressure_rise}
+                />
+            </>
+        },
+        Some(Err(err)) => {
+            let (label, err_msg) = get_machine_err_message(err);
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PumpFormProps {}
+
+#[function_component(PumpForm)]
+pub fn pump_form(PumpFormProps {}: &PumpFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<Pump> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<PumpPower, MachineErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <PumpInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = &*query_opt {
+                            let result = get_pump_power(query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <PumpOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1,600 @@
+use crate::heat_transfer::exchangers::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use std::fmt::Display;
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiFlowArrangement {
+    ParallelFlow,
+    CounterFlow,
+    CrossFlowUnmixed,
+    CrossFlowCmaxMixed,
+    CrossFlowCminMixed,
+    ShellAndTube,
+}
+
+impl TryFrom<String> for UiFlowArrangement {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "ParallelFlow" => Ok(UiFlowArrangement::ParallelFlow),
+            "CounterFlow" => Ok(UiFlowArrangement::CounterFlow),
+            "CrossFlowUnmixed" => Ok(UiFlowArrangement::CrossFlowUnmixed),
+            "CrossFlowCmaxMixed" => Ok(UiFlowArrangement::CrossFlowCmaxMixed),
+            "CrossFlowCminMixed" => Ok(UiFlowArrangement::CrossFlowCminMixed),
+            "ShellAndTube" => Ok(UiFlowArrangement::ShellAndTube),
+            _ => Err(format!("Unknown Flow Arrangement \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiFlowArrangement> for String {
+    fn from(val: UiFlowArrangement) -> Self {
+        match val {
+            UiFlowArrangement::ParallelFlow => "ParallelFlow".to_owned(),
+            UiFlowArrangement::CounterFlow => "CounterFlow".to_owned(),
+            UiFlowArrangement::CrossFlowUnmixed => "CrossFlowUnmixed".to_owned(),
+            UiFlowArrangement::CrossFlowCmaxMixed => "CrossFlowCmaxMixed".to_owned(),
+            UiFlowArrangement::CrossFlowCminMixed => "CrossFlowCminMixed".to_owned(),
+            UiFlowArrangement::ShellAndTube => "ShellAndTube".to_owned(),
+        }
+    }
+}
+
+impl Display for UiFlowArrangement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiFlowArrangement::ParallelFlow => "Parallel Flow",
+                UiFlowArrangement::CounterFlow => "Counterflow",
+                UiFlowArrangement::CrossFlowUnmixed => "Crossflow, Both Unmixed",
+                UiFlowArrangement::CrossFlowCmaxMixed => "Crossflow, Cmax Mixed",
+                UiFlowArrangement::CrossFlowCminMixed => "Crossflow, Cmin Mixed",
+                UiFlowArrangement::ShellAndTube => "Shell and Tube",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiExchangerQuery {
+    Rating,
+    Sizing,
+}
+
+impl TryFrom<String> for UiExchangerQuery {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Rating" => Ok(UiExchangerQuery::Rating),
+            "Sizing" => Ok(UiExchangerQuery::Sizing),
+            _ => Err(format!("Unknown Query \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiExchangerQuery> for String {
+    fn from(val: UiExchangerQuery) -> Self {
+        match val {
+            UiExchangerQuery::Rating => "Rating".to_owned(),
+            UiExchangerQuery::Sizing => "Sizing".to_owned(),
+        }
+    }
+}
+
+impl Display for UiExchangerQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiExchangerQuery::Rating => "Rating (Known UA)",
+                UiExchangerQuery::Sizing => "Sizing (Known Duty)",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiStreamFluid {
+    SpecificHeat,
+    Water,
+}
+
+impl TryFrom<String> for UiStreamFluid {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "SpecificHeat" => Ok(UiStreamFluid::SpecificHeat),
+            "Water" => Ok(UiStreamFluid::Water),
+            _ => Err(format!("Unknown Fluid \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiStreamFluid> for String {
+    fn from(val: UiStreamFluid) -> Self {
+        match val {
+            UiStreamFluid::SpecificHeat => "SpecificHeat".to_owned(),
+            UiStreamFluid::Water => "Water".to_owned(),
+        }
+    }
+}
+
+impl Display for UiStreamFluid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiStreamFluid::SpecificHeat => "Constant Specific Heat",
+                UiStreamFluid::Water => "Water (Steam Table)",
+            }
+        )
+    }
+}
+
+fn get_exchanger_err_message(err: &ExchangerErr) -> (String, String) {
+    match err {
+        ExchangerErr::TemperatureOutOfOrder => (
+            String::from("Temperature Error"),
+            String::from("Hot stream must enter hotter than the cold stream"),
+        ),
+        ExchangerErr::CapacityRateLow => (
+            String::from("Stream Error"),
+            String::from("Mass flow rate and specific heat must be above 0"),
+        ),
+        ExchangerErr::ConductanceLow => {
+            (String::from("UA Error"), String::from("UA must be above 0"))
+        }
+        ExchangerErr::DutyLow => (
+            String::from("Duty Error"),
+            String::from("Duty must be above 0"),
+        ),
+        ExchangerErr::EffectivenessUnreachable => (
+            String::from("Duty Error"),
+            String::from("No area can transfer this duty with this flow arrangement"),
+        ),
+        ExchangerErr::NoShellPasses => (
+            String::from("Shell Passes Error"),
+            String::from("Exchanger needs at least one shell pass"),
+        ),
+        ExchangerErr::PhaseChange => (
+            String::from("Phase Change Error"),
+            String::from("Water stream boils or condenses"),
+        ),
+        ExchangerErr::SteamQueryErr(steam_err) => get_steam_query_err_message(steam_err),
+        ExchangerErr::FailedToConverge(_) => (
+            String::from("Convergence Error"),
+            String::from("Failed to find the outlet temperatures"),
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct StreamInputProps {
+    // Prefixes the input ids and labels
+    pub name: String,
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<Stream>>,
+}
+
+#[function_component(StreamInput)]
+fn stream_input(StreamInputProps { name, onchange }: &StreamInputProps) -> Html {
+    let mass_flow_rate_opt = use_state(|| -> Option<MassFlowRate> { None });
+    let on_mass_flow_rate_change = {
+        let mass_flow_rate_opt = mass_flow_rate_opt.clone();
+        Callback::from(move |val| {
+            mass_flow_rate_opt.set(val);
+        })
+    };
+
+    let inlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_inlet_temperature_change = {
+        let inlet_temperature_opt = inlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            inlet_temperature_opt.set(val);
+        })
+    };
+
+    let fluid_opt = use_state(|| -> Option<UiStreamFluid> { Some(UiStreamFluid::SpecificHeat) });
+    let on_fluid_change = {
+        let fluid_opt = fluid_opt.clone();
+        Callback::from(move |val| {
+            fluid_opt.set(val);
+        })
+    };
+
+    let specific_heat_opt = use_state(|| -> Option<EnergyPerMassTemperature> { None });
+    let on_specific_heat_change = {
+        let specific_heat_opt = specific_heat_opt.clone();
+        Callback::from(move |val| {
+            specific_heat_opt.set(val);
+        })
+    };
+
+    let pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_change = {
+        let pressure_opt = pressure_opt.clone();
+        Callback::from(move |val| {
+            pressure_opt.set(val);
+        })
+    };
+
+    {
+        let mass_flow_rate_opt = *mass_flow_rate_opt;
+        let inlet_temperature_opt = *inlet_temperature_opt;
+        let fluid_opt = *fluid_opt;
+        let specific_heat_opt = *specific_heat_opt;
+        let pressure_opt = *pressure_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let fluid_opt = match (fluid_opt, specific_heat_opt, pressure_opt) {
+                (Some(UiStreamFluid::SpecificHeat), Some(specific_heat), _) => {
+                    Some(StreamFluid::SpecificHeat(specific_heat))
+                }
+                (Some(UiStreamFluid::Water), _, Some(pressure)) => {
+                    Some(StreamFluid::Water { pressure })
+                }
+                _ => None,
+            };
+            let stream_opt = match (mass_flow_rate_opt, inlet_temperature_opt, fluid_opt) {
+                (Some(mass_flow_rate), Some(inlet_temperature), Some(fluid)) => Some(Stream {
+                    mass_flow_rate,
+                    inlet_temperature,
+                    fluid,
+                }),
+                _ => None,
+            };
+            onchange.emit(stream_opt);
+            || {}
+        });
+    }
+    let id = name.to_lowercase();
+    html! {
+    <>
+        <UnitInput<MassFlowRate>
+            id={format!("{}_mass_flow_rate", id)}
+            label={format!("{} Mass Flow Rate", name)}
+            onchange={on_mass_flow_rate_change}
+        />
+        <UnitInput<Temperature>
+            id={format!("{}_inlet_temperature", id)}
+            label={format!("{} Inlet Temperature", name)}
+            onchange={on_inlet_temperature_change}
+        />
+        <SelectInput<UiStreamFluid>
+            id={format!("{}_fluid", id)}
+            label={format!("{} Fluid", name)}
+            onchange={on_fluid_change}
+            value={*fluid_opt}
+            options={vec![UiStreamFluid::SpecificHeat, UiStreamFluid::Water]}
+        />
+        {
+        match *fluid_opt {
+            Some(UiStreamFluid::SpecificHeat) => html! {
+                <UnitInput<EnergyPerMassTemperature>
+                    id={format!("{}_specific_heat", id)}
+                    label={format!("{} Specific Heat", name)}
+                    onchange={on_specific_heat_change}
+                />
+            },
+            Some(UiStreamFluid::Water) => html! {
+                <UnitInput<Pressure>
+                    id={format!("{}_pressure", id)}
+                    label={format!("{} Pressure", name)}
+                    onchange={on_pressure_change}
+                />
+            },
+            None => html! {},
+        }
+        }
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ExchangerInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<HeatExchanger>>,
+}
+
+#[function_component(ExchangerInput)]
+fn exchanger_input(ExchangerInputProps { onchange }: &ExchangerInputProps) -> Html {
+    let arrangement_opt =
+        use_state(|| -> Option<UiFlowArrangement> { Some(UiFlowArrangement::CounterFlow) });
+    let on_arrangement_change = {
+        let arrangement_opt = arrangement_opt.clone();
+        Callback::from(move |val| {
+            arrangement_opt.set(val);
+        })
+    };
+
+    let shell_passes_opt = use_state(|| -> Option<f64> { None });
+    let on_shell_passes_change = {
+        let shell_passes_opt = shell_passes_opt.clone();
+        Callback::from(move |val| {
+            shell_passes_opt.set(val);
+        })
+    };
+
+    let query_type_opt =
+        use_state(|| -> Option<UiExchangerQuery> { Some(UiExchangerQuery::Rating) });
+    let on_query_type_change = {
+        let query_type_opt = query_type_opt.clone();
+        Callback::from(move |val| {
+            query_type_opt.set(val);
+        })
+    };
+
+    let conductance_opt = use_state(|| -> Option<PowerPerTemperature> { None });
+    let on_conductance_change = {
+        let conductance_opt = conductance_opt.clone();
+        Callback::from(move |val| {
+            conductance_opt.set(val);
+        })
+    };
+
+    let duty_opt = use_state(|| -> Option<Power> { None });
+    let on_duty_change = {
+        let duty_opt = duty_opt.clone();
+        Callback::from(move |val| {
+            duty_opt.set(val);
+        })
+    };
+
+    let hot_stream_opt = use_state_eq(|| -> Option<Stream> { None });
+    let on_hot_stream_change = {
+        let hot_stream_opt = hot_stream_opt.clone();
+        Callback::from(move |val| {
+            hot_stream_opt.set(val);
+        })
+    };
+
+    let cold_stream_opt = use_state_eq(|| -> Option<Stream> { None });
+    let on_cold_stream_change = {
+        let cold_stream_opt = cold_stream_opt.clone();
+        Callback::from(move |val| {
+            cold_stream_opt.set(val);
+        })
+    };
+
+    {
+        let arrangement_opt = *arrangement_opt;
+        let shell_passes_opt = *shell_passes_opt;
+        let query_type_opt = *query_type_opt;
+        let conductance_opt = *conductance_opt;
+        let duty_opt = *duty_opt;
+        let hot_stream_opt = *hot_stream_opt;
+        let cold_stream_opt = *cold_stream_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let arrangement_opt = match (arrangement_opt, shell_passes_opt) {
+                (Some(UiFlowArrangement::ParallelFlow), _) => Some(FlowArrangement::ParallelFlow),
+                (Some(UiFlowArrangement::CounterFlow), _) => Some(FlowArrangement::CounterFlow),
+                (Some(UiFlowArrangement::CrossFlowUnmixed), _) => {
+                    Some(FlowArrangement::CrossFlowUnmixed)
+                }
+                (Some(UiFlowArrangement::CrossFlowCmaxMixed), _) => {
+                    Some(FlowArrangement::CrossFlowCmaxMixed)
+                }
+                (Some(UiFlowArrangement::CrossFlowCminMixed), _) => {
+                    Some(FlowArrangement::CrossFlowCminMixed)
+                }
+                (Some(UiFlowArrangement::ShellAndTube), Some(shell_passes)) => {
+                    Some(FlowArrangement::ShellAndTube {
+                        shell_passes: shell_passes.round() as u32,
+                    })
+                }
+                _ => None,
+            };
+            let exchanger_query_opt = match (query_type_opt, conductance_opt, duty_opt) {
+                (Some(UiExchangerQuery::Rating), Some(conductance), _) => {
+                    Some(ExchangerQuery::Rating { conductance })
+                }
+                (Some(UiExchangerQuery::Sizing), _, Some(duty)) => {
+                    Some(ExchangerQuery::Sizing { duty })
+                }
+                _ => None,
+            };
+            let query_opt = match (
+                hot_stream_opt,
+                cold_stream_opt,
+                arrangement_opt,
+                exchanger_query_opt,
+            ) {
+                (Some(hot_stream), Some(cold_stream), Some(arrangement), Some(query)) => {
+                    Some(HeatExchanger {
+                        hot_stream,
+                        cold_stream,
+                        arrangement,
+                        query,
+                    })
+                }
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <SelectInput<UiFlowArrangement>
+            id="arrangement"
+            label="Flow Arrangement"
+            onchange={on_arrangement_change}
+            value={*arrangement_opt}
+            options={vec![
+                UiFlowArrangement::ParallelFlow,
+                UiFlowArrangement::CounterFlow,
+                UiFlowArrangement::CrossFlowUnmixed,
+                UiFlowArrangement::CrossFlowCmaxMixed,
+                UiFlowArrangement::CrossFlowCminMixed,
+                UiFlowArrangement::ShellAndTube,
+            ]}
+        />
+        {
+        match *arrangement_opt {
+            Some(UiFlowArrangement::ShellAndTube) => html! {
+                <NumberInput id={"shell_passes"} label={"Shell Passes"} onchange={on_shell_passes_change}/>
+            },
+            _ => html! {},
+        }
+        }
+        <SelectInput<UiExchangerQuery>
+            id="query_type"
+            label="Query Type"
+            onchange={on_query_type_change}
+            value={*query_type_opt}
+            options={vec![UiExchangerQuery::Rating, UiExchangerQuery::Sizing]}
+        />
+        {
+        match *query_type_opt {
+            Some(UiExchangerQuery::Rating) => html! {
+                <UnitInput<PowerPerTemperature> id={"conductance"} label={"UA"} onchange={on_conductance_change}/>
+            },
+            Some(UiExchangerQuery::Sizing) => html! {
+                <UnitInput<Power> id={"duty"} label={"Duty"} onchange={on_duty_change}/>
+            },
+            None => html! {},
+        }
+        }
+        <StreamInput name={"Hot"} onchange={on_hot_stream_change}/>
+        <StreamInput name={"Cold"} onchange={on_cold_stream_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ExchangerOutputProps {
+    result_opt: Option<Result<HeatExchangerResult, ExchangerErr>>,
+}
+
+#[function_component(ExchangerOutput)]
+fn exchanger_output(ExchangerOutputProps { result_opt }: &ExchangerOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<Power>
+                    id={"duty_output"}
+                    label={"Duty"}
+                    value={result.duty}
+                />
+                <UnitOutput<PowerPerTemperature>
+                    id={"conductance_output"}
+                    label={"UA"}
+                    value={result.conductance}
+                />
+                <UnitOutput<Temperature>
+                    id={"hot_outlet_temperature_output"}
+                    label={"Hot Outlet Temperature"}
+                    value={result.hot_outlet_temperature}
+                />
+                <UnitOutput<Temperature>
+                    id={"cold_outlet_temperature_output"}
+                    label={"Cold Outlet Temperature"}
+                    value={result.cold_outlet_temperature}
+                />
+                <NumberOutput
+                    id={"effectiveness_output"}
+                    label={"Effectiveness"}
+                    value={result.effectiveness}
+                />
+                <NumberOutput
+                    id={"ntu_output"}
+                    label={"NTU"}
+                    value={result.ntu}
+                />
+                <NumberOutput
+                    id={"capacity_ratio_output"}
+                    label={"Capacity Ratio (Cmin/Cmax)"}
+                    value={result.capacity_ratio}
+                />
+                <NumberOutput
+                    id={"lmtd_output"}
+                    label={"Counterflow LMTD in K"}
+                    value={result.log_mean_temperature_difference}
+                />
+                <NumberOutput
+                    id={"correction_factor_output"}
+                    label={"LMTD Correction Factor (F)"}
+                    value={result.correction_factor}
+                />
+                <UnitOutput<PowerPerTemperature>
+                    id={"hot_capacity_rate_output"}
+                    label={"Hot Capacity Rate"}
+                    value={result.hot_capacity_rate}
+                />
+                <UnitOutput<PowerPerTemperature>
+                    id={"cold_capacity_rate_output"}
+                    label={"Cold Capacity Rate"}
+                    value={result.cold_capacity_rate}
+                />
+            </>
+        },
+        Some(Err(err)) => {
+            let (label, err_msg) = get_exchanger_err_message(err);
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ExchangerFormProps {}
+
+#[function_component(ExchangerForm)]
+pub fn exchanger_form(ExchangerFormProps {}: &ExchangerFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<HeatExchanger> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<HeatExchangerResult, ExchangerErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <ExchangerInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = &*query_opt {
+                            let result = get_heat_exchanger(query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <ExchangerOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
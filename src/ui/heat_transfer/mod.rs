@@ -0,0 +1 @@
+pub mod exchanger_form;
@@ -5,5 +5,6 @@ mod thermo;
 mod js_bindings;
 mod assets;
 mod fluids;
+mod heat_transfer;
 mod logo;
 mod splash;
@@ -0,0 +1,33 @@
+use super::download_link::*;
+use crate::thermo::diagrams::Diagram;
+use yew::prelude::*;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 600;
+
+#[derive(Properties, PartialEq)]
+pub struct DiagramOutputProps {
+    pub diagram: Diagram,
+    // Name of the downloaded file without the extension
+    pub file_name: String,
+}
+
+#[function_component(DiagramOutput)]
+pub fn diagram_output(DiagramOutputProps { diagram, file_name }: &DiagramOutputProps) -> Html {
+    let svg = diagram.to_svg(WIDTH, HEIGHT);
+    html! {
+        <>
+            <img
+                src={get_data_url("image/svg+xml", &svg)}
+                alt={diagram.title.clone()}
+                class={classes!("w-full", "max-w-3xl")}
+            />
+            <DownloadLink
+                label={"SVG"}
+                file_name={format!("{}.svg", file_name)}
+                mime_type={"image/svg+xml"}
+                text={svg}
+            />
+        </>
+    }
+}
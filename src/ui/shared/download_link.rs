@@ -0,0 +1,49 @@
+use yew::prelude::*;
+
+// Percent encodes everything but unreserved characters so the text can be used in a data url
+fn encode_uri_component(text: &str) -> String {
+    text.bytes()
+        .map(|x| match x {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (x as char).to_string()
+            }
+            _ => format!("%{:02X}", x),
+        })
+        .collect()
+}
+
+pub fn get_data_url(mime_type: &str, text: &str) -> String {
+    format!(
+        "data:{};charset=utf-8,{}",
+        mime_type,
+        encode_uri_component(text)
+    )
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DownloadLinkProps {
+    pub label: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub text: String,
+}
+
+#[function_component(DownloadLink)]
+pub fn download_link(
+    DownloadLinkProps {
+        label,
+        file_name,
+        mime_type,
+        text,
+    }: &DownloadLinkProps,
+) -> Html {
+    html! {
+        <a
+            href={get_data_url(mime_type, text)}
+            download={file_name.clone()}
+            class={classes!("border-2", "rounded-md", "border-gray-200", "p-2", "hover:underline")}
+        >
+            {format!("Download {}", label)}
+        </a>
+    }
+}
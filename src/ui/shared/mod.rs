@@ -1,3 +1,5 @@
+pub mod diagram_output;
+pub mod download_link;
 pub mod forms;
 pub mod hooks;
 pub mod modal;
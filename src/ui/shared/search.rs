@@ -1,4 +1,4 @@
-use crate::ui::app::{FluidsRoute, ThermoRoute};
+use crate::ui::app::{FluidsRoute, HeatTransferRoute, ThermoRoute};
 use crate::ui::assets::svg::*;
 use strum::IntoEnumIterator;
 use wasm_bindgen::JsCast;
@@ -6,10 +6,12 @@ use web_sys::{EventTarget, HtmlInputElement, InputEvent};
 use yew::{classes, function_component, html, use_state, Callback, Properties};
 use yew_router::prelude::*;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum AllRoutes {
     ThermoRoute(ThermoRoute),
     FluidsRoute(FluidsRoute),
+    HeatTransferRoute(HeatTransferRoute),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +36,116 @@ fn create_link_configs() -> Vec<SearchableGroup> {
             .filter_map(|route| {
                 let opt = match route {
                     ThermoRoute::SteamTable => Some(("Steam Table".to_owned(), vec![])),
+                    ThermoRoute::PrintableSteamTable => Some((
+                        "Printable Steam Tables".to_owned(),
+                        vec![
+                            "Tables".to_owned(),
+                            "Saturation".to_owned(),
+                            "Superheat".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::RankineCycle => Some((
+                        "Rankine Cycle".to_owned(),
+                        vec![
+                            "Cycles".to_owned(),
+                            "Power Plant".to_owned(),
+                            "Reheat".to_owned(),
+                            "Regeneration".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::SteamExpansion => Some((
+                        "Steam Turbine and Nozzle Expansion".to_owned(),
+                        vec![
+                            "Turbine".to_owned(),
+                            "Nozzle".to_owned(),
+                            "Choked Flow".to_owned(),
+                            "Isentropic".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::SteamThrottling => Some((
+                        "Steam Throttling".to_owned(),
+                        vec![
+                            "Valve".to_owned(),
+                            "Isenthalpic".to_owned(),
+                            "Pressure Reduction".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::Desuperheater => Some((
+                        "Desuperheater".to_owned(),
+                        vec![
+                            "Attemperator".to_owned(),
+                            "Spray Water".to_owned(),
+                            "Superheat".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::SteamMixing => Some((
+                        "Steam Mixing".to_owned(),
+                        vec![
+                            "Streams".to_owned(),
+                            "Header".to_owned(),
+                            "Energy Balance".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::FlashSteam => Some((
+                        "Flash Steam".to_owned(),
+                        vec![
+                            "Condensate Recovery".to_owned(),
+                            "Blowdown".to_owned(),
+                            "Flash Vessel".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::Psychrometrics => Some((
+                        "Psychrometrics".to_owned(),
+                        vec![
+                            "Moist Air".to_owned(),
+                            "Humidity".to_owned(),
+                            "Wet Bulb".to_owned(),
+                            "Dew Point".to_owned(),
+                            "HVAC".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::CubicEos => Some((
+                        "Cubic Equations of State".to_owned(),
+                        vec![
+                            "Peng-Robinson".to_owned(),
+                            "Soave-Redlich-Kwong".to_owned(),
+                            "Compressibility Factor".to_owned(),
+                            "Real Gas".to_owned(),
+                            "Fugacity".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::RefrigerantTable => Some((
+                        "Refrigerant Tables".to_owned(),
+                        vec![
+                            "R-134a".to_owned(),
+                            "R-410A".to_owned(),
+                            "R-32".to_owned(),
+                            "Ammonia".to_owned(),
+                            "Carbon Dioxide".to_owned(),
+                            "HVAC".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::VaporCompressionCycle => Some((
+                        "Vapor Compression Cycle".to_owned(),
+                        vec![
+                            "Refrigeration".to_owned(),
+                            "Heat Pump".to_owned(),
+                            "COP".to_owned(),
+                            "P-h Diagram".to_owned(),
+                            "HVAC".to_owned(),
+                        ],
+                    )),
+                    ThermoRoute::Combustion => Some((
+                        "Combustion".to_owned(),
+                        vec![
+                            "Air Fuel Ratio".to_owned(),
+                            "Excess Air".to_owned(),
+                            "Flue Gas".to_owned(),
+                            "Heating Value".to_owned(),
+                            "Adiabatic Flame Temperature".to_owned(),
+                            "Boiler".to_owned(),
+                        ],
+                    )),
                     ThermoRoute::NotFound => None,
                 };
                 if let Some((label, tags)) = opt {
@@ -56,6 +168,24 @@ fn create_link_configs() -> Vec<SearchableGroup> {
             .filter_map(|route| {
                 let opt = match route {
                     FluidsRoute::OrificePlate => Some(("Orifice Plate".to_owned(), vec![])),
+                    FluidsRoute::Pump => Some((
+                        "Pump Power".to_owned(),
+                        vec![
+                            "Head".to_owned(),
+                            "Hydraulic Power".to_owned(),
+                            "Efficiency".to_owned(),
+                        ],
+                    )),
+                    FluidsRoute::Compressor => Some((
+                        "Compressor Power".to_owned(),
+                        vec![
+                            "Polytropic".to_owned(),
+                            "Isentropic".to_owned(),
+                            "Head".to_owned(),
+                            "Discharge Temperature".to_owned(),
+                            "Intercooling".to_owned(),
+                        ],
+                    )),
                     FluidsRoute::NotFound => None,
                 };
                 if let Some((label, tags)) = opt {
@@ -70,7 +200,38 @@ fn create_link_configs() -> Vec<SearchableGroup> {
             })
             .collect::<Vec<SearchableLinkConfig>>(),
     };
-    vec![fluids_routes, thermo_routes]
+    let heat_transfer_routes = SearchableGroup {
+        tags: vec![],
+        label: "Heat Transfer".to_owned(),
+        configs: HeatTransferRoute::iter()
+            .filter_map(|route| {
+                let opt = match route {
+                    HeatTransferRoute::HeatExchanger => Some((
+                        "Heat Exchanger".to_owned(),
+                        vec![
+                            "LMTD".to_owned(),
+                            "Effectiveness".to_owned(),
+                            "NTU".to_owned(),
+                            "Shell and Tube".to_owned(),
+                            "Crossflow".to_owned(),
+                            "Counterflow".to_owned(),
+                        ],
+                    )),
+                    HeatTransferRoute::NotFound => None,
+                };
+                if let Some((label, tags)) = opt {
+                    Some(SearchableLinkConfig {
+                        tags,
+                        label,
+                        route: AllRoutes::HeatTransferRoute(route),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<SearchableLinkConfig>>(),
+    };
+    vec![fluids_routes, heat_transfer_routes, thermo_routes]
         .iter()
         .filter(|x| !x.configs.is_empty())
         .map(|x| (*x).clone())
@@ -140,6 +301,15 @@ fn search_result(SearchResultProps { group }: &SearchResultProps) -> html {
 
                             }
                         }
+                        AllRoutes::HeatTransferRoute(route) => {
+                            html! {
+                            <SearchResultLink<HeatTransferRoute>
+                                to={route}
+                                label={config.label.to_owned()}
+                                />
+
+                            }
+                        }
                     };
                     html! {
 
@@ -0,0 +1,370 @@
+use crate::thermo::combustion::*;
+use crate::thermo::ideal_gas::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use std::fmt::Display;
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiAirSpecification {
+    ExcessAir,
+    DryOxygen,
+    DryCarbonDioxide,
+}
+
+impl TryFrom<String> for UiAirSpecification {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "ExcessAir" => Ok(UiAirSpecification::ExcessAir),
+            "DryOxygen" => Ok(UiAirSpecification::DryOxygen),
+            "DryCarbonDioxide" => Ok(UiAirSpecification::DryCarbonDioxide),
+            _ => Err(format!("Unknown Air Specification \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiAirSpecification> for String {
+    fn from(val: UiAirSpecification) -> Self {
+        match val {
+            UiAirSpecification::ExcessAir => "ExcessAir".to_owned(),
+            UiAirSpecification::DryOxygen => "DryOxygen".to_owned(),
+            UiAirSpecification::DryCarbonDioxide => "DryCarbonDioxide".to_owned(),
+        }
+    }
+}
+
+impl Display for UiAirSpecification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiAirSpecification::ExcessAir => "Excess Air in %",
+                UiAirSpecification::DryOxygen => "Dry Flue Gas O2 in %",
+                UiAirSpecification::DryCarbonDioxide => "Dry Flue Gas CO2 in %",
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct CombustionQuery {
+    fuel: Species,
+    air_specification: UiAirSpecification,
+    // Percent
+    air_value: f64,
+    fuel_temperature: Temperature,
+    air_temperature: Temperature,
+    pressure: Pressure,
+}
+
+fn get_combustion_result(query: &CombustionQuery) -> Result<CombustionResult, CombustionErr> {
+    let fuel = Mixture::pure(query.fuel);
+    let fraction = query.air_value / 100f64;
+    let excess_air = match query.air_specification {
+        UiAirSpecification::ExcessAir => fraction,
+        UiAirSpecification::DryOxygen => get_excess_air_from_dry_oxygen(&fuel, fraction)?,
+        UiAirSpecification::DryCarbonDioxide => {
+            get_excess_air_from_dry_carbon_dioxide(&fuel, fraction)?
+        }
+    };
+    get_combustion(&Combustion {
+        fuel,
+        excess_air,
+        fuel_temperature: query.fuel_temperature,
+        air_temperature: query.air_temperature,
+        pressure: query.pressure,
+    })
+}
+
+fn get_combustion_err_message(err: &CombustionErr) -> (String, String) {
+    match err {
+        CombustionErr::NoCombustibles => (
+            String::from("Fuel Error"),
+            String::from("Fuel has nothing to burn"),
+        ),
+        CombustionErr::NegativeExcessAir => (
+            String::from("Excess Air Error"),
+            String::from("Excess air can not be negative"),
+        ),
+        CombustionErr::FlueGasFractionOutOfRange => (
+            String::from("Flue Gas Error"),
+            String::from("No amount of excess air gives this flue gas fraction"),
+        ),
+        CombustionErr::IdealGasErr(IdealGasErr::TemperatureLow) => (
+            String::from("Out of Range Error"),
+            String::from("Temperature is Low"),
+        ),
+        CombustionErr::IdealGasErr(IdealGasErr::TemperatureHigh) => (
+            String::from("Out of Range Error"),
+            String::from("Temperature is High"),
+        ),
+        CombustionErr::IdealGasErr(_) => (
+            String::from("Composition Error"),
+            String::from("Invalid mixture composition"),
+        ),
+        CombustionErr::SteamQueryErr(steam_err) => get_steam_query_err_message(steam_err),
+        CombustionErr::FailedToConverge(_) => (
+            String::from("Convergence Error"),
+            String::from("Failed to find the adiabatic flame temperature"),
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CombustionInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<CombustionQuery>>,
+}
+
+#[function_component(CombustionInput)]
+fn combustion_input(CombustionInputProps { onchange }: &CombustionInputProps) -> Html {
+    let fuel_opt = use_state(|| -> Option<Species> { Some(Species::CH4) });
+    let on_fuel_change = {
+        let fuel_opt = fuel_opt.clone();
+        Callback::from(move |val| {
+            fuel_opt.set(val);
+        })
+    };
+
+    let air_specification_opt =
+        use_state(|| -> Option<UiAirSpecification> { Some(UiAirSpecification::ExcessAir) });
+    let on_air_specification_change = {
+        let air_specification_opt = air_specification_opt.clone();
+        Callback::from(move |val| {
+            air_specification_opt.set(val);
+        })
+    };
+
+    let air_value_opt = use_state(|| -> Option<f64> { None });
+    let on_air_value_change = {
+        let air_value_opt = air_value_opt.clone();
+        Callback::from(move |val| {
+            air_value_opt.set(val);
+        })
+    };
+
+    let fuel_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_fuel_temperature_change = {
+        let fuel_temperature_opt = fuel_temperature_opt.clone();
+        Callback::from(move |val| {
+            fuel_temperature_opt.set(val);
+        })
+    };
+
+    let air_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_air_temperature_change = {
+        let air_temperature_opt = air_temperature_opt.clone();
+        Callback::from(move |val| {
+            air_temperature_opt.set(val);
+        })
+    };
+
+    let pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_change = {
+        let pressure_opt = pressure_opt.clone();
+        Callback::from(move |val| {
+            pressure_opt.set(val);
+        })
+    };
+
+    {
+        let fuel_opt = *fuel_opt;
+        let air_specification_opt = *air_specification_opt;
+        let air_value_opt = *air_value_opt;
+        let fuel_temperature_opt = *fuel_temperature_opt;
+        let air_temperature_opt = *air_temperature_opt;
+        let pressure_opt = *pressure_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                fuel_opt,
+                air_specification_opt,
+                air_value_opt,
+                fuel_temperature_opt,
+                air_temperature_opt,
+                pressure_opt,
+            ) {
+                (
+                    Some(fuel),
+                    Some(air_specification),
+                    Some(air_value),
+                    Some(fuel_temperature),
+                    Some(air_temperature),
+                    Some(pressure),
+                ) => Some(CombustionQuery {
+                    fuel,
+                    air_specification,
+                    air_value,
+                    fuel_temperature,
+                    air_temperature,
+                    pressure,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <SelectInput<Species>
+            id="fuel"
+            label="Fuel"
+            onchange={on_fuel_change}
+            value={*fuel_opt}
+            options={vec![Species::CH4, Species::C2H6, Species::C3H8, Species::H2, Species::CO]}
+        />
+        <SelectInput<UiAirSpecification>
+            id="air_specification"
+            label="Air Specification"
+            onchange={on_air_specification_change}
+            value={*air_specification_opt}
+            options={vec![
+                UiAirSpecification::ExcessAir,
+                UiAirSpecification::DryOxygen,
+                UiAirSpecification::DryCarbonDioxide,
+            ]}
+        />
+        <NumberInput
+            id={"air_value"}
+            label={(*air_specification_opt)
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| String::from("Excess Air in %"))}
+            onchange={on_air_value_change}
+        />
+        <UnitInput<Temperature> id={"fuel_temperature"} label={"Fuel Temperature"} onchange={on_fuel_temperature_change}/>
+        <UnitInput<Temperature> id={"air_temperature"} label={"Air Temperature"} onchange={on_air_temperature_change}/>
+        <UnitInput<Pressure> id={"pressure"} label={"Pressure"} onchange={on_pressure_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CombustionOutputProps {
+    result_opt: Option<Result<CombustionResult, CombustionErr>>,
+}
+
+#[function_component(CombustionOutput)]
+fn combustion_output(CombustionOutputProps { result_opt }: &CombustionOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => {
+            let dew_point_html = match result.dew_point {
+                Some(dew_point) => html! {
+                    <UnitOutput<Temperature>
+                        id={"dew_point_output"}
+                        label={"Flue Gas Dew Point"}
+                        value={dew_point}
+                    />
+                },
+                None => html! {},
+            };
+            let composition_html = |mixture: &Mixture, basis: &str| {
+                mixture
+                    .get_mole_fractions()
+                    .iter()
+                    .map(|(species, x)| {
+                        html! {
+                            <NumberOutput
+                                id={format!("{}_{}_output", basis, String::from(*species))}
+                                label={format!("{} {} in %", basis, species)}
+                                value={100f64 * x}
+                            />
+                        }
+                    })
+                    .collect::<Html>()
+            };
+            html! {
+                <>
+                    <UnitOutput<Temperature>
+                        id={"adiabatic_flame_temperature_output"}
+                        label={"Adiabatic Flame Temperature"}
+                        value={result.adiabatic_flame_temperature}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"higher_heating_value_output"}
+                        label={"Higher Heating Value"}
+                        value={result.higher_heating_value}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"lower_heating_value_output"}
+                        label={"Lower Heating Value"}
+                        value={result.lower_heating_value}
+                    />
+                    <NumberOutput
+                        id={"stoichiometric_air_fuel_ratio_output"}
+                        label={"Stoichiometric Air Fuel Ratio (Mass)"}
+                        value={result.stoichiometric_air_fuel_ratio}
+                    />
+                    <NumberOutput
+                        id={"air_fuel_ratio_output"}
+                        label={"Air Fuel Ratio (Mass)"}
+                        value={result.air_fuel_ratio}
+                    />
+                    {dew_point_html}
+                    {composition_html(&result.flue_gas, "Wet")}
+                    {composition_html(&result.dry_flue_gas, "Dry")}
+                </>
+            }
+        }
+        Some(Err(err)) => {
+            let (label, err_msg) = get_combustion_err_message(err);
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CombustionFormProps {}
+
+#[function_component(CombustionForm)]
+pub fn combustion_form(CombustionFormProps {}: &CombustionFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<CombustionQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<CombustionResult, CombustionErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <CombustionInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = &*query_opt {
+                            let result = get_combustion_result(query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <CombustionOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1 @@
+pub mod combustion_form;
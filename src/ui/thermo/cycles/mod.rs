@@ -0,0 +1,2 @@
+pub mod rankine_cycle_form;
+pub mod vapor_compression_form;
@@ -0,0 +1,297 @@
+use crate::thermo::cycles::rankine::*;
+use crate::thermo::cycles::*;
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::property_diagrams::PropertyDiagramKind;
+use crate::thermo::steam::tables::*;
+use crate::ui::shared::diagram_output::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::printable_steam_table_form::SteamTableView;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct RankineCycleInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<RankineCycle>>,
+}
+
+#[function_component(RankineCycleInput)]
+fn rankine_cycle_input(RankineCycleInputProps { onchange }: &RankineCycleInputProps) -> Html {
+    let boiler_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_boiler_pressure_change = {
+        let boiler_pressure_opt = boiler_pressure_opt.clone();
+        Callback::from(move |val| {
+            boiler_pressure_opt.set(val);
+        })
+    };
+
+    let turbine_inlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_turbine_inlet_temperature_change = {
+        let turbine_inlet_temperature_opt = turbine_inlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            turbine_inlet_temperature_opt.set(val);
+        })
+    };
+
+    let condenser_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_condenser_pressure_change = {
+        let condenser_pressure_opt = condenser_pressure_opt.clone();
+        Callback::from(move |val| {
+            condenser_pressure_opt.set(val);
+        })
+    };
+
+    let turbine_efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_turbine_efficiency_change = {
+        let turbine_efficiency_opt = turbine_efficiency_opt.clone();
+        Callback::from(move |val| {
+            turbine_efficiency_opt.set(val);
+        })
+    };
+
+    let pump_efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_pump_efficiency_change = {
+        let pump_efficiency_opt = pump_efficiency_opt.clone();
+        Callback::from(move |val| {
+            pump_efficiency_opt.set(val);
+        })
+    };
+
+    let reheat_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_reheat_pressure_change = {
+        let reheat_pressure_opt = reheat_pressure_opt.clone();
+        Callback::from(move |val| {
+            reheat_pressure_opt.set(val);
+        })
+    };
+
+    let reheat_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_reheat_temperature_change = {
+        let reheat_temperature_opt = reheat_temperature_opt.clone();
+        Callback::from(move |val| {
+            reheat_temperature_opt.set(val);
+        })
+    };
+
+    let high_heater_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_high_heater_pressure_change = {
+        let high_heater_pressure_opt = high_heater_pressure_opt.clone();
+        Callback::from(move |val| {
+            high_heater_pressure_opt.set(val);
+        })
+    };
+
+    let low_heater_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_low_heater_pressure_change = {
+        let low_heater_pressure_opt = low_heater_pressure_opt.clone();
+        Callback::from(move |val| {
+            low_heater_pressure_opt.set(val);
+        })
+    };
+
+    {
+        let boiler_pressure_opt = *boiler_pressure_opt;
+        let turbine_inlet_temperature_opt = *turbine_inlet_temperature_opt;
+        let condenser_pressure_opt = *condenser_pressure_opt;
+        let turbine_efficiency_opt = *turbine_efficiency_opt;
+        let pump_efficiency_opt = *pump_efficiency_opt;
+        let reheat_pressure_opt = *reheat_pressure_opt;
+        let reheat_temperature_opt = *reheat_temperature_opt;
+        let high_heater_pressure_opt = *high_heater_pressure_opt;
+        let low_heater_pressure_opt = *low_heater_pressure_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            // Reheat is optional but needs both its pressure and temperature
+            let reheat_opt = match (reheat_pressure_opt, reheat_temperature_opt) {
+                (Some(pressure), Some(temperature)) => Some(Some(Reheat {
+                    pressure,
+                    temperature,
+                })),
+                (None, None) => Some(None),
+                _ => None,
+            };
+            let query_opt = match (
+                boiler_pressure_opt,
+                turbine_inlet_temperature_opt,
+                condenser_pressure_opt,
+                turbine_efficiency_opt,
+                pump_efficiency_opt,
+                reheat_opt,
+            ) {
+                (
+                    Some(boiler_pressure),
+                    Some(turbine_inlet_temperature),
+                    Some(condenser_pressure),
+                    Some(turbine_efficiency),
+                    Some(pump_efficiency),
+                    Some(reheat),
+                ) => Some(RankineCycle {
+                    boiler_pressure,
+                    turbine_inlet_temperature,
+                    condenser_pressure,
+                    turbine_efficiency,
+                    pump_efficiency,
+                    reheat,
+                    feedwater_heater_pressures: [high_heater_pressure_opt, low_heater_pressure_opt]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <UnitInput<Pressure> id={"boiler_pressure"} label={"Boiler Pressure"} onchange={on_boiler_pressure_change}/>
+        <UnitInput<Temperature> id={"turbine_inlet_temperature"} label={"Turbine Inlet Temperature"} onchange={on_turbine_inlet_temperature_change}/>
+        <UnitInput<Pressure> id={"condenser_pressure"} label={"Condenser Pressure"} onchange={on_condenser_pressure_change}/>
+        <NumberInput id={"turbine_efficiency"} label={"Turbine Isentropic Efficiency"} onchange={on_turbine_efficiency_change}/>
+        <NumberInput id={"pump_efficiency"} label={"Pump Isentropic Efficiency"} onchange={on_pump_efficiency_change}/>
+        <UnitInput<Pressure> id={"reheat_pressure"} label={"Reheat Pressure (Optional)"} onchange={on_reheat_pressure_change}/>
+        <UnitInput<Temperature> id={"reheat_temperature"} label={"Reheat Temperature (Optional)"} onchange={on_reheat_temperature_change}/>
+        <UnitInput<Pressure> id={"high_heater_pressure"} label={"Feedwater Heater Pressure (Optional)"} onchange={on_high_heater_pressure_change}/>
+        <UnitInput<Pressure> id={"low_heater_pressure"} label={"Second Feedwater Heater Pressure (Optional)"} onchange={on_low_heater_pressure_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct RankineCycleOutputProps {
+    result_opt: Option<Result<RankineCycleResult, RankineCycleErr>>,
+}
+
+#[function_component(RankineCycleOutput)]
+fn rankine_cycle_output(RankineCycleOutputProps { result_opt }: &RankineCycleOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => {
+            let table_html =
+                match get_cycle_state_table(&result.states, &SteamTableUnits::default()) {
+                    Ok(table) => html! { <SteamTableView table={table}/> },
+                    Err(_) => html! {},
+                };
+            html! {
+                <>
+                    <UnitOutput<EnergyPerMass>
+                        id={"turbine_work_output"}
+                        label={"Turbine Work"}
+                        value={result.turbine_work}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"pump_work_output"}
+                        label={"Pump Work"}
+                        value={result.pump_work}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"net_work_output"}
+                        label={"Net Work"}
+                        value={result.net_work}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"heat_input_output"}
+                        label={"Heat Input"}
+                        value={result.heat_input}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"heat_rejected_output"}
+                        label={"Heat Rejected"}
+                        value={result.heat_rejected}
+                    />
+                    <NumberOutput
+                        id={"thermal_efficiency_output"}
+                        label={"Thermal Efficiency"}
+                        value={result.thermal_efficiency}
+                    />
+                    <NumberOutput
+                        id={"back_work_ratio_output"}
+                        label={"Back Work Ratio"}
+                        value={result.back_work_ratio}
+                    />
+                    <UnitOutput<MassPerEnergy>
+                        id={"steam_rate_output"}
+                        label={"Steam Rate"}
+                        value={result.steam_rate}
+                    />
+                    {table_html}
+                    <DiagramOutput
+                        diagram={get_steam_cycle_diagram(
+                            PropertyDiagramKind::TemperatureEntropy,
+                            &result.states,
+                        )}
+                        file_name={"rankine_cycle"}
+                    />
+                </>
+            }
+        }
+        Some(Err(err)) => {
+            let (label, err_msg) = match err {
+                RankineCycleErr::InvalidEfficiency => (
+                    String::from("Efficiency Error"),
+                    String::from("Efficiencies must be above 0 and at most 1"),
+                ),
+                RankineCycleErr::PressureOutOfOrder => (
+                    String::from("Pressure Error"),
+                    String::from(
+                        "Reheat and feedwater heater pressures must be between the condenser and boiler pressures",
+                    ),
+                ),
+                RankineCycleErr::SteamQueryErr(steam_err) => get_steam_query_err_message(steam_err),
+            };
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RankineCycleFormProps {}
+
+#[function_component(RankineCycleForm)]
+pub fn rankine_cycle_form(RankineCycleFormProps {}: &RankineCycleFormProps) -> Html {
+    let cycle_opt = use_state_eq(|| -> Option<RankineCycle> { None });
+    let on_cycle_change = {
+        let cycle_opt = cycle_opt.clone();
+        Callback::from(move |val| {
+            cycle_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<RankineCycleResult, RankineCycleErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <RankineCycleInput onchange={on_cycle_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(cycle) = &*cycle_opt {
+                            let result = get_rankine_cycle(&Iapws97, cycle);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <RankineCycleOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
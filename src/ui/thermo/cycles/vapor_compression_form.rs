@@ -0,0 +1,298 @@
+use crate::thermo::cycles::refrigeration::*;
+use crate::thermo::cycles::*;
+use crate::thermo::refrigerants::*;
+use crate::thermo::steam::tables::*;
+use crate::ui::shared::diagram_output::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::printable_steam_table_form::SteamTableView;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct VaporCompressionInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<VaporCompressionCycle>>,
+}
+
+#[function_component(VaporCompressionInput)]
+fn vapor_compression_input(
+    VaporCompressionInputProps { onchange }: &VaporCompressionInputProps,
+) -> Html {
+    let refrigerant_opt = use_state(|| -> Option<Refrigerant> { Some(Refrigerant::R134a) });
+    let on_refrigerant_change = {
+        let refrigerant_opt = refrigerant_opt.clone();
+        Callback::from(move |val| {
+            refrigerant_opt.set(val);
+        })
+    };
+
+    let evaporator_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_evaporator_temperature_change = {
+        let evaporator_temperature_opt = evaporator_temperature_opt.clone();
+        Callback::from(move |val| {
+            evaporator_temperature_opt.set(val);
+        })
+    };
+
+    let condenser_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_condenser_temperature_change = {
+        let condenser_temperature_opt = condenser_temperature_opt.clone();
+        Callback::from(move |val| {
+            condenser_temperature_opt.set(val);
+        })
+    };
+
+    let superheat_opt = use_state(|| -> Option<f64> { None });
+    let on_superheat_change = {
+        let superheat_opt = superheat_opt.clone();
+        Callback::from(move |val| {
+            superheat_opt.set(val);
+        })
+    };
+
+    let subcooling_opt = use_state(|| -> Option<f64> { None });
+    let on_subcooling_change = {
+        let subcooling_opt = subcooling_opt.clone();
+        Callback::from(move |val| {
+            subcooling_opt.set(val);
+        })
+    };
+
+    let compressor_efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_compressor_efficiency_change = {
+        let compressor_efficiency_opt = compressor_efficiency_opt.clone();
+        Callback::from(move |val| {
+            compressor_efficiency_opt.set(val);
+        })
+    };
+
+    let capacity_opt = use_state(|| -> Option<Power> { None });
+    let on_capacity_change = {
+        let capacity_opt = capacity_opt.clone();
+        Callback::from(move |val| {
+            capacity_opt.set(val);
+        })
+    };
+
+    {
+        let refrigerant_opt = *refrigerant_opt;
+        let evaporator_temperature_opt = *evaporator_temperature_opt;
+        let condenser_temperature_opt = *condenser_temperature_opt;
+        let superheat_opt = *superheat_opt;
+        let subcooling_opt = *subcooling_opt;
+        let compressor_efficiency_opt = *compressor_efficiency_opt;
+        let capacity_opt = *capacity_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                refrigerant_opt,
+                evaporator_temperature_opt,
+                condenser_temperature_opt,
+                compressor_efficiency_opt,
+                capacity_opt,
+            ) {
+                (
+                    Some(refrigerant),
+                    Some(evaporator_temperature),
+                    Some(condenser_temperature),
+                    Some(compressor_efficiency),
+                    Some(capacity),
+                ) => Some(VaporCompressionCycle {
+                    refrigerant,
+                    evaporator_temperature,
+                    condenser_temperature,
+                    // Saturated states when left blank
+                    superheat: superheat_opt.unwrap_or(0f64),
+                    subcooling: subcooling_opt.unwrap_or(0f64),
+                    compressor_efficiency,
+                    capacity,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+    html! {
+    <>
+        <SelectInput<Refrigerant>
+            id="refrigerant"
+            label="Refrigerant"
+            onchange={on_refrigerant_change}
+            value={*refrigerant_opt}
+            options={Refrigerant::all()}
+        />
+        <UnitInput<Temperature> id={"evaporator_temperature"} label={"Evaporator Temperature"} onchange={on_evaporator_temperature_change}/>
+        <UnitInput<Temperature> id={"condenser_temperature"} label={"Condenser Temperature"} onchange={on_condenser_temperature_change}/>
+        <NumberInput id={"superheat"} label={"Superheat in K (Optional)"} onchange={on_superheat_change}/>
+        <NumberInput id={"subcooling"} label={"Subcooling in K (Optional)"} onchange={on_subcooling_change}/>
+        <NumberInput id={"compressor_efficiency"} label={"Compressor Isentropic Efficiency"} onchange={on_compressor_efficiency_change}/>
+        <UnitInput<Power> id={"capacity"} label={"Refrigerating Capacity"} onchange={on_capacity_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct VaporCompressionOutputProps {
+    result_opt: Option<Result<VaporCompressionCycleResult, VaporCompressionCycleErr>>,
+    refrigerant: Refrigerant,
+}
+
+#[function_component(VaporCompressionOutput)]
+fn vapor_compression_output(
+    VaporCompressionOutputProps {
+        result_opt,
+        refrigerant,
+    }: &VaporCompressionOutputProps,
+) -> Html {
+    match result_opt {
+        Some(Ok(result)) => {
+            let table_html =
+                match get_cycle_state_table(&result.states, &SteamTableUnits::default()) {
+                    Ok(table) => html! { <SteamTableView table={table}/> },
+                    Err(_) => html! {},
+                };
+            html! {
+                <>
+                    <NumberOutput
+                        id={"refrigeration_cop_output"}
+                        label={"Refrigeration COP"}
+                        value={result.refrigeration_cop}
+                    />
+                    <NumberOutput
+                        id={"heat_pump_cop_output"}
+                        label={"Heat Pump COP"}
+                        value={result.heat_pump_cop}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"refrigerating_effect_output"}
+                        label={"Refrigerating Effect"}
+                        value={result.refrigerating_effect}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"compressor_work_output"}
+                        label={"Compressor Work"}
+                        value={result.compressor_work}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"heat_rejected_output"}
+                        label={"Heat Rejected"}
+                        value={result.heat_rejected}
+                    />
+                    <UnitOutput<MassFlowRate>
+                        id={"mass_flow_rate_output"}
+                        label={"Refrigerant Mass Flow Rate"}
+                        value={result.mass_flow_rate}
+                    />
+                    <UnitOutput<Power>
+                        id={"compressor_power_output"}
+                        label={"Compressor Power"}
+                        value={result.compressor_power}
+                    />
+                    <UnitOutput<Power>
+                        id={"heat_rejection_rate_output"}
+                        label={"Condenser Heat Rejection Rate"}
+                        value={result.heat_rejection_rate}
+                    />
+                    <UnitOutput<Pressure>
+                        id={"evaporator_pressure_output"}
+                        label={"Evaporator Pressure"}
+                        value={result.evaporator_pressure}
+                    />
+                    <UnitOutput<Pressure>
+                        id={"condenser_pressure_output"}
+                        label={"Condenser Pressure"}
+                        value={result.condenser_pressure}
+                    />
+                    {table_html}
+                    <DiagramOutput
+                        diagram={get_refrigeration_cycle_diagram(*refrigerant, &result.states)}
+                        file_name={"vapor_compression_cycle"}
+                    />
+                </>
+            }
+        }
+        Some(Err(err)) => {
+            let (label, err_msg) = match err {
+                VaporCompressionCycleErr::InvalidEfficiency => (
+                    String::from("Efficiency Error"),
+                    String::from("Efficiency must be above 0 and at most 1"),
+                ),
+                VaporCompressionCycleErr::NegativeTemperatureDifference => (
+                    String::from("Temperature Error"),
+                    String::from("Superheat and subcooling can not be negative"),
+                ),
+                VaporCompressionCycleErr::TemperatureOutOfOrder => (
+                    String::from("Temperature Error"),
+                    String::from("Condenser temperature must be above the evaporator temperature"),
+                ),
+                VaporCompressionCycleErr::CapacityMustBePositive => (
+                    String::from("Capacity Error"),
+                    String::from("Refrigerating capacity must be above 0"),
+                ),
+                VaporCompressionCycleErr::SteamQueryErr(steam_err) => {
+                    get_steam_query_err_message(steam_err)
+                }
+            };
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct VaporCompressionFormProps {}
+
+#[function_component(VaporCompressionForm)]
+pub fn vapor_compression_form(VaporCompressionFormProps {}: &VaporCompressionFormProps) -> Html {
+    let cycle_opt = use_state_eq(|| -> Option<VaporCompressionCycle> { None });
+    let on_cycle_change = {
+        let cycle_opt = cycle_opt.clone();
+        Callback::from(move |val| {
+            cycle_opt.set(val);
+        })
+    };
+    let result_opt = use_state(
+        || -> Option<Result<VaporCompressionCycleResult, VaporCompressionCycleErr>> { None },
+    );
+    let refrigerant = use_state(|| Refrigerant::R134a);
+
+    let result_opt_output = (*result_opt).clone();
+    let refrigerant_output = *refrigerant;
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <VaporCompressionInput onchange={on_cycle_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(cycle) = &*cycle_opt {
+                            let result = get_vapor_compression_cycle(cycle);
+                            refrigerant.set(cycle.refrigerant);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <VaporCompressionOutput result_opt={result_opt_output} refrigerant={refrigerant_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1,234 @@
+use crate::thermo::eos::*;
+use crate::thermo::ideal_gas::*;
+use crate::thermo::steam::*;
+use crate::thermo::PtvEntry;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::PtvEntryOutput;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct EosQuery {
+    eos: CubicEos,
+    species: Species,
+    point: PtPoint,
+}
+
+pub fn get_eos_err_message(err: &EosErr) -> (String, String) {
+    match err {
+        EosErr::MissingCriticalConstants(species) => (
+            String::from("Species Error"),
+            format!("No critical constants for {}", species),
+        ),
+        EosErr::PressureMustBePositive => (
+            String::from("Pressure Error"),
+            String::from("Pressure must be above 0"),
+        ),
+        EosErr::TemperatureMustBePositive => (
+            String::from("Temperature Error"),
+            String::from("Temperature must be above 0"),
+        ),
+        EosErr::AboveCriticalTemperature => (
+            String::from("Temperature Error"),
+            String::from("Above Critical Temperature"),
+        ),
+        EosErr::FailedToConverge => (
+            String::from("Convergence Error"),
+            String::from("Failed to find a root of the equation of state"),
+        ),
+        EosErr::IdealGasErr(IdealGasErr::TemperatureLow) => (
+            String::from("Out of Range Error"),
+            String::from("Temperature is Low"),
+        ),
+        EosErr::IdealGasErr(IdealGasErr::TemperatureHigh) => (
+            String::from("Out of Range Error"),
+            String::from("Temperature is High"),
+        ),
+        EosErr::IdealGasErr(_) => (
+            String::from("Composition Error"),
+            String::from("Invalid mixture composition"),
+        ),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct EosInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<EosQuery>>,
+}
+
+#[function_component(EosInput)]
+fn eos_input(EosInputProps { onchange }: &EosInputProps) -> Html {
+    let eos_opt = use_state(|| -> Option<CubicEos> { Some(CubicEos::PengRobinson) });
+    let on_eos_change = {
+        let eos_opt = eos_opt.clone();
+        Callback::from(move |val| {
+            eos_opt.set(val);
+        })
+    };
+
+    let species_opt = use_state(|| -> Option<Species> { Some(Species::CH4) });
+    let on_species_change = {
+        let species_opt = species_opt.clone();
+        Callback::from(move |val| {
+            species_opt.set(val);
+        })
+    };
+
+    let pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_change = {
+        let pressure_opt = pressure_opt.clone();
+        Callback::from(move |val| {
+            pressure_opt.set(val);
+        })
+    };
+
+    let temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_temperature_change = {
+        let temperature_opt = temperature_opt.clone();
+        Callback::from(move |val| {
+            temperature_opt.set(val);
+        })
+    };
+
+    {
+        let eos_opt = *eos_opt;
+        let species_opt = *species_opt;
+        let pressure_opt = *pressure_opt;
+        let temperature_opt = *temperature_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (eos_opt, species_opt, pressure_opt, temperature_opt) {
+                (Some(eos), Some(species), Some(pressure), Some(temperature)) => Some(EosQuery {
+                    eos,
+                    species,
+                    point: PtPoint {
+                        pressure,
+                        temperature,
+                    },
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SelectInput<CubicEos>
+            id="eos"
+            label="Equation of State"
+            onchange={on_eos_change}
+            value={*eos_opt}
+            options={vec![CubicEos::PengRobinson, CubicEos::SoaveRedlichKwong]}
+        />
+        <SelectInput<Species>
+            id="species"
+            label="Species"
+            onchange={on_species_change}
+            value={*species_opt}
+            options={Species::all()
+                .into_iter()
+                .filter(|x| get_critical_constants(*x).is_ok())
+                .collect::<Vec<Species>>()}
+        />
+        <UnitInput<Pressure> id={"pressure"} label={"Pressure"} onchange={on_pressure_change}/>
+        <UnitInput<Temperature> id={"temperature"} label={"Temperature"} onchange={on_temperature_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct EosOutputProps {
+    result_opt: Option<Result<PtvEntry, EosErr>>,
+    species: Option<Species>,
+}
+
+#[function_component(EosOutput)]
+fn eos_output(
+    EosOutputProps {
+        result_opt,
+        species,
+    }: &EosOutputProps,
+) -> Html {
+    match (result_opt, species) {
+        (Some(Ok(entry)), Some(species)) => {
+            let compressibility = entry.pressure.convert_to_si_unit().value
+                * entry.specific_volume.convert_to_si_unit().value
+                * species.get_molar_mass()
+                / (UNIVERSAL_GAS_CONSTANT * entry.temperature.convert_to_si_unit().value);
+            html! {
+                <>
+                    <NumberOutput
+                        id={"compressibility_output"}
+                        label={"Compressibility Factor"}
+                        value={compressibility}
+                    />
+                    <PtvEntryOutput entry_opt={Some(Ok(entry.clone()))}/>
+                </>
+            }
+        }
+        (Some(Err(err)), _) => {
+            let (label, err_msg) = get_eos_err_message(err);
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        _ => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EosFormProps {}
+
+#[function_component(EosForm)]
+pub fn eos_form(EosFormProps {}: &EosFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<EosQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<PtvEntry, EosErr>> { None });
+    let species_opt = use_state(|| -> Option<Species> { None });
+
+    let result_opt_output = (*result_opt).clone();
+    let species_opt_output = *species_opt;
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <EosInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_eos_entry(
+                                query.eos,
+                                &Mixture::pure(query.species),
+                                query.point,
+                            );
+                            species_opt.set(Some(query.species));
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <EosOutput result_opt={result_opt_output} species={species_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
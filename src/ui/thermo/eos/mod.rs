@@ -0,0 +1 @@
+pub mod eos_form;
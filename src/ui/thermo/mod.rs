@@ -1 +1,7 @@
+pub mod combustion;
+pub mod cycles;
+pub mod eos;
+pub mod psychrometrics;
+pub mod refrigerants;
+pub mod steam_devices;
 pub mod steam_table;
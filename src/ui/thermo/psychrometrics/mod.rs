@@ -0,0 +1 @@
+pub mod psychrometric_form;
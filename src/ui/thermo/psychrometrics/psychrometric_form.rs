@@ -0,0 +1,362 @@
+use std::fmt::Display;
+
+use crate::thermo::psychrometrics::*;
+use crate::ui::shared::diagram_output::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum UiPsychrometricQuery {
+    RelativeHumidity,
+    HumidityRatio,
+    WetBulb,
+    DewPoint,
+}
+
+impl TryFrom<String> for UiPsychrometricQuery {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "RelativeHumidityQuery" => Ok(UiPsychrometricQuery::RelativeHumidity),
+            "HumidityRatioQuery" => Ok(UiPsychrometricQuery::HumidityRatio),
+            "WetBulbQuery" => Ok(UiPsychrometricQuery::WetBulb),
+            "DewPointQuery" => Ok(UiPsychrometricQuery::DewPoint),
+            _ => Err(format!("Unknown Query \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiPsychrometricQuery> for String {
+    fn from(val: UiPsychrometricQuery) -> Self {
+        match val {
+            UiPsychrometricQuery::RelativeHumidity => "RelativeHumidityQuery".to_owned(),
+            UiPsychrometricQuery::HumidityRatio => "HumidityRatioQuery".to_owned(),
+            UiPsychrometricQuery::WetBulb => "WetBulbQuery".to_owned(),
+            UiPsychrometricQuery::DewPoint => "DewPointQuery".to_owned(),
+        }
+    }
+}
+
+impl Display for UiPsychrometricQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiPsychrometricQuery::RelativeHumidity => "Dry Bulb and Relative Humidity",
+                UiPsychrometricQuery::HumidityRatio => "Dry Bulb and Humidity Ratio",
+                UiPsychrometricQuery::WetBulb => "Dry Bulb and Wet Bulb",
+                UiPsychrometricQuery::DewPoint => "Dry Bulb and Dew Point",
+            }
+        )
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PsychrometricInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<PsychrometricQuery>>,
+}
+
+#[function_component(PsychrometricInput)]
+fn psychrometric_input(PsychrometricInputProps { onchange }: &PsychrometricInputProps) -> Html {
+    let query_type_opt = use_state(|| -> Option<UiPsychrometricQuery> {
+        Some(UiPsychrometricQuery::RelativeHumidity)
+    });
+    let on_query_type_change = {
+        let query_type_opt = query_type_opt.clone();
+        Callback::from(move |val| {
+            query_type_opt.set(val);
+        })
+    };
+
+    let dry_bulb_opt = use_state(|| -> Option<Temperature> { None });
+    let on_dry_bulb_change = {
+        let dry_bulb_opt = dry_bulb_opt.clone();
+        Callback::from(move |val| {
+            dry_bulb_opt.set(val);
+        })
+    };
+
+    let pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_change = {
+        let pressure_opt = pressure_opt.clone();
+        Callback::from(move |val| {
+            pressure_opt.set(val);
+        })
+    };
+
+    let relative_humidity_opt = use_state(|| -> Option<f64> { None });
+    let on_relative_humidity_change = {
+        let relative_humidity_opt = relative_humidity_opt.clone();
+        Callback::from(move |val| {
+            relative_humidity_opt.set(val);
+        })
+    };
+
+    let humidity_ratio_opt = use_state(|| -> Option<f64> { None });
+    let on_humidity_ratio_change = {
+        let humidity_ratio_opt = humidity_ratio_opt.clone();
+        Callback::from(move |val| {
+            humidity_ratio_opt.set(val);
+        })
+    };
+
+    let wet_bulb_opt = use_state(|| -> Option<Temperature> { None });
+    let on_wet_bulb_change = {
+        let wet_bulb_opt = wet_bulb_opt.clone();
+        Callback::from(move |val| {
+            wet_bulb_opt.set(val);
+        })
+    };
+
+    let dew_point_opt = use_state(|| -> Option<Temperature> { None });
+    let on_dew_point_change = {
+        let dew_point_opt = dew_point_opt.clone();
+        Callback::from(move |val| {
+            dew_point_opt.set(val);
+        })
+    };
+
+    {
+        let query_type_opt = *query_type_opt;
+        let dry_bulb_opt = *dry_bulb_opt;
+        let pressure_opt = *pressure_opt;
+        let relative_humidity_opt = *relative_humidity_opt;
+        let humidity_ratio_opt = *humidity_ratio_opt;
+        let wet_bulb_opt = *wet_bulb_opt;
+        let dew_point_opt = *dew_point_opt;
+        let onchange = onchange.clone();
+        use_effect(move || {
+            let query_opt = match (query_type_opt, dry_bulb_opt, pressure_opt) {
+                (Some(query_type), Some(dry_bulb), Some(pressure)) => match query_type {
+                    UiPsychrometricQuery::RelativeHumidity => {
+                        relative_humidity_opt.map(|relative_humidity| {
+                            PsychrometricQuery::RelativeHumidity {
+                                dry_bulb,
+                                pressure,
+                                relative_humidity,
+                            }
+                        })
+                    }
+                    UiPsychrometricQuery::HumidityRatio => {
+                        humidity_ratio_opt.map(|humidity_ratio| PsychrometricQuery::HumidityRatio {
+                            dry_bulb,
+                            pressure,
+                            humidity_ratio,
+                        })
+                    }
+                    UiPsychrometricQuery::WetBulb => {
+                        wet_bulb_opt.map(|wet_bulb| PsychrometricQuery::WetBulb {
+                            dry_bulb,
+                            pressure,
+                            wet_bulb,
+                        })
+                    }
+                    UiPsychrometricQuery::DewPoint => {
+                        dew_point_opt.map(|dew_point| PsychrometricQuery::DewPoint {
+                            dry_bulb,
+                            pressure,
+                            dew_point,
+                        })
+                    }
+                },
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SelectInput<UiPsychrometricQuery>
+            id="query_type"
+            label="Query Type"
+            onchange={on_query_type_change}
+            value={*query_type_opt}
+            options={vec![
+                UiPsychrometricQuery::RelativeHumidity,
+                UiPsychrometricQuery::HumidityRatio,
+                UiPsychrometricQuery::WetBulb,
+                UiPsychrometricQuery::DewPoint,
+            ]}
+        />
+        <UnitInput<Temperature> id={"dry_bulb"} label={"Dry Bulb Temperature"} onchange={on_dry_bulb_change}/>
+        <UnitInput<Pressure> id={"pressure"} label={"Pressure"} onchange={on_pressure_change}/>
+        {
+        match *query_type_opt {
+            Some(UiPsychrometricQuery::RelativeHumidity) => html! {
+        <NumberInput id={"relative_humidity"} label={"Relative Humidity (0 to 1)"} onchange={on_relative_humidity_change}/>
+            },
+            Some(UiPsychrometricQuery::HumidityRatio) => html! {
+        <NumberInput id={"humidity_ratio"} label={"Humidity Ratio (kg/kg dry air)"} onchange={on_humidity_ratio_change}/>
+            },
+            Some(UiPsychrometricQuery::WetBulb) => html! {
+        <UnitInput<Temperature> id={"wet_bulb"} label={"Wet Bulb Temperature"} onchange={on_wet_bulb_change}/>
+            },
+            Some(UiPsychrometricQuery::DewPoint) => html! {
+        <UnitInput<Temperature> id={"dew_point"} label={"Dew Point Temperature"} onchange={on_dew_point_change}/>
+            },
+            None => html! {},
+        }
+        }
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PsychrometricOutputProps {
+    result_opt: Option<Result<MoistAirEntry, PsychrometricErr>>,
+}
+
+#[function_component(PsychrometricOutput)]
+fn psychrometric_output(
+    PsychrometricOutputProps { result_opt }: &PsychrometricOutputProps,
+) -> Html {
+    match result_opt {
+        Some(Ok(entry)) => {
+            let chart_html = match get_psychrometric_chart(entry.pressure, Some(entry)) {
+                Ok(diagram) => html! {
+                    <DiagramOutput diagram={diagram} file_name={"psychrometric_chart"}/>
+                },
+                Err(_) => html! {},
+            };
+            html! {
+                <>
+                    <NumberOutput
+                        id={"humidity_ratio_output"}
+                        label={"Humidity Ratio (kg/kg dry air)"}
+                        value={entry.humidity_ratio}
+                    />
+                    <NumberOutput
+                        id={"relative_humidity_output"}
+                        label={"Relative Humidity"}
+                        value={entry.relative_humidity}
+                    />
+                    <NumberOutput
+                        id={"degree_of_saturation_output"}
+                        label={"Degree of Saturation"}
+                        value={entry.degree_of_saturation}
+                    />
+                    <UnitOutput<Temperature>
+                        id={"wet_bulb_output"}
+                        label={"Wet Bulb Temperature"}
+                        value={entry.wet_bulb}
+                    />
+                    <UnitOutput<Temperature>
+                        id={"dew_point_output"}
+                        label={"Dew Point Temperature"}
+                        value={entry.dew_point}
+                    />
+                    <UnitOutput<Pressure>
+                        id={"vapor_pressure_output"}
+                        label={"Vapor Partial Pressure"}
+                        value={entry.vapor_pressure}
+                    />
+                    <UnitOutput<Pressure>
+                        id={"saturation_pressure_output"}
+                        label={"Saturation Pressure"}
+                        value={entry.saturation_pressure}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"enthalpy_output"}
+                        label={"Enthalpy (per kg dry air)"}
+                        value={entry.enthalpy}
+                    />
+                    <UnitOutput<SpecificVolume>
+                        id={"specific_volume_output"}
+                        label={"Specific Volume (per kg dry air)"}
+                        value={entry.specific_volume}
+                    />
+                    <UnitOutput<Density>
+                        id={"density_output"}
+                        label={"Moist Air Density"}
+                        value={entry.density}
+                    />
+                    {chart_html}
+                </>
+            }
+        }
+        Some(Err(err)) => {
+            let (label, err_msg) = match err {
+                PsychrometricErr::RelativeHumidityOutOfRange => (
+                    String::from("Humidity Error"),
+                    String::from("Relative humidity must be above 0 and at most 1"),
+                ),
+                PsychrometricErr::HumidityRatioOutOfRange => (
+                    String::from("Humidity Error"),
+                    String::from(
+                        "Humidity ratio must be above 0 and at most the saturated humidity ratio",
+                    ),
+                ),
+                PsychrometricErr::TemperatureAboveDryBulb => (
+                    String::from("Temperature Error"),
+                    String::from("Wet bulb and dew point cannot be above the dry bulb temperature"),
+                ),
+                PsychrometricErr::PressureBelowSaturation => (
+                    String::from("Pressure Error"),
+                    String::from("Water boils at this dry bulb temperature and pressure"),
+                ),
+                PsychrometricErr::SteamQueryErr(steam_err) => {
+                    get_steam_query_err_message(steam_err)
+                }
+            };
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PsychrometricFormProps {}
+
+#[function_component(PsychrometricForm)]
+pub fn psychrometric_form(PsychrometricFormProps {}: &PsychrometricFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<PsychrometricQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<MoistAirEntry, PsychrometricErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <PsychrometricInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_moist_air_entry(query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <PsychrometricOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
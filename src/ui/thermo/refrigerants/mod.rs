@@ -0,0 +1 @@
+pub mod refrigerant_table_form;
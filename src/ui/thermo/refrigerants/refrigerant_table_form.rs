@@ -0,0 +1,104 @@
+use crate::thermo::refrigerants::*;
+use crate::thermo::steam::*;
+use crate::thermo::PtvEntry;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::thermo::steam_table::steam_table_form::{PtvEntryOutput, SteamTableInput};
+use yew::prelude::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct RefrigerantQuery {
+    refrigerant: Refrigerant,
+    query: SteamQuery,
+}
+
+#[derive(Properties, PartialEq)]
+struct RefrigerantTableInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<RefrigerantQuery>>,
+}
+
+#[function_component(RefrigerantTableInput)]
+fn refrigerant_table_input(
+    RefrigerantTableInputProps { onchange }: &RefrigerantTableInputProps,
+) -> Html {
+    let refrigerant_opt = use_state(|| -> Option<Refrigerant> { Some(Refrigerant::R134a) });
+    let on_refrigerant_change = {
+        let refrigerant_opt = refrigerant_opt.clone();
+        Callback::from(move |val| {
+            refrigerant_opt.set(val);
+        })
+    };
+
+    let query_opt = use_state(|| -> Option<SteamQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+
+    {
+        let refrigerant_opt = *refrigerant_opt;
+        let query_opt = *query_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let refrigerant_query_opt = match (refrigerant_opt, query_opt) {
+                (Some(refrigerant), Some(query)) => Some(RefrigerantQuery { refrigerant, query }),
+                _ => None,
+            };
+            onchange.emit(refrigerant_query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SelectInput<Refrigerant>
+            id="refrigerant"
+            label="Refrigerant"
+            onchange={on_refrigerant_change}
+            value={*refrigerant_opt}
+            options={Refrigerant::all()}
+        />
+        <SteamTableInput onchange={on_query_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RefrigerantTableFormProps {}
+
+#[function_component(RefrigerantTableForm)]
+pub fn refrigerant_table_form(RefrigerantTableFormProps {}: &RefrigerantTableFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<RefrigerantQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let entry_opt = use_state(|| -> Option<Result<PtvEntry, SteamQueryErr>> { None });
+
+    let entry_opt_output = (*entry_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <RefrigerantTableInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = query.refrigerant.get_steam_table_entry(query.query);
+                            entry_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <PtvEntryOutput entry_opt={entry_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
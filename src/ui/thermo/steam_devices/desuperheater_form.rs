@@ -0,0 +1,160 @@
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::process_units::*;
+use crate::thermo::steam::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::thermo::steam_devices::process_unit_err_output::*;
+use crate::ui::thermo::steam_devices::steam_stream_input::*;
+use crate::ui::thermo::steam_table::steam_table_form::*;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct DesuperheaterInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<DesuperheaterQuery>>,
+}
+
+#[function_component(DesuperheaterInput)]
+fn desuperheater_input(DesuperheaterInputProps { onchange }: &DesuperheaterInputProps) -> Html {
+    let steam_opt = use_state(|| -> Option<SteamStream> { None });
+    let on_steam_change = {
+        let steam_opt = steam_opt.clone();
+        Callback::from(move |val| {
+            steam_opt.set(val);
+        })
+    };
+
+    let water_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_water_pressure_change = {
+        let water_pressure_opt = water_pressure_opt.clone();
+        Callback::from(move |val| {
+            water_pressure_opt.set(val);
+        })
+    };
+
+    let water_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_water_temperature_change = {
+        let water_temperature_opt = water_temperature_opt.clone();
+        Callback::from(move |val| {
+            water_temperature_opt.set(val);
+        })
+    };
+
+    let outlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_outlet_temperature_change = {
+        let outlet_temperature_opt = outlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            outlet_temperature_opt.set(val);
+        })
+    };
+
+    {
+        let steam_opt = *steam_opt;
+        let water_pressure_opt = *water_pressure_opt;
+        let water_temperature_opt = *water_temperature_opt;
+        let outlet_temperature_opt = *outlet_temperature_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                steam_opt,
+                water_pressure_opt,
+                water_temperature_opt,
+                outlet_temperature_opt,
+            ) {
+                (Some(steam), Some(pressure), Some(temperature), Some(outlet_temperature)) => {
+                    Some(DesuperheaterQuery {
+                        steam,
+                        spray_water: PtPoint {
+                            pressure,
+                            temperature,
+                        },
+                        outlet_temperature,
+                    })
+                }
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SteamStreamInput id={"steam"} label={"Steam"} onchange={on_steam_change}/>
+        <UnitInput<Pressure> id={"spray_water_pressure"} label={"Spray Water Pressure"} onchange={on_water_pressure_change}/>
+        <UnitInput<Temperature> id={"spray_water_temperature"} label={"Spray Water Temperature"} onchange={on_water_temperature_change}/>
+        <UnitInput<Temperature> id={"outlet_temperature"} label={"Outlet Temperature"} onchange={on_outlet_temperature_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DesuperheaterOutputProps {
+    result_opt: Option<Result<Desuperheater, ProcessUnitErr>>,
+}
+
+#[function_component(DesuperheaterOutput)]
+fn desuperheater_output(
+    DesuperheaterOutputProps { result_opt }: &DesuperheaterOutputProps,
+) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<MassFlowRate>
+                    id={"spray_water_flow_output"}
+                    label={"Spray Water Flow"}
+                    value={result.spray_water_flow}
+                />
+                <UnitOutput<MassFlowRate>
+                    id={"outlet_flow_output"}
+                    label={"Outlet Flow"}
+                    value={result.outlet_flow}
+                />
+                <PtvEntryOutput entry_opt={Some(Ok(result.outlet.clone()))}/>
+            </>
+        },
+        Some(Err(err)) => html! {
+            <ProcessUnitErrOutput err={err.clone()}/>
+        },
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DesuperheaterFormProps {}
+
+#[function_component(DesuperheaterForm)]
+pub fn desuperheater_form(DesuperheaterFormProps {}: &DesuperheaterFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<DesuperheaterQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<Desuperheater, ProcessUnitErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <DesuperheaterInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_desuperheater(&Iapws97, query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <DesuperheaterOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1,286 @@
+use crate::thermo::steam::expansion::*;
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct ExpansionInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<ExpansionQuery>>,
+}
+
+#[function_component(ExpansionInput)]
+fn expansion_input(ExpansionInputProps { onchange }: &ExpansionInputProps) -> Html {
+    let device_opt = use_state(|| -> Option<ExpansionDevice> { Some(ExpansionDevice::Turbine) });
+    let on_device_change = {
+        let device_opt = device_opt.clone();
+        Callback::from(move |val| {
+            device_opt.set(val);
+        })
+    };
+
+    let inlet_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_inlet_pressure_change = {
+        let inlet_pressure_opt = inlet_pressure_opt.clone();
+        Callback::from(move |val| {
+            inlet_pressure_opt.set(val);
+        })
+    };
+
+    let inlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_inlet_temperature_change = {
+        let inlet_temperature_opt = inlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            inlet_temperature_opt.set(val);
+        })
+    };
+
+    let outlet_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_outlet_pressure_change = {
+        let outlet_pressure_opt = outlet_pressure_opt.clone();
+        Callback::from(move |val| {
+            outlet_pressure_opt.set(val);
+        })
+    };
+
+    let efficiency_opt = use_state(|| -> Option<f64> { None });
+    let on_efficiency_change = {
+        let efficiency_opt = efficiency_opt.clone();
+        Callback::from(move |val| {
+            efficiency_opt.set(val);
+        })
+    };
+
+    {
+        let device_opt = *device_opt;
+        let inlet_pressure_opt = *inlet_pressure_opt;
+        let inlet_temperature_opt = *inlet_temperature_opt;
+        let outlet_pressure_opt = *outlet_pressure_opt;
+        let efficiency_opt = *efficiency_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                device_opt,
+                inlet_pressure_opt,
+                inlet_temperature_opt,
+                outlet_pressure_opt,
+                efficiency_opt,
+            ) {
+                (
+                    Some(device),
+                    Some(pressure),
+                    Some(temperature),
+                    Some(outlet_pressure),
+                    Some(efficiency),
+                ) => Some(ExpansionQuery {
+                    device,
+                    inlet: PtPoint {
+                        pressure,
+                        temperature,
+                    },
+                    outlet_pressure,
+                    efficiency,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SelectInput<ExpansionDevice>
+            id="device"
+            label="Device"
+            onchange={on_device_change}
+            value={*device_opt}
+            options={vec![
+                ExpansionDevice::Turbine,
+                ExpansionDevice::ConvergingNozzle,
+                ExpansionDevice::ConvergingDivergingNozzle,
+            ]}
+        />
+        <UnitInput<Pressure> id={"inlet_pressure"} label={"Inlet Pressure"} onchange={on_inlet_pressure_change}/>
+        <UnitInput<Temperature> id={"inlet_temperature"} label={"Inlet Temperature"} onchange={on_inlet_temperature_change}/>
+        <UnitInput<Pressure> id={"outlet_pressure"} label={"Outlet Pressure"} onchange={on_outlet_pressure_change}/>
+        <NumberInput id={"efficiency"} label={"Isentropic Efficiency"} onchange={on_efficiency_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ExpansionOutputProps {
+    result_opt: Option<Result<Expansion, ExpansionErr>>,
+}
+
+#[function_component(ExpansionOutput)]
+fn expansion_output(ExpansionOutputProps { result_opt }: &ExpansionOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<Pressure>
+                    id={"outlet_pressure_output"}
+                    label={"Outlet Pressure"}
+                    value={result.outlet.pressure}
+                />
+                <UnitOutput<Temperature>
+                    id={"outlet_temperature_output"}
+                    label={"Outlet Temperature"}
+                    value={result.outlet.temperature}
+                />
+                <UnitOutput<EnergyPerMass>
+                    id={"isentropic_enthalpy_output"}
+                    label={"Isentropic Outlet Enthalpy"}
+                    value={result.isentropic_outlet.enthalpy}
+                />
+                <UnitOutput<EnergyPerMass>
+                    id={"outlet_enthalpy_output"}
+                    label={"Outlet Enthalpy"}
+                    value={result.outlet.enthalpy}
+                />
+                <UnitOutput<EnergyPerMassTemperature>
+                    id={"outlet_entropy_output"}
+                    label={"Outlet Entropy"}
+                    value={result.outlet.entropy}
+                />
+                {
+                    match result.outlet_quality {
+                        Some(quality) => html! {
+                            <NumberOutput
+                                id={"outlet_quality_output"}
+                                label={"Outlet Quality"}
+                                value={quality}
+                            />
+                        },
+                        None => html! {},
+                    }
+                }
+                <UnitOutput<EnergyPerMass>
+                    id={"isentropic_work_output"}
+                    label={"Isentropic Work"}
+                    value={result.isentropic_work}
+                />
+                <UnitOutput<EnergyPerMass>
+                    id={"specific_work_output"}
+                    label={"Specific Work"}
+                    value={result.specific_work}
+                />
+                <UnitOutput<Velocity>
+                    id={"exit_velocity_output"}
+                    label={"Exit Velocity"}
+                    value={result.exit_velocity}
+                />
+                {
+                    match result.choking {
+                        Some(choking) => html! {
+                            <>
+                                <NumberOutput
+                                    id={"critical_pressure_ratio_output"}
+                                    label={"Critical Pressure Ratio"}
+                                    value={choking.critical_pressure_ratio}
+                                />
+                                <UnitOutput<Pressure>
+                                    id={"critical_pressure_output"}
+                                    label={"Critical Pressure"}
+                                    value={choking.critical_pressure}
+                                />
+                                <UnitOutput<Velocity>
+                                    id={"throat_velocity_output"}
+                                    label={"Sonic Throat Velocity"}
+                                    value={choking.throat_velocity}
+                                />
+                                <StrOutput
+                                    id={"is_choked_output"}
+                                    label={"Choked Flow"}
+                                    value={if choking.is_choked { "Yes" } else { "No" }}
+                                    output_type={OutputType::Success}
+                                />
+                            </>
+                        },
+                        None => html! {},
+                    }
+                }
+            </>
+        },
+        Some(Err(err)) => {
+            let (label, err_msg) = match err {
+                ExpansionErr::InvalidEfficiency => (
+                    String::from("Efficiency Error"),
+                    String::from("Efficiency must be above 0 and at most 1"),
+                ),
+                ExpansionErr::OutletPressureHigh => (
+                    String::from("Pressure Error"),
+                    String::from("Outlet pressure must be below the inlet pressure"),
+                ),
+                ExpansionErr::SteamQueryErr(steam_err) => get_steam_query_err_message(steam_err),
+                ExpansionErr::FailedToConverge(_) => (
+                    String::from("Convergence Error"),
+                    String::from("Failed to find the critical pressure ratio"),
+                ),
+                ExpansionErr::CriticalPressureNotFound => (
+                    String::from("Convergence Error"),
+                    String::from(
+                        "The mass flux has no maximum between the inlet and zero pressure",
+                    ),
+                ),
+            };
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={label}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ExpansionFormProps {}
+
+#[function_component(ExpansionForm)]
+pub fn expansion_form(ExpansionFormProps {}: &ExpansionFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<ExpansionQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<Expansion, ExpansionErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <ExpansionInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_expansion(&Iapws97, query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <ExpansionOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
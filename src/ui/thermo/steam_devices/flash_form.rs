@@ -0,0 +1,172 @@
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::process_units::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::thermo::steam_devices::process_unit_err_output::*;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct FlashInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<FlashQuery>>,
+}
+
+#[function_component(FlashInput)]
+fn flash_input(FlashInputProps { onchange }: &FlashInputProps) -> Html {
+    let condensate_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_condensate_pressure_change = {
+        let condensate_pressure_opt = condensate_pressure_opt.clone();
+        Callback::from(move |val| {
+            condensate_pressure_opt.set(val);
+        })
+    };
+
+    let condensate_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_condensate_temperature_change = {
+        let condensate_temperature_opt = condensate_temperature_opt.clone();
+        Callback::from(move |val| {
+            condensate_temperature_opt.set(val);
+        })
+    };
+
+    let condensate_flow_opt = use_state(|| -> Option<MassFlowRate> { None });
+    let on_condensate_flow_change = {
+        let condensate_flow_opt = condensate_flow_opt.clone();
+        Callback::from(move |val| {
+            condensate_flow_opt.set(val);
+        })
+    };
+
+    let flash_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_flash_pressure_change = {
+        let flash_pressure_opt = flash_pressure_opt.clone();
+        Callback::from(move |val| {
+            flash_pressure_opt.set(val);
+        })
+    };
+
+    {
+        let condensate_pressure_opt = *condensate_pressure_opt;
+        let condensate_temperature = *condensate_temperature_opt;
+        let condensate_flow_opt = *condensate_flow_opt;
+        let flash_pressure_opt = *flash_pressure_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                condensate_pressure_opt,
+                condensate_flow_opt,
+                flash_pressure_opt,
+            ) {
+                (Some(condensate_pressure), Some(condensate_flow), Some(flash_pressure)) => {
+                    Some(FlashQuery {
+                        condensate_pressure,
+                        condensate_temperature,
+                        condensate_flow,
+                        flash_pressure,
+                    })
+                }
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <UnitInput<Pressure> id={"condensate_pressure"} label={"Condensate Pressure"} onchange={on_condensate_pressure_change}/>
+        <UnitInput<Temperature> id={"condensate_temperature"} label={"Condensate Temperature (Blank if Saturated)"} onchange={on_condensate_temperature_change}/>
+        <UnitInput<MassFlowRate> id={"condensate_flow"} label={"Condensate Flow"} onchange={on_condensate_flow_change}/>
+        <UnitInput<Pressure> id={"flash_pressure"} label={"Flash Vessel Pressure"} onchange={on_flash_pressure_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct FlashOutputProps {
+    result_opt: Option<Result<Flash, ProcessUnitErr>>,
+}
+
+#[function_component(FlashOutput)]
+fn flash_output(FlashOutputProps { result_opt }: &FlashOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <NumberOutput
+                    id={"flash_fraction_output"}
+                    label={"Flash Fraction"}
+                    value={result.flash_fraction}
+                />
+                <UnitOutput<MassFlowRate>
+                    id={"vapor_flow_output"}
+                    label={"Flash Steam Flow"}
+                    value={result.vapor_flow}
+                />
+                <UnitOutput<MassFlowRate>
+                    id={"liquid_flow_output"}
+                    label={"Liquid Flow"}
+                    value={result.liquid_flow}
+                />
+                <UnitOutput<Power>
+                    id={"energy_recovered_output"}
+                    label={"Energy Recovered"}
+                    value={result.energy_recovered}
+                />
+                <UnitOutput<Temperature>
+                    id={"flash_temperature_output"}
+                    label={"Flash Vessel Temperature"}
+                    value={result.flash_steam.temperature}
+                />
+                <UnitOutput<EnergyPerMass>
+                    id={"condensate_enthalpy_output"}
+                    label={"Condensate Enthalpy"}
+                    value={result.condensate.enthalpy}
+                />
+            </>
+        },
+        Some(Err(err)) => html! {
+            <ProcessUnitErrOutput err={err.clone()}/>
+        },
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FlashFormProps {}
+
+#[function_component(FlashForm)]
+pub fn flash_form(FlashFormProps {}: &FlashFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<FlashQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<Flash, ProcessUnitErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <FlashInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_flash(&Iapws97, query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <FlashOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1,133 @@
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::process_units::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::unit_output::*;
+use crate::ui::thermo::steam_devices::process_unit_err_output::*;
+use crate::ui::thermo::steam_devices::steam_stream_input::*;
+use crate::ui::thermo::steam_table::steam_table_form::*;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct MixingInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<MixingQuery>>,
+}
+
+#[function_component(MixingInput)]
+fn mixing_input(MixingInputProps { onchange }: &MixingInputProps) -> Html {
+    let first_stream_opt = use_state(|| -> Option<SteamStream> { None });
+    let on_first_stream_change = {
+        let first_stream_opt = first_stream_opt.clone();
+        Callback::from(move |val| {
+            first_stream_opt.set(val);
+        })
+    };
+
+    let second_stream_opt = use_state(|| -> Option<SteamStream> { None });
+    let on_second_stream_change = {
+        let second_stream_opt = second_stream_opt.clone();
+        Callback::from(move |val| {
+            second_stream_opt.set(val);
+        })
+    };
+
+    let third_stream_opt = use_state(|| -> Option<SteamStream> { None });
+    let on_third_stream_change = {
+        let third_stream_opt = third_stream_opt.clone();
+        Callback::from(move |val| {
+            third_stream_opt.set(val);
+        })
+    };
+
+    {
+        let first_stream_opt = *first_stream_opt;
+        let second_stream_opt = *second_stream_opt;
+        let third_stream_opt = *third_stream_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            // The third stream is only mixed in once all of its fields are filled
+            let query_opt = match (first_stream_opt, second_stream_opt) {
+                (Some(first_stream), Some(second_stream)) => Some(MixingQuery {
+                    streams: [Some(first_stream), Some(second_stream), third_stream_opt]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <SteamStreamInput id={"first_stream"} label={"Stream 1"} onchange={on_first_stream_change}/>
+        <SteamStreamInput id={"second_stream"} label={"Stream 2"} onchange={on_second_stream_change}/>
+        <SteamStreamInput id={"third_stream"} label={"Stream 3 (Optional)"} onchange={on_third_stream_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct MixingOutputProps {
+    result_opt: Option<Result<Mixing, ProcessUnitErr>>,
+}
+
+#[function_component(MixingOutput)]
+fn mixing_output(MixingOutputProps { result_opt }: &MixingOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <>
+                <UnitOutput<MassFlowRate>
+                    id={"outlet_flow_output"}
+                    label={"Outlet Flow"}
+                    value={result.outlet_flow}
+                />
+                <PtvEntryOutput entry_opt={Some(Ok(result.outlet.clone()))}/>
+            </>
+        },
+        Some(Err(err)) => html! {
+            <ProcessUnitErrOutput err={err.clone()}/>
+        },
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MixingFormProps {}
+
+#[function_component(MixingForm)]
+pub fn mixing_form(MixingFormProps {}: &MixingFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<MixingQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<Mixing, ProcessUnitErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <MixingInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = &*query_opt {
+                            let result = get_mixing(&Iapws97, query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <MixingOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -0,0 +1,7 @@
+pub mod desuperheater_form;
+pub mod expansion_form;
+pub mod flash_form;
+pub mod mixing_form;
+pub mod process_unit_err_output;
+pub mod steam_stream_input;
+pub mod throttle_form;
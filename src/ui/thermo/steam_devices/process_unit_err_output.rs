@@ -0,0 +1,59 @@
+use crate::thermo::steam::process_units::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::*;
+use crate::ui::thermo::steam_table::steam_table_form::get_steam_query_err_message;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ProcessUnitErrOutputProps {
+    pub err: ProcessUnitErr,
+}
+
+#[function_component(ProcessUnitErrOutput)]
+pub fn process_unit_err_output(
+    ProcessUnitErrOutputProps { err }: &ProcessUnitErrOutputProps,
+) -> Html {
+    let (label, err_msg) = match err {
+        ProcessUnitErr::OutletPressureHigh => (
+            String::from("Pressure Error"),
+            String::from("Outlet pressure must be below the inlet pressure"),
+        ),
+        ProcessUnitErr::NoStreams => (
+            String::from("Stream Error"),
+            String::from("At least one stream is required"),
+        ),
+        ProcessUnitErr::InvalidMassFlow => (
+            String::from("Mass Flow Error"),
+            String::from("Mass flows must be above 0"),
+        ),
+        ProcessUnitErr::OutletTemperatureHigh => (
+            String::from("Temperature Error"),
+            String::from("Outlet temperature must be below the steam temperature"),
+        ),
+        ProcessUnitErr::OutletNotSuperheated => (
+            String::from("Temperature Error"),
+            String::from("Outlet temperature must be above the saturation temperature"),
+        ),
+        ProcessUnitErr::SprayWaterPressureLow => (
+            String::from("Pressure Error"),
+            String::from("Spray water pressure must be at least the steam pressure"),
+        ),
+        ProcessUnitErr::SprayWaterTooHot => (
+            String::from("Temperature Error"),
+            String::from("Spray water must be colder than the outlet"),
+        ),
+        ProcessUnitErr::CondensateNotLiquid => (
+            String::from("Temperature Error"),
+            String::from("Condensate must be at or below its saturation temperature"),
+        ),
+        ProcessUnitErr::SteamQueryErr(steam_err) => get_steam_query_err_message(steam_err),
+    };
+    html! {
+        <StrOutput
+            id={"error_output"}
+            label={label}
+            value={err_msg}
+            output_type={OutputType::Error}
+        />
+    }
+}
@@ -0,0 +1,77 @@
+use crate::thermo::steam::process_units::*;
+use crate::thermo::steam::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SteamStreamInputProps {
+    // Prefixes the ids so several streams can share a form
+    pub id: String,
+    pub label: String,
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<SteamStream>>,
+}
+
+#[function_component(SteamStreamInput)]
+pub fn steam_stream_input(
+    SteamStreamInputProps {
+        id,
+        label,
+        onchange,
+    }: &SteamStreamInputProps,
+) -> Html {
+    let pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_change = {
+        let pressure_opt = pressure_opt.clone();
+        Callback::from(move |val| {
+            pressure_opt.set(val);
+        })
+    };
+
+    let temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_temperature_change = {
+        let temperature_opt = temperature_opt.clone();
+        Callback::from(move |val| {
+            temperature_opt.set(val);
+        })
+    };
+
+    let mass_flow_opt = use_state(|| -> Option<MassFlowRate> { None });
+    let on_mass_flow_change = {
+        let mass_flow_opt = mass_flow_opt.clone();
+        Callback::from(move |val| {
+            mass_flow_opt.set(val);
+        })
+    };
+
+    {
+        let pressure_opt = *pressure_opt;
+        let temperature_opt = *temperature_opt;
+        let mass_flow_opt = *mass_flow_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let stream_opt = match (pressure_opt, temperature_opt, mass_flow_opt) {
+                (Some(pressure), Some(temperature), Some(mass_flow)) => Some(SteamStream {
+                    state: PtPoint {
+                        pressure,
+                        temperature,
+                    },
+                    mass_flow,
+                }),
+                _ => None,
+            };
+            onchange.emit(stream_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <UnitInput<Pressure> id={format!("{}_pressure", id)} label={format!("{} Pressure", label)} onchange={on_pressure_change}/>
+        <UnitInput<Temperature> id={format!("{}_temperature", id)} label={format!("{} Temperature", label)} onchange={on_temperature_change}/>
+        <UnitInput<MassFlowRate> id={format!("{}_mass_flow", id)} label={format!("{} Mass Flow", label)} onchange={on_mass_flow_change}/>
+    </>
+    }
+}
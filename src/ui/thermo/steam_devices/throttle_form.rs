@@ -0,0 +1,130 @@
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::process_units::*;
+use crate::thermo::steam::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::thermo::steam_devices::process_unit_err_output::*;
+use crate::ui::thermo::steam_table::steam_table_form::*;
+use crate::units::*;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct ThrottleInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<ThrottleQuery>>,
+}
+
+#[function_component(ThrottleInput)]
+fn throttle_input(ThrottleInputProps { onchange }: &ThrottleInputProps) -> Html {
+    let inlet_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_inlet_pressure_change = {
+        let inlet_pressure_opt = inlet_pressure_opt.clone();
+        Callback::from(move |val| {
+            inlet_pressure_opt.set(val);
+        })
+    };
+
+    let inlet_temperature_opt = use_state(|| -> Option<Temperature> { None });
+    let on_inlet_temperature_change = {
+        let inlet_temperature_opt = inlet_temperature_opt.clone();
+        Callback::from(move |val| {
+            inlet_temperature_opt.set(val);
+        })
+    };
+
+    let outlet_pressure_opt = use_state(|| -> Option<Pressure> { None });
+    let on_outlet_pressure_change = {
+        let outlet_pressure_opt = outlet_pressure_opt.clone();
+        Callback::from(move |val| {
+            outlet_pressure_opt.set(val);
+        })
+    };
+
+    {
+        let inlet_pressure_opt = *inlet_pressure_opt;
+        let inlet_temperature_opt = *inlet_temperature_opt;
+        let outlet_pressure_opt = *outlet_pressure_opt;
+        let onchange = onchange.clone();
+
+        use_effect(move || {
+            let query_opt = match (
+                inlet_pressure_opt,
+                inlet_temperature_opt,
+                outlet_pressure_opt,
+            ) {
+                (Some(pressure), Some(temperature), Some(outlet_pressure)) => Some(ThrottleQuery {
+                    inlet: PtPoint {
+                        pressure,
+                        temperature,
+                    },
+                    outlet_pressure,
+                }),
+                _ => None,
+            };
+            onchange.emit(query_opt);
+            || {}
+        });
+    }
+
+    html! {
+    <>
+        <UnitInput<Pressure> id={"inlet_pressure"} label={"Inlet Pressure"} onchange={on_inlet_pressure_change}/>
+        <UnitInput<Temperature> id={"inlet_temperature"} label={"Inlet Temperature"} onchange={on_inlet_temperature_change}/>
+        <UnitInput<Pressure> id={"outlet_pressure"} label={"Outlet Pressure"} onchange={on_outlet_pressure_change}/>
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ThrottleOutputProps {
+    result_opt: Option<Result<Throttle, ProcessUnitErr>>,
+}
+
+#[function_component(ThrottleOutput)]
+fn throttle_output(ThrottleOutputProps { result_opt }: &ThrottleOutputProps) -> Html {
+    match result_opt {
+        Some(Ok(result)) => html! {
+            <PtvEntryOutput entry_opt={Some(Ok(result.outlet.clone()))}/>
+        },
+        Some(Err(err)) => html! {
+            <ProcessUnitErrOutput err={err.clone()}/>
+        },
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ThrottleFormProps {}
+
+#[function_component(ThrottleForm)]
+pub fn throttle_form(ThrottleFormProps {}: &ThrottleFormProps) -> Html {
+    let query_opt = use_state_eq(|| -> Option<ThrottleQuery> { None });
+    let on_query_change = {
+        let query_opt = query_opt.clone();
+        Callback::from(move |val| {
+            query_opt.set(val);
+        })
+    };
+    let result_opt = use_state(|| -> Option<Result<Throttle, ProcessUnitErr>> { None });
+
+    let result_opt_output = (*result_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <ThrottleInput onchange={on_query_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(query) = *query_opt {
+                            let result = get_throttle(&Iapws97, query);
+                            result_opt.set(Some(result));
+                        }
+                    })}/>
+            <CalculationSection>
+                <ThrottleOutput result_opt={result_opt_output}/>
+            </CalculationSection>
+        </CalculationForm>
+    }
+}
@@ -1 +1,2 @@
+pub mod printable_steam_table_form;
 pub mod steam_table_form;
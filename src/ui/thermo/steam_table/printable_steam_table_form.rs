@@ -0,0 +1,492 @@
+use crate::thermo::steam::iapws97::Iapws97;
+use crate::thermo::steam::tables::*;
+use crate::ui::shared::download_link::*;
+use crate::ui::shared::forms::calculation_button_section::*;
+use crate::ui::shared::forms::calculation_form::*;
+use crate::ui::shared::forms::calculation_section::*;
+use crate::ui::shared::forms::number_input::NumberInput;
+use crate::ui::shared::forms::select_input::*;
+use crate::ui::shared::forms::str_output::*;
+use crate::ui::shared::forms::unit_input::*;
+use crate::ui::shared::forms::*;
+use crate::units::*;
+use std::fmt::Display;
+use yew::prelude::*;
+
+const MAX_POINTS: f64 = 500f64;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum UiSteamTableKind {
+    SaturationByTemperature,
+    SaturationByPressure,
+    PressureTemperature,
+}
+
+impl TryFrom<String> for UiSteamTableKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "SaturationByTemperature" => Ok(UiSteamTableKind::SaturationByTemperature),
+            "SaturationByPressure" => Ok(UiSteamTableKind::SaturationByPressure),
+            "PressureTemperature" => Ok(UiSteamTableKind::PressureTemperature),
+            _ => Err(format!("Unknown Table \"{}\"", value)),
+        }
+    }
+}
+
+impl From<UiSteamTableKind> for String {
+    fn from(val: UiSteamTableKind) -> Self {
+        match val {
+            UiSteamTableKind::SaturationByTemperature => "SaturationByTemperature".to_owned(),
+            UiSteamTableKind::SaturationByPressure => "SaturationByPressure".to_owned(),
+            UiSteamTableKind::PressureTemperature => "PressureTemperature".to_owned(),
+        }
+    }
+}
+
+impl Display for UiSteamTableKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UiSteamTableKind::SaturationByTemperature => "Saturation by Temperature",
+                UiSteamTableKind::SaturationByPressure => "Saturation by Pressure",
+                UiSteamTableKind::PressureTemperature => {
+                    "Compressed Liquid and Superheated Vapor"
+                }
+            }
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct SteamTableRequest {
+    kind: UiSteamTableKind,
+    temperatures: Vec<Temperature>,
+    pressures: Vec<Pressure>,
+    units: SteamTableUnits,
+}
+
+fn get_points(points_opt: Option<f64>) -> Option<usize> {
+    match points_opt {
+        Some(x) if (1f64..=MAX_POINTS).contains(&x) => Some(x as usize),
+        _ => None,
+    }
+}
+
+fn get_temperatures(
+    start_opt: Option<Temperature>,
+    end_opt: Option<Temperature>,
+    points_opt: Option<f64>,
+) -> Option<Vec<Temperature>> {
+    match (start_opt, end_opt, get_points(points_opt)) {
+        (Some(start), Some(end), Some(points)) => Some(
+            get_linear_range(
+                start.convert_to_si_unit().value,
+                end.convert_to_si_unit().value,
+                points,
+            )
+            .into_iter()
+            .map(|x| Temperature::K(K::new(x)))
+            .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn get_pressures(
+    start_opt: Option<Pressure>,
+    end_opt: Option<Pressure>,
+    points_opt: Option<f64>,
+) -> Option<Vec<Pressure>> {
+    match (start_opt, end_opt, get_points(points_opt)) {
+        (Some(start), Some(end), Some(points)) => Some(
+            get_linear_range(
+                start.convert_to_si_unit().value,
+                end.convert_to_si_unit().value,
+                points,
+            )
+            .into_iter()
+            .map(|x| Pressure::Pa(Pa::new(x)))
+            .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn get_unit_options<T: Unit>() -> Vec<String> {
+    T::list_unit_labels()
+        .into_iter()
+        .map(|x| x.abbreviation)
+        .collect()
+}
+
+#[derive(Properties, PartialEq)]
+struct PrintableSteamTableInputProps {
+    #[prop_or_else(Callback::noop)]
+    pub onchange: Callback<Option<SteamTableRequest>>,
+}
+
+#[function_component(PrintableSteamTableInput)]
+fn printable_steam_table_input(
+    PrintableSteamTableInputProps { onchange }: &PrintableSteamTableInputProps,
+) -> Html {
+    let kind_opt = use_state(|| Some(UiSteamTableKind::SaturationByTemperature));
+    let on_kind_change = {
+        let kind_opt = kind_opt.clone();
+        Callback::from(move |val| {
+            kind_opt.set(val);
+        })
+    };
+
+    let temperature_start_opt = use_state(|| -> Option<Temperature> { None });
+    let on_temperature_start_change = {
+        let temperature_start_opt = temperature_start_opt.clone();
+        Callback::from(move |val| {
+            temperature_start_opt.set(val);
+        })
+    };
+
+    let temperature_end_opt = use_state(|| -> Option<Temperature> { None });
+    let on_temperature_end_change = {
+        let temperature_end_opt = temperature_end_opt.clone();
+        Callback::from(move |val| {
+            temperature_end_opt.set(val);
+        })
+    };
+
+    let temperature_points_opt = use_state(|| -> Option<f64> { None });
+    let on_temperature_points_change = {
+        let temperature_points_opt = temperature_points_opt.clone();
+        Callback::from(move |val| {
+            temperature_points_opt.set(val);
+        })
+    };
+
+    let pressure_start_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_start_change = {
+        let pressure_start_opt = pressure_start_opt.clone();
+        Callback::from(move |val| {
+            pressure_start_opt.set(val);
+        })
+    };
+
+    let pressure_end_opt = use_state(|| -> Option<Pressure> { None });
+    let on_pressure_end_change = {
+        let pressure_end_opt = pressure_end_opt.clone();
+        Callback::from(move |val| {
+            pressure_end_opt.set(val);
+        })
+    };
+
+    let pressure_points_opt = use_state(|| -> Option<f64> { None });
+    let on_pressure_points_change = {
+        let pressure_points_opt = pressure_points_opt.clone();
+        Callback::from(move |val| {
+            pressure_points_opt.set(val);
+        })
+    };
+
+    let units = use_state(SteamTableUnits::default);
+    let on_unit_change = |set_unit: fn(&mut SteamTableUnits, String)| {
+        let units = units.clone();
+        Callback::from(move |val: Option<String>| {
+            if let Some(unit) = val {
+                let mut new_units = (*units).clone();
+                set_unit(&mut new_units, unit);
+                units.set(new_units);
+            }
+        })
+    };
+
+    {
+        let kind_opt = *kind_opt;
+        let temperatures_opt = get_temperatures(
+            *temperature_start_opt,
+            *temperature_end_opt,
+            *temperature_points_opt,
+        );
+        let pressures_opt =
+            get_pressures(*pressure_start_opt, *pressure_end_opt, *pressure_points_opt);
+        let units = (*units).clone();
+        let onchange = onchange.clone();
+        use_effect(move || {
+            let request_opt = match (kind_opt, temperatures_opt, pressures_opt) {
+                (Some(UiSteamTableKind::SaturationByTemperature), Some(temperatures), _) => Some((
+                    UiSteamTableKind::SaturationByTemperature,
+                    temperatures,
+                    vec![],
+                )),
+                (Some(UiSteamTableKind::SaturationByPressure), _, Some(pressures)) => {
+                    Some((UiSteamTableKind::SaturationByPressure, vec![], pressures))
+                }
+                (
+                    Some(UiSteamTableKind::PressureTemperature),
+                    Some(temperatures),
+                    Some(pressures),
+                ) => Some((
+                    UiSteamTableKind::PressureTemperature,
+                    temperatures,
+                    pressures,
+                )),
+                _ => None,
+            };
+            onchange.emit(
+                request_opt.map(|(kind, temperatures, pressures)| SteamTableRequest {
+                    kind,
+                    temperatures,
+                    pressures,
+                    units,
+                }),
+            );
+            || {}
+        });
+    }
+
+    let show_temperature = *kind_opt != Some(UiSteamTableKind::SaturationByPressure);
+    let show_pressure = *kind_opt != Some(UiSteamTableKind::SaturationByTemperature);
+
+    html! {
+    <>
+        <SelectInput<UiSteamTableKind>
+            id="table_kind"
+            label="Table"
+            onchange={on_kind_change}
+            value={*kind_opt}
+            options={vec![
+                UiSteamTableKind::SaturationByTemperature,
+                UiSteamTableKind::SaturationByPressure,
+                UiSteamTableKind::PressureTemperature,
+                ]}
+        />
+        {
+            if show_temperature {
+                html! {
+                    <>
+        <UnitInput<Temperature> id={"temperature_start"} label={"Start Temperature"} onchange={on_temperature_start_change}/>
+        <UnitInput<Temperature> id={"temperature_end"} label={"End Temperature"} onchange={on_temperature_end_change}/>
+        <NumberInput id={"temperature_points"} label={"Temperature Points"} onchange={on_temperature_points_change}/>
+                    </>
+                }
+            } else {
+                html! {}
+            }
+        }
+        {
+            if show_pressure {
+                html! {
+                    <>
+        <UnitInput<Pressure> id={"pressure_start"} label={"Start Pressure"} onchange={on_pressure_start_change}/>
+        <UnitInput<Pressure> id={"pressure_end"} label={"End Pressure"} onchange={on_pressure_end_change}/>
+        <NumberInput id={"pressure_points"} label={"Pressure Points"} onchange={on_pressure_points_change}/>
+                    </>
+                }
+            } else {
+                html! {}
+            }
+        }
+        <SelectInput<String>
+            id="temperature_unit"
+            label="Temperature Unit"
+            onchange={on_unit_change(|x, unit| x.temperature = unit)}
+            value={Some(units.temperature.clone())}
+            options={get_unit_options::<Temperature>()}
+        />
+        <SelectInput<String>
+            id="pressure_unit"
+            label="Pressure Unit"
+            onchange={on_unit_change(|x, unit| x.pressure = unit)}
+            value={Some(units.pressure.clone())}
+            options={get_unit_options::<Pressure>()}
+        />
+        <SelectInput<String>
+            id="specific_volume_unit"
+            label="Specific Volume Unit"
+            onchange={on_unit_change(|x, unit| x.specific_volume = unit)}
+            value={Some(units.specific_volume.clone())}
+            options={get_unit_options::<SpecificVolume>()}
+        />
+        <SelectInput<String>
+            id="energy_per_mass_unit"
+            label="Energy Unit"
+            onchange={on_unit_change(|x, unit| x.energy_per_mass = unit)}
+            value={Some(units.energy_per_mass.clone())}
+            options={get_unit_options::<EnergyPerMass>()}
+        />
+        <SelectInput<String>
+            id="entropy_unit"
+            label="Entropy Unit"
+            onchange={on_unit_change(|x, unit| x.entropy = unit)}
+            value={Some(units.entropy.clone())}
+            options={get_unit_options::<EnergyPerMassTemperature>()}
+        />
+    </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SteamTableDownloadProps {
+    table: SteamTable,
+}
+
+#[function_component(SteamTableDownload)]
+fn steam_table_download(SteamTableDownloadProps { table }: &SteamTableDownloadProps) -> Html {
+    let downloads = [
+        ("CSV", "steam_table.csv", "text/csv", table.to_csv()),
+        (
+            "Markdown",
+            "steam_table.md",
+            "text/markdown",
+            table.to_markdown(),
+        ),
+        ("HTML", "steam_table.html", "text/html", table.to_html()),
+    ];
+    html! {
+        <div class={classes!("flex", "gap-4")}>
+        {
+            downloads.into_iter().map(|(label, file_name, mime_type, text)| {
+                html! {
+                    <DownloadLink
+                        label={label}
+                        file_name={file_name}
+                        mime_type={mime_type}
+                        text={text}
+                    />
+                }
+            }).collect::<Html>()
+        }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SteamTableViewProps {
+    pub table: SteamTable,
+}
+
+#[function_component(SteamTableView)]
+pub fn steam_table_view(SteamTableViewProps { table }: &SteamTableViewProps) -> Html {
+    html! {
+        <div class={classes!("w-full", "overflow-x-auto")}>
+            <table class={classes!("text-right", "[&_td]:px-2", "[&_th]:px-2")}>
+                <thead>
+                    <tr class={classes!("bg-sky-100")}>
+                    {
+                        table.headers.iter().map(|x| html! {
+                            <th>{x}</th>
+                        }).collect::<Html>()
+                    }
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    table.rows.iter().map(|row| html! {
+                        <tr class={classes!("even:bg-gray-100")}>
+                        {
+                            row.iter().map(|x| html! {
+                                <td>{x}</td>
+                            }).collect::<Html>()
+                        }
+                        </tr>
+                    }).collect::<Html>()
+                }
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SteamTableOutputProps {
+    table_opt: Option<Result<SteamTable, SteamTableErr>>,
+}
+
+#[function_component(SteamTableOutput)]
+fn steam_table_output(SteamTableOutputProps { table_opt }: &SteamTableOutputProps) -> Html {
+    match table_opt {
+        Some(Ok(table)) => html! {
+            <>
+                <SteamTableDownload table={table.clone()}/>
+                <SteamTableView table={table.clone()}/>
+            </>
+        },
+        Some(Err(err)) => {
+            let err_msg = match err {
+                SteamTableErr::EmptyRange => "Range is Empty".to_owned(),
+                SteamTableErr::ParseUnitError(ParseUnitError::UnknownUnit(unit)) => {
+                    format!("Unknown Unit \"{}\"", unit)
+                }
+            };
+            html! {
+                <StrOutput
+                    id={"error_output"}
+                    label={"Table Error"}
+                    value={err_msg}
+                    output_type={OutputType::Error}
+                />
+            }
+        }
+        None => html! {},
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PrintableSteamTableFormProps {}
+
+#[function_component(PrintableSteamTableForm)]
+pub fn printable_steam_table_form(
+    PrintableSteamTableFormProps {}: &PrintableSteamTableFormProps,
+) -> Html {
+    let request_opt = use_state_eq(|| -> Option<SteamTableRequest> { None });
+    let on_request_change = {
+        let request_opt = request_opt.clone();
+        Callback::from(move |val| {
+            request_opt.set(val);
+        })
+    };
+    let table_opt = use_state(|| -> Option<Result<SteamTable, SteamTableErr>> { None });
+
+    let table_opt_output = (*table_opt).clone();
+
+    html! {
+        <CalculationForm>
+            <CalculationSection>
+                <PrintableSteamTableInput onchange={on_request_change}/>
+            </CalculationSection>
+            <CalculationButtonSection on_click={Callback::from(move |_: Event| {
+                        if let Some(request) = &*request_opt {
+                            let result = match request.kind {
+                                UiSteamTableKind::SaturationByTemperature => {
+                                    get_saturation_table_by_temperature(
+                                        &Iapws97,
+                                        &request.temperatures,
+                                        &request.units,
+                                    )
+                                }
+                                UiSteamTableKind::SaturationByPressure => {
+                                    get_saturation_table_by_pressure(
+                                        &Iapws97,
+                                        &request.pressures,
+                                        &request.units,
+                                    )
+                                }
+                                UiSteamTableKind::PressureTemperature => {
+                                    get_pressure_temperature_table(
+                                        &Iapws97,
+                                        &request.pressures,
+                                        &request.temperatures,
+                                        &request.units,
+                                    )
+                                }
+                            };
+                            table_opt.set(Some(result));
+                        }
+                    })}/>
+            <section class={classes!("w-full", "grid", "gap-4")}>
+                <SteamTableOutput table_opt={table_opt_output}/>
+            </section>
+        </CalculationForm>
+    }
+}
@@ -3,26 +3,80 @@ use std::fmt::Display;
 use super::super::super::shared::forms::calculation_button_section::*;
 use super::super::super::shared::forms::calculation_form::*;
 use super::super::super::shared::forms::calculation_section::*;
+use super::super::super::shared::forms::number_output::*;
 use super::super::super::shared::forms::select_input::*;
 use super::super::super::shared::forms::str_output::*;
 use super::super::super::shared::forms::unit_input::*;
 use super::super::super::shared::forms::unit_output::*;
 use super::super::super::shared::forms::*;
 use crate::numerical_methods::*;
+use crate::thermo::steam::property_diagrams::*;
 use crate::thermo::steam::*;
 use crate::thermo::*;
+use crate::ui::shared::diagram_output::*;
 use crate::ui::thermo::steam_table::steam_table_form::iapws97::get_steam_table_entry;
 use crate::units::EnergyPerMass;
 use crate::units::*;
 use yew::prelude::*;
 
+/// Label and message shown for a failed steam query
+pub fn get_steam_query_err_message(err: &SteamQueryErr) -> (String, String) {
+    match err {
+        SteamQueryErr::OutOfRange(range_err) => {
+            let range_err_msg = match range_err {
+                OutOfRange::AboveCriticalPressure => "Above Critical Pressure",
+                OutOfRange::AboveCriticalTemperature => "Above Critical Temperature",
+                OutOfRange::BelowCriticalTemperature => "Below Critical Temperature",
+                OutOfRange::PressureHigh => "Pressure is High",
+                OutOfRange::PressureLow => "Pressure is Low",
+                OutOfRange::TemperatureHigh => "Temperature is High",
+                OutOfRange::TemperatureLow => "Temperature is Low",
+                OutOfRange::DensityHigh => "Density is High",
+                OutOfRange::DensityLow => "Density is Low",
+                OutOfRange::WavelengthHigh => "Wavelength is High",
+                OutOfRange::WavelengthLow => "Wavelength is Low",
+            };
+            (
+                String::from("Out of Range Error"),
+                String::from(range_err_msg),
+            )
+        }
+        SteamQueryErr::CompositePhaseRegionErr(composite_err) => {
+            let composite_err_msg = match composite_err {
+                CompositePhaseRegionErr::FractionsDoNotAddUpToOne => {
+                    "Fractions Do Not Add Up To One"
+                }
+                CompositePhaseRegionErr::FractionsMustBePositive => "Fractions Must Sum to 1",
+                CompositePhaseRegionErr::CompositionLengthMismatch => {
+                    "Phase Compositions Have Different Lengths"
+                }
+            };
+            (
+                String::from("Phase Composition Error"),
+                String::from(composite_err_msg),
+            )
+        }
+        SteamQueryErr::FailedToConverge(converge_err) => {
+            let converge_err_msg = match converge_err {
+                RootFinderErr::ToleranceBelowZero => "Tolerance Below Zero",
+                RootFinderErr::MaxIterationsReached => "Max Iterations Reached",
+                RootFinderErr::SingularMatrix => "Singular Matrix",
+            };
+            (
+                String::from("Converge Error"),
+                String::from(converge_err_msg),
+            )
+        }
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct PtvEntryOutputProps {
-    entry_opt: Option<Result<PtvEntry, SteamQueryErr>>,
+    pub entry_opt: Option<Result<PtvEntry, SteamQueryErr>>,
 }
 
 #[function_component(PtvEntryOutput)]
-fn ptv_entry_output(PtvEntryOutputProps { entry_opt }: &PtvEntryOutputProps) -> Html {
+pub fn ptv_entry_output(PtvEntryOutputProps { entry_opt }: &PtvEntryOutputProps) -> Html {
     match entry_opt {
         Some(Ok(entry)) => {
             html! {
@@ -78,51 +132,46 @@ fn ptv_entry_output(PtvEntryOutputProps { entry_opt }: &PtvEntryOutputProps) ->
                         label={"Specific Volume"}
                         value={entry.specific_volume}
                     />
+                    <UnitOutput<Density>
+                        id={"density_output"}
+                        label={"Density"}
+                        value={entry.density}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"gibbs_free_energy_output"}
+                        label={"Gibbs Free Energy"}
+                        value={entry.gibbs_free_energy}
+                    />
+                    <UnitOutput<EnergyPerMass>
+                        id={"helmholtz_free_energy_output"}
+                        label={"Helmholtz Free Energy"}
+                        value={entry.helmholtz_free_energy}
+                    />
+                    <UnitOutput<InverseTemperature>
+                        id={"isobaric_expansion_output"}
+                        label={"Isobaric Expansion Coefficient"}
+                        value={entry.isobaric_expansion}
+                    />
+                    <UnitOutput<InversePressure>
+                        id={"isothermal_compressibility_output"}
+                        label={"Isothermal Compressibility"}
+                        value={entry.isothermal_compressibility}
+                    />
+                    <UnitOutput<TemperaturePerPressure>
+                        id={"joule_thomson_output"}
+                        label={"Joule-Thomson Coefficient"}
+                        value={entry.joule_thomson}
+                    />
+                    <NumberOutput
+                        id={"isentropic_exponent_output"}
+                        label={"Isentropic Exponent"}
+                        value={entry.isentropic_exponent}
+                    />
                 </>
             }
         }
         Some(Err(err)) => {
-            let (label, err_msg) = match err {
-                SteamQueryErr::OutOfRange(range_err) => {
-                    let range_err_msg = match range_err {
-                        OutOfRange::AboveCriticalPressure => "Above Critical Pressure",
-                        OutOfRange::AboveCriticalTemperature => "Above Critical Temperature",
-                        OutOfRange::BelowCriticalTemperature => "Below Critical Temperature",
-                        OutOfRange::PressureHigh => "Pressure is High",
-                        OutOfRange::PressureLow => "Pressure is Low",
-                        OutOfRange::TemperatureHigh => "Temperature is High",
-                        OutOfRange::TemperatureLow => "Temperature is Low",
-                    };
-                    (
-                        String::from("Out of Range Error"),
-                        String::from(range_err_msg),
-                    )
-                }
-                SteamQueryErr::CompositePhaseRegionErr(composite_err) => {
-                    let composite_err_msg = match composite_err {
-                        CompositePhaseRegionErr::FractionsDoNotAddUpToOne => {
-                            "Fractions Do Not Add Up To One"
-                        }
-                        CompositePhaseRegionErr::FractionsMustBePositive => {
-                            "Fractions Must Sum to 1"
-                        }
-                    };
-                    (
-                        String::from("Phase Composition Error"),
-                        String::from(composite_err_msg),
-                    )
-                }
-                SteamQueryErr::FailedToConverge(converge_err) => {
-                    let converge_err_msg = match converge_err {
-                        RootFinderErr::ToleranceBelowZero => "Tolerance Below Zero",
-                        RootFinderErr::MaxIterationsReached => "Max Iterations Reached",
-                    };
-                    (
-                        String::from("Converge Error"),
-                        String::from(converge_err_msg),
-                    )
-                }
-            };
+            let (label, err_msg) = get_steam_query_err_message(err);
             html! {
                 <StrOutput
                     id={"error_output"}
@@ -189,13 +238,13 @@ impl Display for UiSteamQuery {
 }
 
 #[derive(Properties, PartialEq)]
-struct SteamTableInputProps {
+pub struct SteamTableInputProps {
     #[prop_or_else(Callback::noop)]
     pub onchange: Callback<Option<SteamQuery>>,
 }
 
 #[function_component(SteamTableInput)]
-fn steam_table_input(SteamTableInputProps { onchange }: &SteamTableInputProps) -> Html {
+pub fn steam_table_input(SteamTableInputProps { onchange }: &SteamTableInputProps) -> Html {
     let query_type_opt = use_state(|| -> Option<UiSteamQuery> { Some(UiSteamQuery::Pt) });
     let on_query_type_change = {
         let query_type_opt = query_type_opt.clone();
@@ -402,6 +451,7 @@ fn steam_table_input(SteamTableInputProps { onchange }: &SteamTableInputProps) -
             options={vec![
                     SteamNonCriticalPhaseRegion::Liquid,
                     SteamNonCriticalPhaseRegion::Vapor,
+                    SteamNonCriticalPhaseRegion::Solid,
                 ]}
         />
                     }
@@ -420,6 +470,45 @@ fn steam_table_input(SteamTableInputProps { onchange }: &SteamTableInputProps) -
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct PropertyDiagramOutputProps {
+    entry: PtvEntry,
+}
+
+#[function_component(PropertyDiagramOutput)]
+fn property_diagram_output(
+    PropertyDiagramOutputProps { entry }: &PropertyDiagramOutputProps,
+) -> Html {
+    let kind_opt = use_state(|| Some(PropertyDiagramKind::TemperatureEntropy));
+    let on_kind_change = {
+        let kind_opt = kind_opt.clone();
+        Callback::from(move |val| {
+            kind_opt.set(val);
+        })
+    };
+    let kind = (*kind_opt).unwrap_or(PropertyDiagramKind::TemperatureEntropy);
+    html! {
+        <>
+            <SelectInput<PropertyDiagramKind>
+                id={"diagram_kind"}
+                label={"Diagram"}
+                onchange={on_kind_change}
+                value={*kind_opt}
+                options={vec![
+                    PropertyDiagramKind::TemperatureEntropy,
+                    PropertyDiagramKind::EnthalpyEntropy,
+                    PropertyDiagramKind::PressureEnthalpy,
+                    PropertyDiagramKind::PressureVolume,
+                ]}
+            />
+            <DiagramOutput
+                diagram={get_property_diagram(kind, Some(entry))}
+                file_name={String::from(kind)}
+            />
+        </>
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct SteamTableFormProps {}
 
@@ -449,8 +538,18 @@ pub fn steam_table_form(SteamTableFormProps {}: &SteamTableFormProps) -> Html {
 
                     })}/>
             <CalculationSection>
-                <PtvEntryOutput entry_opt={entry_opt_output}/>
+                <PtvEntryOutput entry_opt={entry_opt_output.clone()}/>
             </CalculationSection>
+            {
+                match entry_opt_output {
+                    Some(Ok(entry)) => html! {
+                        <CalculationSection>
+                            <PropertyDiagramOutput entry={entry}/>
+                        </CalculationSection>
+                    },
+                    _ => html! {},
+                }
+            }
         </CalculationForm>
     }
 }
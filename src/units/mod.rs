@@ -441,6 +441,198 @@ units! {
             |x| x / (35.3147 / 2.20462),
         }
     }
+    SurfaceTension {
+        NPerM {
+            "N/m",
+            "newtons per meter",
+        },
+        DynPerCm {
+            "dyn/cm",
+            "dynes per centimeter",
+            |x| x / 1000f64,
+            |x| x * 1000f64,
+        },
+        LbfPerFt {
+            "lbf/ft",
+            "pounds-force per foot",
+            |x| x * 14.5939,
+            |x| x / 14.5939,
+        }
+    }
+    InverseTemperature {
+        PerK {
+            "1/K",
+            "per kelvin",
+        },
+        PerR {
+            "1/°R",
+            "per degree rankine",
+            |x| x * 9f64 / 5f64,
+            |x| x * 5f64 / 9f64,
+        }
+    }
+    InversePressure {
+        PerPa {
+            "1/Pa",
+            "per pascal",
+        },
+        PerKPa {
+            "1/kPa",
+            "per kilopascal",
+            |x| x / 1000f64,
+            |x| x * 1000f64,
+        },
+        PerLbf {
+            "1/(lbf/in²)",
+            "per pound-force per square inch",
+            |x| x / 6894.76,
+            |x| x * 6894.76,
+        }
+    }
+    TemperaturePerPressure {
+        KPerPa {
+            "K/Pa",
+            "kelvin per pascal",
+        },
+        KPerKPa {
+            "K/kPa",
+            "kelvin per kilopascal",
+            |x| x / 1000f64,
+            |x| x * 1000f64,
+        },
+        RPerLbf {
+            "°R/(lbf/in²)",
+            "degrees rankine per pound-force per square inch",
+            |x| x * 5f64 / (9f64 * 6894.76),
+            |x| x * (9f64 * 6894.76) / 5f64,
+        }
+    }
+    MassPerEnergy {
+        KgPerJ {
+            "kg/J",
+            "kilograms per joule",
+        },
+        KgPerKWh {
+            "kg/(kW · h)",
+            "kilograms per kilowatt hour",
+            |x| x / 3.6e6,
+            |x| x * 3.6e6,
+        },
+        LbsmPerKWh {
+            "Lbsₘ/(kW · h)",
+            "pounds mass per kilowatt hour",
+            |x| x / (2.20462 * 3.6e6),
+            |x| x * (2.20462 * 3.6e6),
+        }
+    }
+    MassFlowRate {
+        KgPerSec {
+            "kg/s",
+            "kilograms per second",
+        },
+        KgPerHr {
+            "kg/h",
+            "kilograms per hour",
+            |x| x / 3600f64,
+            |x| x * 3600f64,
+        },
+        LbsmPerHr {
+            "Lbsₘ/h",
+            "pounds mass per hour",
+            |x| x / (2.20462 * 3600f64),
+            |x| x * (2.20462 * 3600f64),
+        }
+    }
+    Power {
+        W {
+            "W",
+            "watts",
+        },
+        KW {
+            "kW",
+            "kilowatts",
+            |x| x * 1000f64,
+            |x| x / 1000f64,
+        },
+        BtuPerHr {
+            "Btu/h",
+            "british thermal units per hour",
+            |x| x / 3.41214,
+            |x| x * 3.41214,
+        },
+        Hp {
+            "hp",
+            "horsepower",
+            |x| x * 745.69987,
+            |x| x / 745.69987,
+        }
+    }
+    Head {
+        MOfFluid {
+            "m",
+            "meters of fluid",
+        },
+        FtOfFluid {
+            "ft",
+            "feet of fluid",
+            |x| x / 3.28084,
+            |x| x * 3.28084,
+        }
+    }
+    PowerPerTemperature {
+        WPerK {
+            "W/K",
+            "watts per kelvin",
+        },
+        BtuPerHrF {
+            "Btu/(h · °F)",
+            "british thermal units per hour degree fahrenheit",
+            |x| x * 9f64 / (5f64 * 3.41214),
+            |x| x * (5f64 * 3.41214) / 9f64,
+        }
+    }
+    PowerPerAreaTemperature {
+        WPerM2K {
+            "W/(m² · K)",
+            "watts per square meter kelvin",
+        },
+        BtuPerHrFt2F {
+            "Btu/(h · ft² · °F)",
+            "british thermal units per hour square foot degree fahrenheit",
+            |x| x * 9f64 * 3.28084 * 3.28084 / (5f64 * 3.41214),
+            |x| x * (5f64 * 3.41214) / (9f64 * 3.28084 * 3.28084),
+        }
+    }
+    DynamicViscosity {
+        PaS {
+            "Pa · s",
+            "pascal seconds",
+        },
+        CentiPoise {
+            "cP",
+            "centipoise",
+            |x| x / 1000f64,
+            |x| x * 1000f64,
+        },
+        LbsmPerFtHr {
+            "Lbsₘ/(ft · h)",
+            "pounds mass per foot hour",
+            |x| x * 3.28084 / (2.20462 * 3600f64),
+            |x| x * (2.20462 * 3600f64) / 3.28084,
+        }
+    }
+    ThermalConductivity {
+        WPerMK {
+            "W/(m · K)",
+            "watts per meter kelvin",
+        },
+        BtuPerHrFtF {
+            "Btu/(h · ft · °F)",
+            "british thermal units per hour foot degree fahrenheit",
+            |x| x * 9f64 * 3.28084 / (5f64 * 3.41214),
+            |x| x * (5f64 * 3.41214) / (9f64 * 3.28084),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -615,4 +807,174 @@ mod tests {
             Density::KgPerM3(KgPerM3::new(35.3147 / 2.20462))
         );
     }
+
+    #[test]
+    fn surface_tension_conversion() {
+        assert_approx_eq!(
+            SurfaceTension::NPerM(NPerM::new(1f64)).convert_to_si_unit(),
+            NPerM::new(1f64)
+        );
+        assert_approx_eq!(
+            SurfaceTension::DynPerCm(DynPerCm::new(1f64)),
+            SurfaceTension::NPerM(NPerM::new(1e-3))
+        );
+        assert_approx_eq!(
+            SurfaceTension::LbfPerFt(LbfPerFt::new(1f64)),
+            SurfaceTension::NPerM(NPerM::new(14.5939))
+        );
+    }
+
+    #[test]
+    fn inverse_temperature_conversion() {
+        assert_approx_eq!(
+            InverseTemperature::PerK(PerK::new(1f64)).convert_to_si_unit(),
+            PerK::new(1f64)
+        );
+        assert_approx_eq!(
+            InverseTemperature::PerR(PerR::new(1f64)),
+            InverseTemperature::PerK(PerK::new(1.8))
+        );
+    }
+
+    #[test]
+    fn inverse_pressure_conversion() {
+        assert_approx_eq!(
+            InversePressure::PerPa(PerPa::new(1f64)).convert_to_si_unit(),
+            PerPa::new(1f64)
+        );
+        assert_approx_eq!(
+            InversePressure::PerKPa(PerKPa::new(1f64)),
+            InversePressure::PerPa(PerPa::new(1e-3))
+        );
+        assert_approx_eq!(
+            InversePressure::PerLbf(PerLbf::new(1f64)),
+            InversePressure::PerPa(PerPa::new(1f64 / 6894.76))
+        );
+    }
+
+    #[test]
+    fn temperature_per_pressure_conversion() {
+        assert_approx_eq!(
+            TemperaturePerPressure::KPerPa(KPerPa::new(1f64)).convert_to_si_unit(),
+            KPerPa::new(1f64)
+        );
+        assert_approx_eq!(
+            TemperaturePerPressure::KPerKPa(KPerKPa::new(1f64)),
+            TemperaturePerPressure::KPerPa(KPerPa::new(1e-3))
+        );
+        assert_approx_eq!(
+            TemperaturePerPressure::RPerLbf(RPerLbf::new(1f64)),
+            TemperaturePerPressure::KPerPa(KPerPa::new(5f64 / (9f64 * 6894.76)))
+        );
+    }
+
+    #[test]
+    fn mass_per_energy_conversion() {
+        assert_approx_eq!(
+            MassPerEnergy::KgPerJ(KgPerJ::new(1f64)).convert_to_si_unit(),
+            KgPerJ::new(1f64)
+        );
+        assert_approx_eq!(
+            MassPerEnergy::KgPerKWh(KgPerKWh::new(3.6e6)),
+            MassPerEnergy::KgPerJ(KgPerJ::new(1f64))
+        );
+        assert_approx_eq!(
+            MassPerEnergy::LbsmPerKWh(LbsmPerKWh::new(2.20462 * 3.6e6)),
+            MassPerEnergy::KgPerJ(KgPerJ::new(1f64))
+        );
+    }
+
+    #[test]
+    fn mass_flow_rate_conversion() {
+        assert_approx_eq!(
+            MassFlowRate::KgPerSec(KgPerSec::new(1f64)).convert_to_si_unit(),
+            KgPerSec::new(1f64)
+        );
+        assert_approx_eq!(
+            MassFlowRate::KgPerHr(KgPerHr::new(3600f64)),
+            MassFlowRate::KgPerSec(KgPerSec::new(1f64))
+        );
+        assert_approx_eq!(
+            MassFlowRate::LbsmPerHr(LbsmPerHr::new(2.20462 * 3600f64)),
+            MassFlowRate::KgPerSec(KgPerSec::new(1f64))
+        );
+    }
+
+    #[test]
+    fn power_conversion() {
+        assert_approx_eq!(Power::W(W::new(1f64)).convert_to_si_unit(), W::new(1f64));
+        assert_approx_eq!(Power::KW(KW::new(1f64)), Power::W(W::new(1000f64)));
+        assert_approx_eq!(
+            Power::BtuPerHr(BtuPerHr::new(3.41214)),
+            Power::W(W::new(1f64))
+        );
+        assert_approx_eq!(Power::Hp(Hp::new(1f64)), Power::W(W::new(745.69987)));
+    }
+
+    #[test]
+    fn head_conversion() {
+        assert_approx_eq!(
+            Head::MOfFluid(MOfFluid::new(1f64)).convert_to_si_unit(),
+            MOfFluid::new(1f64)
+        );
+        assert_approx_eq!(
+            Head::FtOfFluid(FtOfFluid::new(3.28084)),
+            Head::MOfFluid(MOfFluid::new(1f64))
+        );
+    }
+    #[test]
+    fn power_per_temperature_conversion() {
+        assert_approx_eq!(
+            PowerPerTemperature::WPerK(WPerK::new(1f64)).convert_to_si_unit(),
+            WPerK::new(1f64)
+        );
+        assert_approx_eq!(
+            PowerPerTemperature::BtuPerHrF(BtuPerHrF::new(1f64)),
+            PowerPerTemperature::WPerK(WPerK::new(0.527527)),
+            1e-5
+        );
+    }
+
+    #[test]
+    fn power_per_area_temperature_conversion() {
+        assert_approx_eq!(
+            PowerPerAreaTemperature::WPerM2K(WPerM2K::new(1f64)).convert_to_si_unit(),
+            WPerM2K::new(1f64)
+        );
+        assert_approx_eq!(
+            PowerPerAreaTemperature::BtuPerHrFt2F(BtuPerHrFt2F::new(1f64)),
+            PowerPerAreaTemperature::WPerM2K(WPerM2K::new(5.678263)),
+            1e-5
+        );
+    }
+
+    #[test]
+    fn dynamic_viscosity_conversion() {
+        assert_approx_eq!(
+            DynamicViscosity::PaS(PaS::new(1f64)).convert_to_si_unit(),
+            PaS::new(1f64)
+        );
+        assert_approx_eq!(
+            DynamicViscosity::CentiPoise(CentiPoise::new(1000f64)),
+            DynamicViscosity::PaS(PaS::new(1f64))
+        );
+        assert_approx_eq!(
+            DynamicViscosity::LbsmPerFtHr(LbsmPerFtHr::new(2419.09)),
+            DynamicViscosity::PaS(PaS::new(1f64)),
+            1e-5
+        );
+    }
+
+    #[test]
+    fn thermal_conductivity_conversion() {
+        assert_approx_eq!(
+            ThermalConductivity::WPerMK(WPerMK::new(1f64)).convert_to_si_unit(),
+            WPerMK::new(1f64)
+        );
+        assert_approx_eq!(
+            ThermalConductivity::BtuPerHrFtF(BtuPerHrFtF::new(1f64)),
+            ThermalConductivity::WPerMK(WPerMK::new(1.730735)),
+            1e-5
+        );
+    }
 }